@@ -0,0 +1,212 @@
+//! Contains the [proptest](https://docs.rs/proptest) strategies that generate arbitrary Desmos values,
+//! to write property tests over the contracts that interact with the Desmos modules.
+//!
+//! Every model, query response and message of the enabled modules implements [`Arbitrary`],
+//! so that they can be generated with [`any`]. The generated values respect the invariants checked by the chain
+//! (e.g. the addresses are valid bech32 addresses and the ids are never zero), while the
+//! strategies exposed by this module can be used to build custom values.
+//!
+//! # Example
+//! ```
+//! use desmos_bindings::posts::{models::PostAttachment, msg::PostsMsg};
+//! use proptest::prelude::*;
+//! use std::convert::TryFrom;
+//!
+//! proptest! {
+//!     fn attachments_are_well_formed(msg in any::<PostsMsg>()) {
+//!         if let PostsMsg::AddPostAttachment { content, .. } = msg {
+//!             prop_assert!(PostAttachment::try_from(content).is_ok());
+//!         }
+//!     }
+//! }
+//! # attachments_are_well_formed();
+//! ```
+
+use crate::types::{Height, PageRequest, PageResponse, PubKey};
+use bech32::{ToBase32, Variant};
+use cosmwasm_std::{Addr, Binary, Uint64};
+use proptest::prelude::*;
+
+/// Bech32 prefix of the Desmos addresses.
+pub const DESMOS_BECH32_PREFIX: &str = "desmos";
+
+/// Implements [`Arbitrary`] for the given types, using the provided strategies.
+macro_rules! impl_arbitrary {
+    ($($ty:ty => $strategy:expr;)*) => {
+        $(
+            impl proptest::arbitrary::Arbitrary for $ty {
+                type Parameters = ();
+                type Strategy = proptest::strategy::BoxedStrategy<Self>;
+
+                fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+                    proptest::strategy::Strategy::boxed($strategy)
+                }
+            }
+        )*
+    };
+}
+
+#[allow(unused_imports)]
+pub(crate) use impl_arbitrary;
+
+/// Returns a strategy generating valid bech32 addresses having the given prefix.
+pub fn bech32_address(prefix: &'static str) -> impl Strategy<Value = String> {
+    proptest::array::uniform20(any::<u8>()).prop_map(move |bytes| {
+        bech32::encode(prefix, bytes.to_base32(), Variant::Bech32)
+            .expect("the prefix must be a valid bech32 prefix")
+    })
+}
+
+/// Returns a strategy generating valid Desmos addresses.
+pub fn addr() -> impl Strategy<Value = Addr> {
+    bech32_address(DESMOS_BECH32_PREFIX).prop_map(Addr::unchecked)
+}
+
+/// Returns a strategy generating two different Desmos addresses.
+pub fn distinct_addrs() -> impl Strategy<Value = (Addr, Addr)> {
+    (addr(), addr()).prop_filter("the addresses must be different", |(first, second)| {
+        first != second
+    })
+}
+
+/// Returns a strategy generating the ids of the objects stored on chain, that are never zero.
+pub fn id() -> impl Strategy<Value = Uint64> {
+    (1..=u64::MAX).prop_map(Uint64::new)
+}
+
+/// Returns a strategy generating the ids of the objects stored on chain as [`u32`],
+/// that are never zero.
+pub fn small_id() -> impl Strategy<Value = u32> {
+    1..=u32::MAX
+}
+
+/// Returns a strategy generating RFC 3339 times with the same precision used by the chain
+/// (e.g. 2022-02-21T13:18:27.257641Z).
+pub fn rfc3339_time() -> impl Strategy<Value = String> {
+    (
+        (2000u32..2100, 1u32..=12, 1u32..=28),
+        (0u32..24, 0u32..60, 0u32..60),
+        prop_oneof![Just(0u32), 1u32..1_000_000_000],
+    )
+        .prop_map(|((year, month, day), (hours, minutes, seconds), nanos)| {
+            let mut time = format!(
+                "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}",
+                year, month, day, hours, minutes, seconds
+            );
+            if nanos > 0 {
+                time.push('.');
+                time.push_str(format!("{:09}", nanos).trim_end_matches('0'));
+            }
+            time.push('Z');
+            time
+        })
+}
+
+/// Returns a strategy generating texts made of at least one and up to `max_length` characters,
+/// without leading or trailing whitespaces.
+pub fn text(max_length: usize) -> impl Strategy<Value = String> {
+    let pattern = if max_length > 1 {
+        format!(
+            "[a-zA-Z0-9]([a-zA-Z0-9 .,!?_-]{{0,{}}}[a-zA-Z0-9])?",
+            max_length - 2
+        )
+    } else {
+        "[a-zA-Z0-9]".to_string()
+    };
+    proptest::string::string_regex(&pattern).expect("the text pattern must be a valid regex")
+}
+
+/// Returns a strategy generating HTTPS URIs.
+pub fn uri() -> impl Strategy<Value = String> {
+    "https://[a-z]{3,12}\\.(com|org|network)/[a-z0-9]{1,16}"
+}
+
+/// Returns a strategy generating the items of a query response page,
+/// along with its optional [`PageResponse`].
+pub fn page<T: Arbitrary>() -> impl Strategy<Value = (Vec<T>, Option<PageResponse>)> {
+    (
+        proptest::collection::vec(any::<T>(), 0..10),
+        proptest::option::of(any::<PageResponse>()),
+    )
+}
+
+impl_arbitrary! {
+    PubKey => proptest::array::uniform32(any::<u8>()).prop_map(|key| {
+        // Compressed secp256k1 public key
+        let mut bytes = vec![2];
+        bytes.extend_from_slice(&key);
+        PubKey {
+            proto_type: "/cosmos.crypto.secp256k1.PubKey".to_string(),
+            key: Binary::from(bytes),
+        }
+    });
+    Height => (any::<u64>(), any::<u64>()).prop_map(|(revision_number, revision_height)| Height {
+        revision_number: Uint64::new(revision_number),
+        revision_height: Uint64::new(revision_height),
+    });
+    // The chain allows to paginate either by key or by offset.
+    PageRequest => (
+        prop_oneof![
+            Just((None, None)),
+            proptest::collection::vec(any::<u8>(), 1..32)
+                .prop_map(|key| (Some(Binary::from(key)), None)),
+            any::<u64>().prop_map(|offset| (None, Some(Uint64::new(offset)))),
+        ],
+        1u64..=100,
+        any::<bool>(),
+        any::<bool>(),
+    )
+        .prop_map(|((key, offset), limit, count_total, reverse)| PageRequest {
+            key,
+            offset,
+            limit: Uint64::new(limit),
+            count_total,
+            reverse,
+        });
+    PageResponse => (
+        proptest::option::of(proptest::collection::vec(any::<u8>(), 1..32)),
+        proptest::option::of(any::<u64>()),
+    )
+        .prop_map(|(next_key, total)| PageResponse {
+            next_key: next_key.map(Binary::from),
+            total: total.map(Uint64::new),
+        });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{addr, rfc3339_time, text, DESMOS_BECH32_PREFIX};
+    use crate::types::PageRequest;
+    use bech32::Variant;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn addresses_are_valid_bech32(addr in addr()) {
+            let (prefix, data, variant) = bech32::decode(addr.as_str()).unwrap();
+            prop_assert_eq!(DESMOS_BECH32_PREFIX, prefix);
+            prop_assert_eq!(Variant::Bech32, variant);
+            prop_assert_eq!(32, data.len());
+        }
+
+        #[test]
+        fn times_are_rfc3339(time in rfc3339_time()) {
+            prop_assert!(time.ends_with('Z'));
+            prop_assert_eq!(Some('T'), time.chars().nth(10));
+            // The fractional seconds are only present when not zero and never end with a zero
+            prop_assert!(time.len() == 20 || !time.trim_end_matches('Z').ends_with('0'));
+        }
+
+        #[test]
+        fn texts_respect_the_length(text in text(10)) {
+            prop_assert!(!text.is_empty() && text.len() <= 10);
+            prop_assert_eq!(text.trim(), text.as_str());
+        }
+
+        #[test]
+        fn page_requests_use_either_key_or_offset(request in any::<PageRequest>()) {
+            prop_assert!(request.key.is_none() || request.offset.is_none());
+            prop_assert!(!request.limit.is_zero());
+        }
+    }
+}
@@ -0,0 +1,360 @@
+//! Contains the [`DesmosQueryError`], returned by the queriers when a query toward
+//! a Desmos module fails.
+
+use cosmwasm_std::{StdError, SystemError};
+use thiserror::Error;
+
+/// Codespace of the errors registered by the Cosmos SDK.
+const SDK_CODESPACE: &str = "sdk";
+
+/// Codespace and code that the Cosmos SDK assigns to the errors that have not been registered.
+const UNDEFINED_CODESPACE: &str = "undefined";
+const UNDEFINED_CODE: u32 = 1;
+
+/// Code of the `ErrKeyNotFound` error of the Cosmos SDK.
+const KEY_NOT_FOUND_CODE: u32 = 22;
+
+/// Code of the `ErrNotFound` error of the Cosmos SDK.
+const NOT_FOUND_CODE: u32 = 38;
+
+/// Codespaces and codes of the errors returned when the queried object does not exist.
+///
+/// The Desmos modules don't register their own not found errors, but wrap the `ErrKeyNotFound`
+/// and `ErrNotFound` errors of the Cosmos SDK instead.
+const NOT_FOUND_ERRORS: [(&str, u32); 2] = [
+    (SDK_CODESPACE, KEY_NOT_FOUND_CODE),
+    (SDK_CODESPACE, NOT_FOUND_CODE),
+];
+
+/// Prefix of the logs of the gRPC status errors, followed by the status code name.
+const GRPC_STATUS_PREFIX: &str = "rpc error: code = ";
+
+/// Name of the gRPC status code returned when the queried object does not exist.
+const GRPC_NOT_FOUND: &str = "NotFound";
+
+/// Codes and descriptions of the Cosmos SDK errors returned by the Desmos modules queries.
+const SDK_ERRORS: [(u32, &str); 7] = [
+    (1, "internal"),
+    (4, "unauthorized"),
+    (7, "invalid address"),
+    (11, "out of gas"),
+    (18, "invalid request"),
+    (KEY_NOT_FOUND_CODE, "key not found"),
+    (NOT_FOUND_CODE, "not found"),
+];
+
+/// Codes and descriptions of the errors registered by each Desmos module, grouped by codespace.
+const MODULE_ERRORS: [(&str, &[(u32, &str)]); 6] = [
+    ("profiles", &[(1, "invalid genesis state")]),
+    (
+        "subspaces",
+        &[(1, "invalid genesis state"), (2, "permission denied")],
+    ),
+    ("posts", &[(1, "invalid genesis state")]),
+    ("relationships", &[(1, "invalid genesis state")]),
+    ("reactions", &[(1, "invalid genesis state")]),
+    ("reports", &[(1, "invalid genesis state")]),
+];
+
+/// Result of a query performed toward a Desmos module.
+pub type DesmosQueryResult<T> = Result<T, DesmosQueryError>;
+
+/// Represents the errors that can occur when performing a query toward a Desmos module.
+///
+/// # Example
+/// ```
+/// use cosmwasm_std::{Addr, Deps, StdResult};
+/// use desmos_bindings::errors::DesmosQueryError;
+/// use desmos_bindings::profiles::querier::ProfilesQuerier;
+/// use desmos_bindings::query::DesmosQuery;
+/// use std::ops::Deref;
+///
+/// pub fn has_profile(deps: Deps<DesmosQuery>, user: Addr) -> StdResult<bool> {
+///     match ProfilesQuerier::new(deps.querier.deref()).query_profile(user) {
+///         Ok(_) => Ok(true),
+///         Err(DesmosQueryError::NotFound { .. }) => Ok(false),
+///         Err(error) => Err(error.into()),
+///     }
+/// }
+/// ```
+#[derive(Error, Clone, Debug, PartialEq, Eq)]
+pub enum DesmosQueryError {
+    /// The queried object does not exist.
+    #[error("{message}")]
+    NotFound {
+        /// Codespace of the module that returned the error.
+        codespace: String,
+        /// Code of the error inside its codespace.
+        code: u32,
+        /// Error message returned by the chain.
+        message: String,
+    },
+
+    /// The chain refused to answer the query, as an example because its arguments are not valid.
+    #[error("{message}")]
+    InvalidRequest {
+        /// Codespace of the module that returned the error.
+        codespace: String,
+        /// Code of the error inside its codespace.
+        code: u32,
+        /// Error message returned by the chain.
+        message: String,
+    },
+
+    /// The chain response can't be parsed into the expected type.
+    #[error("Error parsing into type {target_type}: {message}")]
+    ParseResponse {
+        /// Name of the type into which the response should have been parsed.
+        target_type: String,
+        /// Reason why the response can't be parsed.
+        message: String,
+    },
+
+    /// The querier can't handle the query, as an example because the chain does not support it.
+    #[error("Querier system error: {0}")]
+    System(SystemError),
+}
+
+impl DesmosQueryError {
+    /// Decodes the error returned by a Desmos module when handling a query.
+    ///
+    /// The error can either be:
+    /// - the redacted version of the error, that contains only its codespace and code;
+    /// - the log of a gRPC status error, made of the status code followed by its description;
+    /// - the log of a module error, made of the context in which the error occurred
+    ///   followed by the registered error description.
+    ///
+    /// Only the `ErrKeyNotFound` and `ErrNotFound` errors of the Cosmos SDK and the gRPC
+    /// `NotFound` status are reported as [`DesmosQueryError::NotFound`].
+    /// The chain redacts all the errors that have not been registered, including the gRPC status
+    /// errors, with the `undefined` codespace and the code `1`. Since that can either be a missing
+    /// object or an invalid request, such errors are reported as
+    /// [`DesmosQueryError::InvalidRequest`].
+    pub fn from_chain_error(error: &str) -> Self {
+        let (codespace, code, not_found) = match parse_redacted_error(error) {
+            Some((codespace, code)) => {
+                let not_found = NOT_FOUND_ERRORS.contains(&(codespace.as_str(), code));
+                (codespace, code, not_found)
+            }
+            None => match parse_grpc_status_code(error) {
+                // The gRPC status errors are not registered, so they have no codespace
+                Some(status_code) => (
+                    UNDEFINED_CODESPACE.to_string(),
+                    UNDEFINED_CODE,
+                    status_code == GRPC_NOT_FOUND,
+                ),
+                None => {
+                    let (codespace, code) = registered_error_code(error);
+                    let not_found = NOT_FOUND_ERRORS.contains(&(codespace, code));
+                    (codespace.to_string(), code, not_found)
+                }
+            },
+        };
+        let message = error.to_string();
+        if not_found {
+            DesmosQueryError::NotFound {
+                codespace,
+                code,
+                message,
+            }
+        } else {
+            DesmosQueryError::InvalidRequest {
+                codespace,
+                code,
+                message,
+            }
+        }
+    }
+
+    /// Tells whether the error has been returned because the queried object does not exist.
+    pub fn is_not_found(&self) -> bool {
+        matches!(self, DesmosQueryError::NotFound { .. })
+    }
+}
+
+/// Turns the not found error of the given result into `None`, propagating any other error.
+// Hide this warning since when we compile the package without any module feature
+// there is no querier using this function.
+#[allow(dead_code)]
+pub(crate) fn not_found_as_none<T>(result: DesmosQueryResult<T>) -> DesmosQueryResult<Option<T>> {
+    match result {
+        Ok(value) => Ok(Some(value)),
+        Err(error) if error.is_not_found() => Ok(None),
+        Err(error) => Err(error),
+    }
+}
+
+/// Parses the codespace and code of an error redacted by the chain,
+/// having the `codespace: <codespace>, code: <code>` format.
+fn parse_redacted_error(error: &str) -> Option<(String, u32)> {
+    let (codespace, code) = error.strip_prefix("codespace: ")?.split_once(", code: ")?;
+    Some((codespace.to_string(), code.trim().parse().ok()?))
+}
+
+/// Parses the name of the status code of a gRPC status error log,
+/// having the `rpc error: code = <code> desc = <description>` format.
+fn parse_grpc_status_code(error: &str) -> Option<&str> {
+    error
+        .strip_prefix(GRPC_STATUS_PREFIX)?
+        .split_whitespace()
+        .next()
+}
+
+/// Returns the codespace and code of the registered error whose description ends the given log.
+///
+/// The Cosmos SDK errors take precedence over the ones of the Desmos modules, and the logs
+/// ending with a description that has not been registered are reported as not registered.
+fn registered_error_code(error: &str) -> (&'static str, u32) {
+    let description = error.rsplit(": ").next().unwrap_or(error);
+    let sdk_error = SDK_ERRORS
+        .iter()
+        .find(|(_, registered)| *registered == description)
+        .map(|(code, _)| (SDK_CODESPACE, *code));
+    let module_error = || {
+        MODULE_ERRORS.iter().find_map(|(codespace, errors)| {
+            errors
+                .iter()
+                .find(|(_, registered)| *registered == description)
+                .map(|(code, _)| (*codespace, *code))
+        })
+    };
+    sdk_error
+        .or_else(module_error)
+        .unwrap_or((UNDEFINED_CODESPACE, UNDEFINED_CODE))
+}
+
+impl From<DesmosQueryError> for StdError {
+    fn from(error: DesmosQueryError) -> Self {
+        match error {
+            DesmosQueryError::NotFound { message, .. }
+            | DesmosQueryError::InvalidRequest { message, .. } => {
+                StdError::generic_err(format!("Querier contract error: {}", message))
+            }
+            DesmosQueryError::ParseResponse {
+                target_type,
+                message,
+            } => StdError::parse_err(target_type, message),
+            DesmosQueryError::System(error) => {
+                StdError::generic_err(format!("Querier system error: {}", error))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_redacted_errors() {
+        assert_eq!(
+            DesmosQueryError::NotFound {
+                codespace: "sdk".to_string(),
+                code: 38,
+                message: "codespace: sdk, code: 38".to_string(),
+            },
+            DesmosQueryError::from_chain_error("codespace: sdk, code: 38")
+        );
+        assert_eq!(
+            DesmosQueryError::InvalidRequest {
+                codespace: "subspaces".to_string(),
+                code: 2,
+                message: "codespace: subspaces, code: 2".to_string(),
+            },
+            DesmosQueryError::from_chain_error("codespace: subspaces, code: 2")
+        );
+    }
+
+    #[test]
+    fn test_decode_redacted_unregistered_errors() {
+        // The chain redacts both the gRPC NotFound status and any other unregistered error
+        // in the same way, so they can't be told apart
+        assert_eq!(
+            DesmosQueryError::InvalidRequest {
+                codespace: "undefined".to_string(),
+                code: 1,
+                message: "codespace: undefined, code: 1".to_string(),
+            },
+            DesmosQueryError::from_chain_error("codespace: undefined, code: 1")
+        );
+        assert!(DesmosQueryError::from_chain_error("codespace: sdk, code: 22").is_not_found());
+        assert!(!DesmosQueryError::from_chain_error("codespace: sdk, code: 18").is_not_found());
+    }
+
+    #[test]
+    fn test_decode_grpc_status_logs() {
+        let error = DesmosQueryError::from_chain_error(
+            "rpc error: code = NotFound desc = subspace with id 1 not found",
+        );
+        assert_eq!(
+            DesmosQueryError::NotFound {
+                codespace: "undefined".to_string(),
+                code: 1,
+                message: "rpc error: code = NotFound desc = subspace with id 1 not found"
+                    .to_string(),
+            },
+            error
+        );
+
+        let error = DesmosQueryError::from_chain_error(
+            "rpc error: code = InvalidArgument desc = invalid post id",
+        );
+        assert!(matches!(
+            error,
+            DesmosQueryError::InvalidRequest { ref codespace, code: 1, .. } if codespace == "undefined"
+        ));
+    }
+
+    #[test]
+    fn test_decode_errors_logs() {
+        let error = DesmosQueryError::from_chain_error("post with id 1 not found: not found");
+        assert_eq!(
+            DesmosQueryError::NotFound {
+                codespace: "sdk".to_string(),
+                code: 38,
+                message: "post with id 1 not found: not found".to_string(),
+            },
+            error
+        );
+        assert_eq!("post with id 1 not found: not found", error.to_string());
+
+        let error = DesmosQueryError::from_chain_error("invalid subspace id: invalid request");
+        assert!(matches!(
+            error,
+            DesmosQueryError::InvalidRequest { code: 18, .. }
+        ));
+
+        let error = DesmosQueryError::from_chain_error(
+            "subspace with id 1 does not allow editing: permission denied",
+        );
+        assert!(matches!(
+            error,
+            DesmosQueryError::InvalidRequest { ref codespace, code: 2, .. } if codespace == "subspaces"
+        ));
+
+        // Only the registered descriptions are considered, whatever the log says
+        let error = DesmosQueryError::from_chain_error("application link not found");
+        assert!(matches!(
+            error,
+            DesmosQueryError::InvalidRequest { ref codespace, code: 1, .. } if codespace == "undefined"
+        ));
+
+        let error = DesmosQueryError::from_chain_error("unknown failure");
+        assert!(matches!(
+            error,
+            DesmosQueryError::InvalidRequest { ref codespace, code: 1, .. } if codespace == "undefined"
+        ));
+    }
+
+    #[test]
+    fn test_convert_into_std_error() {
+        let error: StdError = DesmosQueryError::from_chain_error("not found").into();
+        assert_eq!(
+            StdError::generic_err("Querier contract error: not found"),
+            error
+        );
+
+        let error: StdError = DesmosQueryError::System(SystemError::Unknown {}).into();
+        assert!(error.to_string().contains("Querier system error"));
+    }
+}
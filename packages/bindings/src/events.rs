@@ -0,0 +1,292 @@
+//! Contains the utilities to parse the events emitted by the Desmos modules, that can be found
+//! inside the responses of the executed messages (e.g. the `SubMsgResponse` received inside a
+//! `reply` or the `AppResponse` returned by the mocked app).
+//!
+//! Each module exposes its own events inside its `events` module, along with the
+//! table of the events emitted by each of its messages.
+//! Besides them, the chain emits a [`MessageEvent`] for each executed message.
+
+use cosmwasm_std::{Addr, Event, StdError, StdResult};
+
+/// Event emitted by a Desmos module, that can be parsed from and converted into an [`Event`].
+pub trait DesmosEvent: Sized + Into<Event> {
+    /// Type of the event, as emitted by the chain.
+    const EVENT_TYPE: &'static str;
+    /// Keys of the attributes that the chain includes into the event.
+    const ATTRIBUTE_KEYS: &'static [&'static str];
+
+    /// Parses the given [`Event`], returning an error if its type is not [`Self::EVENT_TYPE`]
+    /// or if some of the required attributes are missing or invalid.
+    fn from_event(event: &Event) -> StdResult<Self>;
+}
+
+/// Value of an event attribute.
+pub trait EventValue: Sized {
+    /// Parses the value of the attribute having the given key, that is `None` if the
+    /// attribute is missing.
+    fn from_attribute(key: &str, value: Option<&str>) -> StdResult<Self>;
+
+    /// Returns the attribute value, or `None` if the attribute should not be emitted.
+    fn to_value(&self) -> Option<String>;
+}
+
+/// Returns the error produced when a required attribute is missing.
+fn missing_attribute(key: &str) -> StdError {
+    StdError::parse_err(key, "missing event attribute")
+}
+
+impl EventValue for String {
+    fn from_attribute(key: &str, value: Option<&str>) -> StdResult<Self> {
+        value
+            .map(str::to_string)
+            .ok_or_else(|| missing_attribute(key))
+    }
+
+    fn to_value(&self) -> Option<String> {
+        Some(self.clone())
+    }
+}
+
+impl EventValue for Addr {
+    fn from_attribute(key: &str, value: Option<&str>) -> StdResult<Self> {
+        String::from_attribute(key, value).map(Addr::unchecked)
+    }
+
+    fn to_value(&self) -> Option<String> {
+        Some(self.to_string())
+    }
+}
+
+impl EventValue for u64 {
+    fn from_attribute(key: &str, value: Option<&str>) -> StdResult<Self> {
+        String::from_attribute(key, value)?
+            .parse()
+            .map_err(|_| StdError::parse_err(key, "invalid u64 event attribute"))
+    }
+
+    fn to_value(&self) -> Option<String> {
+        Some(self.to_string())
+    }
+}
+
+impl EventValue for u32 {
+    fn from_attribute(key: &str, value: Option<&str>) -> StdResult<Self> {
+        String::from_attribute(key, value)?
+            .parse()
+            .map_err(|_| StdError::parse_err(key, "invalid u32 event attribute"))
+    }
+
+    fn to_value(&self) -> Option<String> {
+        Some(self.to_string())
+    }
+}
+
+impl<T: EventValue> EventValue for Option<T> {
+    fn from_attribute(key: &str, value: Option<&str>) -> StdResult<Self> {
+        value
+            .map(|value| T::from_attribute(key, Some(value)))
+            .transpose()
+    }
+
+    fn to_value(&self) -> Option<String> {
+        self.as_ref().and_then(EventValue::to_value)
+    }
+}
+
+/// Returns the value of the first attribute of the event having the given key.
+#[doc(hidden)]
+pub fn attribute_value<'a>(event: &'a Event, key: &str) -> Option<&'a str> {
+    event
+        .attributes
+        .iter()
+        .find(|attribute| attribute.key == key)
+        .map(|attribute| attribute.value.as_str())
+}
+
+/// Returns an error if the given event is not of the type `E`.
+#[doc(hidden)]
+pub fn check_event_type<E: DesmosEvent>(event: &Event) -> StdResult<()> {
+    if event.ty != E::EVENT_TYPE {
+        return Err(StdError::parse_err(
+            E::EVENT_TYPE,
+            format!("unexpected event type {}", event.ty),
+        ));
+    }
+    Ok(())
+}
+
+/// Parses all the events of type `E` contained inside the given events.
+///
+/// # Example
+/// ```
+/// use cosmwasm_std::Event;
+/// use desmos_bindings::events::{parse_events, MessageEvent};
+///
+/// let events = vec![Event::new("message")
+///     .add_attribute("module", "posts")
+///     .add_attribute("action", "/desmos.posts.v2.MsgCreatePost")
+///     .add_attribute("sender", "desmos1nwp8gxrnmrsrzjdhvk47vvmthzxjtphgxp5ftc")];
+/// let messages: Vec<MessageEvent> = parse_events(&events).unwrap();
+/// assert_eq!("posts", messages[0].module);
+/// ```
+pub fn parse_events<E: DesmosEvent>(events: &[Event]) -> StdResult<Vec<E>> {
+    events
+        .iter()
+        .filter(|event| event.ty == E::EVENT_TYPE)
+        .map(E::from_event)
+        .collect()
+}
+
+/// Parses the first event of type `E` contained inside the given events, if any.
+pub fn find_event<E: DesmosEvent>(events: &[Event]) -> StdResult<Option<E>> {
+    events
+        .iter()
+        .find(|event| event.ty == E::EVENT_TYPE)
+        .map(E::from_event)
+        .transpose()
+}
+
+/// Defines the structs representing the events emitted by a module, implementing
+/// [`DesmosEvent`] and the conversion into [`Event`] for each of them.
+macro_rules! desmos_events {
+    ($(
+        $(#[$meta:meta])*
+        $name:ident = $event_type:literal {
+            $(
+                $(#[$field_meta:meta])*
+                $field:ident: $field_type:ty = $key:literal
+            ),* $(,)?
+        }
+    )*) => {
+        $(
+            $(#[$meta])*
+            #[derive(Clone, Debug, PartialEq)]
+            pub struct $name {
+                $(
+                    $(#[$field_meta])*
+                    pub $field: $field_type,
+                )*
+            }
+
+            impl $crate::events::DesmosEvent for $name {
+                const EVENT_TYPE: &'static str = $event_type;
+                const ATTRIBUTE_KEYS: &'static [&'static str] = &[$($key),*];
+
+                fn from_event(event: &cosmwasm_std::Event) -> cosmwasm_std::StdResult<Self> {
+                    $crate::events::check_event_type::<Self>(event)?;
+                    Ok($name {
+                        $(
+                            $field: $crate::events::EventValue::from_attribute(
+                                $key,
+                                $crate::events::attribute_value(event, $key),
+                            )?,
+                        )*
+                    })
+                }
+            }
+
+            impl From<$name> for cosmwasm_std::Event {
+                #[allow(unused_mut, unused_variables)]
+                fn from(event: $name) -> Self {
+                    let mut result = cosmwasm_std::Event::new($event_type);
+                    $(
+                        if let Some(value) = $crate::events::EventValue::to_value(&event.$field) {
+                            result = result.add_attribute($key, value);
+                        }
+                    )*
+                    result
+                }
+            }
+        )*
+    };
+}
+
+// Not used when the package is compiled without any module feature.
+#[allow(unused_imports)]
+pub(crate) use desmos_events;
+
+desmos_events! {
+    /// Event emitted by the chain for each executed message.
+    MessageEvent = "message" {
+        /// Name of the module that handled the message.
+        module: String = "module",
+        /// Type URL of the message.
+        action: String = "action",
+        /// Address of the message signer.
+        sender: Addr = "sender",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{find_event, parse_events, DesmosEvent, MessageEvent};
+    use cosmwasm_std::{Addr, Event};
+
+    desmos_events! {
+        /// Event used to test the optional attributes.
+        TestEvent = "test" {
+            /// Required attribute.
+            id: u64 = "id",
+            /// Optional attribute.
+            user: Option<Addr> = "user",
+        }
+    }
+
+    #[test]
+    fn events_are_converted_back_and_forth() {
+        let event = MessageEvent {
+            module: "profiles".to_string(),
+            action: "/desmos.profiles.v3.MsgSaveProfile".to_string(),
+            sender: Addr::unchecked("desmos1nwp8gxrnmrsrzjdhvk47vvmthzxjtphgxp5ftc"),
+        };
+        let raw: Event = event.clone().into();
+        assert_eq!(
+            MessageEvent::ATTRIBUTE_KEYS,
+            raw.attributes
+                .iter()
+                .map(|attribute| attribute.key.as_str())
+                .collect::<Vec<_>>()
+                .as_slice()
+        );
+        assert_eq!(event, MessageEvent::from_event(&raw).unwrap());
+    }
+
+    #[test]
+    fn optional_attributes_are_skipped() {
+        let event = TestEvent { id: 1, user: None };
+        let raw: Event = event.clone().into();
+        assert_eq!(1, raw.attributes.len());
+        assert_eq!(event, TestEvent::from_event(&raw).unwrap());
+    }
+
+    #[test]
+    fn invalid_events_are_rejected() {
+        assert!(TestEvent::from_event(&Event::new("other").add_attribute("id", "1")).is_err());
+        assert!(TestEvent::from_event(&Event::new("test")).is_err());
+        assert!(TestEvent::from_event(&Event::new("test").add_attribute("id", "a")).is_err());
+    }
+
+    #[test]
+    fn events_are_found_by_type() {
+        let events = vec![
+            Event::new("other"),
+            TestEvent { id: 1, user: None }.into(),
+            TestEvent { id: 2, user: None }.into(),
+        ];
+        assert_eq!(
+            vec![1, 2],
+            parse_events::<TestEvent>(&events)
+                .unwrap()
+                .into_iter()
+                .map(|event| event.id)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            Some(1),
+            find_event::<TestEvent>(&events)
+                .unwrap()
+                .map(|event| event.id)
+        );
+        assert_eq!(None, find_event::<MessageEvent>(&events).unwrap());
+    }
+}
@@ -1,11 +1,12 @@
 //! Contains an iterator that lazily loads paginated data from the chain.
 
-use cosmwasm_std::StdResult;
+use cosmwasm_std::StdError;
 
 /// Type alias of a function that fetch a page given as first argument an optional key
 /// that references the next page to fetch and as second argument how many items to fetch.
 /// If the first argument is None means that this function should fetch the first page.
-pub type Fetcher<'a, T, K> = Box<dyn Fn(Option<K>, u64) -> StdResult<Page<T, K>> + 'a>;
+pub type Fetcher<'a, T, K, E = StdError> =
+    Box<dyn Fn(Option<K>, u64) -> Result<Page<T, K>, E> + 'a>;
 
 /// A page of elements.
 pub struct Page<T, K> {
@@ -18,9 +19,10 @@ pub struct Page<T, K> {
 
 /// Iterator that fetch paginated elements and allow to iterate over
 /// them as a continuous sequence of elements.
-pub struct PageIterator<'a, T: Clone, K: Clone> {
+/// The iterator yields the errors of type `E` returned when fetching the pages.
+pub struct PageIterator<'a, T: Clone, K: Clone, E = StdError> {
     /// Function to fetch a page.
-    fetch_page: Fetcher<'a, T, K>,
+    fetch_page: Fetcher<'a, T, K, E>,
     /// Optional cached page.
     current_page: Option<Page<T, K>>,
     /// Position to the current element of the current page.
@@ -31,7 +33,7 @@ pub struct PageIterator<'a, T: Clone, K: Clone> {
     consumed: bool,
 }
 
-impl<'a, T: Clone, K: Clone> PageIterator<'a, T, K> {
+impl<'a, T: Clone, K: Clone, E> PageIterator<'a, T, K, E> {
     /// Creates a new iterator that fetch paginated items and allow to iterate over them
     /// as a continuous sequence of elements.
     ///
@@ -59,7 +61,7 @@ impl<'a, T: Clone, K: Clone> PageIterator<'a, T, K> {
     ///            10,
     ///        );
     /// ```
-    pub fn new(fetch_page: Fetcher<'a, T, K>, page_size: u64) -> PageIterator<'a, T, K> {
+    pub fn new(fetch_page: Fetcher<'a, T, K, E>, page_size: u64) -> PageIterator<'a, T, K, E> {
         PageIterator {
             fetch_page,
             current_page: None,
@@ -70,8 +72,8 @@ impl<'a, T: Clone, K: Clone> PageIterator<'a, T, K> {
     }
 }
 
-impl<'a, T: Clone, K: Clone> Iterator for PageIterator<'a, T, K> {
-    type Item = StdResult<T>;
+impl<'a, T: Clone, K: Clone, E> Iterator for PageIterator<'a, T, K, E> {
+    type Item = Result<T, E>;
 
     fn next(&mut self) -> Option<Self::Item> {
         // If the iterator is consumed just return None
@@ -97,7 +99,7 @@ impl<'a, T: Clone, K: Clone> Iterator for PageIterator<'a, T, K> {
             }
 
             // Fetch a new page
-            let fetch_result: StdResult<Page<T, K>> = (self.fetch_page)(next_key, self.page_size);
+            let fetch_result: Result<Page<T, K>, E> = (self.fetch_page)(next_key, self.page_size);
 
             match fetch_result {
                 Ok(page) => {
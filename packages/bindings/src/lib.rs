@@ -4,17 +4,43 @@
 //! smart contract.
 
 extern crate core;
+#[cfg(feature = "arbitrary")]
+pub mod arbitrary;
+#[cfg(feature = "query")]
+pub mod errors;
+#[cfg(feature = "msg")]
+pub mod events;
 #[cfg(feature = "iterators")]
 pub mod iter;
 #[cfg(all(not(target_arch = "wasm32"), feature = "mocks"))]
 pub mod mocks;
 #[cfg(feature = "msg")]
 pub mod msg;
+#[cfg(all(
+    not(target_arch = "wasm32"),
+    feature = "query",
+    any(
+        feature = "profiles",
+        feature = "subspaces",
+        all(
+            feature = "mocks",
+            any(
+                feature = "posts",
+                feature = "reactions",
+                feature = "relationships",
+                feature = "reports"
+            )
+        )
+    )
+))]
+mod pagination;
 #[cfg(feature = "posts")]
 pub mod posts;
 #[cfg(feature = "profiles")]
 pub mod profiles;
 #[cfg(feature = "query")]
+pub mod querier;
+#[cfg(feature = "query")]
 pub mod query;
 #[cfg(feature = "reactions")]
 pub mod reactions;
@@ -0,0 +1,333 @@
+//! Contains the [`ChainError`], used by the mocks to reproduce the errors returned by the
+//! Desmos modules, and the utilities to inject them into the mocked queries.
+
+use crate::mocks::mock_queriers::QueriedModule;
+use crate::query::DesmosQuery;
+use std::fmt;
+
+/// Codespace of the errors registered by the Cosmos SDK.
+pub const SDK_CODESPACE: &str = "sdk";
+
+/// Codespace and code that the Cosmos SDK assigns to the errors that have not been registered.
+const UNDEFINED_CODESPACE: &str = "undefined";
+const UNDEFINED_CODE: u32 = 1;
+
+/// Represents an error returned by the chain, identified by the codespace and the code
+/// under which it has been registered, like the Cosmos SDK errors.
+///
+/// When formatted, the error produces the same log returned by the chain when a message fails,
+/// that is the context in which the error occurred followed by the registered error description.
+/// The errors of the queries instead reach the contracts in their [redacted](ChainError::redacted)
+/// form, since the chain hides everything but the codespace and the code.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ChainError {
+    /// Codespace of the module that registered the error.
+    pub codespace: String,
+    /// Code of the error inside its codespace.
+    pub code: u32,
+    /// Description of the error, as registered by the module.
+    pub description: String,
+    /// Context in which the error occurred.
+    pub context: String,
+}
+
+impl ChainError {
+    /// Creates a new [`ChainError`] with the given registration details and context.
+    pub fn new(
+        codespace: impl Into<String>,
+        code: u32,
+        description: impl Into<String>,
+        context: impl Into<String>,
+    ) -> Self {
+        ChainError {
+            codespace: codespace.into(),
+            code,
+            description: description.into(),
+            context: context.into(),
+        }
+    }
+
+    /// Returns the `ErrUnauthorized` error of the Cosmos SDK with the given context.
+    pub fn unauthorized(context: impl Into<String>) -> Self {
+        ChainError::new(SDK_CODESPACE, 4, "unauthorized", context)
+    }
+
+    /// Returns the `ErrInvalidAddress` error of the Cosmos SDK with the given context.
+    pub fn invalid_address(context: impl Into<String>) -> Self {
+        ChainError::new(SDK_CODESPACE, 7, "invalid address", context)
+    }
+
+    /// Returns the `ErrOutOfGas` error of the Cosmos SDK with the given context.
+    pub fn out_of_gas(context: impl Into<String>) -> Self {
+        ChainError::new(SDK_CODESPACE, 11, "out of gas", context)
+    }
+
+    /// Returns the `ErrInvalidRequest` error of the Cosmos SDK with the given context.
+    pub fn invalid_request(context: impl Into<String>) -> Self {
+        ChainError::new(SDK_CODESPACE, 18, "invalid request", context)
+    }
+
+    /// Returns the `ErrNotFound` error of the Cosmos SDK with the given context,
+    /// used by the Desmos modules when the queried object does not exist.
+    pub fn not_found(context: impl Into<String>) -> Self {
+        ChainError::new(SDK_CODESPACE, 38, "not found", context)
+    }
+
+    /// Returns the error returned by the x/profiles module when the profile of a user does not exist.
+    #[cfg(feature = "profiles")]
+    pub fn profile_not_found(user: &str) -> Self {
+        ChainError::not_found(format!("profile for {} not found", user))
+    }
+
+    /// Returns the error returned by the x/profiles module when a user has not received
+    /// a DTag transfer request from the given sender.
+    #[cfg(feature = "profiles")]
+    pub fn dtag_transfer_request_not_found(sender: &str) -> Self {
+        ChainError::not_found(format!("no request made from {}", sender))
+    }
+
+    /// Returns the error returned by the x/profiles module when a chain link does not exist.
+    #[cfg(feature = "profiles")]
+    pub fn chain_link_not_found() -> Self {
+        ChainError::not_found("chain link not found")
+    }
+
+    /// Returns the error returned by the x/profiles module when an application link does not exist.
+    #[cfg(feature = "profiles")]
+    pub fn application_link_not_found() -> Self {
+        ChainError::not_found("application link not found")
+    }
+
+    /// Returns the error returned by the x/profiles module when no application link
+    /// is associated to the given client id.
+    #[cfg(feature = "profiles")]
+    pub fn client_id_not_found(client_id: &str) -> Self {
+        ChainError::not_found(format!("link for client id {} not found", client_id))
+    }
+
+    /// Returns the error returned by the x/relationships module when a relationship does not exist.
+    #[cfg(feature = "relationships")]
+    pub fn relationship_not_found() -> Self {
+        ChainError::not_found("relationship does not exist")
+    }
+
+    /// Returns the error returned by the x/relationships module when a user block does not exist.
+    #[cfg(feature = "relationships")]
+    pub fn user_block_not_found() -> Self {
+        ChainError::not_found("block does not exist")
+    }
+
+    /// Returns the error returned by the x/subspaces module when a subspace does not exist.
+    #[cfg(feature = "subspaces")]
+    pub fn subspace_not_found(subspace_id: u64) -> Self {
+        ChainError::not_found(format!("subspace with id {} not found", subspace_id))
+    }
+
+    /// Returns the error returned by the x/subspaces module when a section does not exist.
+    #[cfg(feature = "subspaces")]
+    pub fn section_not_found(subspace_id: u64, section_id: u32) -> Self {
+        ChainError::not_found(format!(
+            "section with id {} not found inside subspace {}",
+            section_id, subspace_id
+        ))
+    }
+
+    /// Returns the error returned by the x/subspaces module when a user group does not exist.
+    #[cfg(feature = "subspaces")]
+    pub fn user_group_not_found(subspace_id: u64, group_id: u32) -> Self {
+        ChainError::not_found(format!(
+            "group with id {} not found inside subspace {}",
+            group_id, subspace_id
+        ))
+    }
+
+    /// Returns the error returned by the x/posts module when a post does not exist.
+    #[cfg(feature = "posts")]
+    pub fn post_not_found(subspace_id: u64, post_id: u64) -> Self {
+        ChainError::not_found(format!(
+            "post with id {} not found inside subspace {}",
+            post_id, subspace_id
+        ))
+    }
+
+    /// Returns the error returned by the x/posts module when an attachment does not exist.
+    #[cfg(feature = "posts")]
+    pub fn attachment_not_found(post_id: u64, attachment_id: u32) -> Self {
+        ChainError::not_found(format!(
+            "attachment with id {} not found inside post {}",
+            attachment_id, post_id
+        ))
+    }
+
+    /// Returns the error returned by the x/reactions module when a reaction does not exist.
+    #[cfg(feature = "reactions")]
+    pub fn reaction_not_found(reaction_id: u32) -> Self {
+        ChainError::not_found(format!("reaction with id {} not found", reaction_id))
+    }
+
+    /// Returns the error returned by the x/reactions module when a registered reaction does not exist.
+    #[cfg(feature = "reactions")]
+    pub fn registered_reaction_not_found(registered_reaction_id: u32) -> Self {
+        ChainError::not_found(format!(
+            "registered reaction with id {} not found",
+            registered_reaction_id
+        ))
+    }
+
+    /// Returns the error returned by the x/reports module when a report does not exist.
+    #[cfg(feature = "reports")]
+    pub fn report_not_found(report_id: u64) -> Self {
+        ChainError::not_found(format!("report with id {} not found", report_id))
+    }
+
+    /// Returns the error returned by the x/reports module when a reason does not exist.
+    #[cfg(feature = "reports")]
+    pub fn reason_not_found(reason_id: u32) -> Self {
+        ChainError::not_found(format!("reason with id {} not found", reason_id))
+    }
+}
+
+impl ChainError {
+    /// Returns the redacted version of the error, containing only its codespace and code,
+    /// that is what the chain returns to a contract when one of its queries fails.
+    pub fn redacted(&self) -> String {
+        format!("codespace: {}, code: {}", self.codespace, self.code)
+    }
+}
+
+/// Redacts an error returned by a mocked query as the chain does before returning it
+/// to the contract. The errors that are not a [`ChainError`] have not been registered,
+/// so they are reported with the codespace and code of the unregistered errors.
+pub(crate) fn redact_query_error(error: anyhow::Error) -> anyhow::Error {
+    match error.downcast_ref::<ChainError>() {
+        Some(error) => anyhow::anyhow!(error.redacted()),
+        None => anyhow::anyhow!(
+            "codespace: {}, code: {}",
+            UNDEFINED_CODESPACE,
+            UNDEFINED_CODE
+        ),
+    }
+}
+
+impl fmt::Display for ChainError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.context.is_empty() {
+            write!(f, "{}", self.description)
+        } else {
+            write!(f, "{}: {}", self.context, self.description)
+        }
+    }
+}
+
+impl std::error::Error for ChainError {}
+
+/// Set of errors that should be returned in place of the responses of some queries.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct QueryErrors {
+    /// Errors returned when a query is performed with exactly the same arguments.
+    queries: Vec<(DesmosQuery, ChainError)>,
+    /// Errors returned for all the queries toward a module.
+    modules: Vec<(QueriedModule, ChainError)>,
+}
+
+impl QueryErrors {
+    /// Makes the given query fail with `error`, replacing the error previously set for it.
+    pub fn set_query_error(&mut self, query: DesmosQuery, error: ChainError) {
+        self.queries.retain(|(stored, _)| *stored != query);
+        self.queries.push((query, error));
+    }
+
+    /// Makes all the queries toward the given module fail with `error`,
+    /// replacing the error previously set for it.
+    pub fn set_module_error(&mut self, module: QueriedModule, error: ChainError) {
+        self.modules.retain(|(stored, _)| *stored != module);
+        self.modules.push((module, error));
+    }
+
+    /// Returns the error that should be returned for the given query, if any.
+    /// The errors set for a single query take precedence over the ones set for its module.
+    // Hide these warnings since when we compile the package without any module feature
+    // there is no query to look for.
+    #[cfg_attr(
+        not(any(
+            feature = "profiles",
+            feature = "subspaces",
+            feature = "relationships",
+            feature = "posts",
+            feature = "reactions",
+            feature = "reports"
+        )),
+        allow(unreachable_code, unused_variables)
+    )]
+    pub fn find(&self, query: &DesmosQuery) -> Option<&ChainError> {
+        let module = QueriedModule::of(query);
+        self.queries
+            .iter()
+            .find(|(stored, _)| stored == query)
+            .map(|(_, error)| error)
+            .or_else(|| {
+                self.modules
+                    .iter()
+                    .find(|(stored, _)| *stored == module)
+                    .map(|(_, error)| error)
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{redact_query_error, ChainError, QueryErrors};
+    use crate::mocks::mock_queriers::QueriedModule;
+    use crate::posts::query::PostsQuery;
+    use crate::query::DesmosQuery;
+    use cosmwasm_std::Uint64;
+
+    #[test]
+    fn chain_error_is_formatted_like_the_chain_log() {
+        let error = ChainError::post_not_found(1, 2);
+        assert_eq!(
+            "post with id 2 not found inside subspace 1: not found",
+            error.to_string()
+        );
+        assert_eq!(("sdk", 38), (error.codespace.as_str(), error.code));
+        assert_eq!("codespace: sdk, code: 38", error.redacted());
+        assert_eq!(
+            "invalid request",
+            ChainError::invalid_request("").to_string()
+        );
+    }
+
+    #[test]
+    fn query_errors_are_redacted() {
+        let error = redact_query_error(ChainError::post_not_found(1, 2).into());
+        assert_eq!("codespace: sdk, code: 38", error.to_string());
+
+        let error = redact_query_error(anyhow::anyhow!("storage failure"));
+        assert_eq!("codespace: undefined, code: 1", error.to_string());
+    }
+
+    #[test]
+    fn query_errors_prefer_the_query_ones() {
+        let post_query = DesmosQuery::from(PostsQuery::Post {
+            subspace_id: Uint64::new(1),
+            post_id: Uint64::new(1),
+        });
+        let other_query = DesmosQuery::from(PostsQuery::Post {
+            subspace_id: Uint64::new(1),
+            post_id: Uint64::new(2),
+        });
+        let mut errors = QueryErrors::default();
+        assert_eq!(None, errors.find(&post_query));
+
+        errors.set_module_error(QueriedModule::Posts, ChainError::unauthorized(""));
+        errors.set_query_error(post_query.clone(), ChainError::post_not_found(1, 1));
+        assert_eq!(
+            Some(&ChainError::post_not_found(1, 1)),
+            errors.find(&post_query)
+        );
+        assert_eq!(
+            Some(&ChainError::unauthorized("")),
+            errors.find(&other_query)
+        );
+    }
+}
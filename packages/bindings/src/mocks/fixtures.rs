@@ -0,0 +1,802 @@
+//! Contains the [`DesmosFixtures`], the store of objects used by the
+//! [`MockDesmosQuerier`](crate::mocks::mock_queriers::MockDesmosQuerier) to answer the queries
+//! according to their arguments.
+
+use crate::mocks::errors::ChainError;
+#[cfg(feature = "subspaces")]
+use crate::mocks::mock_keeper::genesis::{UserGroupMemberEntry, UserPermissionEntry};
+#[cfg(any(
+    feature = "subspaces",
+    feature = "posts",
+    feature = "relationships",
+    feature = "reactions",
+    feature = "reports"
+))]
+use crate::pagination::paginate;
+#[cfg(feature = "posts")]
+use crate::posts::{
+    models::{Attachment, Post, UserAnswer},
+    models_query::{
+        QueryPollAnswersResponse, QueryPostAttachmentsResponse, QueryPostResponse,
+        QuerySectionPostsResponse, QuerySubspacePostsResponse,
+    },
+    query::PostsQuery,
+};
+#[cfg(feature = "profiles")]
+use crate::profiles::{
+    models_profile::Profile, models_query::QueryProfileResponse, query::ProfilesQuery,
+};
+#[cfg(feature = "reactions")]
+use crate::reactions::{
+    models::Reaction,
+    models_query::{QueryReactionResponse, QueryReactionsResponse},
+    query::ReactionsQuery,
+};
+#[cfg(feature = "relationships")]
+use crate::relationships::{
+    models::Relationship, models_query::QueryRelationshipsResponse, query::RelationshipsQuery,
+};
+#[cfg(feature = "reports")]
+use crate::reports::{
+    models::Report,
+    models_query::{QueryReportResponse, QueryReportsResponse},
+    query::ReportsQuery,
+};
+#[cfg(feature = "subspaces")]
+use crate::subspaces::{
+    models::{GroupPermissions, PermissionDetail, Section, Subspace, UserGroup, UserPermissions},
+    models_query::{
+        QuerySectionResponse, QuerySectionsResponse, QuerySubspaceResponse, QuerySubspacesResponse,
+        QueryUserGroupMembersResponse, QueryUserGroupResponse, QueryUserGroupsResponse,
+        QueryUserPermissionsResponse,
+    },
+    query::SubspacesQuery,
+};
+#[cfg(any(
+    feature = "subspaces",
+    feature = "posts",
+    feature = "relationships",
+    feature = "reactions",
+    feature = "reports"
+))]
+use crate::types::{PageRequest, PageResponse};
+#[allow(unused_imports)]
+use cosmwasm_std::{to_binary, Addr, Binary, ContractResult, Uint64};
+use serde::Serialize;
+
+/// Id of the root section that every subspace has.
+#[cfg(feature = "subspaces")]
+const ROOT_SECTION_ID: u32 = 0;
+/// Id of the default user group of which all the users are implicitly members.
+#[cfg(feature = "subspaces")]
+const DEFAULT_GROUP_ID: u32 = 0;
+
+/// Store of the objects that should be returned by the mocked queries.
+///
+/// Once any object has been added, all the queries about the kinds of objects kept by the store
+/// are answered with the stored objects matching their arguments, or with the not found error
+/// returned by the chain if there is none. The root section of a stored subspace always exists,
+/// while its default user group must be added like any other group.
+/// The queries about the objects that can't be kept by the store, like the chain links
+/// or the module parameters, are answered with the default mocked responses.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DesmosFixtures {
+    #[cfg(feature = "profiles")]
+    profiles: Vec<Profile>,
+    #[cfg(feature = "subspaces")]
+    subspaces: Vec<Subspace>,
+    #[cfg(feature = "subspaces")]
+    sections: Vec<Section>,
+    #[cfg(feature = "subspaces")]
+    user_groups: Vec<UserGroup>,
+    #[cfg(feature = "subspaces")]
+    user_group_members: Vec<UserGroupMemberEntry>,
+    #[cfg(feature = "subspaces")]
+    user_permissions: Vec<UserPermissionEntry>,
+    #[cfg(feature = "posts")]
+    posts: Vec<Post>,
+    #[cfg(feature = "posts")]
+    attachments: Vec<Attachment>,
+    #[cfg(feature = "posts")]
+    poll_answers: Vec<UserAnswer>,
+    #[cfg(feature = "relationships")]
+    relationships: Vec<Relationship>,
+    #[cfg(feature = "reactions")]
+    reactions: Vec<Reaction>,
+    #[cfg(feature = "reports")]
+    reports: Vec<Report>,
+}
+
+/// Result of a query answered with the stored objects.
+type FixtureResult = Result<Binary, ChainError>;
+
+/// Serializes the response of a query answered with the stored objects.
+#[allow(dead_code)]
+fn response(response: &impl Serialize) -> FixtureResult {
+    to_binary(response).map_err(|error| ChainError::invalid_request(error.to_string()))
+}
+
+/// Returns the page of `items` selected by the given pagination.
+#[cfg(any(
+    feature = "subspaces",
+    feature = "posts",
+    feature = "relationships",
+    feature = "reactions",
+    feature = "reports"
+))]
+fn page<T>(
+    items: Vec<T>,
+    pagination: Option<&PageRequest>,
+) -> Result<(Vec<T>, Option<PageResponse>), ChainError> {
+    paginate(items, pagination).map_err(|error| ChainError::invalid_request(error.to_string()))
+}
+
+/// Converts the result of a fixtures query into the [`ContractResult`] returned by the querier,
+/// redacting the errors as the chain does.
+#[allow(dead_code)]
+fn into_contract_result(result: FixtureResult) -> ContractResult<Binary> {
+    result.map_err(|error| error.redacted()).into()
+}
+
+impl DesmosFixtures {
+    /// Tells whether no object has been added to the store.
+    #[allow(dead_code)]
+    fn is_empty(&self) -> bool {
+        *self == DesmosFixtures::default()
+    }
+
+    /// Adds the given profile to the store, replacing the one owned by the same address.
+    #[cfg(feature = "profiles")]
+    pub fn add_profile(&mut self, profile: Profile) {
+        self.profiles
+            .retain(|stored| stored.account.address != profile.account.address);
+        self.profiles.push(profile);
+    }
+
+    /// Adds the given subspace to the store, replacing the one having the same id.
+    #[cfg(feature = "subspaces")]
+    pub fn add_subspace(&mut self, subspace: Subspace) {
+        self.subspaces.retain(|stored| stored.id != subspace.id);
+        self.subspaces.push(subspace);
+        self.subspaces.sort_by_key(|subspace| subspace.id);
+    }
+
+    /// Adds the given section to the store, replacing the one having the same subspace and id.
+    #[cfg(feature = "subspaces")]
+    pub fn add_section(&mut self, section: Section) {
+        self.sections
+            .retain(|stored| (stored.subspace_id, stored.id) != (section.subspace_id, section.id));
+        self.sections.push(section);
+        self.sections
+            .sort_by_key(|section| (section.subspace_id, section.id));
+    }
+
+    /// Adds the given user group to the store, replacing the one having the same subspace and id.
+    #[cfg(feature = "subspaces")]
+    pub fn add_user_group(&mut self, group: UserGroup) {
+        self.user_groups
+            .retain(|stored| (stored.subspace_id, stored.id) != (group.subspace_id, group.id));
+        self.user_groups.push(group);
+        self.user_groups
+            .sort_by_key(|group| (group.subspace_id, group.id));
+    }
+
+    /// Adds the given member to the store, if not already present.
+    #[cfg(feature = "subspaces")]
+    pub fn add_user_group_member(&mut self, member: UserGroupMemberEntry) {
+        if !self.user_group_members.contains(&member) {
+            self.user_group_members.push(member);
+        }
+    }
+
+    /// Adds the given user permissions to the store, replacing the ones set
+    /// to the same user inside the same section.
+    #[cfg(feature = "subspaces")]
+    pub fn add_user_permissions(&mut self, entry: UserPermissionEntry) {
+        self.user_permissions.retain(|stored| {
+            (stored.subspace_id, stored.section_id, &stored.user)
+                != (entry.subspace_id, entry.section_id, &entry.user)
+        });
+        self.user_permissions.push(entry);
+    }
+
+    /// Adds the given post to the store, replacing the one having the same subspace and id.
+    #[cfg(feature = "posts")]
+    pub fn add_post(&mut self, post: Post) {
+        self.posts
+            .retain(|stored| (stored.subspace_id, stored.id) != (post.subspace_id, post.id));
+        self.posts.push(post);
+        self.posts.sort_by_key(|post| (post.subspace_id, post.id));
+    }
+
+    /// Adds the given attachment to the store, replacing the one having the same subspace, post and id.
+    #[cfg(feature = "posts")]
+    pub fn add_attachment(&mut self, attachment: Attachment) {
+        self.attachments.retain(|stored| {
+            (stored.subspace_id, stored.post_id, stored.id)
+                != (attachment.subspace_id, attachment.post_id, attachment.id)
+        });
+        self.attachments.push(attachment);
+        self.attachments
+            .sort_by_key(|attachment| (attachment.subspace_id, attachment.post_id, attachment.id));
+    }
+
+    /// Adds the given poll answer to the store, replacing the one given by the same user
+    /// to the same poll.
+    #[cfg(feature = "posts")]
+    pub fn add_poll_answer(&mut self, answer: UserAnswer) {
+        self.poll_answers.retain(|stored| {
+            (
+                stored.subspace_id,
+                stored.post_id,
+                stored.poll_id,
+                &stored.user,
+            ) != (
+                answer.subspace_id,
+                answer.post_id,
+                answer.poll_id,
+                &answer.user,
+            )
+        });
+        self.poll_answers.push(answer);
+    }
+
+    /// Adds the given relationship to the store, if not already present.
+    #[cfg(feature = "relationships")]
+    pub fn add_relationship(&mut self, relationship: Relationship) {
+        if !self.relationships.contains(&relationship) {
+            self.relationships.push(relationship);
+        }
+    }
+
+    /// Adds the given reaction to the store, replacing the one having the same subspace, post and id.
+    #[cfg(feature = "reactions")]
+    pub fn add_reaction(&mut self, reaction: Reaction) {
+        self.reactions.retain(|stored| {
+            (stored.subspace_id, stored.post_id, stored.id)
+                != (reaction.subspace_id, reaction.post_id, reaction.id)
+        });
+        self.reactions.push(reaction);
+        self.reactions
+            .sort_by_key(|reaction| (reaction.subspace_id, reaction.post_id, reaction.id));
+    }
+
+    /// Adds the given report to the store, replacing the one having the same subspace and id.
+    #[cfg(feature = "reports")]
+    pub fn add_report(&mut self, report: Report) {
+        self.reports
+            .retain(|stored| (stored.subspace_id, stored.id) != (report.subspace_id, report.id));
+        self.reports.push(report);
+        self.reports
+            .sort_by_key(|report| (report.subspace_id, report.id));
+    }
+
+    /// Answers the given query with the stored profiles.
+    /// Returns `None` if the store is empty or if the query can't be answered with its objects.
+    #[cfg(feature = "profiles")]
+    pub fn profiles_query_response(&self, query: &ProfilesQuery) -> Option<ContractResult<Binary>> {
+        if self.is_empty() {
+            return None;
+        }
+        let result = match query {
+            ProfilesQuery::Profile { user } => self
+                .profiles
+                .iter()
+                .find(|profile| {
+                    profile.account.address.as_str() == user
+                        || profile.dtag.eq_ignore_ascii_case(user)
+                })
+                .ok_or_else(|| ChainError::profile_not_found(user))
+                .and_then(|profile| {
+                    response(&QueryProfileResponse {
+                        profile: profile.clone(),
+                    })
+                }),
+            _ => return None,
+        };
+        Some(into_contract_result(result))
+    }
+
+    /// Returns the stored subspace having the given id.
+    #[cfg(feature = "subspaces")]
+    fn subspace(&self, subspace_id: Uint64) -> Result<&Subspace, ChainError> {
+        self.subspaces
+            .iter()
+            .find(|subspace| subspace.id == subspace_id)
+            .ok_or_else(|| ChainError::subspace_not_found(subspace_id.u64()))
+    }
+
+    /// Returns the section having the given id inside a stored subspace.
+    /// The root section exists inside every stored subspace, even if it has not been added.
+    #[cfg(feature = "subspaces")]
+    fn section(&self, subspace_id: Uint64, section_id: u32) -> Result<Section, ChainError> {
+        self.subspace(subspace_id)?;
+        match self
+            .sections
+            .iter()
+            .find(|section| section.subspace_id == subspace_id && section.id == section_id)
+        {
+            Some(section) => Ok(section.clone()),
+            None if section_id == ROOT_SECTION_ID => Ok(Section {
+                subspace_id,
+                id: ROOT_SECTION_ID,
+                parent_id: None,
+                name: "Default section".to_string(),
+                description: String::new(),
+            }),
+            None => Err(ChainError::section_not_found(subspace_id.u64(), section_id)),
+        }
+    }
+
+    /// Returns the user group having the given id inside a stored subspace.
+    #[cfg(feature = "subspaces")]
+    fn user_group(&self, subspace_id: Uint64, group_id: u32) -> Result<&UserGroup, ChainError> {
+        self.subspace(subspace_id)?;
+        self.user_groups
+            .iter()
+            .find(|group| group.subspace_id == subspace_id && group.id == group_id)
+            .ok_or_else(|| ChainError::user_group_not_found(subspace_id.u64(), group_id))
+    }
+
+    /// Tells whether the given user is a member of the provided group.
+    #[cfg(feature = "subspaces")]
+    fn is_group_member(&self, group: &UserGroup, user: &Addr) -> bool {
+        group.id == DEFAULT_GROUP_ID
+            || self.user_group_members.iter().any(|member| {
+                member.subspace_id == group.subspace_id
+                    && member.group_id == group.id
+                    && member.user == *user
+            })
+    }
+
+    /// Returns the details of the permissions that the given user has inside the section path
+    /// that goes from the provided section up to the root section.
+    #[cfg(feature = "subspaces")]
+    fn permission_details(
+        &self,
+        subspace_id: Uint64,
+        section_id: u32,
+        user: &Addr,
+    ) -> Result<Vec<PermissionDetail>, ChainError> {
+        // As on chain, the sections without a parent are children of the root section.
+        let mut path = vec![section_id];
+        let mut section = self.section(subspace_id, section_id)?;
+        while section.id != ROOT_SECTION_ID {
+            let parent_id = section.parent_id.unwrap_or(ROOT_SECTION_ID);
+            if path.contains(&parent_id) {
+                break;
+            }
+            path.push(parent_id);
+            section = self.section(subspace_id, parent_id)?;
+        }
+
+        let mut details = Vec::new();
+        for section_id in path {
+            details.extend(
+                self.user_permissions
+                    .iter()
+                    .filter(|entry| {
+                        entry.subspace_id == subspace_id
+                            && entry.section_id == section_id
+                            && entry.user == *user
+                    })
+                    .map(|entry| PermissionDetail {
+                        subspace_id,
+                        section_id,
+                        user: Some(UserPermissions {
+                            user: user.clone(),
+                            permission: entry.permissions.clone(),
+                        }),
+                        group: None,
+                    }),
+            );
+            details.extend(
+                self.user_groups
+                    .iter()
+                    .filter(|group| {
+                        group.subspace_id == subspace_id
+                            && group.section_id.unwrap_or(ROOT_SECTION_ID) == section_id
+                            && self.is_group_member(group, user)
+                    })
+                    .map(|group| PermissionDetail {
+                        subspace_id,
+                        section_id,
+                        user: None,
+                        group: Some(GroupPermissions {
+                            group_id: group.id,
+                            permission: group.permissions.clone(),
+                        }),
+                    }),
+            );
+        }
+        Ok(details)
+    }
+
+    /// Answers the given query with the stored subspaces and their contents.
+    /// Returns `None` if the store is empty.
+    #[cfg(feature = "subspaces")]
+    pub fn subspaces_query_response(
+        &self,
+        query: &SubspacesQuery,
+    ) -> Option<ContractResult<Binary>> {
+        if self.is_empty() {
+            return None;
+        }
+        let result = match query {
+            SubspacesQuery::Subspaces { pagination } => {
+                page(self.subspaces.clone(), pagination.as_ref()).and_then(
+                    |(subspaces, pagination)| {
+                        response(&QuerySubspacesResponse {
+                            subspaces,
+                            pagination,
+                        })
+                    },
+                )
+            }
+            SubspacesQuery::Subspace { subspace_id } => {
+                self.subspace(*subspace_id).and_then(|subspace| {
+                    response(&QuerySubspaceResponse {
+                        subspace: subspace.clone(),
+                    })
+                })
+            }
+            SubspacesQuery::Sections {
+                subspace_id,
+                pagination,
+            } => self
+                .section(*subspace_id, ROOT_SECTION_ID)
+                .and_then(|root| {
+                    let mut sections: Vec<Section> = self
+                        .sections
+                        .iter()
+                        .filter(|section| section.subspace_id == *subspace_id)
+                        .cloned()
+                        .collect();
+                    if !sections.contains(&root) {
+                        sections.insert(0, root);
+                    }
+                    let (sections, pagination) = page(sections, pagination.as_ref())?;
+                    response(&QuerySectionsResponse {
+                        sections,
+                        pagination,
+                    })
+                }),
+            SubspacesQuery::Section {
+                subspace_id,
+                section_id,
+            } => self
+                .section(*subspace_id, *section_id)
+                .and_then(|section| response(&QuerySectionResponse { section })),
+            SubspacesQuery::UserGroups {
+                subspace_id,
+                section_id,
+                pagination,
+            } => self.subspace(*subspace_id).and_then(|_| {
+                let groups = self
+                    .user_groups
+                    .iter()
+                    .filter(|group| group.subspace_id == *subspace_id)
+                    .filter(|group| match section_id {
+                        Some(section_id) => {
+                            group.section_id.unwrap_or(ROOT_SECTION_ID) == *section_id
+                        }
+                        None => true,
+                    })
+                    .cloned()
+                    .collect();
+                let (groups, pagination) = page(groups, pagination.as_ref())?;
+                response(&QueryUserGroupsResponse { groups, pagination })
+            }),
+            SubspacesQuery::UserGroup {
+                subspace_id,
+                group_id,
+            } => self.user_group(*subspace_id, *group_id).and_then(|group| {
+                response(&QueryUserGroupResponse {
+                    group: group.clone(),
+                })
+            }),
+            SubspacesQuery::UserGroupMembers {
+                subspace_id,
+                group_id,
+                pagination,
+            } => self.user_group(*subspace_id, *group_id).and_then(|group| {
+                let members = self
+                    .user_group_members
+                    .iter()
+                    .filter(|member| {
+                        member.subspace_id == group.subspace_id && member.group_id == group.id
+                    })
+                    .map(|member| member.user.clone())
+                    .collect();
+                let (members, pagination) = page(members, pagination.as_ref())?;
+                response(&QueryUserGroupMembersResponse {
+                    members,
+                    pagination,
+                })
+            }),
+            SubspacesQuery::UserPermissions {
+                subspace_id,
+                section_id,
+                user,
+            } => self
+                .permission_details(*subspace_id, section_id.unwrap_or(ROOT_SECTION_ID), user)
+                .and_then(|details| {
+                    let mut permissions = Vec::new();
+                    for permission in details.iter().flat_map(|detail| {
+                        let user_permissions = detail.user.iter().map(|user| &user.permission);
+                        let group_permissions = detail.group.iter().map(|group| &group.permission);
+                        user_permissions.chain(group_permissions).flatten()
+                    }) {
+                        if !permissions.contains(permission) {
+                            permissions.push(permission.clone());
+                        }
+                    }
+                    response(&QueryUserPermissionsResponse {
+                        permissions,
+                        details,
+                    })
+                }),
+        };
+        Some(into_contract_result(result))
+    }
+
+    /// Returns the stored post having the given subspace and id.
+    #[cfg(feature = "posts")]
+    fn post(&self, subspace_id: Uint64, post_id: Uint64) -> Result<&Post, ChainError> {
+        self.posts
+            .iter()
+            .find(|post| post.subspace_id == subspace_id && post.id == post_id)
+            .ok_or_else(|| ChainError::post_not_found(subspace_id.u64(), post_id.u64()))
+    }
+
+    /// Answers the given query with the stored posts and their contents.
+    /// Returns `None` if the store is empty.
+    #[cfg(feature = "posts")]
+    pub fn posts_query_response(&self, query: &PostsQuery) -> Option<ContractResult<Binary>> {
+        if self.is_empty() {
+            return None;
+        }
+        let result = match query {
+            PostsQuery::SubspacePosts {
+                subspace_id,
+                pagination,
+            } => {
+                let posts = self
+                    .posts
+                    .iter()
+                    .filter(|post| post.subspace_id == *subspace_id)
+                    .cloned()
+                    .collect();
+                page(posts, pagination.as_ref()).and_then(|(posts, pagination)| {
+                    response(&QuerySubspacePostsResponse { posts, pagination })
+                })
+            }
+            PostsQuery::SectionPosts {
+                subspace_id,
+                section_id,
+                pagination,
+            } => {
+                let posts = self
+                    .posts
+                    .iter()
+                    .filter(|post| {
+                        post.subspace_id == *subspace_id && post.section_id == *section_id
+                    })
+                    .cloned()
+                    .collect();
+                page(posts, pagination.as_ref()).and_then(|(posts, pagination)| {
+                    response(&QuerySectionPostsResponse { posts, pagination })
+                })
+            }
+            PostsQuery::Post {
+                subspace_id,
+                post_id,
+            } => self
+                .post(*subspace_id, *post_id)
+                .and_then(|post| response(&QueryPostResponse { post: post.clone() })),
+            PostsQuery::PostAttachments {
+                subspace_id,
+                post_id,
+                pagination,
+            } => self.post(*subspace_id, *post_id).and_then(|_| {
+                let attachments = self
+                    .attachments
+                    .iter()
+                    .filter(|attachment| {
+                        attachment.subspace_id == *subspace_id && attachment.post_id == *post_id
+                    })
+                    .cloned()
+                    .collect();
+                let (attachments, pagination) = page(attachments, pagination.as_ref())?;
+                response(&QueryPostAttachmentsResponse {
+                    attachments,
+                    pagination,
+                })
+            }),
+            PostsQuery::PollAnswers {
+                subspace_id,
+                post_id,
+                poll_id,
+                user,
+                pagination,
+            } => self.post(*subspace_id, *post_id).and_then(|_| {
+                let answers = self
+                    .poll_answers
+                    .iter()
+                    .filter(|answer| {
+                        answer.subspace_id == *subspace_id
+                            && answer.post_id == *post_id
+                            && answer.poll_id == *poll_id
+                    })
+                    .filter(|answer| match user {
+                        Some(user) => answer.user == *user,
+                        None => true,
+                    })
+                    .cloned()
+                    .collect();
+                let (answers, pagination) = page(answers, pagination.as_ref())?;
+                response(&QueryPollAnswersResponse {
+                    answers,
+                    pagination,
+                })
+            }),
+        };
+        Some(into_contract_result(result))
+    }
+
+    /// Answers the given query with the stored relationships.
+    /// Returns `None` if the store is empty or if the query can't be answered with its objects.
+    #[cfg(feature = "relationships")]
+    pub fn relationships_query_response(
+        &self,
+        query: &RelationshipsQuery,
+    ) -> Option<ContractResult<Binary>> {
+        if self.is_empty() {
+            return None;
+        }
+        let result = match query {
+            RelationshipsQuery::Relationships {
+                subspace_id,
+                user,
+                counterparty,
+                pagination,
+            } => {
+                // As on chain, the counterparty is only considered when the user is provided.
+                let relationships = self
+                    .relationships
+                    .iter()
+                    .filter(|relationship| relationship.subspace_id == *subspace_id)
+                    .filter(|relationship| match (user, counterparty) {
+                        (Some(user), Some(counterparty)) => {
+                            relationship.creator == *user
+                                && relationship.counterparty == *counterparty
+                        }
+                        (Some(user), None) => relationship.creator == *user,
+                        _ => true,
+                    })
+                    .cloned()
+                    .collect();
+                page(relationships, pagination.as_ref()).and_then(|(relationships, pagination)| {
+                    response(&QueryRelationshipsResponse {
+                        relationships,
+                        pagination,
+                    })
+                })
+            }
+            _ => return None,
+        };
+        Some(into_contract_result(result))
+    }
+
+    /// Answers the given query with the stored reactions.
+    /// Returns `None` if the store is empty or if the query can't be answered with its objects.
+    #[cfg(feature = "reactions")]
+    pub fn reactions_query_response(
+        &self,
+        query: &ReactionsQuery,
+    ) -> Option<ContractResult<Binary>> {
+        if self.is_empty() {
+            return None;
+        }
+        let result = match query {
+            ReactionsQuery::Reactions {
+                subspace_id,
+                post_id,
+                user,
+                pagination,
+            } => {
+                let reactions = self
+                    .reactions
+                    .iter()
+                    .filter(|reaction| {
+                        reaction.subspace_id == *subspace_id && reaction.post_id == *post_id
+                    })
+                    .filter(|reaction| match user {
+                        Some(user) => reaction.author == *user,
+                        None => true,
+                    })
+                    .cloned()
+                    .collect();
+                page(reactions, pagination.as_ref()).and_then(|(reactions, pagination)| {
+                    response(&QueryReactionsResponse {
+                        reactions,
+                        pagination,
+                    })
+                })
+            }
+            ReactionsQuery::Reaction {
+                subspace_id,
+                post_id,
+                reaction_id,
+            } => self
+                .reactions
+                .iter()
+                .find(|reaction| {
+                    reaction.subspace_id == *subspace_id
+                        && reaction.post_id == *post_id
+                        && reaction.id == *reaction_id
+                })
+                .ok_or_else(|| ChainError::reaction_not_found(*reaction_id))
+                .and_then(|reaction| {
+                    response(&QueryReactionResponse {
+                        reaction: reaction.clone(),
+                    })
+                }),
+            _ => return None,
+        };
+        Some(into_contract_result(result))
+    }
+
+    /// Answers the given query with the stored reports.
+    /// Returns `None` if the store is empty or if the query can't be answered with its objects.
+    #[cfg(feature = "reports")]
+    pub fn reports_query_response(&self, query: &ReportsQuery) -> Option<ContractResult<Binary>> {
+        if self.is_empty() {
+            return None;
+        }
+        let result = match query {
+            ReportsQuery::Reports {
+                subspace_id,
+                target,
+                reporter,
+                pagination,
+            } => {
+                let reports = self
+                    .reports
+                    .iter()
+                    .filter(|report| report.subspace_id == *subspace_id)
+                    .filter(|report| match target {
+                        Some(target) => report.target == *target,
+                        None => true,
+                    })
+                    .filter(|report| match reporter {
+                        Some(reporter) => report.reporter == *reporter,
+                        None => true,
+                    })
+                    .cloned()
+                    .collect();
+                page(reports, pagination.as_ref()).and_then(|(reports, pagination)| {
+                    response(&QueryReportsResponse {
+                        reports,
+                        pagination,
+                    })
+                })
+            }
+            ReportsQuery::Report {
+                subspace_id,
+                report_id,
+            } => self
+                .reports
+                .iter()
+                .find(|report| report.subspace_id == *subspace_id && report.id == *report_id)
+                .ok_or_else(|| ChainError::report_not_found(report_id.u64()))
+                .and_then(|report| {
+                    response(&QueryReportResponse {
+                        report: report.clone(),
+                    })
+                }),
+            _ => return None,
+        };
+        Some(into_contract_result(result))
+    }
+}
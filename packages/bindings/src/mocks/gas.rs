@@ -0,0 +1,227 @@
+//! Contains the [`GasMeter`], used by the [`DesmosKeeper`](crate::mocks::mock_keeper::DesmosKeeper)
+//! and the [`MockDesmosQuerier`](crate::mocks::mock_queriers::MockDesmosQuerier) to estimate the
+//! gas consumed by a contract when interacting with the Desmos modules.
+
+use crate::mocks::errors::ChainError;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// Represents the costs charged by a [`GasMeter`].
+///
+/// The default costs are the ones that the Cosmos SDK charges to read a single object from
+/// the store, plus a flat cost for each message that approximates the writes of a handler.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct GasConfig {
+    /// Gas charged for each query performed toward a Desmos module.
+    pub query_cost: u64,
+    /// Gas charged for each byte of the query responses.
+    pub query_byte_cost: u64,
+    /// Gas charged for each message sent to a Desmos module.
+    pub message_cost: u64,
+}
+
+impl Default for GasConfig {
+    fn default() -> Self {
+        GasConfig {
+            query_cost: 1_000,
+            query_byte_cost: 3,
+            message_cost: 20_000,
+        }
+    }
+}
+
+/// Represents the gas consumed since a [`GasMeter`] has been created or reset.
+///
+/// Since [`GasMeter::measure`] resets the meter, its report only contains the gas consumed
+/// by the measured action. The consumed gas is not attached to the
+/// [`AppResponse`](cw_multi_test::AppResponse) of the messages, so it can only be read
+/// through the meter.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct GasReport {
+    /// Number of queries performed toward the Desmos modules.
+    pub queries: u64,
+    /// Total size in bytes of the query responses.
+    pub query_bytes: u64,
+    /// Number of messages sent to the Desmos modules.
+    pub messages: u64,
+    /// Total gas consumed.
+    pub gas_used: u64,
+}
+
+/// Meter that charges the gas of the queries and messages handled by the mocks it has been given to.
+///
+/// The meter can be cloned, and all the clones share the same consumed gas,
+/// so that a test can keep a clone to read the consumption of the contract under test.
+///
+/// # Example
+/// ```
+/// use cosmwasm_std::{Addr, QuerierWrapper};
+/// use desmos_bindings::mocks::gas::{GasConfig, GasMeter};
+/// use desmos_bindings::mocks::mock_queriers::MockDesmosQuerier;
+/// use desmos_bindings::profiles::querier::ProfilesQuerier;
+/// use desmos_bindings::query::DesmosQuery;
+/// use std::ops::Deref;
+///
+/// let gas_meter = GasMeter::new(GasConfig::default());
+/// let querier = MockDesmosQuerier::default().with_gas_meter(gas_meter.clone());
+/// let wrapper = QuerierWrapper::<DesmosQuery>::new(&querier);
+///
+/// let (_, report) = gas_meter.measure(|| {
+///     ProfilesQuerier::new(wrapper.deref())
+///         .query_profile(Addr::unchecked("user"))
+///         .unwrap();
+/// });
+/// assert_eq!(1, report.queries);
+/// assert!(report.gas_used < 5_000);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct GasMeter {
+    config: GasConfig,
+    /// Maximum gas that can be consumed before the queries and messages start failing.
+    limit: Option<u64>,
+    report: Rc<RefCell<GasReport>>,
+}
+
+impl GasMeter {
+    /// Returns a new [`GasMeter`] that charges the given costs.
+    pub fn new(config: GasConfig) -> Self {
+        GasMeter {
+            config,
+            limit: None,
+            report: Rc::default(),
+        }
+    }
+
+    /// Makes the messages fail with an out of gas error, and the queries panic with it,
+    /// as the chain would do, once the consumed gas exceeds the given limit.
+    pub fn with_limit(mut self, limit: u64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Returns the costs charged by the meter.
+    pub fn config(&self) -> GasConfig {
+        self.config
+    }
+
+    /// Returns the gas consumed since the meter has been created or reset.
+    pub fn report(&self) -> GasReport {
+        *self.report.borrow()
+    }
+
+    /// Forgets all the consumed gas.
+    pub fn reset(&self) {
+        *self.report.borrow_mut() = GasReport::default();
+    }
+
+    /// Resets the meter and runs the given action, returning its result
+    /// along with the gas it consumed.
+    pub fn measure<T>(&self, action: impl FnOnce() -> T) -> (T, GasReport) {
+        self.reset();
+        let result = action();
+        (result, self.report())
+    }
+
+    /// Charges the gas of a query whose response is `response_size` bytes long.
+    pub(crate) fn consume_query(&self, response_size: usize) -> Result<(), ChainError> {
+        let response_size = response_size as u64;
+        let mut report = self.report.borrow_mut();
+        report.queries += 1;
+        report.query_bytes += response_size;
+        self.consume(
+            &mut report,
+            self.config
+                .query_cost
+                .saturating_add(self.config.query_byte_cost.saturating_mul(response_size)),
+            "desmos query",
+        )
+    }
+
+    /// Charges the gas of a query whose response is `response_size` bytes long,
+    /// aborting the execution if the limit has been exceeded.
+    ///
+    /// # Panics
+    /// Panics with an out of gas error if the meter limit has been exceeded.
+    pub(crate) fn charge_query(&self, response_size: usize) {
+        // As cosmwasm-vm does, running out of gas aborts the contract execution,
+        // so that the contract cannot handle the error like a failed query
+        if let Err(error) = self.consume_query(response_size) {
+            panic!("{}", error);
+        }
+    }
+
+    /// Charges the gas of a message.
+    pub(crate) fn consume_message(&self) -> Result<(), ChainError> {
+        let mut report = self.report.borrow_mut();
+        report.messages += 1;
+        self.consume(&mut report, self.config.message_cost, "desmos message")
+    }
+
+    fn consume(&self, report: &mut GasReport, gas: u64, location: &str) -> Result<(), ChainError> {
+        report.gas_used = report.gas_used.saturating_add(gas);
+        match self.limit {
+            Some(limit) if report.gas_used > limit => Err(ChainError::out_of_gas(format!(
+                "out of gas in location: {}; gasWanted: {}, gasUsed: {}",
+                location, limit, report.gas_used
+            ))),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONFIG: GasConfig = GasConfig {
+        query_cost: 100,
+        query_byte_cost: 2,
+        message_cost: 1_000,
+    };
+
+    #[test]
+    fn consumed_gas_is_shared_between_clones() {
+        let gas_meter = GasMeter::new(CONFIG);
+        let clone = gas_meter.clone();
+
+        clone.consume_query(10).unwrap();
+        clone.consume_message().unwrap();
+
+        assert_eq!(
+            GasReport {
+                queries: 1,
+                query_bytes: 10,
+                messages: 1,
+                gas_used: 100 + 2 * 10 + 1_000,
+            },
+            gas_meter.report()
+        );
+    }
+
+    #[test]
+    fn measure_reports_only_the_action_consumption() {
+        let gas_meter = GasMeter::new(CONFIG);
+        gas_meter.consume_message().unwrap();
+
+        let (_, report) = gas_meter.measure(|| gas_meter.consume_query(0).unwrap());
+
+        assert_eq!(1, report.queries);
+        assert_eq!(0, report.messages);
+        assert_eq!(100, report.gas_used);
+    }
+
+    #[test]
+    fn exceeding_the_limit_returns_out_of_gas() {
+        let gas_meter = GasMeter::new(CONFIG).with_limit(1_100);
+
+        gas_meter.consume_message().unwrap();
+        gas_meter.consume_query(0).unwrap();
+        let error = gas_meter.consume_query(0).unwrap_err();
+
+        assert_eq!(11, error.code);
+        assert_eq!(
+            "out of gas in location: desmos query; gasWanted: 1100, gasUsed: 1200: out of gas",
+            error.to_string()
+        );
+    }
+}
@@ -1,18 +1,20 @@
 //! Contains integration test utils for desmos custom modules.
 
 #![cfg(not(tarpaulin_include))]
-use crate::mocks::mock_keeper::DesmosKeeper;
+use crate::mocks::mock_keeper::{DesmosGenesis, DesmosKeeper, DesmosSudoMsg};
+use crate::mocks::world::DesmosWorld;
 use crate::msg::DesmosMsg;
 use crate::query::DesmosQuery;
+use anyhow::Result as AnyResult;
 use cosmwasm_std::testing::{MockApi, MockStorage};
 use cosmwasm_std::{Addr, Api, Empty, Storage};
 use cw_multi_test::{
-    App, BankKeeper, BasicAppBuilder, FailingDistribution, FailingModule, FailingStaking, Module,
-    Router, WasmKeeper,
+    App, AppResponse, BankKeeper, BasicAppBuilder, FailingDistribution, FailingModule,
+    FailingStaking, Module, Router, WasmKeeper,
 };
 
 /// Defines the module trait for desmos app.
-pub trait DesmosModule: Module<ExecT = DesmosMsg, QueryT = DesmosQuery, SudoT = Empty> {}
+pub trait DesmosModule: Module<ExecT = DesmosMsg, QueryT = DesmosQuery> {}
 
 impl DesmosModule for DesmosKeeper {}
 impl DesmosModule for FailingModule<DesmosMsg, DesmosQuery, Empty> {}
@@ -55,45 +57,136 @@ pub fn mock_desmos_app() -> DesmosApp {
         .build(|_, _, _| {})
 }
 
+/// Returns a mock desmos app that rejects the messages whose signer
+/// does not have the required subspace permissions.
+#[cfg(feature = "subspaces")]
+pub fn mock_strict_desmos_app() -> DesmosApp {
+    BasicAppBuilder::<DesmosMsg, DesmosQuery>::new_custom()
+        .with_custom(DesmosKeeper::new().with_strict_permissions())
+        .build(|_, _, _| {})
+}
+
+/// Runs the given [`DesmosSudoMsg`] against the [`DesmosKeeper`] of the app.
+///
+/// This is needed since [`App::sudo`] does not support custom sudo messages.
+/// Like it, no state change is persisted if an error is returned.
+pub fn desmos_sudo(app: &mut DesmosApp, msg: impl Into<DesmosSudoMsg>) -> AnyResult<AppResponse> {
+    let block = app.block_info();
+    app.init_modules(|router, api, storage| {
+        router
+            .custom
+            .sudo(api, storage, &*router, &block, msg.into())
+    })
+}
+
+/// Seeds the [`DesmosKeeper`] of the app with the given [`DesmosGenesis`].
+///
+/// Like [`desmos_sudo`], no state change is persisted if an error is returned.
+pub fn load_desmos_genesis(app: &mut DesmosApp, genesis: DesmosGenesis) -> AnyResult<()> {
+    app.init_modules(|router, _, storage| router.custom.init_genesis(storage, genesis))
+}
+
+/// Returns a mock desmos app whose [`DesmosKeeper`] contains the state of the given [`DesmosWorld`].
+///
+/// Returns an error if the world objects reference objects that do not exist.
+pub fn mock_desmos_app_with_world(world: &DesmosWorld) -> AnyResult<DesmosApp> {
+    let mut app = mock_desmos_app();
+    load_desmos_genesis(&mut app, world.genesis().clone())?;
+    Ok(app)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::{
+        mocks::{
+            errors::ChainError,
+            gas::{GasConfig, GasMeter},
+            mock_queriers::QueriedModule,
+        },
         posts::{
-            mocks::MockPostsQueries, models_query::QueryPostResponse, msg::PostsMsg,
+            mocks::MockPostsQueries,
+            models::{Post, ReplySetting},
+            models_query::QueryPostResponse,
+            msg::PostsMsg,
             querier::PostsQuerier,
         },
-        profiles::{
-            mocks::MockProfilesQueries, models_query::QueryProfileResponse, msg::ProfilesMsg,
-            querier::ProfilesQuerier,
-        },
+        profiles::{mocks::MockProfilesQueries, msg::ProfilesMsg, querier::ProfilesQuerier},
         reactions::{
-            mocks::MockReactionsQueries, models_query::QueryReactionsResponse, msg::ReactionsMsg,
-            querier::ReactionsQuerier,
+            mocks::MockReactionsQueries, models::ReactionValue,
+            models_query::QueryReactionsResponse, msg::ReactionsMsg, querier::ReactionsQuerier,
         },
         relationships::{
             mocks::MockRelationshipsQueries, models_query::QueryRelationshipsResponse,
             msg::RelationshipsMsg, querier::RelationshipsQuerier,
         },
         reports::{
-            mocks::MockReportsQueries, models_query::QueryReportResponse, msg::ReportsMsg,
+            mocks::MockReportsQueries,
+            models::{Report, ReportTarget},
+            models_query::QueryReportResponse,
+            msg::ReportsMsg,
             querier::ReportsQuerier,
         },
         subspaces::{
-            mocks::MockSubspacesQueries, models_query::QuerySubspaceResponse, msg::SubspacesMsg,
-            querier::SubspacesQuerier,
+            mocks::MockSubspacesQueries, models::Subspace, models_query::QuerySubspaceResponse,
+            msg::SubspacesMsg, querier::SubspacesQuerier, query::SubspacesQuery,
         },
     };
+    use cosmwasm_std::Uint64;
     use cw_multi_test::Executor;
+    use std::convert::TryFrom;
     use std::ops::Deref;
     const SENDER: &str = "sender";
 
+    fn create_test_subspace(app: &mut DesmosApp) {
+        app.execute(
+            Addr::unchecked(SENDER),
+            SubspacesMsg::create_subspace(
+                "Test subspace",
+                "Test subspace",
+                Addr::unchecked(SENDER),
+                Addr::unchecked(SENDER),
+                Addr::unchecked(SENDER),
+            )
+            .into(),
+        )
+        .unwrap();
+    }
+
+    fn create_test_post(app: &mut DesmosApp) {
+        app.execute(
+            Addr::unchecked(SENDER),
+            PostsMsg::create_post(
+                1,
+                0,
+                None,
+                Some("Hello"),
+                None,
+                None,
+                Addr::unchecked(SENDER),
+                None,
+                ReplySetting::Everyone,
+                vec![],
+            )
+            .into(),
+        )
+        .unwrap();
+    }
+
     #[test]
     fn execute_profiles_msg_properly() {
         let mut app = mock_desmos_app();
         let result = app.execute(
             Addr::unchecked(SENDER),
-            DesmosMsg::Profiles(ProfilesMsg::delete_profile(Addr::unchecked(SENDER))).into(),
+            DesmosMsg::Profiles(ProfilesMsg::save_profile(
+                "goldrake",
+                Addr::unchecked(SENDER),
+                "Goldrake",
+                "This is Goldrake",
+                "",
+                "",
+            ))
+            .into(),
         );
         assert!(result.is_ok());
     }
@@ -101,11 +194,13 @@ mod tests {
     #[test]
     fn execute_relationships_msg_properly() {
         let mut app = mock_desmos_app();
+        create_test_subspace(&mut app);
         let result = app.execute(
             Addr::unchecked(SENDER),
-            DesmosMsg::Relationships(RelationshipsMsg::unblock_user(
-                Addr::unchecked(SENDER),
+            DesmosMsg::Relationships(RelationshipsMsg::block_user(
                 Addr::unchecked(SENDER),
+                Addr::unchecked("desmos1rfv0f7mx7w9d3jv3h803u38vqym9ygg344asm3"),
+                "test".to_string(),
                 1,
             ))
             .into(),
@@ -118,7 +213,14 @@ mod tests {
         let mut app = mock_desmos_app();
         let result = app.execute(
             Addr::unchecked(SENDER),
-            DesmosMsg::Subspaces(SubspacesMsg::delete_subspace(1, Addr::unchecked(SENDER))).into(),
+            DesmosMsg::Subspaces(SubspacesMsg::create_subspace(
+                "Test subspace",
+                "Test subspace",
+                Addr::unchecked(SENDER),
+                Addr::unchecked(SENDER),
+                Addr::unchecked(SENDER),
+            ))
+            .into(),
         );
         assert!(result.is_ok());
     }
@@ -126,9 +228,22 @@ mod tests {
     #[test]
     fn execute_posts_msg_properly() {
         let mut app = mock_desmos_app();
+        create_test_subspace(&mut app);
         let result = app.execute(
             Addr::unchecked(SENDER),
-            DesmosMsg::Posts(PostsMsg::delete_post(1, 4, Addr::unchecked(SENDER))).into(),
+            DesmosMsg::Posts(PostsMsg::create_post(
+                1,
+                0,
+                None,
+                Some("Hello"),
+                None,
+                None,
+                Addr::unchecked(SENDER),
+                None,
+                ReplySetting::Everyone,
+                vec![],
+            ))
+            .into(),
         );
         assert!(result.is_ok());
     }
@@ -136,9 +251,16 @@ mod tests {
     #[test]
     fn execute_reports_msg_properly() {
         let mut app = mock_desmos_app();
+        create_test_subspace(&mut app);
         let result = app.execute(
             Addr::unchecked(SENDER),
-            DesmosMsg::Reports(ReportsMsg::delete_report(1, 1, Addr::unchecked(SENDER))).into(),
+            DesmosMsg::Reports(ReportsMsg::add_reason(
+                1,
+                "Spam",
+                None::<String>,
+                Addr::unchecked(SENDER),
+            ))
+            .into(),
         );
         assert!(result.is_ok());
     }
@@ -146,12 +268,13 @@ mod tests {
     #[test]
     fn execute_reactions_msg_properly() {
         let mut app = mock_desmos_app();
+        create_test_subspace(&mut app);
         let result = app.execute(
             Addr::unchecked(SENDER),
-            DesmosMsg::Reactions(ReactionsMsg::remove_reaction(
-                1,
-                1,
+            DesmosMsg::Reactions(ReactionsMsg::add_registered_reaction(
                 1,
+                ":smile:",
+                "😄",
                 Addr::unchecked(SENDER),
             ))
             .into(),
@@ -160,38 +283,88 @@ mod tests {
     }
     #[test]
     fn test_profiles_query_properly() {
-        let app = mock_desmos_app();
+        let mut app = mock_desmos_app();
+        let mock_profile = MockProfilesQueries::get_mock_profile();
+        app.execute(
+            mock_profile.account.address.clone(),
+            ProfilesMsg::save_profile(
+                &mock_profile.dtag,
+                mock_profile.account.address.clone(),
+                &mock_profile.nickname,
+                &mock_profile.bio,
+                &mock_profile.pictures.profile,
+                &mock_profile.pictures.cover,
+            )
+            .into(),
+        )
+        .unwrap();
+
         let app_querier = app.wrap();
         let querier = ProfilesQuerier::new(app_querier.deref());
-        let expected = QueryProfileResponse {
-            profile: MockProfilesQueries::get_mock_profile(),
-        };
-        let response = querier.query_profile(Addr::unchecked("")).unwrap();
-        assert_eq!(expected, response)
+        let response = querier
+            .query_profile(mock_profile.account.address.clone())
+            .unwrap();
+        assert_eq!(
+            mock_profile.account.address,
+            response.profile.account.address
+        );
+        assert_eq!(mock_profile.dtag, response.profile.dtag);
+        assert_eq!(mock_profile.nickname, response.profile.nickname);
+        assert_eq!(mock_profile.bio, response.profile.bio);
     }
 
     #[test]
     fn test_subspaces_query_properly() {
-        let app = mock_desmos_app();
+        let mut app = mock_desmos_app();
+        let mock_subspace = MockSubspacesQueries::get_mock_subspace();
+        app.execute(
+            mock_subspace.creator.clone(),
+            SubspacesMsg::create_subspace(
+                &mock_subspace.name,
+                &mock_subspace.description,
+                mock_subspace.treasury.clone(),
+                mock_subspace.owner.clone(),
+                mock_subspace.creator.clone(),
+            )
+            .into(),
+        )
+        .unwrap();
+
         let app_querier = app.wrap();
         let querier = SubspacesQuerier::new(app_querier.deref());
         let response = querier.query_subspace(1).unwrap();
         let expected = QuerySubspaceResponse {
-            subspace: MockSubspacesQueries::get_mock_subspace(),
+            subspace: Subspace {
+                creation_time: response.subspace.creation_time.clone(),
+                ..mock_subspace
+            },
         };
         assert_eq!(expected, response);
     }
 
     #[test]
     fn test_relationships_query_properly() {
-        let app = mock_desmos_app();
+        let mut app = mock_desmos_app();
+        create_test_subspace(&mut app);
+        let mock_relationship = MockRelationshipsQueries::get_mock_relationship();
+        app.execute(
+            mock_relationship.creator.clone(),
+            RelationshipsMsg::create_relationship(
+                mock_relationship.creator.clone(),
+                mock_relationship.counterparty.clone(),
+                1,
+            )
+            .into(),
+        )
+        .unwrap();
+
         let app_querier = app.wrap();
         let querier = RelationshipsQuerier::new(app_querier.deref());
         let response = querier
             .query_relationships(
                 1,
-                Some(Addr::unchecked("")),
-                Some(Addr::unchecked("")),
+                Some(mock_relationship.creator),
+                Some(mock_relationship.counterparty),
                 None,
             )
             .unwrap();
@@ -204,19 +377,57 @@ mod tests {
 
     #[test]
     fn test_posts_query_properly() {
-        let app = mock_desmos_app();
+        let mut app = mock_desmos_app();
+        create_test_subspace(&mut app);
+        let mock_post = MockPostsQueries::get_mocked_post(1u64.into(), 1u64.into());
+        app.execute(
+            mock_post.author.clone(),
+            PostsMsg::create_post(
+                1,
+                mock_post.section_id,
+                None,
+                None,
+                None,
+                None,
+                mock_post.author.clone(),
+                None,
+                mock_post.reply_settings.clone(),
+                vec![],
+            )
+            .into(),
+        )
+        .unwrap();
+
         let app_querier = app.wrap();
         let querier = PostsQuerier::new(app_querier.deref());
         let response = querier.query_post(1, 1).unwrap();
         let expected = QueryPostResponse {
-            post: MockPostsQueries::get_mocked_post(1u64.into(), 1u64.into()),
+            post: Post {
+                creation_date: response.post.creation_date.clone(),
+                ..mock_post
+            },
         };
         assert_eq!(expected, response)
     }
 
     #[test]
     fn test_reactions_query_properly() {
-        let app = mock_desmos_app();
+        let mut app = mock_desmos_app();
+        create_test_subspace(&mut app);
+        create_test_post(&mut app);
+        let mock_reaction = MockReactionsQueries::get_mock_reaction();
+        app.execute(
+            mock_reaction.author.clone(),
+            ReactionsMsg::add_reaction(
+                1,
+                1,
+                ReactionValue::try_from(mock_reaction.value).unwrap(),
+                mock_reaction.author,
+            )
+            .into(),
+        )
+        .unwrap();
+
         let app_querier = app.wrap();
         let querier = ReactionsQuerier::new(app_querier.deref());
         let response = querier.query_reactions(1, 1, None, None).unwrap();
@@ -229,12 +440,39 @@ mod tests {
 
     #[test]
     fn test_reports_query_properly() {
-        let app = mock_desmos_app();
+        let mut app = mock_desmos_app();
+        create_test_subspace(&mut app);
+        for title in ["First reason", "Second reason"] {
+            app.execute(
+                Addr::unchecked(SENDER),
+                ReportsMsg::add_reason(1, title, None::<String>, Addr::unchecked(SENDER)).into(),
+            )
+            .unwrap();
+        }
+        let mock_report = MockReportsQueries::get_mocked_report(&1u64.into());
+        app.execute(
+            mock_report.reporter.clone(),
+            ReportsMsg::create_report(
+                1,
+                vec![1, 2],
+                mock_report.message.clone(),
+                mock_report.reporter.clone(),
+                ReportTarget::try_from(mock_report.target.clone()).unwrap(),
+            )
+            .into(),
+        )
+        .unwrap();
+
         let app_querier = app.wrap();
         let querier = ReportsQuerier::new(app_querier.deref());
         let response = querier.query_report(1, 1).unwrap();
         let expected = QueryReportResponse {
-            report: MockReportsQueries::get_mocked_report(&1u64.into()),
+            report: Report {
+                id: 1u64.into(),
+                reasons_ids: vec![1, 2],
+                creation_date: response.report.creation_date.clone(),
+                ..mock_report
+            },
         };
         assert_eq!(expected, response)
     }
@@ -257,4 +495,247 @@ mod tests {
         let result = querier.query_profile(Addr::unchecked(""));
         assert!(result.is_err())
     }
+
+    #[test]
+    fn missing_objects_queries_return_not_found() {
+        let app = mock_desmos_app();
+        let wrapper = app.wrap();
+
+        let error = ProfilesQuerier::new(wrapper.deref())
+            .query_profile(Addr::unchecked(SENDER))
+            .unwrap_err();
+        assert!(error
+            .to_string()
+            .contains(&ChainError::profile_not_found(SENDER).redacted()));
+
+        let error = PostsQuerier::new(wrapper.deref())
+            .query_post(1, 1)
+            .unwrap_err();
+        assert!(error
+            .to_string()
+            .contains(&ChainError::post_not_found(1, 1).redacted()));
+    }
+
+    #[test]
+    fn injected_query_errors_are_returned() {
+        let mut app = custom_desmos_app(
+            DesmosKeeper::new()
+                .with_query_error(
+                    SubspacesQuery::Subspace {
+                        subspace_id: Uint64::new(1),
+                    },
+                    ChainError::unauthorized("subspace is private"),
+                )
+                .with_module_error(
+                    QueriedModule::Posts,
+                    ChainError::invalid_request("posts are disabled"),
+                ),
+            |_, _, _| {},
+        );
+        create_test_subspace(&mut app);
+        let wrapper = app.wrap();
+
+        let subspaces_querier = SubspacesQuerier::new(wrapper.deref());
+        let error = subspaces_querier.query_subspace(1).unwrap_err();
+        assert!(error
+            .to_string()
+            .contains(&ChainError::unauthorized("subspace is private").redacted()));
+        assert!(subspaces_querier.query_subspaces(None).is_ok());
+
+        let error = PostsQuerier::new(wrapper.deref())
+            .query_subspace_posts(1, None)
+            .unwrap_err();
+        assert!(error
+            .to_string()
+            .contains(&ChainError::invalid_request("posts are disabled").redacted()));
+    }
+
+    /// Messages of the contract used to test the submessages rollback.
+    #[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
+    #[serde(rename_all = "snake_case")]
+    enum RollbackMsg {
+        /// Runs [`RollbackMsg::CreateSubspaceWithSection`] inside a submessage,
+        /// handling its failure.
+        Dispatch { parent_id: u32 },
+        /// Creates a subspace and a section inside it, under the given parent.
+        CreateSubspaceWithSection { parent_id: u32 },
+    }
+
+    fn store_rollback_contract(app: &mut DesmosApp) -> Addr {
+        use cosmwasm_std::{
+            to_binary, Binary, Deps, DepsMut, Env, MessageInfo, Reply, Response, StdError,
+            StdResult, SubMsg, WasmMsg,
+        };
+        use cw_multi_test::ContractWrapper;
+
+        fn execute(
+            _: DepsMut<DesmosQuery>,
+            env: Env,
+            _: MessageInfo,
+            msg: RollbackMsg,
+        ) -> StdResult<Response<DesmosMsg>> {
+            let contract = env.contract.address;
+            match msg {
+                RollbackMsg::Dispatch { parent_id } => {
+                    let msg = WasmMsg::Execute {
+                        contract_addr: contract.to_string(),
+                        msg: to_binary(&RollbackMsg::CreateSubspaceWithSection { parent_id })?,
+                        funds: vec![],
+                    };
+                    Ok(Response::new().add_submessage(SubMsg::reply_on_error(msg, 1)))
+                }
+                RollbackMsg::CreateSubspaceWithSection { parent_id } => Ok(Response::new()
+                    .add_message(SubspacesMsg::create_subspace(
+                        "Test subspace",
+                        "",
+                        contract.clone(),
+                        contract.clone(),
+                        contract.clone(),
+                    ))
+                    .add_message(SubspacesMsg::create_section(
+                        1,
+                        "Test section",
+                        None,
+                        Some(parent_id),
+                        contract,
+                    ))),
+            }
+        }
+
+        fn instantiate(
+            _: DepsMut<DesmosQuery>,
+            _: Env,
+            _: MessageInfo,
+            _: Empty,
+        ) -> StdResult<Response<DesmosMsg>> {
+            Ok(Response::new())
+        }
+
+        fn query(_: Deps<DesmosQuery>, _: Env, _: Empty) -> StdResult<Binary> {
+            Err(StdError::generic_err("not supported"))
+        }
+
+        fn reply(_: DepsMut<DesmosQuery>, _: Env, reply: Reply) -> StdResult<Response<DesmosMsg>> {
+            Ok(Response::new().add_attribute("rolled_back", reply.result.is_err().to_string()))
+        }
+
+        let code_id = app.store_code(Box::new(
+            ContractWrapper::new(execute, instantiate, query).with_reply(reply),
+        ));
+        app.instantiate_contract(
+            code_id,
+            Addr::unchecked(SENDER),
+            &Empty {},
+            &[],
+            "rollback",
+            None,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn failed_submessage_changes_are_rolled_back() {
+        let mut app = mock_desmos_app();
+        let contract = store_rollback_contract(&mut app);
+
+        // The section parent does not exist, so the App must revert the subspace creation too
+        let response = app
+            .execute_contract(
+                Addr::unchecked(SENDER),
+                contract.clone(),
+                &RollbackMsg::Dispatch { parent_id: 1 },
+                &[],
+            )
+            .unwrap();
+        assert!(response
+            .has_event(&cosmwasm_std::Event::new("wasm").add_attribute("rolled_back", "true")));
+        let wrapper = app.wrap();
+        let error = SubspacesQuerier::new(wrapper.deref())
+            .query_subspace(1)
+            .unwrap_err();
+        assert!(error
+            .to_string()
+            .contains(&ChainError::subspace_not_found(1).redacted()));
+
+        app.execute_contract(
+            Addr::unchecked(SENDER),
+            contract,
+            &RollbackMsg::Dispatch { parent_id: 0 },
+            &[],
+        )
+        .unwrap();
+        let wrapper = app.wrap();
+        let querier = SubspacesQuerier::new(wrapper.deref());
+        assert_eq!(
+            Uint64::new(1),
+            querier.query_subspace(1).unwrap().subspace.id
+        );
+        assert_eq!(1, querier.query_section(1, 1).unwrap().section.id);
+    }
+
+    #[test]
+    fn gas_is_charged_for_messages_and_queries() {
+        let gas_meter = GasMeter::new(GasConfig {
+            query_cost: 1_000,
+            query_byte_cost: 3,
+            message_cost: 20_000,
+        });
+        let mut app = custom_desmos_app(
+            DesmosKeeper::new().with_gas_meter(gas_meter.clone()),
+            |_, _, _| {},
+        );
+
+        let (_, report) = gas_meter.measure(|| create_test_subspace(&mut app));
+        assert_eq!(1, report.messages);
+        assert_eq!(0, report.queries);
+        assert_eq!(20_000, report.gas_used);
+
+        let wrapper = app.wrap();
+        let (response, report) =
+            gas_meter.measure(|| SubspacesQuerier::new(wrapper.deref()).query_subspace(1));
+        let response_size = cosmwasm_std::to_binary(&response.unwrap()).unwrap().len() as u64;
+        assert_eq!(1, report.queries);
+        assert_eq!(response_size, report.query_bytes);
+        assert_eq!(1_000 + 3 * response_size, report.gas_used);
+    }
+
+    #[test]
+    fn messages_exceeding_the_gas_limit_fail() {
+        let gas_meter = GasMeter::new(GasConfig::default()).with_limit(30_000);
+        let mut app = custom_desmos_app(
+            DesmosKeeper::new().with_gas_meter(gas_meter.clone()),
+            |_, _, _| {},
+        );
+        create_test_subspace(&mut app);
+
+        let error = app
+            .execute(
+                Addr::unchecked(SENDER),
+                SubspacesMsg::create_subspace(
+                    "Second subspace",
+                    "",
+                    Addr::unchecked(SENDER),
+                    Addr::unchecked(SENDER),
+                    Addr::unchecked(SENDER),
+                )
+                .into(),
+            )
+            .unwrap_err();
+        assert!(error.root_cause().to_string().contains("out of gas"));
+        gas_meter.reset();
+        let wrapper = app.wrap();
+        assert!(SubspacesQuerier::new(wrapper.deref())
+            .query_subspace(2)
+            .is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "out of gas in location: desmos query")]
+    fn queries_exceeding_the_gas_limit_abort() {
+        let gas_meter = GasMeter::new(GasConfig::default()).with_limit(500);
+        let app = custom_desmos_app(DesmosKeeper::new().with_gas_meter(gas_meter), |_, _, _| {});
+
+        let wrapper = app.wrap();
+        let _ = SubspacesQuerier::new(wrapper.deref()).query_subspace(1);
+    }
 }
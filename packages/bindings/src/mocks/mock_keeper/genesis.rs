@@ -0,0 +1,552 @@
+//! Contains the [`DesmosGenesis`], a document shaped like the genesis state of the Desmos
+//! custom modules that can be used to seed the [`DesmosKeeper`] with a realistic world.
+
+use crate::mocks::mock_keeper::{transaction::atomically, DesmosKeeper};
+#[cfg(feature = "posts")]
+use crate::posts::models::{Attachment, Post, UserAnswer};
+#[cfg(feature = "profiles")]
+use crate::profiles::{
+    models_app_links::ApplicationLink, models_chain_links::ChainLink,
+    models_dtag_requests::DtagTransferRequest, models_profile::Profile,
+};
+#[cfg(feature = "reactions")]
+use crate::reactions::models::{Reaction, RegisteredReaction, SubspaceReactionsParams};
+#[cfg(feature = "relationships")]
+use crate::relationships::models::{Relationship, UserBlock};
+#[cfg(feature = "reports")]
+use crate::reports::models::{Reason, Report};
+#[cfg(feature = "subspaces")]
+use crate::subspaces::models::{Permission, Section, Subspace, UserGroup};
+use anyhow::Result as AnyResult;
+use cosmwasm_std::{from_slice, Storage};
+#[cfg(feature = "subspaces")]
+use cosmwasm_std::{Addr, Uint64};
+use serde::{Deserialize, Serialize};
+
+/// Genesis state of the x/profiles module.
+#[cfg(feature = "profiles")]
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+#[serde(rename_all = "snake_case", default)]
+pub struct ProfilesGenesis {
+    /// Profiles of the users, that on chain are stored inside the x/auth module accounts.
+    pub profiles: Vec<Profile>,
+    /// Pending DTag transfer requests.
+    pub dtag_transfer_requests: Vec<DtagTransferRequest>,
+    /// Chain links of the users.
+    pub chain_links: Vec<ChainLink>,
+    /// Application links of the users.
+    pub application_links: Vec<ApplicationLink>,
+}
+
+/// Genesis state of the x/subspaces module.
+#[cfg(feature = "subspaces")]
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+#[serde(rename_all = "snake_case", default)]
+pub struct SubspacesGenesis {
+    /// Existing subspaces.
+    /// Their root section and default user group are created if not provided.
+    pub subspaces: Vec<Subspace>,
+    /// Sections of the subspaces.
+    pub sections: Vec<Section>,
+    /// User groups of the subspaces.
+    pub user_groups: Vec<UserGroup>,
+    /// Members of the user groups.
+    pub user_groups_members: Vec<UserGroupMemberEntry>,
+    /// Permissions set to the users.
+    pub user_permissions: Vec<UserPermissionEntry>,
+}
+
+/// Tells that a user is a member of a user group.
+#[cfg(feature = "subspaces")]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub struct UserGroupMemberEntry {
+    /// Id of the subspace inside which the group exists.
+    pub subspace_id: Uint64,
+    /// Id of the group.
+    pub group_id: u32,
+    /// Address of the member.
+    pub user: Addr,
+}
+
+/// Contains the permissions set to a user inside a subspace section.
+#[cfg(feature = "subspaces")]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub struct UserPermissionEntry {
+    /// Id of the subspace inside which the permissions are set.
+    pub subspace_id: Uint64,
+    /// Id of the section inside which the permissions are set.
+    pub section_id: u32,
+    /// Address of the user.
+    pub user: Addr,
+    /// Permissions set to the user.
+    pub permissions: Vec<Permission>,
+}
+
+/// Genesis state of the x/posts module.
+#[cfg(feature = "posts")]
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+#[serde(rename_all = "snake_case", default)]
+pub struct PostsGenesis {
+    /// Existing posts.
+    pub posts: Vec<Post>,
+    /// Attachments of the posts.
+    pub attachments: Vec<Attachment>,
+    /// Answers given by the users to the posts polls.
+    pub user_answers: Vec<UserAnswer>,
+}
+
+/// Genesis state of the x/relationships module.
+#[cfg(feature = "relationships")]
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+#[serde(rename_all = "snake_case", default)]
+pub struct RelationshipsGenesis {
+    /// Existing relationships.
+    pub relationships: Vec<Relationship>,
+    /// Existing user blocks.
+    pub blocks: Vec<UserBlock>,
+}
+
+/// Genesis state of the x/reactions module.
+#[cfg(feature = "reactions")]
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+#[serde(rename_all = "snake_case", default)]
+pub struct ReactionsGenesis {
+    /// Reactions registered inside the subspaces.
+    pub registered_reactions: Vec<RegisteredReaction>,
+    /// Reactions added to the posts.
+    pub reactions: Vec<Reaction>,
+    /// Reactions params of the subspaces.
+    pub subspaces_params: Vec<SubspaceReactionsParams>,
+}
+
+/// Genesis state of the x/reports module.
+#[cfg(feature = "reports")]
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+#[serde(rename_all = "snake_case", default)]
+pub struct ReportsGenesis {
+    /// Reasons defined inside the subspaces.
+    pub reasons: Vec<Reason>,
+    /// Existing reports.
+    pub reports: Vec<Report>,
+}
+
+/// Document shaped like the `app_state` of a Desmos genesis file, containing the state of
+/// the custom modules. Missing modules are considered empty and unknown fields are ignored,
+/// so that the state exported from a chain can be used as is.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+#[serde(rename_all = "snake_case", default)]
+pub struct DesmosGenesis {
+    /// Genesis state of the x/profiles module.
+    #[cfg(feature = "profiles")]
+    pub profiles: ProfilesGenesis,
+    /// Genesis state of the x/subspaces module.
+    #[cfg(feature = "subspaces")]
+    pub subspaces: SubspacesGenesis,
+    /// Genesis state of the x/posts module.
+    #[cfg(feature = "posts")]
+    pub posts: PostsGenesis,
+    /// Genesis state of the x/relationships module.
+    #[cfg(feature = "relationships")]
+    pub relationships: RelationshipsGenesis,
+    /// Genesis state of the x/reactions module.
+    #[cfg(feature = "reactions")]
+    pub reactions: ReactionsGenesis,
+    /// Genesis state of the x/reports module.
+    #[cfg(feature = "reports")]
+    pub reports: ReportsGenesis,
+}
+
+impl DesmosGenesis {
+    /// Parses a [`DesmosGenesis`] from the given JSON document.
+    pub fn from_json(json: &str) -> AnyResult<Self> {
+        Ok(from_slice(json.as_bytes())?)
+    }
+}
+
+impl DesmosKeeper {
+    /// Stores the given [`DesmosGenesis`] inside the Desmos modules state.
+    /// The modules are initialized in dependency order, so that the objects
+    /// referencing others (e.g. posts referencing subspaces) can be validated.
+    /// If the genesis is not valid no object is stored.
+    #[cfg_attr(
+        not(any(
+            feature = "profiles",
+            feature = "subspaces",
+            feature = "posts",
+            feature = "relationships",
+            feature = "reactions",
+            feature = "reports"
+        )),
+        allow(unused_variables)
+    )]
+    pub fn init_genesis(&self, storage: &mut dyn Storage, genesis: DesmosGenesis) -> AnyResult<()> {
+        atomically(storage, |storage| {
+            #[cfg(feature = "subspaces")]
+            self.init_subspaces_genesis(storage, genesis.subspaces)?;
+            #[cfg(feature = "profiles")]
+            self.init_profiles_genesis(storage, genesis.profiles)?;
+            #[cfg(feature = "relationships")]
+            self.init_relationships_genesis(storage, genesis.relationships)?;
+            #[cfg(feature = "posts")]
+            self.init_posts_genesis(storage, genesis.posts)?;
+            #[cfg(feature = "reactions")]
+            self.init_reactions_genesis(storage, genesis.reactions)?;
+            #[cfg(feature = "reports")]
+            self.init_reports_genesis(storage, genesis.reports)?;
+            Ok(())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DesmosGenesis;
+    use crate::mocks::mock_apps::{load_desmos_genesis, mock_desmos_app, DesmosApp};
+    use crate::posts::{models::ReplySetting, msg::PostsMsg, querier::PostsQuerier};
+    use crate::profiles::querier::ProfilesQuerier;
+    use crate::reactions::{models::ReactionValue, querier::ReactionsQuerier};
+    use crate::relationships::querier::RelationshipsQuerier;
+    use crate::reports::{
+        models::{RawReportTarget, ReportTarget},
+        querier::ReportsQuerier,
+    };
+    use crate::subspaces::{models::Permission, msg::SubspacesMsg, querier::SubspacesQuerier};
+    use cosmwasm_std::{Addr, Uint64};
+    use cw_multi_test::Executor;
+    use std::convert::TryFrom;
+    use std::ops::Deref;
+
+    const OWNER: &str = "desmos1nwp8gxrnmrsrzjdhvk47vvmthzxjtphgxp5ftc";
+    const MODERATOR: &str = "desmos1rfv0f7mx7w9d3jv3h803u38vqym9ygg344asm3";
+    const USER: &str = "desmos1jsdjhs8ggcs7ua0mxjz5e9q5q3j4fe7u4nqvt3";
+
+    const GENESIS: &str = r#"{
+        "auth": { "accounts": [] },
+        "subspaces": {
+            "subspaces": [{
+                "id": "1",
+                "name": "Test subspace",
+                "description": "",
+                "treasury": "desmos1nwp8gxrnmrsrzjdhvk47vvmthzxjtphgxp5ftc",
+                "owner": "desmos1nwp8gxrnmrsrzjdhvk47vvmthzxjtphgxp5ftc",
+                "creator": "desmos1nwp8gxrnmrsrzjdhvk47vvmthzxjtphgxp5ftc",
+                "creation_time": "2022-01-01T00:00:00Z"
+            }],
+            "sections": [{
+                "subspace_id": "1",
+                "id": 1,
+                "parent_id": 0,
+                "name": "News",
+                "description": ""
+            }],
+            "user_groups": [{
+                "subspace_id": "1",
+                "section_id": 1,
+                "id": 1,
+                "name": "Moderators",
+                "description": "",
+                "permissions": ["MODERATE_CONTENT"]
+            }],
+            "user_groups_members": [{
+                "subspace_id": "1",
+                "group_id": 1,
+                "user": "desmos1rfv0f7mx7w9d3jv3h803u38vqym9ygg344asm3"
+            }],
+            "user_permissions": [{
+                "subspace_id": "1",
+                "section_id": 0,
+                "user": "desmos1jsdjhs8ggcs7ua0mxjz5e9q5q3j4fe7u4nqvt3",
+                "permissions": ["WRITE_CONTENT"]
+            }]
+        },
+        "profiles": {
+            "profiles": [{
+                "account": {
+                    "@type": "/cosmos.auth.v1beta1.BaseAccount",
+                    "address": "desmos1nwp8gxrnmrsrzjdhvk47vvmthzxjtphgxp5ftc",
+                    "pub_key": { "@type": "/cosmos.crypto.secp256k1.PubKey", "key": "" },
+                    "account_number": "0",
+                    "sequence": "0"
+                },
+                "dtag": "Owner",
+                "nickname": "Subspace owner",
+                "bio": "",
+                "pictures": { "profile": "", "cover": "" },
+                "creation_date": "2022-01-01T00:00:00Z"
+            }],
+            "dtag_transfer_requests": [{
+                "dtag_to_trade": "Owner",
+                "sender": "desmos1jsdjhs8ggcs7ua0mxjz5e9q5q3j4fe7u4nqvt3",
+                "receiver": "desmos1nwp8gxrnmrsrzjdhvk47vvmthzxjtphgxp5ftc"
+            }]
+        },
+        "relationships": {
+            "relationships": [{
+                "creator": "desmos1jsdjhs8ggcs7ua0mxjz5e9q5q3j4fe7u4nqvt3",
+                "counterparty": "desmos1nwp8gxrnmrsrzjdhvk47vvmthzxjtphgxp5ftc",
+                "subspace_id": "1"
+            }],
+            "blocks": []
+        },
+        "posts": {
+            "posts": [{
+                "id": "2",
+                "subspace_id": "1",
+                "section_id": 1,
+                "text": "Hello world",
+                "tags": [],
+                "author": "desmos1nwp8gxrnmrsrzjdhvk47vvmthzxjtphgxp5ftc",
+                "referenced_posts": [],
+                "reply_settings": "REPLY_SETTING_EVERYONE",
+                "creation_date": "2022-01-01T00:00:00Z"
+            }],
+            "attachments": [{
+                "subspace_id": "1",
+                "post_id": "2",
+                "id": 1,
+                "content": {
+                    "@type": "/desmos.posts.v2.Media",
+                    "mime_type": "image/png",
+                    "uri": "https://example.com/image.png"
+                }
+            }]
+        },
+        "reactions": {
+            "registered_reactions": [{
+                "subspace_id": "1",
+                "id": 1,
+                "shorthand_code": ":hello:",
+                "display_value": "https://example.com/hello.png"
+            }],
+            "reactions": [{
+                "subspace_id": "1",
+                "post_id": "2",
+                "id": 1,
+                "value": {
+                    "@type": "/desmos.reactions.v1.RegisteredReactionValue",
+                    "registered_reaction_id": 1
+                },
+                "author": "desmos1jsdjhs8ggcs7ua0mxjz5e9q5q3j4fe7u4nqvt3"
+            }]
+        },
+        "reports": {
+            "reasons": [{ "subspace_id": "1", "id": 1, "title": "Spam" }],
+            "reports": [{
+                "subspace_id": "1",
+                "id": "1",
+                "reasons_ids": [1],
+                "reporter": "desmos1rfv0f7mx7w9d3jv3h803u38vqym9ygg344asm3",
+                "target": { "@type": "/desmos.reports.v1.PostTarget", "post_id": "2" },
+                "creation_date": "2022-01-01T00:00:00Z"
+            }]
+        }
+    }"#;
+
+    fn genesis_app() -> DesmosApp {
+        let mut app = mock_desmos_app();
+        load_desmos_genesis(&mut app, DesmosGenesis::from_json(GENESIS).unwrap()).unwrap();
+        app
+    }
+
+    #[test]
+    fn load_genesis_stores_the_subspaces_state() {
+        let app = genesis_app();
+        let wrapper = app.wrap();
+        let querier = SubspacesQuerier::new(wrapper.deref());
+
+        let subspace = querier.query_subspace(1).unwrap().subspace;
+        assert_eq!("Test subspace", subspace.name);
+        // The root section and the default group are created as on chain
+        assert_eq!(2, querier.query_sections(1, None).unwrap().sections.len());
+        assert_eq!(
+            2,
+            querier
+                .query_user_groups(1, None, None)
+                .unwrap()
+                .groups
+                .len()
+        );
+
+        let moderator_permissions = querier
+            .query_user_permissions(1, Some(1), Addr::unchecked(MODERATOR))
+            .unwrap()
+            .permissions;
+        assert_eq!(vec![Permission::ModerateContent], moderator_permissions);
+        let user_permissions = querier
+            .query_user_permissions(1, Some(1), Addr::unchecked(USER))
+            .unwrap()
+            .permissions;
+        assert_eq!(vec![Permission::Write], user_permissions);
+    }
+
+    #[test]
+    fn load_genesis_stores_the_modules_state() {
+        let app = genesis_app();
+        let wrapper = app.wrap();
+
+        let profiles_querier = ProfilesQuerier::new(wrapper.deref());
+        let profile = profiles_querier
+            .query_profile(Addr::unchecked(OWNER))
+            .unwrap()
+            .profile;
+        assert_eq!("Owner", profile.dtag);
+        let requests = profiles_querier
+            .query_incoming_dtag_transfer_requests(Addr::unchecked(OWNER), None)
+            .unwrap()
+            .requests;
+        assert_eq!(Addr::unchecked(USER), requests[0].sender);
+
+        let relationships = RelationshipsQuerier::new(wrapper.deref())
+            .query_relationships(1, Some(Addr::unchecked(USER)), None, None)
+            .unwrap()
+            .relationships;
+        assert_eq!(Addr::unchecked(OWNER), relationships[0].counterparty);
+
+        let posts_querier = PostsQuerier::new(wrapper.deref());
+        let post = posts_querier.query_post(1, 2).unwrap().post;
+        assert_eq!(Some("Hello world".to_string()), post.text);
+        let attachments = posts_querier
+            .query_post_attachments(1, 2, None)
+            .unwrap()
+            .attachments;
+        assert_eq!(1, attachments.len());
+
+        let reaction = ReactionsQuerier::new(wrapper.deref())
+            .query_reaction(1, 2, 1)
+            .unwrap()
+            .reaction;
+        assert_eq!(
+            ReactionValue::Registered {
+                registered_reaction_id: 1
+            },
+            ReactionValue::try_from(reaction.value).unwrap()
+        );
+
+        let report = ReportsQuerier::new(wrapper.deref())
+            .query_report(1, 1)
+            .unwrap()
+            .report;
+        let expected_target: RawReportTarget = ReportTarget::Post {
+            post_id: Uint64::new(2),
+        }
+        .into();
+        assert_eq!(expected_target, report.target);
+    }
+
+    #[test]
+    fn objects_created_after_genesis_do_not_overwrite_it() {
+        let mut app = genesis_app();
+        app.execute(
+            Addr::unchecked(OWNER),
+            SubspacesMsg::create_subspace(
+                "Other subspace",
+                "",
+                Addr::unchecked(OWNER),
+                Addr::unchecked(OWNER),
+                Addr::unchecked(OWNER),
+            )
+            .into(),
+        )
+        .unwrap();
+        app.execute(
+            Addr::unchecked(OWNER),
+            PostsMsg::create_post(
+                1,
+                1,
+                None,
+                Some("Second post"),
+                None,
+                None,
+                Addr::unchecked(OWNER),
+                None,
+                ReplySetting::Everyone,
+                vec![],
+            )
+            .into(),
+        )
+        .unwrap();
+
+        let wrapper = app.wrap();
+        let subspace = SubspacesQuerier::new(wrapper.deref())
+            .query_subspace(2)
+            .unwrap()
+            .subspace;
+        assert_eq!("Other subspace", subspace.name);
+        let posts_querier = PostsQuerier::new(wrapper.deref());
+        assert_eq!(
+            Some("Hello world".to_string()),
+            posts_querier.query_post(1, 2).unwrap().post.text
+        );
+        assert_eq!(
+            Some("Second post".to_string()),
+            posts_querier.query_post(1, 3).unwrap().post.text
+        );
+    }
+
+    #[test]
+    fn missing_modules_and_unknown_fields_are_ignored() {
+        let genesis =
+            DesmosGenesis::from_json(r#"{ "bank": { "balances": [] }, "relationships": {} }"#)
+                .unwrap();
+        assert_eq!(DesmosGenesis::default(), genesis);
+    }
+
+    #[test]
+    fn genesis_referencing_missing_subspace_fails() {
+        let genesis = DesmosGenesis::from_json(
+            r#"{
+                "relationships": {
+                    "relationships": [{
+                        "creator": "desmos1jsdjhs8ggcs7ua0mxjz5e9q5q3j4fe7u4nqvt3",
+                        "counterparty": "desmos1nwp8gxrnmrsrzjdhvk47vvmthzxjtphgxp5ftc",
+                        "subspace_id": "1"
+                    }]
+                }
+            }"#,
+        )
+        .unwrap();
+        let mut app = mock_desmos_app();
+        let error = load_desmos_genesis(&mut app, genesis).unwrap_err();
+        assert!(error.to_string().ends_with(": not found"));
+    }
+
+    #[test]
+    fn failed_genesis_stores_nothing() {
+        let genesis = DesmosGenesis::from_json(
+            r#"{
+                "profiles": {
+                    "profiles": [{
+                        "account": {
+                            "@type": "/cosmos.auth.v1beta1.BaseAccount",
+                            "address": "desmos1nwp8gxrnmrsrzjdhvk47vvmthzxjtphgxp5ftc",
+                            "pub_key": { "@type": "/cosmos.crypto.secp256k1.PubKey", "key": "" },
+                            "account_number": "0",
+                            "sequence": "0"
+                        },
+                        "dtag": "Owner",
+                        "nickname": "",
+                        "bio": "",
+                        "pictures": { "profile": "", "cover": "" },
+                        "creation_date": "2022-01-01T00:00:00Z"
+                    }]
+                },
+                "relationships": {
+                    "relationships": [{
+                        "creator": "desmos1jsdjhs8ggcs7ua0mxjz5e9q5q3j4fe7u4nqvt3",
+                        "counterparty": "desmos1nwp8gxrnmrsrzjdhvk47vvmthzxjtphgxp5ftc",
+                        "subspace_id": "1"
+                    }]
+                }
+            }"#,
+        )
+        .unwrap();
+        let mut app = mock_desmos_app();
+        load_desmos_genesis(&mut app, genesis).unwrap_err();
+
+        let wrapper = app.wrap();
+        let querier = ProfilesQuerier::new(wrapper.deref());
+        assert!(querier.query_profile(Addr::unchecked(OWNER)).is_err());
+    }
+}
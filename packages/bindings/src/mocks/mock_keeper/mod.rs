@@ -0,0 +1,320 @@
+//! Contains the [`DesmosKeeper`], the mock of the Desmos custom modules used by the [`DesmosApp`](crate::mocks::mock_apps::DesmosApp).
+
+#![cfg(not(tarpaulin_include))]
+pub mod genesis;
+#[cfg(feature = "subspaces")]
+mod permissions;
+#[cfg(feature = "posts")]
+mod posts;
+#[cfg(feature = "profiles")]
+mod profiles;
+#[cfg(feature = "reactions")]
+mod reactions;
+#[cfg(feature = "relationships")]
+mod relationships;
+#[cfg(feature = "reports")]
+mod reports;
+#[cfg(feature = "subspaces")]
+mod subspaces;
+mod time;
+mod transaction;
+mod validation;
+
+use crate::events::MessageEvent;
+use crate::mocks::errors::{redact_query_error, ChainError, QueryErrors};
+use crate::mocks::gas::GasMeter;
+use crate::mocks::mock_queriers::QueriedModule;
+use crate::msg::DesmosMsg;
+use crate::query::DesmosQuery;
+use anyhow::Result as AnyResult;
+use cosmwasm_std::{Addr, Api, Binary, BlockInfo, Event, Querier, Storage};
+use cw_multi_test::{AppResponse, CosmosRouter, Module};
+
+pub use genesis::DesmosGenesis;
+#[cfg(feature = "profiles")]
+pub use profiles::ProfilesSudoMsg;
+#[cfg(feature = "reports")]
+pub use reports::StandardReason;
+
+/// Value used by the chain to tell that a field should not be modified.
+#[cfg(any(feature = "profiles", feature = "subspaces", feature = "posts"))]
+const DO_NOT_MODIFY: &str = "[do-not-modify]";
+
+/// Returns `new_value` unless it is equal to [`DO_NOT_MODIFY`], in that case returns `current`.
+#[cfg(any(feature = "profiles", feature = "subspaces", feature = "posts"))]
+fn updated_field(current: String, new_value: String) -> String {
+    if new_value == DO_NOT_MODIFY {
+        current
+    } else {
+        new_value
+    }
+}
+
+/// Returns the `message` event that the chain emits when executing the given message.
+// Hide these warnings since when we compile the package without any module feature
+// there is no message to handle.
+#[cfg_attr(
+    not(any(
+        feature = "profiles",
+        feature = "subspaces",
+        feature = "relationships",
+        feature = "posts",
+        feature = "reactions",
+        feature = "reports"
+    )),
+    allow(unreachable_code, unused_variables)
+)]
+fn message_event(sender: Addr, msg: &DesmosMsg) -> Event {
+    let (module, action): (&str, &str) = match *msg {
+        #[cfg(feature = "profiles")]
+        DesmosMsg::Profiles(ref msg) => ("profiles", profiles::profiles_msg_type_url(msg)),
+        #[cfg(feature = "subspaces")]
+        DesmosMsg::Subspaces(ref msg) => ("subspaces", subspaces::subspaces_msg_type_url(msg)),
+        #[cfg(feature = "relationships")]
+        DesmosMsg::Relationships(ref msg) => (
+            "relationships",
+            relationships::relationships_msg_type_url(msg),
+        ),
+        #[cfg(feature = "posts")]
+        DesmosMsg::Posts(ref msg) => ("posts", posts::posts_msg_type_url(msg)),
+        #[cfg(feature = "reports")]
+        DesmosMsg::Reports(ref msg) => ("reports", reports::reports_msg_type_url(msg)),
+        #[cfg(feature = "reactions")]
+        DesmosMsg::Reactions(ref msg) => ("reactions", reactions::reactions_msg_type_url(msg)),
+    };
+    MessageEvent {
+        module: module.to_string(),
+        action: action.to_string(),
+        sender,
+    }
+    .into()
+}
+
+/// Makes sure that the id stored inside `next_ids` for the given key comes after `id`,
+/// so that the objects loaded from a genesis are not overwritten by the ones created later.
+#[cfg(any(
+    feature = "subspaces",
+    feature = "posts",
+    feature = "reactions",
+    feature = "reports"
+))]
+fn bump_next_id<'a, K, T>(
+    storage: &mut dyn Storage,
+    next_ids: &cw_storage_plus::Map<'a, K, T>,
+    key: K,
+    id: T,
+) -> cosmwasm_std::StdResult<()>
+where
+    K: cw_storage_plus::PrimaryKey<'a> + Clone,
+    T: Copy
+        + Ord
+        + From<u8>
+        + std::ops::Add<Output = T>
+        + serde::Serialize
+        + serde::de::DeserializeOwned,
+{
+    let next_id = id + T::from(1);
+    let current = next_ids.may_load(storage, key.clone())?.unwrap_or(next_id);
+    next_ids.save(storage, key, &current.max(next_id))?;
+    Ok(())
+}
+
+/// Represents the privileged messages that can be sent to the [`DesmosKeeper`] in order to
+/// simulate the actions that on chain are not triggered by users, like the oracle responses.
+#[derive(Clone, Debug, PartialEq)]
+pub enum DesmosSudoMsg {
+    /// Sudo messages of the x/profiles module.
+    #[cfg(feature = "profiles")]
+    Profiles(ProfilesSudoMsg),
+}
+
+#[cfg(feature = "profiles")]
+impl From<ProfilesSudoMsg> for DesmosSudoMsg {
+    fn from(msg: ProfilesSudoMsg) -> Self {
+        DesmosSudoMsg::Profiles(msg)
+    }
+}
+
+/// Represents the implementation of [`Module`](cw_multi_test::Module) for handling the desmos execution and query messages.
+#[derive(Default)]
+pub struct DesmosKeeper {
+    /// Tells whether the messages signers permissions should be checked against the subspaces ones.
+    strict_permissions: bool,
+    /// Standard reasons defined inside the x/reports module params.
+    #[cfg(feature = "reports")]
+    standard_reasons: Vec<StandardReason>,
+    /// Errors returned in place of the responses of some queries.
+    query_errors: QueryErrors,
+    /// Meter charging the gas of the handled queries and messages, if one has been provided.
+    gas_meter: Option<GasMeter>,
+}
+
+impl DesmosKeeper {
+    /// Returns a new [DesmosKeeper].
+    pub fn new() -> Self {
+        DesmosKeeper::default()
+    }
+
+    /// Enables the strict permissions mode, in which the posts, reactions, reports and subspaces
+    /// messages are rejected if their signer does not have the permissions
+    /// required by the chain inside the subspace.
+    #[cfg(feature = "subspaces")]
+    pub fn with_strict_permissions(mut self) -> Self {
+        self.strict_permissions = true;
+        self
+    }
+
+    /// Makes the given query fail with the provided error, as the chain would do.
+    /// Like on chain, the contract receives the [redacted](ChainError::redacted) error.
+    /// The error is returned only when the query is performed with exactly the same arguments,
+    /// and takes precedence over the errors set with [`Self::with_module_error`].
+    pub fn with_query_error(mut self, query: impl Into<DesmosQuery>, error: ChainError) -> Self {
+        self.query_errors.set_query_error(query.into(), error);
+        self
+    }
+
+    /// Makes all the queries toward the given module fail with the provided error.
+    pub fn with_module_error(mut self, module: QueriedModule, error: ChainError) -> Self {
+        self.query_errors.set_module_error(module, error);
+        self
+    }
+
+    /// Charges the gas of the handled queries and messages to the given meter.
+    ///
+    /// A message that exceeds the meter limit fails with an out of gas error, while a query
+    /// that exceeds it panics with the same error, since the chain aborts the contract execution
+    /// instead of returning the error to the contract. The
+    /// [`MockDesmosQuerier`](crate::mocks::mock_queriers::MockDesmosQuerier) behaves the same way
+    /// when its meter limit is exceeded.
+    ///
+    /// # Example
+    /// ```
+    /// use cosmwasm_std::Addr;
+    /// use cw_multi_test::Executor;
+    /// use desmos_bindings::mocks::gas::{GasConfig, GasMeter};
+    /// use desmos_bindings::mocks::mock_apps::custom_desmos_app;
+    /// use desmos_bindings::mocks::mock_keeper::DesmosKeeper;
+    /// use desmos_bindings::subspaces::msg::SubspacesMsg;
+    ///
+    /// let gas_meter = GasMeter::new(GasConfig::default());
+    /// let keeper = DesmosKeeper::new().with_gas_meter(gas_meter.clone());
+    /// let mut app = custom_desmos_app(keeper, |_, _, _| {});
+    ///
+    /// let creator = Addr::unchecked("desmos1nwp8gxrnmrsrzjdhvk47vvmthzxjtphgxp5ftc");
+    /// let msg =
+    ///     SubspacesMsg::create_subspace("Test", "", creator.clone(), creator.clone(), creator.clone());
+    /// let (result, report) = gas_meter.measure(|| app.execute(creator.clone(), msg.into()));
+    /// result.unwrap();
+    /// assert_eq!(1, report.messages);
+    /// ```
+    pub fn with_gas_meter(mut self, gas_meter: GasMeter) -> Self {
+        self.gas_meter = Some(gas_meter);
+        self
+    }
+}
+
+impl Module for DesmosKeeper {
+    type ExecT = DesmosMsg;
+    type QueryT = DesmosQuery;
+    type SudoT = DesmosSudoMsg;
+
+    // Hide this warning since when we compile the package without any module feature
+    // there is no message to handle.
+    #[cfg_attr(
+        not(any(
+            feature = "profiles",
+            feature = "subspaces",
+            feature = "relationships",
+            feature = "posts",
+            feature = "reactions",
+            feature = "reports"
+        )),
+        allow(unreachable_code, unused_variables)
+    )]
+    fn execute<ExecC, QueryC>(
+        &self,
+        api: &dyn Api,
+        storage: &mut dyn Storage,
+        _router: &dyn CosmosRouter<ExecC = ExecC, QueryC = QueryC>,
+        block: &BlockInfo,
+        sender: Addr,
+        msg: DesmosMsg,
+    ) -> AnyResult<AppResponse> {
+        if let Some(gas_meter) = &self.gas_meter {
+            gas_meter.consume_message()?;
+        }
+        validation::validate_basic(api, &msg)?;
+        #[cfg(feature = "subspaces")]
+        if self.strict_permissions {
+            self.check_permissions(storage, &msg)?;
+        }
+        let message_event = message_event(sender, &msg);
+        // The App already runs each message inside a storage transaction,
+        // so the changes made by a failed message are discarded without a journal
+        let result: AnyResult<AppResponse> = match msg {
+            #[cfg(feature = "profiles")]
+            DesmosMsg::Profiles(msg) => self.handle_profiles_msg(storage, block, msg),
+            #[cfg(feature = "subspaces")]
+            DesmosMsg::Subspaces(msg) => self.handle_subspaces_msg(storage, block, msg),
+            #[cfg(feature = "relationships")]
+            DesmosMsg::Relationships(msg) => self.handle_relationships_msg(storage, msg),
+            #[cfg(feature = "posts")]
+            DesmosMsg::Posts(msg) => self.handle_posts_msg(storage, block, msg),
+            #[cfg(feature = "reports")]
+            DesmosMsg::Reports(msg) => self.handle_reports_msg(storage, block, msg),
+            #[cfg(feature = "reactions")]
+            DesmosMsg::Reactions(msg) => self.handle_reactions_msg(storage, msg),
+        };
+        let mut response = result?;
+        response.events.insert(0, message_event);
+        Ok(response)
+    }
+
+    fn query(
+        &self,
+        _api: &dyn Api,
+        storage: &dyn Storage,
+        _querier: &dyn Querier,
+        block: &BlockInfo,
+        request: DesmosQuery,
+    ) -> AnyResult<Binary> {
+        let result: AnyResult<Binary> = match self.query_errors.find(&request) {
+            Some(error) => Err(error.clone().into()),
+            None => match request {
+                #[cfg(feature = "profiles")]
+                DesmosQuery::Profiles(query) => self.query_profiles(storage, query),
+                #[cfg(feature = "subspaces")]
+                DesmosQuery::Subspaces(query) => self.query_subspaces(storage, query),
+                #[cfg(feature = "relationships")]
+                DesmosQuery::Relationships(query) => self.query_relationships(storage, query),
+                #[cfg(feature = "posts")]
+                DesmosQuery::Posts(query) => self.query_posts(storage, block, query),
+                #[cfg(feature = "reactions")]
+                DesmosQuery::Reactions(query) => self.query_reactions(storage, query),
+                #[cfg(feature = "reports")]
+                DesmosQuery::Reports(query) => self.query_reports(storage, query),
+            },
+        };
+        // As on chain, the contracts receive only the codespace and code of the errors
+        let result = result.map_err(redact_query_error);
+        if let Some(gas_meter) = &self.gas_meter {
+            gas_meter.charge_query(result.as_ref().map_or(0, |response| response.len()));
+        }
+        result
+    }
+
+    #[cfg_attr(not(feature = "profiles"), allow(unused_variables))]
+    fn sudo<ExecC, QueryC>(
+        &self,
+        _api: &dyn Api,
+        storage: &mut dyn Storage,
+        _router: &dyn CosmosRouter<ExecC = ExecC, QueryC = QueryC>,
+        _block: &BlockInfo,
+        msg: DesmosSudoMsg,
+    ) -> AnyResult<AppResponse> {
+        transaction::atomically(storage, |storage| match msg {
+            #[cfg(feature = "profiles")]
+            DesmosSudoMsg::Profiles(msg) => self.handle_profiles_sudo(storage, msg),
+        })
+    }
+}
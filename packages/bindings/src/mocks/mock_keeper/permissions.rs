@@ -0,0 +1,705 @@
+//! Contains the permissions checks that the [`DesmosKeeper`] performs, when the strict
+//! permissions mode is enabled, before handling a message.
+
+use crate::mocks::errors::ChainError;
+use crate::mocks::mock_keeper::{
+    subspaces::{has_permission, is_group_member, load_subspace, load_user_group, ROOT_SECTION_ID},
+    DesmosKeeper,
+};
+use crate::msg::DesmosMsg;
+use crate::subspaces::{models::Permission, msg::SubspacesMsg};
+use anyhow::{bail, Result as AnyResult};
+use cosmwasm_std::{Addr, Storage, Uint64};
+
+#[cfg(feature = "reactions")]
+use crate::reactions::msg::ReactionsMsg;
+#[cfg(feature = "posts")]
+use crate::{mocks::mock_keeper::posts::load_post, posts::msg::PostsMsg};
+#[cfg(feature = "reports")]
+use crate::{mocks::mock_keeper::reports::load_report, reports::msg::ReportsMsg};
+
+/// Returns the id of the section inside which the given post has been created.
+#[cfg(all(feature = "reactions", feature = "posts"))]
+fn content_section(storage: &dyn Storage, subspace_id: Uint64, post_id: Uint64) -> AnyResult<u32> {
+    Ok(load_post(storage, subspace_id.u64(), post_id.u64())?.section_id)
+}
+
+/// Returns the id of the section inside which the given post has been created.
+#[cfg(all(feature = "reactions", not(feature = "posts")))]
+fn content_section(_: &dyn Storage, _: Uint64, _: Uint64) -> AnyResult<u32> {
+    Ok(ROOT_SECTION_ID)
+}
+
+/// Returns an unauthorized error with the given message if the user has none of the
+/// provided permissions inside the given section.
+fn require_permission(
+    storage: &dyn Storage,
+    subspace_id: Uint64,
+    section_id: u32,
+    user: &Addr,
+    permissions: &[Permission],
+    error: &str,
+) -> AnyResult<()> {
+    for permission in permissions {
+        if has_permission(storage, subspace_id.u64(), section_id, user, permission)? {
+            return Ok(());
+        }
+    }
+    bail!(ChainError::unauthorized(error))
+}
+
+impl DesmosKeeper {
+    /// Checks that the signer of the given message has the permissions
+    /// required by the chain to perform it.
+    pub(super) fn check_permissions(
+        &self,
+        storage: &dyn Storage,
+        msg: &DesmosMsg,
+    ) -> AnyResult<()> {
+        match msg {
+            DesmosMsg::Subspaces(msg) => check_subspaces_msg(storage, msg),
+            #[cfg(feature = "posts")]
+            DesmosMsg::Posts(msg) => check_posts_msg(storage, msg),
+            #[cfg(feature = "reactions")]
+            DesmosMsg::Reactions(msg) => check_reactions_msg(storage, msg),
+            #[cfg(feature = "reports")]
+            DesmosMsg::Reports(msg) => check_reports_msg(storage, msg),
+            #[allow(unreachable_patterns)]
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Checks the permissions required to perform a [`SubspacesMsg`].
+fn check_subspaces_msg(storage: &dyn Storage, msg: &SubspacesMsg) -> AnyResult<()> {
+    match msg {
+        SubspacesMsg::CreateSubspace { .. } => Ok(()),
+        SubspacesMsg::EditSubspace {
+            subspace_id,
+            signer,
+            ..
+        } => require_permission(
+            storage,
+            *subspace_id,
+            ROOT_SECTION_ID,
+            signer,
+            &[Permission::EditSubspace],
+            "you cannot edit this subspace",
+        ),
+        SubspacesMsg::DeleteSubspace {
+            subspace_id,
+            signer,
+        } => require_permission(
+            storage,
+            *subspace_id,
+            ROOT_SECTION_ID,
+            signer,
+            &[Permission::DeleteSubspace],
+            "you cannot delete this subspace",
+        ),
+        SubspacesMsg::CreateSection {
+            subspace_id,
+            parent_id,
+            creator,
+            ..
+        } => require_permission(
+            storage,
+            *subspace_id,
+            parent_id.unwrap_or(ROOT_SECTION_ID),
+            creator,
+            &[Permission::ManageSections],
+            "you cannot manage sections within this subspace",
+        ),
+        SubspacesMsg::EditSection {
+            subspace_id,
+            section_id,
+            editor: signer,
+            ..
+        }
+        | SubspacesMsg::DeleteSection {
+            subspace_id,
+            section_id,
+            signer,
+        } => require_permission(
+            storage,
+            *subspace_id,
+            *section_id,
+            signer,
+            &[Permission::ManageSections],
+            "you cannot manage sections within this subspace",
+        ),
+        SubspacesMsg::MoveSection {
+            subspace_id,
+            section_id,
+            new_parent_id,
+            signer,
+        } => {
+            for section_id in [*section_id, *new_parent_id] {
+                require_permission(
+                    storage,
+                    *subspace_id,
+                    section_id,
+                    signer,
+                    &[Permission::ManageSections],
+                    "you cannot manage sections within this subspace",
+                )?;
+            }
+            Ok(())
+        }
+        SubspacesMsg::CreateUserGroup {
+            subspace_id,
+            section_id,
+            default_permissions,
+            creator,
+            ..
+        } => {
+            let section_id = section_id.unwrap_or(ROOT_SECTION_ID);
+            require_permission(
+                storage,
+                *subspace_id,
+                section_id,
+                creator,
+                &[Permission::ManageGroups],
+                "you cannot manage user groups in this subspace",
+            )?;
+            if !default_permissions.is_empty() {
+                require_permission(
+                    storage,
+                    *subspace_id,
+                    section_id,
+                    creator,
+                    &[Permission::SetPermissions],
+                    "you cannot set permissions in this subspace",
+                )?;
+            }
+            Ok(())
+        }
+        SubspacesMsg::EditUserGroup {
+            subspace_id,
+            group_id,
+            signer,
+            ..
+        }
+        | SubspacesMsg::DeleteUserGroup {
+            subspace_id,
+            group_id,
+            signer,
+        } => {
+            let group = load_user_group(storage, subspace_id.u64(), *group_id)?;
+            require_permission(
+                storage,
+                *subspace_id,
+                group.section_id.unwrap_or(ROOT_SECTION_ID),
+                signer,
+                &[Permission::ManageGroups],
+                "you cannot manage user groups in this subspace",
+            )
+        }
+        SubspacesMsg::MoveUserGroup {
+            subspace_id,
+            group_id,
+            new_section_id,
+            signer,
+        } => {
+            let group = load_user_group(storage, subspace_id.u64(), *group_id)?;
+            for section_id in [group.section_id.unwrap_or(ROOT_SECTION_ID), *new_section_id] {
+                require_permission(
+                    storage,
+                    *subspace_id,
+                    section_id,
+                    signer,
+                    &[Permission::ManageGroups],
+                    "you cannot manage user groups in this subspace",
+                )?;
+            }
+            Ok(())
+        }
+        SubspacesMsg::SetUserGroupPermissions {
+            subspace_id,
+            group_id,
+            signer,
+            ..
+        } => {
+            let group = load_user_group(storage, subspace_id.u64(), *group_id)?;
+            let section_id = group.section_id.unwrap_or(ROOT_SECTION_ID);
+            require_permission(
+                storage,
+                *subspace_id,
+                section_id,
+                signer,
+                &[Permission::SetPermissions],
+                "you cannot manage permissions in this subspace",
+            )?;
+            // Only the subspace owner can edit the permissions of a group they are part of
+            if is_group_member(storage, &group, signer)
+                && load_subspace(storage, subspace_id.u64())?.owner != *signer
+            {
+                bail!(ChainError::unauthorized(
+                    "cannot set the permissions for a group that you are part of"
+                ));
+            }
+            Ok(())
+        }
+        SubspacesMsg::AddUserToUserGroup {
+            subspace_id,
+            group_id,
+            signer,
+            ..
+        }
+        | SubspacesMsg::RemoveUserFromUserGroup {
+            subspace_id,
+            group_id,
+            signer,
+            ..
+        } => {
+            let group = load_user_group(storage, subspace_id.u64(), *group_id)?;
+            require_permission(
+                storage,
+                *subspace_id,
+                group.section_id.unwrap_or(ROOT_SECTION_ID),
+                signer,
+                &[Permission::SetPermissions],
+                "you cannot manage user groups in this subspace",
+            )
+        }
+        SubspacesMsg::SetUserPermissions {
+            subspace_id,
+            section_id,
+            signer,
+            ..
+        } => require_permission(
+            storage,
+            *subspace_id,
+            *section_id,
+            signer,
+            &[Permission::SetPermissions],
+            "you cannot manage permissions in this subspace",
+        ),
+    }
+}
+
+/// Checks the permissions required to perform a [`PostsMsg`].
+#[cfg(feature = "posts")]
+fn check_posts_msg(storage: &dyn Storage, msg: &PostsMsg) -> AnyResult<()> {
+    match msg {
+        PostsMsg::CreatePost {
+            subspace_id,
+            section_id,
+            author,
+            ..
+        } => require_permission(
+            storage,
+            *subspace_id,
+            *section_id,
+            author,
+            &[Permission::Write],
+            "you cannot create content inside this section",
+        ),
+        PostsMsg::EditPost {
+            subspace_id,
+            post_id,
+            editor,
+            ..
+        }
+        | PostsMsg::AddPostAttachment {
+            subspace_id,
+            post_id,
+            editor,
+            ..
+        } => require_permission(
+            storage,
+            *subspace_id,
+            load_post(storage, subspace_id.u64(), post_id.u64())?.section_id,
+            editor,
+            &[Permission::EditOwnContent],
+            "you cannot edit content inside this section",
+        ),
+        PostsMsg::DeletePost {
+            subspace_id,
+            post_id,
+            signer: editor,
+        }
+        | PostsMsg::RemovePostAttachment {
+            subspace_id,
+            post_id,
+            editor,
+            ..
+        } => {
+            // Authors can edit their own content, while moderators can edit anyone's content
+            let post = load_post(storage, subspace_id.u64(), post_id.u64())?;
+            let permission = if post.author == *editor {
+                Permission::EditOwnContent
+            } else {
+                Permission::ModerateContent
+            };
+            require_permission(
+                storage,
+                *subspace_id,
+                post.section_id,
+                editor,
+                &[permission],
+                "you cannot edit content inside this section",
+            )
+        }
+        PostsMsg::AnswerPoll {
+            subspace_id,
+            post_id,
+            signer,
+            ..
+        } => require_permission(
+            storage,
+            *subspace_id,
+            load_post(storage, subspace_id.u64(), post_id.u64())?.section_id,
+            signer,
+            &[Permission::InteractWithContent],
+            "you cannot answer polls inside this section",
+        ),
+    }
+}
+
+/// Checks the permissions required to perform a [`ReactionsMsg`].
+#[cfg(feature = "reactions")]
+fn check_reactions_msg(storage: &dyn Storage, msg: &ReactionsMsg) -> AnyResult<()> {
+    match msg {
+        ReactionsMsg::AddReaction {
+            subspace_id,
+            post_id,
+            user,
+            ..
+        }
+        | ReactionsMsg::RemoveReaction {
+            subspace_id,
+            post_id,
+            user,
+            ..
+        } => require_permission(
+            storage,
+            *subspace_id,
+            content_section(storage, *subspace_id, *post_id)?,
+            user,
+            &[Permission::InteractWithContent],
+            "you cannot react to content inside this section",
+        ),
+        ReactionsMsg::AddRegisteredReaction {
+            subspace_id, user, ..
+        }
+        | ReactionsMsg::EditRegisteredReaction {
+            subspace_id, user, ..
+        }
+        | ReactionsMsg::RemoveRegisteredReaction {
+            subspace_id, user, ..
+        }
+        | ReactionsMsg::SetReactionsParams {
+            subspace_id, user, ..
+        } => require_permission(
+            storage,
+            *subspace_id,
+            ROOT_SECTION_ID,
+            user,
+            &[Permission::EditSubspace],
+            "you cannot manage the reactions of this subspace",
+        ),
+    }
+}
+
+/// Checks the permissions required to perform a [`ReportsMsg`].
+#[cfg(feature = "reports")]
+fn check_reports_msg(storage: &dyn Storage, msg: &ReportsMsg) -> AnyResult<()> {
+    match msg {
+        ReportsMsg::CreateReport {
+            subspace_id,
+            reporter,
+            ..
+        } => require_permission(
+            storage,
+            *subspace_id,
+            ROOT_SECTION_ID,
+            reporter,
+            &[Permission::InteractWithContent],
+            "you cannot report content inside this subspace",
+        ),
+        ReportsMsg::DeleteReport {
+            subspace_id,
+            report_id,
+            signer,
+        } => {
+            // Reporters can always delete their own reports.
+            let report = load_report(storage, subspace_id.u64(), report_id.u64())?;
+            if report.reporter == *signer {
+                return Ok(());
+            }
+            require_permission(
+                storage,
+                *subspace_id,
+                ROOT_SECTION_ID,
+                signer,
+                &[Permission::ModerateContent],
+                "you cannot manage the reports of this subspace",
+            )
+        }
+        ReportsMsg::SupportStandardReason {
+            subspace_id,
+            signer,
+            ..
+        }
+        | ReportsMsg::AddReason {
+            subspace_id,
+            signer,
+            ..
+        }
+        | ReportsMsg::RemoveReason {
+            subspace_id,
+            signer,
+            ..
+        } => require_permission(
+            storage,
+            *subspace_id,
+            ROOT_SECTION_ID,
+            signer,
+            &[Permission::ModerateContent],
+            "you cannot manage the reports of this subspace",
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::mocks::mock_apps::{mock_desmos_app, mock_strict_desmos_app, DesmosApp};
+    use crate::msg::DesmosMsg;
+    use crate::subspaces::{models::Permission, msg::SubspacesMsg};
+    use cosmwasm_std::Addr;
+    use cw_multi_test::{AppResponse, Executor};
+
+    const OWNER: &str = "desmos1nwp8gxrnmrsrzjdhvk47vvmthzxjtphgxp5ftc";
+    const USER: &str = "desmos1rfv0f7mx7w9d3jv3h803u38vqym9ygg344asm3";
+
+    fn execute(app: &mut DesmosApp, msg: impl Into<DesmosMsg>) -> anyhow::Result<AppResponse> {
+        app.execute(Addr::unchecked(OWNER), msg.into().into())
+    }
+
+    fn setup_subspace(app: &mut DesmosApp) {
+        execute(
+            app,
+            SubspacesMsg::create_subspace(
+                "Test subspace",
+                "Test subspace",
+                Addr::unchecked(OWNER),
+                Addr::unchecked(OWNER),
+                Addr::unchecked(OWNER),
+            ),
+        )
+        .unwrap();
+        execute(
+            app,
+            SubspacesMsg::create_section(1, "Section", None, None, Addr::unchecked(OWNER)),
+        )
+        .unwrap();
+    }
+
+    fn set_user_permissions(app: &mut DesmosApp, section_id: u32, permissions: Vec<Permission>) {
+        execute(
+            app,
+            SubspacesMsg::set_user_permissions(
+                1,
+                section_id,
+                Addr::unchecked(USER),
+                permissions,
+                Addr::unchecked(OWNER),
+            ),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn unauthorized_messages_are_rejected() {
+        let mut app = mock_strict_desmos_app();
+        setup_subspace(&mut app);
+
+        let error = execute(
+            &mut app,
+            SubspacesMsg::create_section(1, "Section", None, None, Addr::unchecked(USER)),
+        )
+        .unwrap_err();
+        assert_eq!(
+            "you cannot manage sections within this subspace: unauthorized",
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn permissions_are_not_checked_by_default() {
+        let mut app = mock_desmos_app();
+        setup_subspace(&mut app);
+
+        let result = execute(
+            &mut app,
+            SubspacesMsg::create_section(1, "Section", None, None, Addr::unchecked(USER)),
+        );
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn owner_has_all_permissions() {
+        let mut app = mock_strict_desmos_app();
+        setup_subspace(&mut app);
+
+        let result = execute(
+            &mut app,
+            SubspacesMsg::delete_subspace(1, Addr::unchecked(OWNER)),
+        );
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn groups_permissions_are_granted_to_members() {
+        let mut app = mock_strict_desmos_app();
+        setup_subspace(&mut app);
+        execute(
+            &mut app,
+            SubspacesMsg::create_user_group(
+                1,
+                None,
+                "Admins".to_string(),
+                None,
+                vec![Permission::ManageSections],
+                Addr::unchecked(OWNER),
+            ),
+        )
+        .unwrap();
+        execute(
+            &mut app,
+            SubspacesMsg::add_user_to_user_group(
+                1,
+                1,
+                Addr::unchecked(USER),
+                Addr::unchecked(OWNER),
+            ),
+        )
+        .unwrap();
+
+        let result = execute(
+            &mut app,
+            SubspacesMsg::edit_section(
+                1,
+                1,
+                Some("New name".to_string()),
+                None,
+                Addr::unchecked(USER),
+            ),
+        );
+        assert!(result.is_ok());
+
+        // Members can't edit the permissions of their own group
+        set_user_permissions(&mut app, 0, vec![Permission::SetPermissions]);
+        let result = execute(
+            &mut app,
+            SubspacesMsg::set_user_group_permissions(
+                1,
+                1,
+                vec![Permission::Everything],
+                Addr::unchecked(USER),
+            ),
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn everything_permission_grants_any_permission() {
+        let mut app = mock_strict_desmos_app();
+        setup_subspace(&mut app);
+        set_user_permissions(&mut app, 0, vec![Permission::Everything]);
+
+        let result = execute(
+            &mut app,
+            SubspacesMsg::edit_subspace(
+                1,
+                "New name",
+                "[do-not-modify]",
+                Addr::unchecked("[do-not-modify]"),
+                Addr::unchecked("[do-not-modify]"),
+                Addr::unchecked(USER),
+            ),
+        );
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    #[cfg(feature = "posts")]
+    fn permissions_are_inherited_by_sub_sections() {
+        use crate::posts::{models::ReplySetting, msg::PostsMsg};
+
+        let mut app = mock_strict_desmos_app();
+        setup_subspace(&mut app);
+        let create_post = |section_id| {
+            PostsMsg::create_post(
+                1,
+                section_id,
+                None,
+                Some("Hello"),
+                None,
+                None,
+                Addr::unchecked(USER),
+                None,
+                ReplySetting::Everyone,
+                vec![],
+            )
+        };
+
+        assert!(execute(&mut app, create_post(1)).is_err());
+
+        set_user_permissions(&mut app, 0, vec![Permission::Write]);
+        assert!(execute(&mut app, create_post(1)).is_ok());
+    }
+
+    #[test]
+    #[cfg(feature = "reports")]
+    fn reporters_can_delete_their_own_reports() {
+        use crate::reports::{models::ReportTarget, msg::ReportsMsg};
+
+        let mut app = mock_strict_desmos_app();
+        setup_subspace(&mut app);
+        execute(
+            &mut app,
+            ReportsMsg::add_reason(1, "Spam", None::<String>, Addr::unchecked(OWNER)),
+        )
+        .unwrap();
+        set_user_permissions(&mut app, 0, vec![Permission::InteractWithContent]);
+        execute(
+            &mut app,
+            ReportsMsg::create_report(
+                1,
+                vec![1],
+                None::<String>,
+                Addr::unchecked(USER),
+                ReportTarget::User {
+                    user: Addr::unchecked(OWNER),
+                },
+            ),
+        )
+        .unwrap();
+        execute(
+            &mut app,
+            ReportsMsg::create_report(
+                1,
+                vec![1],
+                None::<String>,
+                Addr::unchecked(OWNER),
+                ReportTarget::User {
+                    user: Addr::unchecked(USER),
+                },
+            ),
+        )
+        .unwrap();
+
+        let result = execute(
+            &mut app,
+            ReportsMsg::delete_report(1, 2, Addr::unchecked(USER)),
+        );
+        assert!(result.is_err());
+        let result = execute(
+            &mut app,
+            ReportsMsg::delete_report(1, 1, Addr::unchecked(USER)),
+        );
+        assert!(result.is_ok());
+    }
+}
@@ -0,0 +1,979 @@
+//! Contains the simulation of the Desmos x/posts module used by the [`DesmosKeeper`].
+
+use crate::mocks::errors::ChainError;
+#[cfg(feature = "reactions")]
+use crate::mocks::mock_keeper::reactions::remove_post_reactions;
+#[cfg(feature = "reports")]
+use crate::mocks::mock_keeper::reports::remove_post_reports;
+#[cfg(feature = "subspaces")]
+use crate::mocks::mock_keeper::subspaces::{load_section, load_subspace};
+use crate::mocks::mock_keeper::{
+    bump_next_id,
+    genesis::PostsGenesis,
+    time::{from_rfc3339, to_rfc3339},
+    DesmosKeeper, DO_NOT_MODIFY,
+};
+use crate::pagination::paginate;
+use crate::posts::{
+    events::{
+        AddPostAttachmentEvent, AnswerPollEvent, CreatePostEvent, DeletePostEvent, EditPostEvent,
+        RemovePostAttachmentEvent,
+    },
+    models::{
+        AnswerResult, Attachment, PollTallyResults, Post, PostAttachment, RawPostAttachment,
+        UserAnswer,
+    },
+    models_query::{
+        QueryPollAnswersResponse, QueryPostAttachmentsResponse, QueryPostResponse,
+        QuerySectionPostsResponse, QuerySubspacePostsResponse,
+    },
+    msg::PostsMsg,
+    query::PostsQuery,
+};
+use anyhow::{anyhow, bail, Result as AnyResult};
+use cosmwasm_std::{to_binary, Addr, Binary, BlockInfo, Order, StdResult, Storage, Uint64};
+use cw_multi_test::AppResponse;
+use cw_storage_plus::Map;
+use std::convert::TryFrom;
+
+/// Id that will be assigned to the next post created inside a subspace.
+const NEXT_POST_IDS: Map<u64, u64> = Map::new("desmos_posts_next_post_ids");
+/// Posts stored by (subspace id, post id).
+const POSTS: Map<(u64, u64), Post> = Map::new("desmos_posts");
+/// Id that will be assigned to the next attachment added to a post.
+const NEXT_ATTACHMENT_IDS: Map<(u64, u64), u32> = Map::new("desmos_posts_next_attachment_ids");
+/// Attachments stored by (subspace id, post id, attachment id).
+const ATTACHMENTS: Map<(u64, u64, u32), Attachment> = Map::new("desmos_posts_attachments");
+/// Polls answers stored by ((subspace id, post id), poll id, user).
+const POLL_ANSWERS: Map<((u64, u64), u32, &Addr), UserAnswer> =
+    Map::new("desmos_posts_poll_answers");
+
+/// Loads the post having the given id, returning an error if it does not exist.
+pub(super) fn load_post(storage: &dyn Storage, subspace_id: u64, post_id: u64) -> AnyResult<Post> {
+    POSTS
+        .may_load(storage, (subspace_id, post_id))?
+        .ok_or_else(|| anyhow!(ChainError::post_not_found(subspace_id, post_id)))
+}
+
+/// Loads the attachment having the given id, returning an error if it does not exist.
+fn load_attachment(
+    storage: &dyn Storage,
+    subspace_id: u64,
+    post_id: u64,
+    attachment_id: u32,
+) -> AnyResult<Attachment> {
+    ATTACHMENTS
+        .may_load(storage, (subspace_id, post_id, attachment_id))?
+        .ok_or_else(|| anyhow!(ChainError::attachment_not_found(post_id, attachment_id)))
+}
+
+/// Stores the given attachment content allocating a new attachment id for the post.
+fn save_attachment(
+    storage: &mut dyn Storage,
+    subspace_id: u64,
+    post_id: u64,
+    content: RawPostAttachment,
+) -> AnyResult<u32> {
+    PostAttachment::try_from(content.clone()).map_err(|err| {
+        ChainError::invalid_request(format!("invalid attachment content: {}", err))
+    })?;
+
+    let attachment_id = NEXT_ATTACHMENT_IDS
+        .may_load(storage, (subspace_id, post_id))?
+        .unwrap_or(1);
+    NEXT_ATTACHMENT_IDS.save(storage, (subspace_id, post_id), &(attachment_id + 1))?;
+    ATTACHMENTS.save(
+        storage,
+        (subspace_id, post_id, attachment_id),
+        &Attachment {
+            subspace_id: Uint64::new(subspace_id),
+            post_id: Uint64::new(post_id),
+            id: attachment_id,
+            content,
+        },
+    )?;
+    Ok(attachment_id)
+}
+
+/// Removes an attachment along with all the answers given to it in case it is a poll.
+fn remove_attachment(
+    storage: &mut dyn Storage,
+    subspace_id: u64,
+    post_id: u64,
+    attachment_id: u32,
+) -> StdResult<()> {
+    let users = POLL_ANSWERS
+        .prefix(((subspace_id, post_id), attachment_id))
+        .keys(storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+    for user in users {
+        POLL_ANSWERS.remove(storage, ((subspace_id, post_id), attachment_id, &user));
+    }
+    ATTACHMENTS.remove(storage, (subspace_id, post_id, attachment_id));
+    Ok(())
+}
+
+/// Removes a post along with its attachments, polls answers, reactions and reports,
+/// as the chain does through the posts hooks.
+fn remove_post(storage: &mut dyn Storage, subspace_id: u64, post_id: u64) -> StdResult<()> {
+    let attachments = ATTACHMENTS
+        .prefix((subspace_id, post_id))
+        .keys(storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+    for attachment_id in attachments {
+        remove_attachment(storage, subspace_id, post_id, attachment_id)?;
+    }
+    NEXT_ATTACHMENT_IDS.remove(storage, (subspace_id, post_id));
+    #[cfg(feature = "reactions")]
+    remove_post_reactions(storage, subspace_id, post_id)?;
+    #[cfg(feature = "reports")]
+    remove_post_reports(storage, subspace_id, post_id)?;
+    POSTS.remove(storage, (subspace_id, post_id));
+    Ok(())
+}
+
+/// Removes all the posts created inside the given section.
+#[cfg(feature = "subspaces")]
+pub(super) fn remove_section_posts(
+    storage: &mut dyn Storage,
+    subspace_id: u64,
+    section_id: u32,
+) -> StdResult<()> {
+    let posts = POSTS
+        .prefix(subspace_id)
+        .range(storage, None, None, Order::Ascending)
+        .filter_map(|item| match item {
+            Ok((id, post)) if post.section_id == section_id => Some(Ok(id)),
+            Ok(_) => None,
+            Err(err) => Some(Err(err)),
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+    for post_id in posts {
+        remove_post(storage, subspace_id, post_id)?;
+    }
+    Ok(())
+}
+
+/// Removes all the posts of the given subspace.
+#[cfg(feature = "subspaces")]
+pub(super) fn remove_subspace_posts(storage: &mut dyn Storage, subspace_id: u64) -> StdResult<()> {
+    let posts = POSTS
+        .prefix(subspace_id)
+        .keys(storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+    for post_id in posts {
+        remove_post(storage, subspace_id, post_id)?;
+    }
+    NEXT_POST_IDS.remove(storage, subspace_id);
+    Ok(())
+}
+
+/// Tells whether the poll having the given end date has already ended.
+fn is_poll_ended(block: &BlockInfo, end_date: &str) -> AnyResult<bool> {
+    Ok(block.time > from_rfc3339(end_date)?)
+}
+
+/// Returns the given attachment filling the poll final tally results
+/// if the poll has ended, as the chain does inside its end blocker.
+fn tally_results(
+    storage: &dyn Storage,
+    block: &BlockInfo,
+    attachment: Attachment,
+) -> AnyResult<Attachment> {
+    let (provided_answers, end_date, allows_multiple_answers, allows_answer_edits, question) =
+        match PostAttachment::try_from(attachment.content.clone()) {
+            Ok(PostAttachment::Poll {
+                question,
+                provided_answers,
+                end_date,
+                allows_multiple_answers,
+                allows_answer_edits,
+                final_tally_results: None,
+            }) => (
+                provided_answers,
+                end_date,
+                allows_multiple_answers,
+                allows_answer_edits,
+                question,
+            ),
+            _ => return Ok(attachment),
+        };
+    if !is_poll_ended(block, &end_date)? {
+        return Ok(attachment);
+    }
+
+    let answers = POLL_ANSWERS
+        .prefix((
+            (attachment.subspace_id.u64(), attachment.post_id.u64()),
+            attachment.id,
+        ))
+        .range(storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(_, answer)| answer))
+        .collect::<StdResult<Vec<_>>>()?;
+    let results = (0..provided_answers.len() as u32)
+        .map(|answer_index| AnswerResult {
+            answer_index,
+            votes: Uint64::new(
+                answers
+                    .iter()
+                    .filter(|answer| answer.answers_indexes.contains(&answer_index))
+                    .count() as u64,
+            ),
+        })
+        .collect();
+
+    Ok(Attachment {
+        content: PostAttachment::Poll {
+            question,
+            provided_answers,
+            end_date,
+            allows_multiple_answers,
+            allows_answer_edits,
+            final_tally_results: Some(PollTallyResults { results }),
+        }
+        .into(),
+        ..attachment
+    })
+}
+
+/// Returns the type URL of the chain message corresponding to the given [`PostsMsg`].
+pub(super) fn posts_msg_type_url(msg: &PostsMsg) -> &'static str {
+    match msg {
+        PostsMsg::CreatePost { .. } => "/desmos.posts.v2.MsgCreatePost",
+        PostsMsg::EditPost { .. } => "/desmos.posts.v2.MsgEditPost",
+        PostsMsg::DeletePost { .. } => "/desmos.posts.v2.MsgDeletePost",
+        PostsMsg::AddPostAttachment { .. } => "/desmos.posts.v2.MsgAddPostAttachment",
+        PostsMsg::RemovePostAttachment { .. } => "/desmos.posts.v2.MsgRemovePostAttachment",
+        PostsMsg::AnswerPoll { .. } => "/desmos.posts.v2.MsgAnswerPoll",
+    }
+}
+
+impl DesmosKeeper {
+    /// Handles [`PostsMsg`](crate::posts::PostsMsg) updating the x/posts module state,
+    /// then returns the response with proper [events](https://github.com/desmos-labs/desmos/blob/master/x/posts/spec/05-events.md).
+    pub(super) fn handle_posts_msg(
+        &self,
+        storage: &mut dyn Storage,
+        block: &BlockInfo,
+        msg: PostsMsg,
+    ) -> AnyResult<AppResponse> {
+        match msg {
+            PostsMsg::CreatePost {
+                subspace_id,
+                section_id,
+                external_id,
+                text,
+                entities,
+                attachments,
+                author,
+                conversation_id,
+                reply_settings,
+                referenced_posts,
+            } => {
+                #[cfg(feature = "subspaces")]
+                {
+                    load_subspace(storage, subspace_id.u64())?;
+                    load_section(storage, subspace_id.u64(), section_id)?;
+                }
+                if let Some(conversation_id) = conversation_id {
+                    load_post(storage, subspace_id.u64(), conversation_id.u64())?;
+                }
+                for reference in referenced_posts.iter() {
+                    load_post(storage, subspace_id.u64(), reference.post_id.u64())?;
+                }
+
+                let post_id = NEXT_POST_IDS
+                    .may_load(storage, subspace_id.u64())?
+                    .unwrap_or(1);
+                NEXT_POST_IDS.save(storage, subspace_id.u64(), &(post_id + 1))?;
+                POSTS.save(
+                    storage,
+                    (subspace_id.u64(), post_id),
+                    &Post {
+                        id: Uint64::new(post_id),
+                        subspace_id,
+                        section_id,
+                        eternal_id: external_id,
+                        text,
+                        entities,
+                        tags: vec![],
+                        author: author.clone(),
+                        conversation_id,
+                        referenced_posts,
+                        reply_settings,
+                        creation_date: to_rfc3339(&block.time),
+                        last_edit_date: None,
+                    },
+                )?;
+                for attachment in attachments.unwrap_or_default() {
+                    save_attachment(storage, subspace_id.u64(), post_id, attachment)?;
+                }
+
+                let mut events = Vec::with_capacity(1);
+                events.push(
+                    CreatePostEvent {
+                        subspace_id: subspace_id.u64(),
+                        section_id,
+                        post_id,
+                        author,
+                        creation_time: to_rfc3339(&block.time),
+                    }
+                    .into(),
+                );
+                AnyResult::Ok(AppResponse { events, data: None })
+            }
+            PostsMsg::EditPost {
+                subspace_id,
+                post_id,
+                text,
+                entities,
+                editor,
+            } => {
+                let post = load_post(storage, subspace_id.u64(), post_id.u64())?;
+                if post.author != editor {
+                    bail!(ChainError::unauthorized(
+                        "you are not the author of this post"
+                    ));
+                }
+                POSTS.save(
+                    storage,
+                    (subspace_id.u64(), post_id.u64()),
+                    &Post {
+                        text: match text.as_str() {
+                            DO_NOT_MODIFY => post.text.clone(),
+                            _ => Some(text),
+                        },
+                        entities,
+                        last_edit_date: Some(to_rfc3339(&block.time)),
+                        ..post
+                    },
+                )?;
+
+                let mut events = Vec::with_capacity(1);
+                events.push(
+                    EditPostEvent {
+                        subspace_id: subspace_id.u64(),
+                        post_id: post_id.u64(),
+                        last_edit_time: to_rfc3339(&block.time),
+                    }
+                    .into(),
+                );
+                AnyResult::Ok(AppResponse { events, data: None })
+            }
+            PostsMsg::DeletePost {
+                subspace_id,
+                post_id,
+                ..
+            } => {
+                load_post(storage, subspace_id.u64(), post_id.u64())?;
+                remove_post(storage, subspace_id.u64(), post_id.u64())?;
+
+                let mut events = Vec::with_capacity(1);
+                events.push(
+                    DeletePostEvent {
+                        subspace_id: subspace_id.u64(),
+                        post_id: post_id.u64(),
+                    }
+                    .into(),
+                );
+                AnyResult::Ok(AppResponse { events, data: None })
+            }
+            PostsMsg::AddPostAttachment {
+                subspace_id,
+                post_id,
+                content,
+                editor,
+            } => {
+                let post = load_post(storage, subspace_id.u64(), post_id.u64())?;
+                if post.author != editor {
+                    bail!(ChainError::unauthorized(
+                        "you are not the author of this post"
+                    ));
+                }
+                let attachment_id =
+                    save_attachment(storage, subspace_id.u64(), post_id.u64(), content)?;
+                POSTS.save(
+                    storage,
+                    (subspace_id.u64(), post_id.u64()),
+                    &Post {
+                        last_edit_date: Some(to_rfc3339(&block.time)),
+                        ..post
+                    },
+                )?;
+
+                let mut events = Vec::with_capacity(1);
+                events.push(
+                    AddPostAttachmentEvent {
+                        subspace_id: subspace_id.u64(),
+                        post_id: post_id.u64(),
+                        attachment_id,
+                        last_edit_time: to_rfc3339(&block.time),
+                    }
+                    .into(),
+                );
+                AnyResult::Ok(AppResponse { events, data: None })
+            }
+            PostsMsg::RemovePostAttachment {
+                subspace_id,
+                post_id,
+                attachment_id,
+                ..
+            } => {
+                let post = load_post(storage, subspace_id.u64(), post_id.u64())?;
+                load_attachment(storage, subspace_id.u64(), post_id.u64(), attachment_id)?;
+                remove_attachment(storage, subspace_id.u64(), post_id.u64(), attachment_id)?;
+                POSTS.save(
+                    storage,
+                    (subspace_id.u64(), post_id.u64()),
+                    &Post {
+                        last_edit_date: Some(to_rfc3339(&block.time)),
+                        ..post
+                    },
+                )?;
+
+                let mut events = Vec::with_capacity(1);
+                events.push(
+                    RemovePostAttachmentEvent {
+                        subspace_id: subspace_id.u64(),
+                        post_id: post_id.u64(),
+                        attachment_id,
+                        last_edit_time: to_rfc3339(&block.time),
+                    }
+                    .into(),
+                );
+                AnyResult::Ok(AppResponse { events, data: None })
+            }
+            PostsMsg::AnswerPoll {
+                subspace_id,
+                post_id,
+                poll_id,
+                answers_indexes,
+                signer,
+            } => {
+                let attachment =
+                    load_attachment(storage, subspace_id.u64(), post_id.u64(), poll_id)?;
+                let (provided_answers, end_date, allows_multiple_answers, allows_answer_edits) =
+                    match PostAttachment::try_from(attachment.content) {
+                        Ok(PostAttachment::Poll {
+                            provided_answers,
+                            end_date,
+                            allows_multiple_answers,
+                            allows_answer_edits,
+                            ..
+                        }) => (
+                            provided_answers,
+                            end_date,
+                            allows_multiple_answers,
+                            allows_answer_edits,
+                        ),
+                        _ => bail!(ChainError::invalid_request(format!(
+                            "attachment with id {} is not a poll",
+                            poll_id
+                        ))),
+                    };
+                if is_poll_ended(block, &end_date)? {
+                    bail!(ChainError::invalid_request("the poll has already ended"));
+                }
+                if !allows_multiple_answers && answers_indexes.len() > 1 {
+                    bail!(ChainError::invalid_request(
+                        "only one answer is allowed on this post"
+                    ));
+                }
+                if answers_indexes
+                    .iter()
+                    .any(|index| *index as usize >= provided_answers.len())
+                {
+                    bail!(ChainError::invalid_request("invalid answer index"));
+                }
+                let key = ((subspace_id.u64(), post_id.u64()), poll_id, &signer);
+                if !allows_answer_edits && POLL_ANSWERS.has(storage, key) {
+                    bail!(ChainError::invalid_request(
+                        "you cannot edit this poll's answer"
+                    ));
+                }
+                POLL_ANSWERS.save(
+                    storage,
+                    key,
+                    &UserAnswer {
+                        subspace_id,
+                        post_id,
+                        poll_id,
+                        answers_indexes,
+                        user: signer.clone(),
+                    },
+                )?;
+
+                let mut events = Vec::with_capacity(1);
+                events.push(
+                    AnswerPollEvent {
+                        subspace_id: subspace_id.u64(),
+                        post_id: post_id.u64(),
+                        poll_id,
+                        user: signer,
+                    }
+                    .into(),
+                );
+                AnyResult::Ok(AppResponse { events, data: None })
+            }
+        }
+    }
+
+    /// Handles [`PostsQuery`](crate::posts::query::PostsQuery) reading the x/posts module state.
+    pub(super) fn query_posts(
+        &self,
+        storage: &dyn Storage,
+        block: &BlockInfo,
+        query: PostsQuery,
+    ) -> AnyResult<Binary> {
+        match query {
+            PostsQuery::SubspacePosts {
+                subspace_id,
+                pagination,
+                ..
+            } => {
+                let posts = POSTS
+                    .prefix(subspace_id.u64())
+                    .range(storage, None, None, Order::Ascending)
+                    .map(|item| item.map(|(_, post)| post))
+                    .collect::<StdResult<Vec<_>>>()?;
+                let (posts, pagination) = paginate(posts, pagination.as_ref())?;
+                Ok(to_binary(&QuerySubspacePostsResponse {
+                    posts,
+                    pagination,
+                })?)
+            }
+            PostsQuery::SectionPosts {
+                subspace_id,
+                section_id,
+                pagination,
+                ..
+            } => {
+                let posts = POSTS
+                    .prefix(subspace_id.u64())
+                    .range(storage, None, None, Order::Ascending)
+                    .filter(|item| match item {
+                        Ok((_, post)) => post.section_id == section_id,
+                        Err(_) => true,
+                    })
+                    .map(|item| item.map(|(_, post)| post))
+                    .collect::<StdResult<Vec<_>>>()?;
+                let (posts, pagination) = paginate(posts, pagination.as_ref())?;
+                Ok(to_binary(&QuerySectionPostsResponse { posts, pagination })?)
+            }
+            PostsQuery::Post {
+                subspace_id,
+                post_id,
+            } => {
+                let post = load_post(storage, subspace_id.u64(), post_id.u64())?;
+                Ok(to_binary(&QueryPostResponse { post })?)
+            }
+            PostsQuery::PostAttachments {
+                subspace_id,
+                post_id,
+                pagination,
+                ..
+            } => {
+                let attachments = ATTACHMENTS
+                    .prefix((subspace_id.u64(), post_id.u64()))
+                    .range(storage, None, None, Order::Ascending)
+                    .map(|item| {
+                        let (_, attachment) = item?;
+                        tally_results(storage, block, attachment)
+                    })
+                    .collect::<AnyResult<Vec<_>>>()?;
+                let (attachments, pagination) = paginate(attachments, pagination.as_ref())?;
+                Ok(to_binary(&QueryPostAttachmentsResponse {
+                    attachments,
+                    pagination,
+                })?)
+            }
+            PostsQuery::PollAnswers {
+                subspace_id,
+                post_id,
+                poll_id,
+                user,
+                pagination,
+                ..
+            } => {
+                let answers = match user {
+                    Some(user) => POLL_ANSWERS
+                        .may_load(
+                            storage,
+                            ((subspace_id.u64(), post_id.u64()), poll_id, &user),
+                        )?
+                        .into_iter()
+                        .collect(),
+                    None => POLL_ANSWERS
+                        .prefix(((subspace_id.u64(), post_id.u64()), poll_id))
+                        .range(storage, None, None, Order::Ascending)
+                        .map(|item| item.map(|(_, answer)| answer))
+                        .collect::<StdResult<Vec<_>>>()?,
+                };
+                let (answers, pagination) = paginate(answers, pagination.as_ref())?;
+                Ok(to_binary(&QueryPollAnswersResponse {
+                    answers,
+                    pagination,
+                })?)
+            }
+        }
+    }
+
+    /// Stores the given [`PostsGenesis`] inside the x/posts module state.
+    pub(super) fn init_posts_genesis(
+        &self,
+        storage: &mut dyn Storage,
+        genesis: PostsGenesis,
+    ) -> AnyResult<()> {
+        for post in genesis.posts {
+            let subspace_id = post.subspace_id.u64();
+            #[cfg(feature = "subspaces")]
+            {
+                load_subspace(storage, subspace_id)?;
+                load_section(storage, subspace_id, post.section_id)?;
+            }
+            POSTS.save(storage, (subspace_id, post.id.u64()), &post)?;
+            bump_next_id(storage, &NEXT_POST_IDS, subspace_id, post.id.u64())?;
+        }
+
+        for attachment in genesis.attachments {
+            let subspace_id = attachment.subspace_id.u64();
+            let post_id = attachment.post_id.u64();
+            load_post(storage, subspace_id, post_id)?;
+            PostAttachment::try_from(attachment.content.clone()).map_err(|err| {
+                ChainError::invalid_request(format!("invalid attachment content: {}", err))
+            })?;
+            ATTACHMENTS.save(storage, (subspace_id, post_id, attachment.id), &attachment)?;
+            bump_next_id(
+                storage,
+                &NEXT_ATTACHMENT_IDS,
+                (subspace_id, post_id),
+                attachment.id,
+            )?;
+        }
+
+        for answer in genesis.user_answers {
+            let subspace_id = answer.subspace_id.u64();
+            let post_id = answer.post_id.u64();
+            load_attachment(storage, subspace_id, post_id, answer.poll_id)?;
+            POLL_ANSWERS.save(
+                storage,
+                ((subspace_id, post_id), answer.poll_id, &answer.user),
+                &answer,
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::events::{find_event, MessageEvent};
+    use crate::mocks::mock_apps::{mock_desmos_app, DesmosApp};
+    use crate::mocks::mock_keeper::time::to_rfc3339;
+    use crate::posts::{
+        events::CreatePostEvent,
+        models::{AnswerResult, PollTallyResults, PostAttachment, ProvidedAnswer, ReplySetting},
+        msg::PostsMsg,
+        querier::PostsQuerier,
+    };
+    use crate::subspaces::msg::SubspacesMsg;
+    use cosmwasm_std::{Addr, Uint64};
+    use cw_multi_test::{AppResponse, Executor};
+    use std::convert::TryFrom;
+    use std::ops::Deref;
+
+    const AUTHOR: &str = "desmos1nwp8gxrnmrsrzjdhvk47vvmthzxjtphgxp5ftc";
+    const USER: &str = "desmos1rfv0f7mx7w9d3jv3h803u38vqym9ygg344asm3";
+
+    fn setup_subspace(app: &mut DesmosApp) {
+        app.execute(
+            Addr::unchecked(AUTHOR),
+            SubspacesMsg::create_subspace(
+                "Test subspace",
+                "Test subspace",
+                Addr::unchecked(AUTHOR),
+                Addr::unchecked(AUTHOR),
+                Addr::unchecked(AUTHOR),
+            )
+            .into(),
+        )
+        .unwrap();
+        app.execute(
+            Addr::unchecked(AUTHOR),
+            SubspacesMsg::create_section(1, "Section", None, None, Addr::unchecked(AUTHOR)).into(),
+        )
+        .unwrap();
+    }
+
+    fn create_post(
+        app: &mut DesmosApp,
+        section_id: u32,
+        attachments: Option<Vec<PostAttachment>>,
+    ) -> AppResponse {
+        app.execute(
+            Addr::unchecked(AUTHOR),
+            PostsMsg::create_post(
+                1,
+                section_id,
+                None,
+                Some("Hello"),
+                None,
+                attachments,
+                Addr::unchecked(AUTHOR),
+                None,
+                ReplySetting::Everyone,
+                vec![],
+            )
+            .into(),
+        )
+        .unwrap()
+    }
+
+    fn poll(end_date: &str) -> PostAttachment {
+        PostAttachment::Poll {
+            question: "Which one?".to_string(),
+            provided_answers: vec![
+                ProvidedAnswer {
+                    text: Some("First".to_string()),
+                    attachments: vec![],
+                },
+                ProvidedAnswer {
+                    text: Some("Second".to_string()),
+                    attachments: vec![],
+                },
+            ],
+            end_date: end_date.to_string(),
+            allows_multiple_answers: false,
+            allows_answer_edits: false,
+            final_tally_results: None,
+        }
+    }
+
+    fn answer_poll(
+        app: &mut DesmosApp,
+        user: &str,
+        answers: Vec<u32>,
+    ) -> anyhow::Result<AppResponse> {
+        app.execute(
+            Addr::unchecked(user),
+            PostsMsg::answer_poll(1, 1, 1, answers, Addr::unchecked(user)).into(),
+        )
+    }
+
+    #[test]
+    fn create_post_emits_the_chain_events() {
+        let mut app = mock_desmos_app();
+        setup_subspace(&mut app);
+        let response = create_post(&mut app, 1, None);
+
+        let message = find_event::<MessageEvent>(&response.events)
+            .unwrap()
+            .unwrap();
+        assert_eq!("posts", message.module);
+        assert_eq!("/desmos.posts.v2.MsgCreatePost", message.action);
+        assert_eq!(Addr::unchecked(AUTHOR), message.sender);
+        assert_eq!(
+            Some(CreatePostEvent {
+                subspace_id: 1,
+                section_id: 1,
+                post_id: 1,
+                author: Addr::unchecked(AUTHOR),
+                creation_time: to_rfc3339(&app.block_info().time),
+            }),
+            find_event(&response.events).unwrap()
+        );
+    }
+
+    #[test]
+    fn create_post_allocates_ids_per_subspace() {
+        let mut app = mock_desmos_app();
+        setup_subspace(&mut app);
+        let response = create_post(&mut app, 0, None);
+        assert_eq!(
+            "1",
+            response
+                .events
+                .iter()
+                .find(|event| event.ty == "create_post")
+                .unwrap()
+                .attributes
+                .iter()
+                .find(|attr| attr.key == "post_id")
+                .unwrap()
+                .value
+        );
+        create_post(&mut app, 1, None);
+
+        let querier = app.wrap();
+        let querier = PostsQuerier::new(querier.deref());
+        let post = querier.query_post(1, 2).unwrap().post;
+        assert_eq!(Uint64::new(2), post.id);
+        assert_eq!(Some("Hello".to_string()), post.text);
+        assert_eq!("2019-10-23T02:23:39.879305533Z", post.creation_date);
+        assert_eq!(
+            2,
+            querier.query_subspace_posts(1, None).unwrap().posts.len()
+        );
+        let section_posts = querier.query_section_posts(1, 1, None).unwrap().posts;
+        assert_eq!(1, section_posts.len());
+        assert_eq!(post, section_posts[0]);
+    }
+
+    #[test]
+    fn create_post_inside_non_existing_section_errors() {
+        let mut app = mock_desmos_app();
+        setup_subspace(&mut app);
+        let result = app.execute(
+            Addr::unchecked(AUTHOR),
+            PostsMsg::create_post(
+                1,
+                5,
+                None,
+                Some("Hello"),
+                None,
+                None,
+                Addr::unchecked(AUTHOR),
+                None,
+                ReplySetting::Everyone,
+                vec![],
+            )
+            .into(),
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn edit_post_updates_the_post() {
+        let mut app = mock_desmos_app();
+        setup_subspace(&mut app);
+        create_post(&mut app, 0, None);
+
+        // Only the author can edit the post
+        let result = app.execute(
+            Addr::unchecked(USER),
+            PostsMsg::edit_post(1, 1, Some("Edited"), None, Addr::unchecked(USER)).into(),
+        );
+        assert!(result.is_err());
+
+        app.execute(
+            Addr::unchecked(AUTHOR),
+            PostsMsg::edit_post(1, 1, Some("Edited"), None, Addr::unchecked(AUTHOR)).into(),
+        )
+        .unwrap();
+        let querier = app.wrap();
+        let post = PostsQuerier::new(querier.deref())
+            .query_post(1, 1)
+            .unwrap()
+            .post;
+        assert_eq!(Some("Edited".to_string()), post.text);
+        assert!(post.last_edit_date.is_some());
+    }
+
+    #[test]
+    fn attachments_are_stored_with_per_post_ids() {
+        let mut app = mock_desmos_app();
+        setup_subspace(&mut app);
+        let media = PostAttachment::Media {
+            mime_type: "image/png".to_string(),
+            uri: "ftp://domain.io/image.png".to_string(),
+        };
+        create_post(&mut app, 0, Some(vec![media.clone()]));
+        app.execute(
+            Addr::unchecked(AUTHOR),
+            PostsMsg::add_post_attachment(1, 1, media, Addr::unchecked(AUTHOR)).into(),
+        )
+        .unwrap();
+        app.execute(
+            Addr::unchecked(AUTHOR),
+            PostsMsg::remove_post_attachment(1, 1, 1, Addr::unchecked(AUTHOR)).into(),
+        )
+        .unwrap();
+
+        let querier = app.wrap();
+        let attachments = PostsQuerier::new(querier.deref())
+            .query_post_attachments(1, 1, None)
+            .unwrap()
+            .attachments;
+        assert_eq!(1, attachments.len());
+        assert_eq!(2, attachments[0].id);
+
+        app.execute(
+            Addr::unchecked(AUTHOR),
+            PostsMsg::delete_post(1, 1, Addr::unchecked(AUTHOR)).into(),
+        )
+        .unwrap();
+        let querier = app.wrap();
+        let querier = PostsQuerier::new(querier.deref());
+        assert!(querier.query_post(1, 1).is_err());
+        assert!(querier
+            .query_post_attachments(1, 1, None)
+            .unwrap()
+            .attachments
+            .is_empty());
+    }
+
+    #[test]
+    fn poll_answers_are_validated_and_tallied() {
+        let mut app = mock_desmos_app();
+        setup_subspace(&mut app);
+        create_post(&mut app, 0, Some(vec![poll("2019-10-24T00:00:00Z")]));
+
+        // Multiple answers are not allowed
+        assert!(answer_poll(&mut app, USER, vec![0, 1]).is_err());
+        // Answers must be valid
+        assert!(answer_poll(&mut app, USER, vec![2]).is_err());
+        answer_poll(&mut app, USER, vec![1]).unwrap();
+        // Answers can't be edited
+        assert!(answer_poll(&mut app, USER, vec![0]).is_err());
+        answer_poll(&mut app, AUTHOR, vec![1]).unwrap();
+
+        let querier = app.wrap();
+        let querier = PostsQuerier::new(querier.deref());
+        let answers = querier
+            .query_poll_answers(1, 1, 1, Some(Addr::unchecked(USER)), None)
+            .unwrap()
+            .answers;
+        assert_eq!(vec![1], answers[0].answers_indexes);
+        let attachment = querier
+            .query_post_attachments(1, 1, None)
+            .unwrap()
+            .attachments;
+        match PostAttachment::try_from(attachment[0].content.clone()).unwrap() {
+            PostAttachment::Poll {
+                final_tally_results,
+                ..
+            } => assert_eq!(None, final_tally_results),
+            _ => panic!("attachment should be a poll"),
+        }
+
+        // Once the poll has ended it gets tallied
+        app.update_block(|block| block.time = block.time.plus_seconds(24 * 60 * 60));
+        assert!(answer_poll(&mut app, "other", vec![0]).is_err());
+        let querier = app.wrap();
+        let attachment = PostsQuerier::new(querier.deref())
+            .query_post_attachments(1, 1, None)
+            .unwrap()
+            .attachments;
+        match PostAttachment::try_from(attachment[0].content.clone()).unwrap() {
+            PostAttachment::Poll {
+                final_tally_results,
+                ..
+            } => assert_eq!(
+                Some(PollTallyResults {
+                    results: vec![
+                        AnswerResult {
+                            answer_index: 0,
+                            votes: Uint64::zero(),
+                        },
+                        AnswerResult {
+                            answer_index: 1,
+                            votes: Uint64::new(2),
+                        },
+                    ]
+                }),
+                final_tally_results
+            ),
+            _ => panic!("attachment should be a poll"),
+        }
+    }
+}
@@ -0,0 +1,1688 @@
+//! Contains the simulation of the Desmos x/profiles module used by the [`DesmosKeeper`].
+
+use crate::mocks::errors::ChainError;
+#[cfg(feature = "relationships")]
+use crate::mocks::mock_keeper::relationships::has_user_blocked;
+use crate::mocks::mock_keeper::{
+    genesis::ProfilesGenesis, time::to_rfc3339, updated_field, DesmosKeeper,
+};
+use crate::pagination::paginate;
+use crate::profiles::{
+    events::{
+        AcceptDtagTransferRequestEvent, CancelDtagTransferRequestEvent,
+        CreateDtagTransferRequestEvent, DeleteProfileEvent, LinkApplicationEvent,
+        LinkChainAccountEvent, RefuseDtagTransferRequestEvent, SaveProfileEvent,
+        SetDefaultExternalAddressEvent, UnlinkApplicationEvent, UnlinkChainAccountEvent,
+    },
+    models_app_links::{
+        AppLinkResult, ApplicationLink, ApplicationLinkOwnerDetails, ApplicationLinkState,
+        CallData, OracleRequest,
+    },
+    models_chain_links::{ChainLink, ChainLinkOwnerDetails},
+    models_dtag_requests::DtagTransferRequest,
+    models_profile::{Account, Pictures, Profile},
+    models_query::{
+        QueryApplicationLinkByClientIDResponse, QueryApplicationLinkOwnersResponse,
+        QueryApplicationLinksResponse, QueryChainLinkOwnersResponse, QueryChainLinksResponse,
+        QueryDefaultExternalAddressesResponse, QueryIncomingDtagTransferRequestResponse,
+        QueryProfileResponse,
+    },
+    msg::ProfilesMsg,
+    query::ProfilesQuery,
+};
+use crate::types::PubKey;
+use anyhow::{anyhow, bail, Result as AnyResult};
+use cosmwasm_std::{to_binary, Addr, Binary, BlockInfo, Order, StdResult, Storage, Uint64};
+use cw_multi_test::AppResponse;
+use cw_storage_plus::Map;
+
+/// Id of the oracle script that the chain uses to verify the application links.
+const APP_LINKS_ORACLE_SCRIPT_ID: u64 = 32;
+/// Amount of time (in seconds) after which an application link expires.
+const APP_LINKS_VALIDITY_DURATION: u64 = 6 * 4 * 7 * 24 * 60 * 60;
+
+/// Profiles stored by their owner address.
+const PROFILES: Map<&Addr, Profile> = Map::new("desmos_profiles");
+/// Owners of the profiles stored by their lower case DTag.
+const DTAGS: Map<&str, Addr> = Map::new("desmos_profiles_dtags");
+/// DTag transfer requests stored by (receiver, sender).
+const DTAG_TRANSFER_REQUESTS: Map<(&Addr, &Addr), DtagTransferRequest> =
+    Map::new("desmos_profiles_dtag_transfer_requests");
+/// Chain links stored by (user, chain name, target).
+const CHAIN_LINKS: Map<(&Addr, &str, &str), ChainLink> = Map::new("desmos_profiles_chain_links");
+/// Default external addresses stored by (owner, chain name).
+const DEFAULT_EXTERNAL_ADDRESSES: Map<(&Addr, &str), String> =
+    Map::new("desmos_profiles_default_external_addresses");
+/// Application links stored by (user, application, username).
+const APPLICATION_LINKS: Map<(&Addr, &str, &str), ApplicationLink> =
+    Map::new("desmos_profiles_application_links");
+/// Keys of the application links stored by their oracle client id.
+const APPLICATION_LINKS_CLIENT_IDS: Map<&str, (Addr, String, String)> =
+    Map::new("desmos_profiles_application_links_client_ids");
+
+/// Sudo messages that simulate the responses of the oracle that verifies the [`ApplicationLink`]s,
+/// moving them through the [`ApplicationLinkState`]s.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ProfilesSudoMsg {
+    /// Simulates the oracle acknowledging the verification request of an application link,
+    /// moving it to [`ApplicationLinkState::VerificationStarted`].
+    StartApplicationLinkVerification {
+        /// Address of the user that owns the link.
+        user: Addr,
+        /// Name of the linked application.
+        application: String,
+        /// Username of the linked application account.
+        username: String,
+    },
+    /// Simulates the oracle sending the verification result of an application link.
+    /// The link is moved to [`ApplicationLinkState::VerificationSuccess`] if the result is
+    /// [`AppLinkResult::Success`], or to [`ApplicationLinkState::VerificationError`] otherwise.
+    CompleteApplicationLinkVerification {
+        /// Address of the user that owns the link.
+        user: Addr,
+        /// Name of the linked application.
+        application: String,
+        /// Username of the linked application account.
+        username: String,
+        /// Result of the verification.
+        result: AppLinkResult,
+    },
+    /// Simulates the verification request of an application link timing out,
+    /// moving it to [`ApplicationLinkState::TimedOut`].
+    TimeOutApplicationLinkVerification {
+        /// Address of the user that owns the link.
+        user: Addr,
+        /// Name of the linked application.
+        application: String,
+        /// Username of the linked application account.
+        username: String,
+    },
+}
+
+impl ProfilesSudoMsg {
+    /// Creates a new instance of [`ProfilesSudoMsg::StartApplicationLinkVerification`].
+    ///
+    /// * `user` - Address of the user that owns the link.
+    /// * `application` - Name of the linked application.
+    /// * `username` - Username of the linked application account.
+    pub fn start_application_link_verification(
+        user: Addr,
+        application: &str,
+        username: &str,
+    ) -> Self {
+        ProfilesSudoMsg::StartApplicationLinkVerification {
+            user,
+            application: application.to_string(),
+            username: username.to_string(),
+        }
+    }
+
+    /// Creates a new instance of [`ProfilesSudoMsg::CompleteApplicationLinkVerification`].
+    ///
+    /// * `user` - Address of the user that owns the link.
+    /// * `application` - Name of the linked application.
+    /// * `username` - Username of the linked application account.
+    /// * `result` - Result of the verification.
+    pub fn complete_application_link_verification(
+        user: Addr,
+        application: &str,
+        username: &str,
+        result: AppLinkResult,
+    ) -> Self {
+        ProfilesSudoMsg::CompleteApplicationLinkVerification {
+            user,
+            application: application.to_string(),
+            username: username.to_string(),
+            result,
+        }
+    }
+
+    /// Creates a new instance of [`ProfilesSudoMsg::TimeOutApplicationLinkVerification`].
+    ///
+    /// * `user` - Address of the user that owns the link.
+    /// * `application` - Name of the linked application.
+    /// * `username` - Username of the linked application account.
+    pub fn time_out_application_link_verification(
+        user: Addr,
+        application: &str,
+        username: &str,
+    ) -> Self {
+        ProfilesSudoMsg::TimeOutApplicationLinkVerification {
+            user,
+            application: application.to_string(),
+            username: username.to_string(),
+        }
+    }
+}
+
+/// Returns the client id used by the chain to identify the oracle request of an application link.
+fn application_link_client_id(user: &Addr, application: &str, username: &str) -> String {
+    format!("{}-{}-{}", user, application, username)
+}
+
+/// Gets the profile associated to the given address or DTag.
+fn get_profile(storage: &dyn Storage, user: &str) -> StdResult<Option<Profile>> {
+    if let Some(profile) = PROFILES.may_load(storage, &Addr::unchecked(user))? {
+        return Ok(Some(profile));
+    }
+    match DTAGS.may_load(storage, &user.to_lowercase())? {
+        Some(owner) => PROFILES.may_load(storage, &owner),
+        None => Ok(None),
+    }
+}
+
+/// Returns a new profile owned by the given user, having all its fields empty.
+fn empty_profile(owner: &Addr, block: &BlockInfo) -> Profile {
+    Profile {
+        account: Account {
+            proto_type: "/cosmos.auth.v1beta1.BaseAccount".to_string(),
+            address: owner.clone(),
+            pub_key: PubKey {
+                proto_type: "/cosmos.crypto.secp256k1.PubKey".to_string(),
+                key: Binary::default(),
+            },
+            account_number: Uint64::zero(),
+            sequence: Uint64::zero(),
+        },
+        dtag: String::new(),
+        nickname: String::new(),
+        bio: String::new(),
+        pictures: Pictures {
+            profile: String::new(),
+            cover: String::new(),
+        },
+        creation_date: to_rfc3339(&block.time),
+    }
+}
+
+/// Loads the DTag transfer request made from `sender` to `receiver`,
+/// returning an error if it does not exist.
+fn load_dtag_transfer_request(
+    storage: &dyn Storage,
+    receiver: &Addr,
+    sender: &Addr,
+) -> AnyResult<DtagTransferRequest> {
+    DTAG_TRANSFER_REQUESTS
+        .may_load(storage, (receiver, sender))?
+        .ok_or_else(|| anyhow!(ChainError::dtag_transfer_request_not_found(sender.as_str())))
+}
+
+/// Removes all the DTag transfer requests received by the given user.
+fn remove_incoming_dtag_transfer_requests(
+    storage: &mut dyn Storage,
+    receiver: &Addr,
+) -> StdResult<()> {
+    let senders = DTAG_TRANSFER_REQUESTS
+        .prefix(receiver)
+        .keys(storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+    for sender in senders {
+        DTAG_TRANSFER_REQUESTS.remove(storage, (receiver, &sender));
+    }
+    Ok(())
+}
+
+/// Removes all the data associated to the profile of the given user.
+fn remove_profile(storage: &mut dyn Storage, user: &Addr) -> AnyResult<Profile> {
+    let profile = PROFILES
+        .may_load(storage, user)?
+        .ok_or_else(|| anyhow!(ChainError::profile_not_found(user.as_str())))?;
+    PROFILES.remove(storage, user);
+    DTAGS.remove(storage, &profile.dtag.to_lowercase());
+    remove_incoming_dtag_transfer_requests(storage, user)?;
+
+    let chain_links = CHAIN_LINKS
+        .sub_prefix(user)
+        .keys(storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+    for (chain_name, target) in chain_links {
+        CHAIN_LINKS.remove(storage, (user, &chain_name, &target));
+        DEFAULT_EXTERNAL_ADDRESSES.remove(storage, (user, &chain_name));
+    }
+
+    let application_links = APPLICATION_LINKS
+        .sub_prefix(user)
+        .keys(storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+    for (application, username) in application_links {
+        remove_application_link(storage, user, &application, &username)?;
+    }
+
+    Ok(profile)
+}
+
+/// Removes an application link along with its client id reference.
+fn remove_application_link(
+    storage: &mut dyn Storage,
+    user: &Addr,
+    application: &str,
+    username: &str,
+) -> AnyResult<ApplicationLink> {
+    let link = APPLICATION_LINKS
+        .may_load(storage, (user, application, username))?
+        .ok_or_else(|| anyhow!(ChainError::application_link_not_found()))?;
+    APPLICATION_LINKS.remove(storage, (user, application, username));
+    APPLICATION_LINKS_CLIENT_IDS.remove(storage, &link.oracle_request.client_id);
+    Ok(link)
+}
+
+/// Returns the type URL of the chain message corresponding to the given [`ProfilesMsg`].
+pub(super) fn profiles_msg_type_url(msg: &ProfilesMsg) -> &'static str {
+    match msg {
+        ProfilesMsg::SaveProfile { .. } => "/desmos.profiles.v3.MsgSaveProfile",
+        ProfilesMsg::DeleteProfile { .. } => "/desmos.profiles.v3.MsgDeleteProfile",
+        ProfilesMsg::RequestDtagTransfer { .. } => "/desmos.profiles.v3.MsgRequestDTagTransfer",
+        ProfilesMsg::AcceptDtagTransferRequest { .. } => {
+            "/desmos.profiles.v3.MsgAcceptDTagTransferRequest"
+        }
+        ProfilesMsg::RefuseDtagTransferRequest { .. } => {
+            "/desmos.profiles.v3.MsgRefuseDTagTransferRequest"
+        }
+        ProfilesMsg::CancelDtagTransferRequest { .. } => {
+            "/desmos.profiles.v3.MsgCancelDTagTransferRequest"
+        }
+        ProfilesMsg::LinkChainAccount { .. } => "/desmos.profiles.v3.MsgLinkChainAccount",
+        ProfilesMsg::UnlinkChainAccount { .. } => "/desmos.profiles.v3.MsgUnlinkChainAccount",
+        ProfilesMsg::SetDefaultExternalAddress { .. } => {
+            "/desmos.profiles.v3.MsgSetDefaultExternalAddress"
+        }
+        ProfilesMsg::LinkApplication { .. } => "/desmos.profiles.v3.MsgLinkApplication",
+        ProfilesMsg::UnlinkApplication { .. } => "/desmos.profiles.v3.MsgUnlinkApplication",
+    }
+}
+
+impl DesmosKeeper {
+    /// Handles [`ProfilesMsg`](crate::profiles::ProfilesMsg) updating the x/profiles module state,
+    /// then returns the response with proper [events](https://github.com/desmos-labs/desmos/blob/master/x/profiles/spec/05-events.md).
+    pub(super) fn handle_profiles_msg(
+        &self,
+        storage: &mut dyn Storage,
+        block: &BlockInfo,
+        msg: ProfilesMsg,
+    ) -> AnyResult<AppResponse> {
+        match msg {
+            ProfilesMsg::SaveProfile {
+                dtag,
+                nickname,
+                bio,
+                profile_picture,
+                cover_picture,
+                creator,
+            } => {
+                let existing_profile = PROFILES.may_load(storage, &creator)?;
+                let previous_dtag_key = existing_profile
+                    .as_ref()
+                    .map(|profile| profile.dtag.to_lowercase())
+                    .unwrap_or_default();
+                let profile = match existing_profile {
+                    Some(profile) => {
+                        DTAGS.remove(storage, &profile.dtag.to_lowercase());
+                        Profile {
+                            dtag: updated_field(profile.dtag, dtag),
+                            nickname: updated_field(profile.nickname, nickname),
+                            bio: updated_field(profile.bio, bio),
+                            pictures: Pictures {
+                                profile: updated_field(profile.pictures.profile, profile_picture),
+                                cover: updated_field(profile.pictures.cover, cover_picture),
+                            },
+                            ..profile
+                        }
+                    }
+                    None => Profile {
+                        dtag: updated_field(String::new(), dtag),
+                        nickname: updated_field(String::new(), nickname),
+                        bio: updated_field(String::new(), bio),
+                        pictures: Pictures {
+                            profile: updated_field(String::new(), profile_picture),
+                            cover: updated_field(String::new(), cover_picture),
+                        },
+                        ..empty_profile(&creator, block)
+                    },
+                };
+
+                if profile.dtag.trim().is_empty() {
+                    bail!(ChainError::invalid_request("invalid profile dtag"));
+                }
+                let dtag_key = profile.dtag.to_lowercase();
+                if DTAGS.has(storage, &dtag_key) {
+                    bail!(ChainError::invalid_request(format!(
+                        "a profile with dtag {} has already been created",
+                        profile.dtag
+                    )));
+                }
+                if dtag_key != previous_dtag_key {
+                    // The pending requests would trade a DTag that is no longer owned
+                    remove_incoming_dtag_transfer_requests(storage, &creator)?;
+                }
+                DTAGS.save(storage, &dtag_key, &creator)?;
+                PROFILES.save(storage, &creator, &profile)?;
+
+                let mut events = Vec::with_capacity(1);
+                events.push(
+                    SaveProfileEvent {
+                        dtag: profile.dtag,
+                        creator,
+                        creation_time: profile.creation_date,
+                    }
+                    .into(),
+                );
+                AnyResult::Ok(AppResponse { events, data: None })
+            }
+            ProfilesMsg::DeleteProfile { creator } => {
+                remove_profile(storage, &creator)?;
+
+                let mut events = Vec::with_capacity(1);
+                events.push(DeleteProfileEvent { creator }.into());
+                AnyResult::Ok(AppResponse { events, data: None })
+            }
+            ProfilesMsg::RequestDtagTransfer {
+                sender: request_sender,
+                receiver: request_receiver,
+            } => {
+                let receiver_profile =
+                    PROFILES
+                        .may_load(storage, &request_receiver)?
+                        .ok_or_else(|| {
+                            anyhow!(ChainError::not_found(format!(
+                                "the user with address {} doesn't have a profile yet so their \
+                                 DTag cannot be transferred",
+                                request_receiver
+                            )))
+                        })?;
+                #[cfg(feature = "relationships")]
+                if has_user_blocked(storage, &request_receiver, &request_sender, 0) {
+                    bail!(ChainError::unauthorized(format!(
+                        "the user with address {} has blocked you",
+                        request_receiver
+                    )));
+                }
+                if DTAG_TRANSFER_REQUESTS.has(storage, (&request_receiver, &request_sender)) {
+                    bail!(ChainError::invalid_request(format!(
+                        "the transfer request from {} to {} has already been made",
+                        request_sender, request_receiver
+                    )));
+                }
+                DTAG_TRANSFER_REQUESTS.save(
+                    storage,
+                    (&request_receiver, &request_sender),
+                    &DtagTransferRequest {
+                        dtag_to_trade: receiver_profile.dtag.clone(),
+                        sender: request_sender.clone(),
+                        receiver: request_receiver.clone(),
+                    },
+                )?;
+
+                let mut events = Vec::with_capacity(1);
+                events.push(
+                    CreateDtagTransferRequestEvent {
+                        dtag_to_trade: receiver_profile.dtag,
+                        request_sender,
+                        request_receiver,
+                    }
+                    .into(),
+                );
+                AnyResult::Ok(AppResponse { events, data: None })
+            }
+            ProfilesMsg::AcceptDtagTransferRequest {
+                new_dtag,
+                sender: request_sender,
+                receiver: request_receiver,
+            } => {
+                let request =
+                    load_dtag_transfer_request(storage, &request_receiver, &request_sender)?;
+                let receiver_profile =
+                    PROFILES
+                        .may_load(storage, &request_receiver)?
+                        .ok_or_else(|| {
+                            anyhow!(ChainError::profile_not_found(request_receiver.as_str()))
+                        })?;
+                let dtag_to_trade = receiver_profile.dtag.clone();
+                if new_dtag.trim().is_empty() {
+                    bail!(ChainError::invalid_request("invalid profile dtag"));
+                }
+                if new_dtag.eq_ignore_ascii_case(&dtag_to_trade) {
+                    bail!(ChainError::invalid_request(
+                        "the new DTag must be different from the one to be traded"
+                    ));
+                }
+                // The receiver can take the DTag of the sender, since they are swapping them
+                match DTAGS.may_load(storage, &new_dtag.to_lowercase())? {
+                    Some(owner) if owner != request_sender => {
+                        bail!(ChainError::invalid_request(format!(
+                            "a profile with dtag {} has already been created",
+                            new_dtag
+                        )))
+                    }
+                    _ => {}
+                }
+
+                let sender_profile = match PROFILES.may_load(storage, &request_sender)? {
+                    Some(profile) => {
+                        DTAGS.remove(storage, &profile.dtag.to_lowercase());
+                        profile
+                    }
+                    None => empty_profile(&request_sender, block),
+                };
+                DTAGS.remove(storage, &dtag_to_trade.to_lowercase());
+                DTAGS.save(storage, &new_dtag.to_lowercase(), &request_receiver)?;
+                DTAGS.save(storage, &dtag_to_trade.to_lowercase(), &request_sender)?;
+                PROFILES.save(
+                    storage,
+                    &request_receiver,
+                    &Profile {
+                        dtag: new_dtag.clone(),
+                        ..receiver_profile
+                    },
+                )?;
+                PROFILES.save(
+                    storage,
+                    &request_sender,
+                    &Profile {
+                        dtag: request.dtag_to_trade,
+                        ..sender_profile
+                    },
+                )?;
+                // The other pending requests would trade a DTag that is no longer owned
+                remove_incoming_dtag_transfer_requests(storage, &request_receiver)?;
+                remove_incoming_dtag_transfer_requests(storage, &request_sender)?;
+
+                let mut events = Vec::with_capacity(1);
+                events.push(
+                    AcceptDtagTransferRequestEvent {
+                        dtag_to_trade,
+                        new_dtag,
+                        request_sender,
+                        request_receiver,
+                    }
+                    .into(),
+                );
+                AnyResult::Ok(AppResponse { events, data: None })
+            }
+            ProfilesMsg::RefuseDtagTransferRequest {
+                sender: request_sender,
+                receiver: request_receiver,
+            } => {
+                load_dtag_transfer_request(storage, &request_receiver, &request_sender)?;
+                DTAG_TRANSFER_REQUESTS.remove(storage, (&request_receiver, &request_sender));
+
+                let mut events = Vec::with_capacity(1);
+                events.push(
+                    RefuseDtagTransferRequestEvent {
+                        request_sender,
+                        request_receiver,
+                    }
+                    .into(),
+                );
+                AnyResult::Ok(AppResponse { events, data: None })
+            }
+            ProfilesMsg::CancelDtagTransferRequest {
+                sender: request_sender,
+                receiver: request_receiver,
+            } => {
+                load_dtag_transfer_request(storage, &request_receiver, &request_sender)?;
+                DTAG_TRANSFER_REQUESTS.remove(storage, (&request_receiver, &request_sender));
+
+                let mut events = Vec::with_capacity(1);
+                events.push(
+                    CancelDtagTransferRequestEvent {
+                        request_sender,
+                        request_receiver,
+                    }
+                    .into(),
+                );
+                AnyResult::Ok(AppResponse { events, data: None })
+            }
+            ProfilesMsg::LinkChainAccount {
+                chain_address,
+                proof,
+                chain_config,
+                signer: owner,
+            } => {
+                if !PROFILES.has(storage, &owner) {
+                    bail!(ChainError::profile_not_found(owner.as_str()));
+                }
+                let key = (
+                    &owner,
+                    chain_config.name.as_str(),
+                    chain_address.value.as_str(),
+                );
+                if CHAIN_LINKS.has(storage, key) {
+                    bail!(ChainError::invalid_request("chain link already exists"));
+                }
+                let link = ChainLink {
+                    user: owner.clone(),
+                    address: chain_address.clone(),
+                    proof,
+                    chain_config: chain_config.clone(),
+                    creation_time: to_rfc3339(&block.time),
+                };
+                CHAIN_LINKS.save(storage, key, &link)?;
+                // The first link of each chain becomes the default external address.
+                let default_key = (&owner, chain_config.name.as_str());
+                if !DEFAULT_EXTERNAL_ADDRESSES.has(storage, default_key) {
+                    DEFAULT_EXTERNAL_ADDRESSES.save(storage, default_key, &chain_address.value)?;
+                }
+
+                let mut events = Vec::with_capacity(1);
+                events.push(
+                    LinkChainAccountEvent {
+                        target: chain_address.value,
+                        chain_name: chain_config.name,
+                        owner,
+                        creation_time: link.creation_time,
+                    }
+                    .into(),
+                );
+                AnyResult::Ok(AppResponse { events, data: None })
+            }
+            ProfilesMsg::UnlinkChainAccount {
+                owner,
+                chain_name,
+                target,
+            } => {
+                let key = (&owner, chain_name.as_str(), target.as_str());
+                if !CHAIN_LINKS.has(storage, key) {
+                    bail!(ChainError::chain_link_not_found());
+                }
+                CHAIN_LINKS.remove(storage, key);
+                // When removing the default external address the chain falls back to
+                // the oldest remaining link of the same chain, if any.
+                let default_key = (&owner, chain_name.as_str());
+                if DEFAULT_EXTERNAL_ADDRESSES.may_load(storage, default_key)?
+                    == Some(target.clone())
+                {
+                    let next_link = CHAIN_LINKS
+                        .prefix((&owner, chain_name.as_str()))
+                        .range(storage, None, None, Order::Ascending)
+                        .map(|item| item.map(|(_, link)| link))
+                        .collect::<StdResult<Vec<_>>>()?
+                        .into_iter()
+                        .min_by(|a, b| a.creation_time.cmp(&b.creation_time));
+                    match next_link {
+                        Some(link) => DEFAULT_EXTERNAL_ADDRESSES.save(
+                            storage,
+                            default_key,
+                            &link.address.value,
+                        )?,
+                        None => DEFAULT_EXTERNAL_ADDRESSES.remove(storage, default_key),
+                    }
+                }
+
+                let mut events = Vec::with_capacity(1);
+                events.push(
+                    UnlinkChainAccountEvent {
+                        target,
+                        chain_name,
+                        owner,
+                    }
+                    .into(),
+                );
+                AnyResult::Ok(AppResponse { events, data: None })
+            }
+            ProfilesMsg::SetDefaultExternalAddress {
+                chain_name,
+                target,
+                signer,
+            } => {
+                if !CHAIN_LINKS.has(storage, (&signer, chain_name.as_str(), target.as_str())) {
+                    bail!(ChainError::chain_link_not_found());
+                }
+                DEFAULT_EXTERNAL_ADDRESSES.save(
+                    storage,
+                    (&signer, chain_name.as_str()),
+                    &target,
+                )?;
+
+                let mut events = Vec::with_capacity(1);
+                events.push(
+                    SetDefaultExternalAddressEvent {
+                        chain_name,
+                        external_address: target,
+                        owner: signer,
+                    }
+                    .into(),
+                );
+                AnyResult::Ok(AppResponse { events, data: None })
+            }
+            ProfilesMsg::LinkApplication {
+                sender: user,
+                link_data,
+                call_data,
+                ..
+            } => {
+                if !PROFILES.has(storage, &user) {
+                    bail!(ChainError::profile_not_found(user.as_str()));
+                }
+                let client_id =
+                    application_link_client_id(&user, &link_data.application, &link_data.username);
+                let link = ApplicationLink {
+                    user: user.clone(),
+                    data: link_data.clone(),
+                    state: ApplicationLinkState::Initialized,
+                    oracle_request: OracleRequest {
+                        id: Uint64::zero(),
+                        oracle_script_id: Uint64::new(APP_LINKS_ORACLE_SCRIPT_ID),
+                        call_data: CallData {
+                            application: link_data.application.clone(),
+                            call_data,
+                        },
+                        client_id: client_id.clone(),
+                    },
+                    result: None,
+                    creation_time: to_rfc3339(&block.time),
+                    expiration_time: to_rfc3339(
+                        &block.time.plus_seconds(APP_LINKS_VALIDITY_DURATION),
+                    ),
+                };
+                APPLICATION_LINKS.save(
+                    storage,
+                    (&user, &link_data.application, &link_data.username),
+                    &link,
+                )?;
+                APPLICATION_LINKS_CLIENT_IDS.save(
+                    storage,
+                    &client_id,
+                    &(
+                        user.clone(),
+                        link_data.application.clone(),
+                        link_data.username.clone(),
+                    ),
+                )?;
+
+                let mut events = Vec::with_capacity(1);
+                events.push(
+                    LinkApplicationEvent {
+                        user,
+                        application: link_data.application,
+                        username: link_data.username,
+                        creation_time: link.creation_time,
+                    }
+                    .into(),
+                );
+                AnyResult::Ok(AppResponse { events, data: None })
+            }
+            ProfilesMsg::UnlinkApplication {
+                application,
+                username,
+                signer: user,
+            } => {
+                remove_application_link(storage, &user, &application, &username)?;
+
+                let mut events = Vec::with_capacity(1);
+                events.push(
+                    UnlinkApplicationEvent {
+                        user,
+                        application,
+                        username,
+                    }
+                    .into(),
+                );
+                AnyResult::Ok(AppResponse { events, data: None })
+            }
+        }
+    }
+
+    /// Handles [`ProfilesSudoMsg`] updating the state of the application links
+    /// as the chain would do when receiving the oracle responses.
+    pub(super) fn handle_profiles_sudo(
+        &self,
+        storage: &mut dyn Storage,
+        msg: ProfilesSudoMsg,
+    ) -> AnyResult<AppResponse> {
+        let (user, application, username, state, result) = match msg {
+            ProfilesSudoMsg::StartApplicationLinkVerification {
+                user,
+                application,
+                username,
+            } => (
+                user,
+                application,
+                username,
+                ApplicationLinkState::VerificationStarted,
+                None,
+            ),
+            ProfilesSudoMsg::CompleteApplicationLinkVerification {
+                user,
+                application,
+                username,
+                result,
+            } => {
+                let state = match result {
+                    AppLinkResult::Success { .. } => ApplicationLinkState::VerificationSuccess,
+                    AppLinkResult::Failed { .. } => ApplicationLinkState::VerificationError,
+                };
+                (user, application, username, state, Some(result))
+            }
+            ProfilesSudoMsg::TimeOutApplicationLinkVerification {
+                user,
+                application,
+                username,
+            } => (
+                user,
+                application,
+                username,
+                ApplicationLinkState::TimedOut,
+                None,
+            ),
+        };
+
+        let key = (&user, application.as_str(), username.as_str());
+        let link = APPLICATION_LINKS
+            .may_load(storage, key)?
+            .ok_or_else(|| anyhow!(ChainError::application_link_not_found()))?;
+        let allowed_states: &[ApplicationLinkState] = match state {
+            ApplicationLinkState::VerificationStarted => &[ApplicationLinkState::Initialized],
+            _ => &[
+                ApplicationLinkState::Initialized,
+                ApplicationLinkState::VerificationStarted,
+            ],
+        };
+        if !allowed_states.contains(&link.state) {
+            bail!(ChainError::invalid_request(format!(
+                "cannot move an application link from state {:?} to {:?}",
+                link.state, state
+            )));
+        }
+        APPLICATION_LINKS.save(
+            storage,
+            key,
+            &ApplicationLink {
+                state,
+                result,
+                ..link
+            },
+        )?;
+        Ok(AppResponse::default())
+    }
+
+    /// Handles [`ProfilesQuery`](crate::profiles::query::ProfilesQuery) reading the x/profiles module state.
+    pub(super) fn query_profiles(
+        &self,
+        storage: &dyn Storage,
+        query: ProfilesQuery,
+    ) -> AnyResult<Binary> {
+        match query {
+            ProfilesQuery::Profile { user } => {
+                let profile = get_profile(storage, &user)?
+                    .ok_or_else(|| anyhow!(ChainError::profile_not_found(&user)))?;
+                Ok(to_binary(&QueryProfileResponse { profile })?)
+            }
+            ProfilesQuery::ChainLinks {
+                user,
+                chain_name,
+                target,
+                pagination,
+                ..
+            } => {
+                let links = match (user, chain_name, target) {
+                    (Some(user), Some(chain_name), Some(target)) => CHAIN_LINKS
+                        .may_load(storage, (&user, &chain_name, &target))?
+                        .into_iter()
+                        .collect(),
+                    (Some(user), Some(chain_name), None) => CHAIN_LINKS
+                        .prefix((&user, &chain_name))
+                        .range(storage, None, None, Order::Ascending)
+                        .map(|item| item.map(|(_, link)| link))
+                        .collect::<StdResult<Vec<_>>>()?,
+                    (Some(user), None, _) => CHAIN_LINKS
+                        .sub_prefix(&user)
+                        .range(storage, None, None, Order::Ascending)
+                        .map(|item| item.map(|(_, link)| link))
+                        .collect::<StdResult<Vec<_>>>()?,
+                    (None, _, _) => CHAIN_LINKS
+                        .range(storage, None, None, Order::Ascending)
+                        .map(|item| item.map(|(_, link)| link))
+                        .collect::<StdResult<Vec<_>>>()?,
+                };
+                let (links, pagination) = paginate(links, pagination.as_ref())?;
+                Ok(to_binary(&QueryChainLinksResponse { links, pagination })?)
+            }
+            ProfilesQuery::ChainLinkOwners {
+                chain_name,
+                target,
+                pagination,
+            } => {
+                let owners = CHAIN_LINKS
+                    .keys(storage, None, None, Order::Ascending)
+                    .filter(|item| match (item, &chain_name, &target) {
+                        (Ok((_, link_chain, _)), Some(chain_name), None) => {
+                            link_chain == chain_name
+                        }
+                        (Ok((_, link_chain, link_target)), Some(chain_name), Some(target)) => {
+                            link_chain == chain_name && link_target == target
+                        }
+                        _ => true,
+                    })
+                    .map(|item| {
+                        item.map(|(user, chain_name, target)| ChainLinkOwnerDetails {
+                            user,
+                            chain_name,
+                            target,
+                        })
+                    })
+                    .collect::<StdResult<Vec<_>>>()?;
+                let (owners, pagination) = paginate(owners, pagination.as_ref())?;
+                Ok(to_binary(&QueryChainLinkOwnersResponse {
+                    owners,
+                    pagination,
+                })?)
+            }
+            ProfilesQuery::DefaultExternalAddresses {
+                owner,
+                chain_name,
+                pagination,
+            } => {
+                let defaults = match (owner, chain_name) {
+                    (Some(owner), Some(chain_name)) => DEFAULT_EXTERNAL_ADDRESSES
+                        .may_load(storage, (&owner, &chain_name))?
+                        .map(|target| (owner, chain_name, target))
+                        .into_iter()
+                        .collect(),
+                    (Some(owner), None) => DEFAULT_EXTERNAL_ADDRESSES
+                        .prefix(&owner)
+                        .range(storage, None, None, Order::Ascending)
+                        .map(|item| {
+                            item.map(|(chain_name, target)| (owner.clone(), chain_name, target))
+                        })
+                        .collect::<StdResult<Vec<_>>>()?,
+                    (None, _) => DEFAULT_EXTERNAL_ADDRESSES
+                        .range(storage, None, None, Order::Ascending)
+                        .map(|item| {
+                            item.map(|((owner, chain_name), target)| (owner, chain_name, target))
+                        })
+                        .collect::<StdResult<Vec<_>>>()?,
+                };
+                let links = defaults
+                    .iter()
+                    .map(|(owner, chain_name, target)| {
+                        CHAIN_LINKS.load(storage, (owner, chain_name, target))
+                    })
+                    .collect::<StdResult<Vec<_>>>()?;
+                let (links, pagination) = paginate(links, pagination.as_ref())?;
+                Ok(to_binary(&QueryDefaultExternalAddressesResponse {
+                    links,
+                    pagination,
+                })?)
+            }
+            ProfilesQuery::ApplicationLinks {
+                user,
+                application,
+                username,
+                pagination,
+                ..
+            } => {
+                let links = match (user, application, username) {
+                    (Some(user), Some(application), Some(username)) => APPLICATION_LINKS
+                        .may_load(storage, (&user, &application, &username))?
+                        .into_iter()
+                        .collect(),
+                    (Some(user), Some(application), None) => APPLICATION_LINKS
+                        .prefix((&user, &application))
+                        .range(storage, None, None, Order::Ascending)
+                        .map(|item| item.map(|(_, link)| link))
+                        .collect::<StdResult<Vec<_>>>()?,
+                    (Some(user), None, _) => APPLICATION_LINKS
+                        .sub_prefix(&user)
+                        .range(storage, None, None, Order::Ascending)
+                        .map(|item| item.map(|(_, link)| link))
+                        .collect::<StdResult<Vec<_>>>()?,
+                    (None, _, _) => APPLICATION_LINKS
+                        .range(storage, None, None, Order::Ascending)
+                        .map(|item| item.map(|(_, link)| link))
+                        .collect::<StdResult<Vec<_>>>()?,
+                };
+                let (links, pagination) = paginate(links, pagination.as_ref())?;
+                Ok(to_binary(&QueryApplicationLinksResponse {
+                    links,
+                    pagination,
+                })?)
+            }
+            ProfilesQuery::ApplicationLinkByChainID { client_id } => {
+                let (user, application, username) = APPLICATION_LINKS_CLIENT_IDS
+                    .may_load(storage, &client_id)?
+                    .ok_or_else(|| anyhow!(ChainError::client_id_not_found(&client_id)))?;
+                let link = APPLICATION_LINKS.load(storage, (&user, &application, &username))?;
+                Ok(to_binary(&QueryApplicationLinkByClientIDResponse { link })?)
+            }
+            ProfilesQuery::ApplicationLinkOwners {
+                application,
+                username,
+                pagination,
+                ..
+            } => {
+                let owners = APPLICATION_LINKS
+                    .keys(storage, None, None, Order::Ascending)
+                    .filter(|item| match (item, &application, &username) {
+                        (Ok((_, link_application, _)), Some(application), None) => {
+                            link_application == application
+                        }
+                        (
+                            Ok((_, link_application, link_username)),
+                            Some(application),
+                            Some(username),
+                        ) => link_application == application && link_username == username,
+                        _ => true,
+                    })
+                    .map(|item| {
+                        item.map(
+                            |(user, application, username)| ApplicationLinkOwnerDetails {
+                                user,
+                                application,
+                                username,
+                            },
+                        )
+                    })
+                    .collect::<StdResult<Vec<_>>>()?;
+                let (owners, pagination) = paginate(owners, pagination.as_ref())?;
+                Ok(to_binary(&QueryApplicationLinkOwnersResponse {
+                    owners,
+                    pagination,
+                })?)
+            }
+            ProfilesQuery::IncomingDtagTransferRequests {
+                receiver,
+                pagination,
+                ..
+            } => {
+                let requests = DTAG_TRANSFER_REQUESTS
+                    .prefix(&receiver)
+                    .range(storage, None, None, Order::Ascending)
+                    .map(|item| item.map(|(_, request)| request))
+                    .collect::<StdResult<Vec<_>>>()?;
+                let (requests, pagination) = paginate(requests, pagination.as_ref())?;
+                Ok(to_binary(&QueryIncomingDtagTransferRequestResponse {
+                    requests,
+                    pagination,
+                })?)
+            }
+        }
+    }
+
+    /// Stores the given [`ProfilesGenesis`] inside the x/profiles module state.
+    pub(super) fn init_profiles_genesis(
+        &self,
+        storage: &mut dyn Storage,
+        genesis: ProfilesGenesis,
+    ) -> AnyResult<()> {
+        for profile in genesis.profiles {
+            let owner = &profile.account.address;
+            if PROFILES.has(storage, owner) {
+                bail!(ChainError::invalid_request(format!(
+                    "profile for address {} already exists",
+                    owner
+                )));
+            }
+            let dtag_key = profile.dtag.to_lowercase();
+            if DTAGS.has(storage, &dtag_key) {
+                bail!(ChainError::invalid_request(format!(
+                    "a profile with dtag {} has already been created",
+                    profile.dtag
+                )));
+            }
+            DTAGS.save(storage, &dtag_key, owner)?;
+            PROFILES.save(storage, owner, &profile)?;
+        }
+
+        for request in genesis.dtag_transfer_requests {
+            if !PROFILES.has(storage, &request.receiver) {
+                bail!(ChainError::profile_not_found(request.receiver.as_str()));
+            }
+            DTAG_TRANSFER_REQUESTS.save(storage, (&request.receiver, &request.sender), &request)?;
+        }
+
+        for link in genesis.chain_links {
+            if !PROFILES.has(storage, &link.user) {
+                bail!(ChainError::profile_not_found(link.user.as_str()));
+            }
+            CHAIN_LINKS.save(
+                storage,
+                (&link.user, &link.chain_config.name, &link.address.value),
+                &link,
+            )?;
+            // The first link of each chain becomes the default external address.
+            let default_key = (&link.user, link.chain_config.name.as_str());
+            if !DEFAULT_EXTERNAL_ADDRESSES.has(storage, default_key) {
+                DEFAULT_EXTERNAL_ADDRESSES.save(storage, default_key, &link.address.value)?;
+            }
+        }
+
+        for link in genesis.application_links {
+            if !PROFILES.has(storage, &link.user) {
+                bail!(ChainError::profile_not_found(link.user.as_str()));
+            }
+            APPLICATION_LINKS.save(
+                storage,
+                (&link.user, &link.data.application, &link.data.username),
+                &link,
+            )?;
+            APPLICATION_LINKS_CLIENT_IDS.save(
+                storage,
+                &link.oracle_request.client_id,
+                &(
+                    link.user.clone(),
+                    link.data.application.clone(),
+                    link.data.username.clone(),
+                ),
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ProfilesSudoMsg;
+    use crate::mocks::mock_apps::{desmos_sudo, mock_desmos_app, DesmosApp};
+    use crate::profiles::{
+        mocks::MockProfilesQueries,
+        models_app_links::{AppLinkResult, ApplicationLink, ApplicationLinkState, Data},
+        models_chain_links::{Address, ChainConfig},
+        msg::ProfilesMsg,
+        querier::ProfilesQuerier,
+    };
+    use crate::types::Height;
+    use cosmwasm_std::Addr;
+    use cw_multi_test::Executor;
+    use std::ops::Deref;
+
+    const USER: &str = "desmos1nwp8gxrnmrsrzjdhvk47vvmthzxjtphgxp5ftc";
+    const OTHER: &str = "desmos1rfv0f7mx7w9d3jv3h803u38vqym9ygg344asm3";
+    const THIRD: &str = "desmos1jsdjhs8ggcs7ua0mxjz5e9q5q3j4fe7u4nqvt3";
+
+    fn save_profile(app: &mut DesmosApp, user: &str, dtag: &str) {
+        app.execute(
+            Addr::unchecked(user),
+            ProfilesMsg::save_profile(dtag, Addr::unchecked(user), "Nickname", "Bio", "", "")
+                .into(),
+        )
+        .unwrap();
+    }
+
+    fn link_chain_account(app: &mut DesmosApp, user: &str, target: &str) {
+        let mock_link = MockProfilesQueries::get_mock_chain_link();
+        app.execute(
+            Addr::unchecked(user),
+            ProfilesMsg::link_chain_account(
+                Address {
+                    value: target.to_string(),
+                    ..mock_link.address
+                },
+                mock_link.proof,
+                mock_link.chain_config,
+                Addr::unchecked(user),
+            )
+            .into(),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn save_profile_stores_the_profile() {
+        let mut app = mock_desmos_app();
+        save_profile(&mut app, USER, "goldrake");
+
+        let querier = app.wrap();
+        let querier = ProfilesQuerier::new(querier.deref());
+        let profile = querier
+            .query_profile(Addr::unchecked(USER))
+            .unwrap()
+            .profile;
+        assert_eq!(Addr::unchecked(USER), profile.account.address);
+        assert_eq!("goldrake", profile.dtag);
+        assert_eq!("Nickname", profile.nickname);
+        assert_eq!("2019-10-23T02:23:39.879305533Z", profile.creation_date);
+
+        // The profile can be queried by its DTag as well
+        let by_dtag = querier
+            .query_profile(Addr::unchecked("GoldRake"))
+            .unwrap()
+            .profile;
+        assert_eq!(profile, by_dtag);
+    }
+
+    #[test]
+    fn save_profile_keeps_do_not_modify_fields() {
+        let mut app = mock_desmos_app();
+        save_profile(&mut app, USER, "goldrake");
+        app.execute(
+            Addr::unchecked(USER),
+            ProfilesMsg::save_profile(
+                "[do-not-modify]",
+                Addr::unchecked(USER),
+                "New nickname",
+                "[do-not-modify]",
+                "[do-not-modify]",
+                "[do-not-modify]",
+            )
+            .into(),
+        )
+        .unwrap();
+
+        let querier = app.wrap();
+        let profile = ProfilesQuerier::new(querier.deref())
+            .query_profile(Addr::unchecked(USER))
+            .unwrap()
+            .profile;
+        assert_eq!("goldrake", profile.dtag);
+        assert_eq!("New nickname", profile.nickname);
+        assert_eq!("Bio", profile.bio);
+    }
+
+    #[test]
+    fn save_profile_with_taken_dtag_errors() {
+        let mut app = mock_desmos_app();
+        save_profile(&mut app, USER, "goldrake");
+        let result = app.execute(
+            Addr::unchecked("other"),
+            ProfilesMsg::save_profile("Goldrake", Addr::unchecked("other"), "", "", "", "").into(),
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn delete_profile_removes_the_profile_and_its_links() {
+        let mut app = mock_desmos_app();
+        save_profile(&mut app, USER, "goldrake");
+        link_chain_account(&mut app, USER, "cosmos1target");
+        app.execute(
+            Addr::unchecked(USER),
+            ProfilesMsg::delete_profile(Addr::unchecked(USER)).into(),
+        )
+        .unwrap();
+
+        let querier = app.wrap();
+        let querier = ProfilesQuerier::new(querier.deref());
+        assert!(querier.query_profile(Addr::unchecked(USER)).is_err());
+        assert!(querier
+            .query_chain_links(Some(Addr::unchecked(USER)), None, None, None)
+            .unwrap()
+            .links
+            .is_empty());
+
+        // The DTag can be used again
+        save_profile(&mut app, "other", "goldrake");
+    }
+
+    #[test]
+    fn chain_links_update_default_external_addresses() {
+        let mut app = mock_desmos_app();
+        save_profile(&mut app, USER, "goldrake");
+        link_chain_account(&mut app, USER, "cosmos1first");
+        link_chain_account(&mut app, USER, "cosmos1second");
+
+        let querier = app.wrap();
+        let querier = ProfilesQuerier::new(querier.deref());
+        let links = querier
+            .query_chain_links(
+                Some(Addr::unchecked(USER)),
+                Some("cosmos".to_string()),
+                None,
+                None,
+            )
+            .unwrap()
+            .links;
+        assert_eq!(2, links.len());
+        let owners = querier
+            .query_chain_link_owners(
+                Some("cosmos".to_string()),
+                Some("cosmos1second".to_string()),
+                None,
+            )
+            .unwrap()
+            .owners;
+        assert_eq!(1, owners.len());
+        assert_eq!(Addr::unchecked(USER), owners[0].user);
+        let defaults = querier
+            .query_default_external_addresses(Some(Addr::unchecked(USER)), None, None)
+            .unwrap()
+            .links;
+        assert_eq!("cosmos1first", defaults[0].address.value);
+
+        app.execute(
+            Addr::unchecked(USER),
+            ProfilesMsg::set_default_external_address(
+                "cosmos",
+                "cosmos1second",
+                Addr::unchecked(USER),
+            )
+            .into(),
+        )
+        .unwrap();
+        let querier = app.wrap();
+        let querier = ProfilesQuerier::new(querier.deref());
+        let defaults = querier
+            .query_default_external_addresses(
+                Some(Addr::unchecked(USER)),
+                Some("cosmos".to_string()),
+                None,
+            )
+            .unwrap()
+            .links;
+        assert_eq!("cosmos1second", defaults[0].address.value);
+
+        app.execute(
+            Addr::unchecked(USER),
+            ProfilesMsg::unlink_chain_account(Addr::unchecked(USER), "cosmos", "cosmos1second")
+                .into(),
+        )
+        .unwrap();
+        let querier = app.wrap();
+        let querier = ProfilesQuerier::new(querier.deref());
+        let defaults = querier
+            .query_default_external_addresses(None, None, None)
+            .unwrap()
+            .links;
+        assert_eq!(1, defaults.len());
+        assert_eq!("cosmos1first", defaults[0].address.value);
+    }
+
+    #[test]
+    fn link_chain_account_without_profile_errors() {
+        let mut app = mock_desmos_app();
+        let mock_link = MockProfilesQueries::get_mock_chain_link();
+        let result = app.execute(
+            Addr::unchecked(USER),
+            ProfilesMsg::link_chain_account(
+                mock_link.address,
+                mock_link.proof,
+                ChainConfig {
+                    name: "cosmos".to_string(),
+                },
+                Addr::unchecked(USER),
+            )
+            .into(),
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn link_application_stores_the_link() {
+        let mut app = mock_desmos_app();
+        save_profile(&mut app, USER, "goldrake");
+        app.execute(
+            Addr::unchecked(USER),
+            ProfilesMsg::link_application(
+                Addr::unchecked(USER),
+                Data {
+                    application: "twitter".to_string(),
+                    username: "goldrake".to_string(),
+                },
+                "call_data".to_string(),
+                "port".to_string(),
+                "channel".to_string(),
+                Height {
+                    revision_number: 0u64.into(),
+                    revision_height: 0u64.into(),
+                },
+                0,
+            )
+            .into(),
+        )
+        .unwrap();
+
+        let querier = app.wrap();
+        let querier = ProfilesQuerier::new(querier.deref());
+        let links = querier
+            .query_application_links(Some(Addr::unchecked(USER)), None, None, None)
+            .unwrap()
+            .links;
+        assert_eq!(1, links.len());
+        assert_eq!(ApplicationLinkState::Initialized, links[0].state);
+        let by_client_id = querier
+            .query_application_link_by_client_id(&links[0].oracle_request.client_id)
+            .unwrap()
+            .link;
+        assert_eq!(links[0], by_client_id);
+        let owners = querier
+            .query_application_link_owners(Some("twitter".to_string()), None, None)
+            .unwrap()
+            .owners;
+        assert_eq!(Addr::unchecked(USER), owners[0].user);
+
+        app.execute(
+            Addr::unchecked(USER),
+            ProfilesMsg::unlink_application("twitter", "goldrake", Addr::unchecked(USER)).into(),
+        )
+        .unwrap();
+        let querier = app.wrap();
+        let querier = ProfilesQuerier::new(querier.deref());
+        assert!(querier
+            .query_application_link_by_client_id(&links[0].oracle_request.client_id)
+            .is_err());
+    }
+
+    fn query_dtag(app: &DesmosApp, user: &str) -> String {
+        let querier = app.wrap();
+        ProfilesQuerier::new(querier.deref())
+            .query_profile(Addr::unchecked(user))
+            .unwrap()
+            .profile
+            .dtag
+    }
+
+    #[test]
+    fn request_dtag_transfer_stores_the_request() {
+        let mut app = mock_desmos_app();
+        save_profile(&mut app, USER, "goldrake");
+        let request =
+            ProfilesMsg::request_dtag_transfer(Addr::unchecked(OTHER), Addr::unchecked(USER));
+        let response = app
+            .execute(Addr::unchecked(OTHER), request.clone().into())
+            .unwrap();
+        let event = response
+            .events
+            .iter()
+            .find(|event| event.ty == "create_dtag_transfer_request")
+            .unwrap();
+        assert!(event
+            .attributes
+            .iter()
+            .any(|attr| attr.key == "dtag_to_trade" && attr.value == "goldrake"));
+        assert!(app.execute(Addr::unchecked(OTHER), request.into()).is_err());
+
+        let querier = app.wrap();
+        let requests = ProfilesQuerier::new(querier.deref())
+            .query_incoming_dtag_transfer_requests(Addr::unchecked(USER), None)
+            .unwrap()
+            .requests;
+        assert_eq!(1, requests.len());
+        assert_eq!("goldrake", requests[0].dtag_to_trade);
+        assert_eq!(Addr::unchecked(OTHER), requests[0].sender);
+    }
+
+    #[test]
+    fn request_dtag_transfer_to_user_without_profile_errors() {
+        let mut app = mock_desmos_app();
+        let result = app.execute(
+            Addr::unchecked(OTHER),
+            ProfilesMsg::request_dtag_transfer(Addr::unchecked(OTHER), Addr::unchecked(USER))
+                .into(),
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn accept_dtag_transfer_request_swaps_the_dtags() {
+        let mut app = mock_desmos_app();
+        save_profile(&mut app, USER, "goldrake");
+        save_profile(&mut app, OTHER, "mazinga");
+        app.execute(
+            Addr::unchecked(OTHER),
+            ProfilesMsg::request_dtag_transfer(Addr::unchecked(OTHER), Addr::unchecked(USER))
+                .into(),
+        )
+        .unwrap();
+        app.execute(
+            Addr::unchecked(USER),
+            ProfilesMsg::accept_dtag_transfer_request(
+                "mazinga",
+                Addr::unchecked(OTHER),
+                Addr::unchecked(USER),
+            )
+            .into(),
+        )
+        .unwrap();
+
+        assert_eq!("mazinga", query_dtag(&app, USER));
+        assert_eq!("goldrake", query_dtag(&app, OTHER));
+        let querier = app.wrap();
+        let querier = ProfilesQuerier::new(querier.deref());
+        assert!(querier
+            .query_incoming_dtag_transfer_requests(Addr::unchecked(USER), None)
+            .unwrap()
+            .requests
+            .is_empty());
+        let by_dtag = querier
+            .query_profile(Addr::unchecked("goldrake"))
+            .unwrap()
+            .profile;
+        assert_eq!(Addr::unchecked(OTHER), by_dtag.account.address);
+    }
+
+    #[test]
+    fn accept_dtag_transfer_request_creates_the_sender_profile() {
+        let mut app = mock_desmos_app();
+        save_profile(&mut app, USER, "goldrake");
+        save_profile(&mut app, THIRD, "jeeg");
+        app.execute(
+            Addr::unchecked(OTHER),
+            ProfilesMsg::request_dtag_transfer(Addr::unchecked(OTHER), Addr::unchecked(USER))
+                .into(),
+        )
+        .unwrap();
+
+        let accept = |new_dtag| {
+            ProfilesMsg::accept_dtag_transfer_request(
+                new_dtag,
+                Addr::unchecked(OTHER),
+                Addr::unchecked(USER),
+            )
+        };
+        // The new DTag can't be owned by a third user or be the traded one
+        assert!(app
+            .execute(Addr::unchecked(USER), accept("Jeeg").into())
+            .is_err());
+        assert!(app
+            .execute(Addr::unchecked(USER), accept("goldrake").into())
+            .is_err());
+        app.execute(Addr::unchecked(USER), accept("actarus").into())
+            .unwrap();
+
+        assert_eq!("actarus", query_dtag(&app, USER));
+        assert_eq!("goldrake", query_dtag(&app, OTHER));
+    }
+
+    #[test]
+    fn refuse_and_cancel_remove_the_request() {
+        let mut app = mock_desmos_app();
+        save_profile(&mut app, USER, "goldrake");
+        let request =
+            ProfilesMsg::request_dtag_transfer(Addr::unchecked(OTHER), Addr::unchecked(USER));
+        let refuse = ProfilesMsg::refuse_dtag_transfer_request(
+            Addr::unchecked(OTHER),
+            Addr::unchecked(USER),
+        );
+        let cancel = ProfilesMsg::cancel_dtag_transfer_request(
+            Addr::unchecked(USER),
+            Addr::unchecked(OTHER),
+        );
+
+        assert!(app
+            .execute(Addr::unchecked(USER), refuse.clone().into())
+            .is_err());
+        assert!(app
+            .execute(Addr::unchecked(OTHER), cancel.clone().into())
+            .is_err());
+
+        app.execute(Addr::unchecked(OTHER), request.clone().into())
+            .unwrap();
+        app.execute(Addr::unchecked(USER), refuse.into()).unwrap();
+        app.execute(Addr::unchecked(OTHER), request.into()).unwrap();
+        app.execute(Addr::unchecked(OTHER), cancel.into()).unwrap();
+
+        let querier = app.wrap();
+        assert!(ProfilesQuerier::new(querier.deref())
+            .query_incoming_dtag_transfer_requests(Addr::unchecked(USER), None)
+            .unwrap()
+            .requests
+            .is_empty());
+    }
+
+    fn link_application(app: &mut DesmosApp) {
+        app.execute(
+            Addr::unchecked(USER),
+            ProfilesMsg::link_application(
+                Addr::unchecked(USER),
+                Data {
+                    application: "twitter".to_string(),
+                    username: "goldrake".to_string(),
+                },
+                "call_data".to_string(),
+                "port".to_string(),
+                "channel".to_string(),
+                Height {
+                    revision_number: 0u64.into(),
+                    revision_height: 0u64.into(),
+                },
+                0,
+            )
+            .into(),
+        )
+        .unwrap();
+    }
+
+    fn query_application_link(app: &DesmosApp) -> ApplicationLink {
+        let querier = app.wrap();
+        let querier = ProfilesQuerier::new(querier.deref());
+        let link = querier
+            .query_application_links(
+                Some(Addr::unchecked(USER)),
+                Some("twitter".to_string()),
+                Some("goldrake".to_string()),
+                None,
+            )
+            .unwrap()
+            .links
+            .remove(0);
+        let by_client_id = querier
+            .query_application_link_by_client_id(&link.oracle_request.client_id)
+            .unwrap()
+            .link;
+        assert_eq!(link, by_client_id);
+        link
+    }
+
+    #[test]
+    fn sudo_moves_application_link_to_verification_success() {
+        let mut app = mock_desmos_app();
+        save_profile(&mut app, USER, "goldrake");
+        link_application(&mut app);
+
+        desmos_sudo(
+            &mut app,
+            ProfilesSudoMsg::start_application_link_verification(
+                Addr::unchecked(USER),
+                "twitter",
+                "goldrake",
+            ),
+        )
+        .unwrap();
+        let link = query_application_link(&app);
+        assert_eq!(ApplicationLinkState::VerificationStarted, link.state);
+        assert_eq!(None, link.result);
+
+        let result = AppLinkResult::Success {
+            value: "goldrake".to_string(),
+            signature: "signature".to_string(),
+        };
+        desmos_sudo(
+            &mut app,
+            ProfilesSudoMsg::complete_application_link_verification(
+                Addr::unchecked(USER),
+                "twitter",
+                "goldrake",
+                result.clone(),
+            ),
+        )
+        .unwrap();
+        let link = query_application_link(&app);
+        assert_eq!(ApplicationLinkState::VerificationSuccess, link.state);
+        assert_eq!(Some(result), link.result);
+
+        // Completed verifications can't be changed anymore
+        let sudo = desmos_sudo(
+            &mut app,
+            ProfilesSudoMsg::time_out_application_link_verification(
+                Addr::unchecked(USER),
+                "twitter",
+                "goldrake",
+            ),
+        );
+        assert!(sudo.is_err());
+    }
+
+    #[test]
+    fn sudo_moves_application_link_to_verification_error() {
+        let mut app = mock_desmos_app();
+        save_profile(&mut app, USER, "goldrake");
+        link_application(&mut app);
+
+        let result = AppLinkResult::Failed {
+            error: "invalid signature".to_string(),
+        };
+        desmos_sudo(
+            &mut app,
+            ProfilesSudoMsg::complete_application_link_verification(
+                Addr::unchecked(USER),
+                "twitter",
+                "goldrake",
+                result.clone(),
+            ),
+        )
+        .unwrap();
+        let link = query_application_link(&app);
+        assert_eq!(ApplicationLinkState::VerificationError, link.state);
+        assert_eq!(Some(result), link.result);
+
+        let querier = app.wrap();
+        let owners = ProfilesQuerier::new(querier.deref())
+            .query_application_link_owners(Some("twitter".to_string()), None, None)
+            .unwrap()
+            .owners;
+        assert_eq!(1, owners.len());
+    }
+
+    #[test]
+    fn sudo_moves_application_link_to_timed_out() {
+        let mut app = mock_desmos_app();
+        save_profile(&mut app, USER, "goldrake");
+        link_application(&mut app);
+
+        desmos_sudo(
+            &mut app,
+            ProfilesSudoMsg::start_application_link_verification(
+                Addr::unchecked(USER),
+                "twitter",
+                "goldrake",
+            ),
+        )
+        .unwrap();
+        // The verification can't be started twice
+        let sudo = desmos_sudo(
+            &mut app,
+            ProfilesSudoMsg::start_application_link_verification(
+                Addr::unchecked(USER),
+                "twitter",
+                "goldrake",
+            ),
+        );
+        assert!(sudo.is_err());
+
+        desmos_sudo(
+            &mut app,
+            ProfilesSudoMsg::time_out_application_link_verification(
+                Addr::unchecked(USER),
+                "twitter",
+                "goldrake",
+            ),
+        )
+        .unwrap();
+        let link = query_application_link(&app);
+        assert_eq!(ApplicationLinkState::TimedOut, link.state);
+        assert_eq!(None, link.result);
+    }
+
+    #[test]
+    fn sudo_on_missing_application_link_errors() {
+        let mut app = mock_desmos_app();
+        let sudo = desmos_sudo(
+            &mut app,
+            ProfilesSudoMsg::start_application_link_verification(
+                Addr::unchecked(USER),
+                "twitter",
+                "goldrake",
+            ),
+        );
+        assert!(sudo.is_err());
+    }
+}
@@ -0,0 +1,808 @@
+//! Contains the simulation of the Desmos x/reactions module used by the [`DesmosKeeper`].
+
+use crate::mocks::errors::ChainError;
+#[cfg(feature = "posts")]
+use crate::mocks::mock_keeper::posts::load_post;
+#[cfg(feature = "subspaces")]
+use crate::mocks::mock_keeper::subspaces::load_subspace;
+use crate::mocks::mock_keeper::{bump_next_id, genesis::ReactionsGenesis, DesmosKeeper};
+use crate::pagination::paginate;
+use crate::reactions::{
+    events::{
+        AddReactionEvent, AddRegisteredReactionEvent, EditRegisteredReactionEvent,
+        RemoveReactionEvent, RemoveRegisteredReactionEvent, SetReactionsParamsEvent,
+    },
+    models::{
+        FreeTextValueParams, Reaction, ReactionValue, RegisteredReaction,
+        RegisteredReactionValueParams, SubspaceReactionsParams,
+    },
+    models_query::{
+        QueryReactionResponse, QueryReactionsParamsResponse, QueryReactionsResponse,
+        QueryRegisteredReactionResponse, QueryRegisteredReactionsResponse,
+    },
+    msg::ReactionsMsg,
+    query::ReactionsQuery,
+};
+use anyhow::{anyhow, bail, Result as AnyResult};
+use cosmwasm_std::{to_binary, Binary, Order, StdResult, Storage, Uint64};
+use cw_multi_test::AppResponse;
+use cw_storage_plus::Map;
+use regex::Regex;
+use std::convert::TryFrom;
+
+/// Id that will be assigned to the next reaction added to a post.
+const NEXT_REACTION_IDS: Map<(u64, u64), u32> = Map::new("desmos_reactions_next_reaction_ids");
+/// Reactions stored by (subspace id, post id, reaction id).
+const REACTIONS: Map<(u64, u64, u32), Reaction> = Map::new("desmos_reactions");
+/// Id that will be assigned to the next registered reaction added to a subspace.
+const NEXT_REGISTERED_REACTION_IDS: Map<u64, u32> =
+    Map::new("desmos_reactions_next_registered_reaction_ids");
+/// Registered reactions stored by (subspace id, registered reaction id).
+const REGISTERED_REACTIONS: Map<(u64, u32), RegisteredReaction> =
+    Map::new("desmos_reactions_registered_reactions");
+/// Reactions params stored by subspace id.
+const REACTIONS_PARAMS: Map<u64, SubspaceReactionsParams> = Map::new("desmos_reactions_params");
+
+/// Returns the reactions params of the given subspace, or the default ones
+/// used by the chain if they have never been set.
+fn load_reactions_params(
+    storage: &dyn Storage,
+    subspace_id: u64,
+) -> StdResult<SubspaceReactionsParams> {
+    Ok(REACTIONS_PARAMS
+        .may_load(storage, subspace_id)?
+        .unwrap_or(SubspaceReactionsParams {
+            subspace_id: Uint64::new(subspace_id),
+            registered_reaction: RegisteredReactionValueParams { enabled: true },
+            free_text: FreeTextValueParams {
+                enabled: true,
+                max_length: 5,
+                reg_ex: String::new(),
+            },
+        }))
+}
+
+/// Loads the registered reaction having the given id, returning an error if it does not exist.
+fn load_registered_reaction(
+    storage: &dyn Storage,
+    subspace_id: u64,
+    registered_reaction_id: u32,
+) -> AnyResult<RegisteredReaction> {
+    REGISTERED_REACTIONS
+        .may_load(storage, (subspace_id, registered_reaction_id))?
+        .ok_or_else(|| {
+            anyhow!(ChainError::registered_reaction_not_found(
+                registered_reaction_id
+            ))
+        })
+}
+
+/// Loads the reaction having the given id, returning an error if it does not exist.
+fn load_reaction(
+    storage: &dyn Storage,
+    subspace_id: u64,
+    post_id: u64,
+    reaction_id: u32,
+) -> AnyResult<Reaction> {
+    REACTIONS
+        .may_load(storage, (subspace_id, post_id, reaction_id))?
+        .ok_or_else(|| anyhow!(ChainError::reaction_not_found(reaction_id)))
+}
+
+/// Removes all the reactions added to the given post.
+#[cfg(feature = "posts")]
+pub(super) fn remove_post_reactions(
+    storage: &mut dyn Storage,
+    subspace_id: u64,
+    post_id: u64,
+) -> StdResult<()> {
+    let reactions = REACTIONS
+        .prefix((subspace_id, post_id))
+        .keys(storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+    for reaction_id in reactions {
+        REACTIONS.remove(storage, (subspace_id, post_id, reaction_id));
+    }
+    NEXT_REACTION_IDS.remove(storage, (subspace_id, post_id));
+    Ok(())
+}
+
+/// Removes all the reactions, registered reactions and reactions params of the given subspace.
+#[cfg(feature = "subspaces")]
+pub(super) fn remove_subspace_reactions(
+    storage: &mut dyn Storage,
+    subspace_id: u64,
+) -> StdResult<()> {
+    let reactions = REACTIONS
+        .sub_prefix(subspace_id)
+        .keys(storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+    for (post_id, reaction_id) in reactions {
+        REACTIONS.remove(storage, (subspace_id, post_id, reaction_id));
+    }
+    let posts = NEXT_REACTION_IDS
+        .prefix(subspace_id)
+        .keys(storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+    for post_id in posts {
+        NEXT_REACTION_IDS.remove(storage, (subspace_id, post_id));
+    }
+    let registered_reactions = REGISTERED_REACTIONS
+        .prefix(subspace_id)
+        .keys(storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+    for registered_reaction_id in registered_reactions {
+        REGISTERED_REACTIONS.remove(storage, (subspace_id, registered_reaction_id));
+    }
+    NEXT_REGISTERED_REACTION_IDS.remove(storage, subspace_id);
+    REACTIONS_PARAMS.remove(storage, subspace_id);
+    Ok(())
+}
+
+/// Checks that the given reaction value respects the subspace reactions params.
+fn validate_reaction_value(
+    storage: &dyn Storage,
+    subspace_id: u64,
+    value: &ReactionValue,
+) -> AnyResult<()> {
+    let params = load_reactions_params(storage, subspace_id)?;
+    match value {
+        ReactionValue::Registered {
+            registered_reaction_id,
+        } => {
+            if !params.registered_reaction.enabled {
+                bail!(ChainError::invalid_request(
+                    "registered reactions are not enabled"
+                ));
+            }
+            load_registered_reaction(storage, subspace_id, *registered_reaction_id)?;
+        }
+        ReactionValue::FreeText { text } => {
+            let free_text = params.free_text;
+            if !free_text.enabled {
+                bail!(ChainError::invalid_request(
+                    "free text reactions are not enabled"
+                ));
+            }
+            if text.chars().count() > free_text.max_length as usize {
+                bail!(ChainError::invalid_request(format!(
+                    "text exceeds max length allowed of {}",
+                    free_text.max_length
+                )));
+            }
+            if !free_text.reg_ex.is_empty()
+                && !Regex::new(&free_text.reg_ex)
+                    .map_err(|_| {
+                        ChainError::invalid_request(format!("invalid regex {}", free_text.reg_ex))
+                    })?
+                    .is_match(text)
+            {
+                bail!(ChainError::invalid_request(format!(
+                    "text does not respect required regex {}",
+                    free_text.reg_ex
+                )));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Returns the type URL of the chain message corresponding to the given [`ReactionsMsg`].
+pub(super) fn reactions_msg_type_url(msg: &ReactionsMsg) -> &'static str {
+    match msg {
+        ReactionsMsg::AddReaction { .. } => "/desmos.reactions.v1.MsgAddReaction",
+        ReactionsMsg::RemoveReaction { .. } => "/desmos.reactions.v1.MsgRemoveReaction",
+        ReactionsMsg::AddRegisteredReaction { .. } => {
+            "/desmos.reactions.v1.MsgAddRegisteredReaction"
+        }
+        ReactionsMsg::EditRegisteredReaction { .. } => {
+            "/desmos.reactions.v1.MsgEditRegisteredReaction"
+        }
+        ReactionsMsg::RemoveRegisteredReaction { .. } => {
+            "/desmos.reactions.v1.MsgRemoveRegisteredReaction"
+        }
+        ReactionsMsg::SetReactionsParams { .. } => "/desmos.reactions.v1.MsgSetReactionsParams",
+    }
+}
+
+impl DesmosKeeper {
+    /// Handles [`ReactionsMsg`](crate::reactions::ReactionsMsg) updating the x/reactions module state,
+    /// then returns the response with proper [events](https://github.com/desmos-labs/desmos/blob/master/x/reactions/spec/05-events.md).
+    pub(super) fn handle_reactions_msg(
+        &self,
+        storage: &mut dyn Storage,
+        msg: ReactionsMsg,
+    ) -> AnyResult<AppResponse> {
+        match msg {
+            ReactionsMsg::AddReaction {
+                subspace_id,
+                post_id,
+                value,
+                user,
+            } => {
+                #[cfg(feature = "posts")]
+                load_post(storage, subspace_id.u64(), post_id.u64())?;
+                let reaction_value = ReactionValue::try_from(value.clone()).map_err(|err| {
+                    ChainError::invalid_request(format!("invalid reaction value: {}", err))
+                })?;
+                validate_reaction_value(storage, subspace_id.u64(), &reaction_value)?;
+
+                let already_reacted = REACTIONS
+                    .prefix((subspace_id.u64(), post_id.u64()))
+                    .range(storage, None, None, Order::Ascending)
+                    .any(|item| match item {
+                        Ok((_, reaction)) => reaction.author == user && reaction.value == value,
+                        Err(_) => false,
+                    });
+                if already_reacted {
+                    bail!(ChainError::invalid_request(
+                        "you have already reacted with the same value to this post"
+                    ));
+                }
+
+                let reaction_id = NEXT_REACTION_IDS
+                    .may_load(storage, (subspace_id.u64(), post_id.u64()))?
+                    .unwrap_or(1);
+                NEXT_REACTION_IDS.save(
+                    storage,
+                    (subspace_id.u64(), post_id.u64()),
+                    &(reaction_id + 1),
+                )?;
+                REACTIONS.save(
+                    storage,
+                    (subspace_id.u64(), post_id.u64(), reaction_id),
+                    &Reaction {
+                        subspace_id,
+                        post_id,
+                        id: reaction_id,
+                        value,
+                        author: user.clone(),
+                    },
+                )?;
+
+                let mut events = Vec::with_capacity(1);
+                events.push(
+                    AddReactionEvent {
+                        subspace_id: subspace_id.u64(),
+                        post_id: post_id.u64(),
+                        reaction_id,
+                        user,
+                    }
+                    .into(),
+                );
+                AnyResult::Ok(AppResponse { events, data: None })
+            }
+            ReactionsMsg::RemoveReaction {
+                subspace_id,
+                post_id,
+                reaction_id,
+                user,
+            } => {
+                let reaction =
+                    load_reaction(storage, subspace_id.u64(), post_id.u64(), reaction_id)?;
+                if reaction.author != user {
+                    bail!(ChainError::unauthorized(
+                        "you cannot remove a reaction you are not the author of"
+                    ));
+                }
+                REACTIONS.remove(storage, (subspace_id.u64(), post_id.u64(), reaction_id));
+
+                let mut events = Vec::with_capacity(1);
+                events.push(
+                    RemoveReactionEvent {
+                        subspace_id: subspace_id.u64(),
+                        post_id: post_id.u64(),
+                        reaction_id,
+                    }
+                    .into(),
+                );
+                AnyResult::Ok(AppResponse { events, data: None })
+            }
+            ReactionsMsg::AddRegisteredReaction {
+                subspace_id,
+                shorthand_code,
+                display_value,
+                ..
+            } => {
+                #[cfg(feature = "subspaces")]
+                load_subspace(storage, subspace_id.u64())?;
+                let registered_reaction_id = NEXT_REGISTERED_REACTION_IDS
+                    .may_load(storage, subspace_id.u64())?
+                    .unwrap_or(1);
+                NEXT_REGISTERED_REACTION_IDS.save(
+                    storage,
+                    subspace_id.u64(),
+                    &(registered_reaction_id + 1),
+                )?;
+                REGISTERED_REACTIONS.save(
+                    storage,
+                    (subspace_id.u64(), registered_reaction_id),
+                    &RegisteredReaction {
+                        subspace_id,
+                        id: registered_reaction_id,
+                        shorthand_code,
+                        display_value,
+                    },
+                )?;
+
+                let mut events = Vec::with_capacity(1);
+                events.push(
+                    AddRegisteredReactionEvent {
+                        subspace_id: subspace_id.u64(),
+                        registered_reaction_id,
+                    }
+                    .into(),
+                );
+                AnyResult::Ok(AppResponse { events, data: None })
+            }
+            ReactionsMsg::EditRegisteredReaction {
+                subspace_id,
+                registered_reaction_id,
+                shorthand_code,
+                display_value,
+                ..
+            } => {
+                let registered_reaction =
+                    load_registered_reaction(storage, subspace_id.u64(), registered_reaction_id)?;
+                REGISTERED_REACTIONS.save(
+                    storage,
+                    (subspace_id.u64(), registered_reaction_id),
+                    &RegisteredReaction {
+                        shorthand_code,
+                        display_value,
+                        ..registered_reaction
+                    },
+                )?;
+
+                let mut events = Vec::with_capacity(1);
+                events.push(
+                    EditRegisteredReactionEvent {
+                        subspace_id: subspace_id.u64(),
+                        registered_reaction_id,
+                    }
+                    .into(),
+                );
+                AnyResult::Ok(AppResponse { events, data: None })
+            }
+            ReactionsMsg::RemoveRegisteredReaction {
+                subspace_id,
+                registered_reaction_id,
+                ..
+            } => {
+                load_registered_reaction(storage, subspace_id.u64(), registered_reaction_id)?;
+                REGISTERED_REACTIONS.remove(storage, (subspace_id.u64(), registered_reaction_id));
+
+                let mut events = Vec::with_capacity(1);
+                events.push(
+                    RemoveRegisteredReactionEvent {
+                        subspace_id: subspace_id.u64(),
+                        registered_reaction_id,
+                    }
+                    .into(),
+                );
+                AnyResult::Ok(AppResponse { events, data: None })
+            }
+            ReactionsMsg::SetReactionsParams {
+                subspace_id,
+                registered_reaction,
+                free_text,
+                ..
+            } => {
+                #[cfg(feature = "subspaces")]
+                load_subspace(storage, subspace_id.u64())?;
+                REACTIONS_PARAMS.save(
+                    storage,
+                    subspace_id.u64(),
+                    &SubspaceReactionsParams {
+                        subspace_id,
+                        registered_reaction,
+                        free_text,
+                    },
+                )?;
+
+                let mut events = Vec::with_capacity(1);
+                events.push(
+                    SetReactionsParamsEvent {
+                        subspace_id: subspace_id.u64(),
+                    }
+                    .into(),
+                );
+                AnyResult::Ok(AppResponse { events, data: None })
+            }
+        }
+    }
+
+    /// Handles [`ReactionsQuery`](crate::reactions::query::ReactionsQuery) reading the
+    /// x/reactions module state.
+    pub(super) fn query_reactions(
+        &self,
+        storage: &dyn Storage,
+        query: ReactionsQuery,
+    ) -> AnyResult<Binary> {
+        match query {
+            ReactionsQuery::Reactions {
+                subspace_id,
+                post_id,
+                user,
+                pagination,
+                ..
+            } => {
+                let reactions = REACTIONS
+                    .prefix((subspace_id.u64(), post_id.u64()))
+                    .range(storage, None, None, Order::Ascending)
+                    .filter(|item| match (item, &user) {
+                        (Ok((_, reaction)), Some(user)) => reaction.author == *user,
+                        _ => true,
+                    })
+                    .map(|item| item.map(|(_, reaction)| reaction))
+                    .collect::<StdResult<Vec<_>>>()?;
+                let (reactions, pagination) = paginate(reactions, pagination.as_ref())?;
+                Ok(to_binary(&QueryReactionsResponse {
+                    reactions,
+                    pagination,
+                })?)
+            }
+            ReactionsQuery::Reaction {
+                subspace_id,
+                post_id,
+                reaction_id,
+            } => {
+                let reaction =
+                    load_reaction(storage, subspace_id.u64(), post_id.u64(), reaction_id)?;
+                Ok(to_binary(&QueryReactionResponse { reaction })?)
+            }
+            ReactionsQuery::RegisteredReactions {
+                subspace_id,
+                pagination,
+                ..
+            } => {
+                let registered_reactions = REGISTERED_REACTIONS
+                    .prefix(subspace_id.u64())
+                    .range(storage, None, None, Order::Ascending)
+                    .map(|item| item.map(|(_, reaction)| reaction))
+                    .collect::<StdResult<Vec<_>>>()?;
+                let (registered_reactions, pagination) =
+                    paginate(registered_reactions, pagination.as_ref())?;
+                Ok(to_binary(&QueryRegisteredReactionsResponse {
+                    registered_reactions,
+                    pagination,
+                })?)
+            }
+            ReactionsQuery::RegisteredReaction {
+                subspace_id,
+                reaction_id,
+            } => {
+                let registered_reaction =
+                    load_registered_reaction(storage, subspace_id.u64(), reaction_id)?;
+                Ok(to_binary(&QueryRegisteredReactionResponse {
+                    registered_reaction,
+                })?)
+            }
+            ReactionsQuery::ReactionsParams { subspace_id } => {
+                let params = load_reactions_params(storage, subspace_id.u64())?;
+                Ok(to_binary(&QueryReactionsParamsResponse { params })?)
+            }
+        }
+    }
+
+    /// Stores the given [`ReactionsGenesis`] inside the x/reactions module state.
+    pub(super) fn init_reactions_genesis(
+        &self,
+        storage: &mut dyn Storage,
+        genesis: ReactionsGenesis,
+    ) -> AnyResult<()> {
+        for params in genesis.subspaces_params {
+            #[cfg(feature = "subspaces")]
+            load_subspace(storage, params.subspace_id.u64())?;
+            REACTIONS_PARAMS.save(storage, params.subspace_id.u64(), &params)?;
+        }
+
+        for registered_reaction in genesis.registered_reactions {
+            let subspace_id = registered_reaction.subspace_id.u64();
+            #[cfg(feature = "subspaces")]
+            load_subspace(storage, subspace_id)?;
+            REGISTERED_REACTIONS.save(
+                storage,
+                (subspace_id, registered_reaction.id),
+                &registered_reaction,
+            )?;
+            bump_next_id(
+                storage,
+                &NEXT_REGISTERED_REACTION_IDS,
+                subspace_id,
+                registered_reaction.id,
+            )?;
+        }
+
+        for reaction in genesis.reactions {
+            let subspace_id = reaction.subspace_id.u64();
+            let post_id = reaction.post_id.u64();
+            #[cfg(feature = "posts")]
+            load_post(storage, subspace_id, post_id)?;
+            ReactionValue::try_from(reaction.value.clone()).map_err(|err| {
+                ChainError::invalid_request(format!("invalid reaction value: {}", err))
+            })?;
+            REACTIONS.save(storage, (subspace_id, post_id, reaction.id), &reaction)?;
+            bump_next_id(
+                storage,
+                &NEXT_REACTION_IDS,
+                (subspace_id, post_id),
+                reaction.id,
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::mocks::mock_apps::{mock_desmos_app, DesmosApp};
+    use crate::posts::{models::ReplySetting, msg::PostsMsg};
+    use crate::reactions::{
+        models::{FreeTextValueParams, ReactionValue, RegisteredReactionValueParams},
+        msg::ReactionsMsg,
+        querier::ReactionsQuerier,
+    };
+    use crate::subspaces::msg::SubspacesMsg;
+    use cosmwasm_std::Addr;
+    use cw_multi_test::{AppResponse, Executor};
+    use std::ops::Deref;
+
+    const AUTHOR: &str = "desmos1nwp8gxrnmrsrzjdhvk47vvmthzxjtphgxp5ftc";
+    const USER: &str = "desmos1rfv0f7mx7w9d3jv3h803u38vqym9ygg344asm3";
+
+    fn setup_post(app: &mut DesmosApp) {
+        app.execute(
+            Addr::unchecked(AUTHOR),
+            SubspacesMsg::create_subspace(
+                "Test subspace",
+                "Test subspace",
+                Addr::unchecked(AUTHOR),
+                Addr::unchecked(AUTHOR),
+                Addr::unchecked(AUTHOR),
+            )
+            .into(),
+        )
+        .unwrap();
+        app.execute(
+            Addr::unchecked(AUTHOR),
+            PostsMsg::create_post(
+                1,
+                0,
+                None,
+                Some("Hello"),
+                None,
+                None,
+                Addr::unchecked(AUTHOR),
+                None,
+                ReplySetting::Everyone,
+                vec![],
+            )
+            .into(),
+        )
+        .unwrap();
+    }
+
+    fn add_reaction(
+        app: &mut DesmosApp,
+        user: &str,
+        value: ReactionValue,
+    ) -> anyhow::Result<AppResponse> {
+        app.execute(
+            Addr::unchecked(user),
+            ReactionsMsg::add_reaction(1, 1, value, Addr::unchecked(user)).into(),
+        )
+    }
+
+    fn free_text(text: &str) -> ReactionValue {
+        ReactionValue::FreeText {
+            text: text.to_string(),
+        }
+    }
+
+    fn set_params(app: &mut DesmosApp, registered_enabled: bool, free_text: FreeTextValueParams) {
+        app.execute(
+            Addr::unchecked(AUTHOR),
+            ReactionsMsg::set_reactions_params(
+                1,
+                RegisteredReactionValueParams {
+                    enabled: registered_enabled,
+                },
+                free_text,
+                Addr::unchecked(AUTHOR),
+            )
+            .into(),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn add_reaction_assigns_incremental_ids() {
+        let mut app = mock_desmos_app();
+        setup_post(&mut app);
+        let response = add_reaction(&mut app, AUTHOR, free_text("a")).unwrap();
+        let event = response
+            .events
+            .iter()
+            .find(|event| event.ty == "add_reaction")
+            .unwrap();
+        assert!(event
+            .attributes
+            .iter()
+            .any(|attr| attr.key == "reaction_id" && attr.value == "1"));
+        add_reaction(&mut app, USER, free_text("a")).unwrap();
+
+        let wrapper = app.wrap();
+        let querier = ReactionsQuerier::new(wrapper.deref());
+        let reaction = querier.query_reaction(1, 1, 2).unwrap().reaction;
+        assert_eq!(Addr::unchecked(USER), reaction.author);
+        let reactions = querier
+            .query_reactions(1, 1, Some(Addr::unchecked(AUTHOR)), None)
+            .unwrap()
+            .reactions;
+        assert_eq!(1, reactions.len());
+        assert_eq!(1, reactions[0].id);
+    }
+
+    #[test]
+    fn add_reaction_to_missing_post_fails() {
+        let mut app = mock_desmos_app();
+        assert!(add_reaction(&mut app, AUTHOR, free_text("a")).is_err());
+    }
+
+    #[test]
+    fn add_duplicated_reaction_fails() {
+        let mut app = mock_desmos_app();
+        setup_post(&mut app);
+        add_reaction(&mut app, AUTHOR, free_text("a")).unwrap();
+        assert!(add_reaction(&mut app, AUTHOR, free_text("a")).is_err());
+        assert!(add_reaction(&mut app, AUTHOR, free_text("b")).is_ok());
+    }
+
+    #[test]
+    fn free_text_reaction_respects_params() {
+        let mut app = mock_desmos_app();
+        setup_post(&mut app);
+        assert!(add_reaction(&mut app, AUTHOR, free_text("too long")).is_err());
+
+        set_params(
+            &mut app,
+            true,
+            FreeTextValueParams {
+                enabled: true,
+                max_length: 10,
+                reg_ex: "^[a-z ]+$".to_string(),
+            },
+        );
+        assert!(add_reaction(&mut app, AUTHOR, free_text("TEST")).is_err());
+        assert!(add_reaction(&mut app, AUTHOR, free_text("too long")).is_ok());
+
+        set_params(
+            &mut app,
+            true,
+            FreeTextValueParams {
+                enabled: false,
+                max_length: 10,
+                reg_ex: String::new(),
+            },
+        );
+        assert!(add_reaction(&mut app, AUTHOR, free_text("a")).is_err());
+    }
+
+    #[test]
+    fn set_params_with_invalid_regex_fails() {
+        let mut app = mock_desmos_app();
+        setup_post(&mut app);
+        let result = app.execute(
+            Addr::unchecked(AUTHOR),
+            ReactionsMsg::set_reactions_params(
+                1,
+                RegisteredReactionValueParams { enabled: true },
+                FreeTextValueParams {
+                    enabled: true,
+                    max_length: 10,
+                    reg_ex: "[a-z".to_string(),
+                },
+                Addr::unchecked(AUTHOR),
+            )
+            .into(),
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn registered_reaction_respects_params() {
+        let mut app = mock_desmos_app();
+        setup_post(&mut app);
+        let registered = ReactionValue::Registered {
+            registered_reaction_id: 1,
+        };
+        assert!(add_reaction(&mut app, AUTHOR, registered.clone()).is_err());
+
+        app.execute(
+            Addr::unchecked(AUTHOR),
+            ReactionsMsg::add_registered_reaction(1, ":smile:", "😄", Addr::unchecked(AUTHOR))
+                .into(),
+        )
+        .unwrap();
+        let registered_reactions = ReactionsQuerier::new(app.wrap().deref())
+            .query_registered_reactions(1, None)
+            .unwrap()
+            .registered_reactions;
+        assert_eq!(1, registered_reactions.len());
+        assert_eq!(":smile:", registered_reactions[0].shorthand_code);
+
+        set_params(
+            &mut app,
+            false,
+            FreeTextValueParams {
+                enabled: true,
+                max_length: 5,
+                reg_ex: String::new(),
+            },
+        );
+        assert!(add_reaction(&mut app, AUTHOR, registered.clone()).is_err());
+
+        set_params(
+            &mut app,
+            true,
+            FreeTextValueParams {
+                enabled: true,
+                max_length: 5,
+                reg_ex: String::new(),
+            },
+        );
+        assert!(add_reaction(&mut app, AUTHOR, registered).is_ok());
+    }
+
+    #[test]
+    fn remove_reaction_from_non_author_fails() {
+        let mut app = mock_desmos_app();
+        setup_post(&mut app);
+        add_reaction(&mut app, AUTHOR, free_text("a")).unwrap();
+        assert!(app
+            .execute(
+                Addr::unchecked(USER),
+                ReactionsMsg::remove_reaction(1, 1, 1, Addr::unchecked(USER)).into(),
+            )
+            .is_err());
+        app.execute(
+            Addr::unchecked(AUTHOR),
+            ReactionsMsg::remove_reaction(1, 1, 1, Addr::unchecked(AUTHOR)).into(),
+        )
+        .unwrap();
+        let wrapper = app.wrap();
+        let querier = ReactionsQuerier::new(wrapper.deref());
+        assert!(querier.query_reaction(1, 1, 1).is_err());
+    }
+
+    #[test]
+    fn delete_post_removes_reactions() {
+        let mut app = mock_desmos_app();
+        setup_post(&mut app);
+        add_reaction(&mut app, AUTHOR, free_text("a")).unwrap();
+        app.execute(
+            Addr::unchecked(AUTHOR),
+            PostsMsg::delete_post(1, 1, Addr::unchecked(AUTHOR)).into(),
+        )
+        .unwrap();
+        let wrapper = app.wrap();
+        let querier = ReactionsQuerier::new(wrapper.deref());
+        assert!(querier
+            .query_reactions(1, 1, None, None)
+            .unwrap()
+            .reactions
+            .is_empty());
+    }
+
+    #[test]
+    fn query_default_params_properly() {
+        let app = mock_desmos_app();
+        let wrapper = app.wrap();
+        let querier = ReactionsQuerier::new(wrapper.deref());
+        let params = querier.query_reactions_params(1).unwrap().params;
+        assert!(params.registered_reaction.enabled);
+        assert!(params.free_text.enabled);
+        assert_eq!(5, params.free_text.max_length);
+    }
+}
@@ -0,0 +1,559 @@
+//! Contains the simulation of the Desmos x/relationships module used by the [`DesmosKeeper`].
+
+use crate::mocks::errors::ChainError;
+#[cfg(feature = "subspaces")]
+use crate::mocks::mock_keeper::subspaces::load_subspace;
+use crate::mocks::mock_keeper::{genesis::RelationshipsGenesis, DesmosKeeper};
+use crate::pagination::paginate;
+use crate::relationships::{
+    events::{BlockUserEvent, CreateRelationshipEvent, DeleteRelationshipEvent, UnblockUserEvent},
+    models::{Relationship, UserBlock},
+    models_query::{QueryBlocksResponse, QueryRelationshipsResponse},
+    msg::RelationshipsMsg,
+    query::RelationshipsQuery,
+};
+use anyhow::{bail, Result as AnyResult};
+use cosmwasm_std::{to_binary, Addr, Binary, Order, StdResult, Storage};
+use cw_multi_test::AppResponse;
+use cw_storage_plus::Map;
+
+/// Relationships stored by (subspace id, creator, counterparty).
+const RELATIONSHIPS: Map<(u64, &Addr, &Addr), Relationship> =
+    Map::new("desmos_relationships_relationships");
+/// User blocks stored by (subspace id, blocker, blocked).
+const USER_BLOCKS: Map<(u64, &Addr, &Addr), UserBlock> = Map::new("desmos_relationships_blocks");
+
+/// Tells whether `blocker` has blocked `blocked` inside the given subspace.
+pub(super) fn has_user_blocked(
+    storage: &dyn Storage,
+    blocker: &Addr,
+    blocked: &Addr,
+    subspace_id: u64,
+) -> bool {
+    USER_BLOCKS.has(storage, (subspace_id, blocker, blocked))
+}
+
+/// Removes all the relationships and user blocks of the given subspace.
+#[cfg(feature = "subspaces")]
+pub(super) fn remove_subspace_relationships(
+    storage: &mut dyn Storage,
+    subspace_id: u64,
+) -> StdResult<()> {
+    let relationships = RELATIONSHIPS
+        .sub_prefix(subspace_id)
+        .keys(storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+    for (creator, counterparty) in relationships {
+        RELATIONSHIPS.remove(storage, (subspace_id, &creator, &counterparty));
+    }
+    let blocks = USER_BLOCKS
+        .sub_prefix(subspace_id)
+        .keys(storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+    for (blocker, blocked) in blocks {
+        USER_BLOCKS.remove(storage, (subspace_id, &blocker, &blocked));
+    }
+    Ok(())
+}
+
+/// Returns the type URL of the chain message corresponding to the given [`RelationshipsMsg`].
+pub(super) fn relationships_msg_type_url(msg: &RelationshipsMsg) -> &'static str {
+    match msg {
+        RelationshipsMsg::CreateRelationship { .. } => {
+            "/desmos.relationships.v1.MsgCreateRelationship"
+        }
+        RelationshipsMsg::DeleteRelationship { .. } => {
+            "/desmos.relationships.v1.MsgDeleteRelationship"
+        }
+        RelationshipsMsg::BlockUser { .. } => "/desmos.relationships.v1.MsgBlockUser",
+        RelationshipsMsg::UnblockUser { .. } => "/desmos.relationships.v1.MsgUnblockUser",
+    }
+}
+
+impl DesmosKeeper {
+    /// Handles [`RelationshipsMsg`](crate::relationships::RelationshipsMsg) updating the x/relationships module state,
+    /// then returns the response with proper [events](https://github.com/desmos-labs/desmos/blob/master/x/relationships/spec/05-events.md).
+    pub(super) fn handle_relationships_msg(
+        &self,
+        storage: &mut dyn Storage,
+        msg: RelationshipsMsg,
+    ) -> AnyResult<AppResponse> {
+        match msg {
+            RelationshipsMsg::CreateRelationship {
+                signer: creator,
+                counterparty,
+                subspace_id,
+            } => {
+                #[cfg(feature = "subspaces")]
+                load_subspace(storage, subspace_id.u64())?;
+                if has_user_blocked(storage, &counterparty, &creator, subspace_id.u64()) {
+                    bail!(ChainError::unauthorized(format!(
+                        "the user with address {} has blocked you",
+                        counterparty
+                    )));
+                }
+                let key = (subspace_id.u64(), &creator, &counterparty);
+                if RELATIONSHIPS.has(storage, key) {
+                    bail!(ChainError::invalid_request(format!(
+                        "relationship already exists with {}",
+                        counterparty
+                    )));
+                }
+                RELATIONSHIPS.save(
+                    storage,
+                    key,
+                    &Relationship {
+                        creator: creator.clone(),
+                        counterparty: counterparty.clone(),
+                        subspace_id,
+                    },
+                )?;
+
+                let mut events = Vec::with_capacity(1);
+                events.push(
+                    CreateRelationshipEvent {
+                        creator,
+                        counterparty,
+                        subspace_id: subspace_id.u64(),
+                    }
+                    .into(),
+                );
+                AnyResult::Ok(AppResponse { events, data: None })
+            }
+            RelationshipsMsg::DeleteRelationship {
+                signer: creator,
+                counterparty,
+                subspace_id,
+            } => {
+                let key = (subspace_id.u64(), &creator, &counterparty);
+                if !RELATIONSHIPS.has(storage, key) {
+                    bail!(ChainError::relationship_not_found());
+                }
+                RELATIONSHIPS.remove(storage, key);
+
+                let mut events = Vec::with_capacity(1);
+                events.push(
+                    DeleteRelationshipEvent {
+                        creator,
+                        counterparty,
+                        subspace_id: subspace_id.u64(),
+                    }
+                    .into(),
+                );
+                AnyResult::Ok(AppResponse { events, data: None })
+            }
+            RelationshipsMsg::BlockUser {
+                blocker,
+                blocked,
+                reason,
+                subspace_id,
+            } => {
+                #[cfg(feature = "subspaces")]
+                load_subspace(storage, subspace_id.u64())?;
+                let key = (subspace_id.u64(), &blocker, &blocked);
+                if USER_BLOCKS.has(storage, key) {
+                    bail!(ChainError::invalid_request(format!(
+                        "the user with address {} has already been blocked",
+                        blocked
+                    )));
+                }
+                USER_BLOCKS.save(
+                    storage,
+                    key,
+                    &UserBlock {
+                        blocker: blocker.clone(),
+                        blocked: blocked.clone(),
+                        reason,
+                        subspace_id,
+                    },
+                )?;
+
+                let mut events = Vec::with_capacity(1);
+                events.push(
+                    BlockUserEvent {
+                        blocker,
+                        blocked,
+                        subspace_id: subspace_id.u64(),
+                    }
+                    .into(),
+                );
+                AnyResult::Ok(AppResponse { events, data: None })
+            }
+            RelationshipsMsg::UnblockUser {
+                blocker,
+                blocked,
+                subspace_id,
+            } => {
+                let key = (subspace_id.u64(), &blocker, &blocked);
+                if !USER_BLOCKS.has(storage, key) {
+                    bail!(ChainError::user_block_not_found());
+                }
+                USER_BLOCKS.remove(storage, key);
+
+                let mut events = Vec::with_capacity(1);
+                events.push(
+                    UnblockUserEvent {
+                        blocker,
+                        blocked,
+                        subspace_id: subspace_id.u64(),
+                    }
+                    .into(),
+                );
+                AnyResult::Ok(AppResponse { events, data: None })
+            }
+        }
+    }
+
+    /// Handles [`RelationshipsQuery`](crate::relationships::query::RelationshipsQuery) reading the
+    /// x/relationships module state.
+    pub(super) fn query_relationships(
+        &self,
+        storage: &dyn Storage,
+        query: RelationshipsQuery,
+    ) -> AnyResult<Binary> {
+        match query {
+            RelationshipsQuery::Relationships {
+                subspace_id,
+                user,
+                counterparty,
+                pagination,
+                ..
+            } => {
+                // As on chain, the counterparty is only considered when the user is provided.
+                let relationships = RELATIONSHIPS
+                    .sub_prefix(subspace_id.u64())
+                    .range(storage, None, None, Order::Ascending)
+                    .map(|item| item.map(|(_, relationship)| relationship))
+                    .filter(|item| match (item, &user, &counterparty) {
+                        (Ok(relationship), Some(user), Some(counterparty)) => {
+                            relationship.creator == *user
+                                && relationship.counterparty == *counterparty
+                        }
+                        (Ok(relationship), Some(user), None) => relationship.creator == *user,
+                        _ => true,
+                    })
+                    .collect::<StdResult<Vec<_>>>()?;
+                let (relationships, pagination) = paginate(relationships, pagination.as_ref())?;
+                Ok(to_binary(&QueryRelationshipsResponse {
+                    relationships,
+                    pagination,
+                })?)
+            }
+            RelationshipsQuery::Blocks {
+                subspace_id,
+                blocker,
+                blocked,
+                pagination,
+                ..
+            } => {
+                // As on chain, the blocked user is only considered when the blocker is provided.
+                let blocks = USER_BLOCKS
+                    .sub_prefix(subspace_id.u64())
+                    .range(storage, None, None, Order::Ascending)
+                    .map(|item| item.map(|(_, block)| block))
+                    .filter(|item| match (item, &blocker, &blocked) {
+                        (Ok(block), Some(blocker), Some(blocked)) => {
+                            block.blocker == *blocker && block.blocked == *blocked
+                        }
+                        (Ok(block), Some(blocker), None) => block.blocker == *blocker,
+                        _ => true,
+                    })
+                    .collect::<StdResult<Vec<_>>>()?;
+                let (blocks, pagination) = paginate(blocks, pagination.as_ref())?;
+                Ok(to_binary(&QueryBlocksResponse { blocks, pagination })?)
+            }
+        }
+    }
+
+    /// Stores the given [`RelationshipsGenesis`] inside the x/relationships module state.
+    pub(super) fn init_relationships_genesis(
+        &self,
+        storage: &mut dyn Storage,
+        genesis: RelationshipsGenesis,
+    ) -> AnyResult<()> {
+        for relationship in genesis.relationships {
+            let subspace_id = relationship.subspace_id.u64();
+            #[cfg(feature = "subspaces")]
+            load_subspace(storage, subspace_id)?;
+            RELATIONSHIPS.save(
+                storage,
+                (
+                    subspace_id,
+                    &relationship.creator,
+                    &relationship.counterparty,
+                ),
+                &relationship,
+            )?;
+        }
+
+        for block in genesis.blocks {
+            let subspace_id = block.subspace_id.u64();
+            #[cfg(feature = "subspaces")]
+            load_subspace(storage, subspace_id)?;
+            USER_BLOCKS.save(
+                storage,
+                (subspace_id, &block.blocker, &block.blocked),
+                &block,
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::mocks::mock_apps::{mock_desmos_app, DesmosApp};
+    use crate::relationships::{msg::RelationshipsMsg, querier::RelationshipsQuerier};
+    use crate::subspaces::msg::SubspacesMsg;
+    use crate::types::PageRequest;
+    use cosmwasm_std::{Addr, Uint64};
+    use cw_multi_test::{AppResponse, Executor};
+    use std::ops::Deref;
+
+    const USER: &str = "desmos1nwp8gxrnmrsrzjdhvk47vvmthzxjtphgxp5ftc";
+    const COUNTERPARTY: &str = "desmos1rfv0f7mx7w9d3jv3h803u38vqym9ygg344asm3";
+    const OTHER: &str = "desmos1jsdjhs8ggcs7ua0mxjz5e9q5q3j4fe7u4nqvt3";
+
+    fn setup_subspace(app: &mut DesmosApp) {
+        app.execute(
+            Addr::unchecked(USER),
+            SubspacesMsg::create_subspace(
+                "Test subspace",
+                "Test subspace",
+                Addr::unchecked(USER),
+                Addr::unchecked(USER),
+                Addr::unchecked(USER),
+            )
+            .into(),
+        )
+        .unwrap();
+    }
+
+    fn create_relationship(
+        app: &mut DesmosApp,
+        creator: &str,
+        counterparty: &str,
+    ) -> anyhow::Result<AppResponse> {
+        app.execute(
+            Addr::unchecked(creator),
+            RelationshipsMsg::create_relationship(
+                Addr::unchecked(creator),
+                Addr::unchecked(counterparty),
+                1,
+            )
+            .into(),
+        )
+    }
+
+    fn block_user(
+        app: &mut DesmosApp,
+        blocker: &str,
+        blocked: &str,
+    ) -> anyhow::Result<AppResponse> {
+        app.execute(
+            Addr::unchecked(blocker),
+            RelationshipsMsg::block_user(
+                Addr::unchecked(blocker),
+                Addr::unchecked(blocked),
+                "spam".to_string(),
+                1,
+            )
+            .into(),
+        )
+    }
+
+    #[test]
+    fn create_relationship_properly() {
+        let mut app = mock_desmos_app();
+        setup_subspace(&mut app);
+        create_relationship(&mut app, USER, COUNTERPARTY).unwrap();
+        assert!(create_relationship(&mut app, USER, COUNTERPARTY).is_err());
+        assert!(create_relationship(&mut app, USER, USER).is_err());
+        assert!(create_relationship(&mut app, COUNTERPARTY, USER).is_ok());
+    }
+
+    #[test]
+    fn create_relationship_in_missing_subspace_fails() {
+        let mut app = mock_desmos_app();
+        assert!(create_relationship(&mut app, USER, COUNTERPARTY).is_err());
+    }
+
+    #[test]
+    fn create_relationship_with_blocker_fails() {
+        let mut app = mock_desmos_app();
+        setup_subspace(&mut app);
+        block_user(&mut app, COUNTERPARTY, USER).unwrap();
+        assert!(create_relationship(&mut app, USER, COUNTERPARTY).is_err());
+        // The blocker can still create a relationship with the blocked user.
+        assert!(create_relationship(&mut app, COUNTERPARTY, USER).is_ok());
+
+        app.execute(
+            Addr::unchecked(COUNTERPARTY),
+            RelationshipsMsg::unblock_user(Addr::unchecked(COUNTERPARTY), Addr::unchecked(USER), 1)
+                .into(),
+        )
+        .unwrap();
+        assert!(create_relationship(&mut app, USER, COUNTERPARTY).is_ok());
+    }
+
+    #[test]
+    fn delete_missing_relationship_fails() {
+        let mut app = mock_desmos_app();
+        setup_subspace(&mut app);
+        let delete = RelationshipsMsg::delete_relationship(
+            Addr::unchecked(USER),
+            Addr::unchecked(COUNTERPARTY),
+            1,
+        );
+        assert!(app
+            .execute(Addr::unchecked(USER), delete.clone().into())
+            .is_err());
+        create_relationship(&mut app, USER, COUNTERPARTY).unwrap();
+        assert!(app.execute(Addr::unchecked(USER), delete.into()).is_ok());
+    }
+
+    #[test]
+    fn query_relationships_paginates_properly() {
+        let mut app = mock_desmos_app();
+        setup_subspace(&mut app);
+        create_relationship(&mut app, USER, COUNTERPARTY).unwrap();
+        create_relationship(&mut app, USER, OTHER).unwrap();
+        create_relationship(&mut app, COUNTERPARTY, USER).unwrap();
+
+        let wrapper = app.wrap();
+        let querier = RelationshipsQuerier::new(wrapper.deref());
+        let request = PageRequest {
+            key: None,
+            offset: None,
+            limit: Uint64::new(2),
+            count_total: true,
+            reverse: false,
+        };
+        let first_page = querier
+            .query_relationships(1, None, None, Some(request.clone()))
+            .unwrap();
+        assert_eq!(2, first_page.relationships.len());
+        let pagination = first_page.pagination.unwrap();
+        assert_eq!(Some(Uint64::new(3)), pagination.total);
+
+        let second_page = querier
+            .query_relationships(
+                1,
+                None,
+                None,
+                Some(PageRequest {
+                    key: pagination.next_key,
+                    ..request
+                }),
+            )
+            .unwrap();
+        assert_eq!(1, second_page.relationships.len());
+        assert_eq!(None, second_page.pagination.unwrap().next_key);
+    }
+
+    #[cfg(feature = "iterators")]
+    #[test]
+    fn iterate_relationships_walks_all_the_pages() {
+        let mut app = mock_desmos_app();
+        setup_subspace(&mut app);
+        create_relationship(&mut app, USER, COUNTERPARTY).unwrap();
+        create_relationship(&mut app, USER, OTHER).unwrap();
+        create_relationship(&mut app, COUNTERPARTY, USER).unwrap();
+
+        let wrapper = app.wrap();
+        let querier = RelationshipsQuerier::new(wrapper.deref());
+        let relationships = querier
+            .iterate_relationships(1, None, 1)
+            .collect::<crate::errors::DesmosQueryResult<Vec<_>>>()
+            .unwrap();
+        assert_eq!(3, relationships.len());
+    }
+
+    #[test]
+    fn block_user_properly() {
+        let mut app = mock_desmos_app();
+        setup_subspace(&mut app);
+        block_user(&mut app, USER, COUNTERPARTY).unwrap();
+        assert!(block_user(&mut app, USER, COUNTERPARTY).is_err());
+        assert!(block_user(&mut app, USER, USER).is_err());
+
+        let unblock =
+            RelationshipsMsg::unblock_user(Addr::unchecked(USER), Addr::unchecked(OTHER), 1);
+        assert!(app.execute(Addr::unchecked(USER), unblock.into()).is_err());
+    }
+
+    #[test]
+    fn query_relationships_filters_properly() {
+        let mut app = mock_desmos_app();
+        setup_subspace(&mut app);
+        create_relationship(&mut app, USER, COUNTERPARTY).unwrap();
+        create_relationship(&mut app, USER, OTHER).unwrap();
+        create_relationship(&mut app, COUNTERPARTY, USER).unwrap();
+
+        let wrapper = app.wrap();
+        let querier = RelationshipsQuerier::new(wrapper.deref());
+        let all = querier.query_relationships(1, None, None, None).unwrap();
+        assert_eq!(3, all.relationships.len());
+
+        let by_user = querier
+            .query_relationships(1, Some(Addr::unchecked(USER)), None, None)
+            .unwrap();
+        assert_eq!(2, by_user.relationships.len());
+
+        let by_counterparty = querier
+            .query_relationships(
+                1,
+                Some(Addr::unchecked(USER)),
+                Some(Addr::unchecked(OTHER)),
+                None,
+            )
+            .unwrap();
+        assert_eq!(1, by_counterparty.relationships.len());
+        assert_eq!(
+            Addr::unchecked(OTHER),
+            by_counterparty.relationships[0].counterparty
+        );
+
+        // The counterparty is ignored when the user is not provided.
+        let only_counterparty = querier
+            .query_relationships(1, None, Some(Addr::unchecked(OTHER)), None)
+            .unwrap();
+        assert_eq!(3, only_counterparty.relationships.len());
+    }
+
+    #[test]
+    fn query_blocks_filters_properly() {
+        let mut app = mock_desmos_app();
+        setup_subspace(&mut app);
+        block_user(&mut app, USER, COUNTERPARTY).unwrap();
+        block_user(&mut app, USER, OTHER).unwrap();
+        block_user(&mut app, OTHER, USER).unwrap();
+
+        let wrapper = app.wrap();
+        let querier = RelationshipsQuerier::new(wrapper.deref());
+        assert_eq!(
+            3,
+            querier
+                .query_blocks(1, None, None, None)
+                .unwrap()
+                .blocks
+                .len()
+        );
+
+        let by_blocker = querier
+            .query_blocks(1, Some(Addr::unchecked(USER)), None, None)
+            .unwrap();
+        assert_eq!(2, by_blocker.blocks.len());
+
+        let by_blocked = querier
+            .query_blocks(
+                1,
+                Some(Addr::unchecked(USER)),
+                Some(Addr::unchecked(COUNTERPARTY)),
+                None,
+            )
+            .unwrap();
+        assert_eq!(1, by_blocked.blocks.len());
+        assert_eq!("spam", by_blocked.blocks[0].reason);
+    }
+}
@@ -0,0 +1,695 @@
+//! Contains the simulation of the Desmos x/reports module used by the [`DesmosKeeper`].
+
+use crate::mocks::errors::ChainError;
+#[cfg(feature = "posts")]
+use crate::mocks::mock_keeper::posts::load_post;
+#[cfg(feature = "subspaces")]
+use crate::mocks::mock_keeper::subspaces::load_subspace;
+use crate::mocks::mock_keeper::{
+    bump_next_id, genesis::ReportsGenesis, time::to_rfc3339, DesmosKeeper,
+};
+use crate::pagination::paginate;
+use crate::reports::{
+    events::{
+        AddReasonEvent, CreateReportEvent, DeleteReportEvent, RemoveReasonEvent, ReportedPostEvent,
+        ReportedUserEvent, SupportStandardReasonEvent,
+    },
+    models::{Reason, Report, ReportTarget},
+    models_query::{
+        QueryReasonResponse, QueryReasonsResponse, QueryReportResponse, QueryReportsResponse,
+    },
+    msg::ReportsMsg,
+    query::ReportsQuery,
+};
+use anyhow::{anyhow, bail, Result as AnyResult};
+use cosmwasm_std::{to_binary, Binary, BlockInfo, Order, StdResult, Storage, Uint64};
+use cw_multi_test::AppResponse;
+use cw_storage_plus::Map;
+use std::convert::TryFrom;
+
+/// Id that will be assigned to the next report created inside a subspace.
+const NEXT_REPORT_IDS: Map<u64, u64> = Map::new("desmos_reports_next_report_ids");
+/// Reports stored by (subspace id, report id).
+const REPORTS: Map<(u64, u64), Report> = Map::new("desmos_reports");
+/// Id that will be assigned to the next reason added to a subspace.
+const NEXT_REASON_IDS: Map<u64, u32> = Map::new("desmos_reports_next_reason_ids");
+/// Reasons stored by (subspace id, reason id).
+const REASONS: Map<(u64, u32), Reason> = Map::new("desmos_reports_reasons");
+
+/// Represents a reporting reason defined inside the x/reports module params,
+/// that subspaces can support using [`ReportsMsg::SupportStandardReason`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct StandardReason {
+    /// Id of the standard reason.
+    pub id: u32,
+    /// Title of the reason.
+    pub title: String,
+    /// Extended description of the reason and the cases it applies to.
+    pub description: Option<String>,
+}
+
+/// Loads the report having the given id, returning an error if it does not exist.
+pub(super) fn load_report(
+    storage: &dyn Storage,
+    subspace_id: u64,
+    report_id: u64,
+) -> AnyResult<Report> {
+    REPORTS
+        .may_load(storage, (subspace_id, report_id))?
+        .ok_or_else(|| anyhow!(ChainError::report_not_found(report_id)))
+}
+
+/// Removes all the reports made toward the given post.
+#[cfg(feature = "posts")]
+pub(super) fn remove_post_reports(
+    storage: &mut dyn Storage,
+    subspace_id: u64,
+    post_id: u64,
+) -> StdResult<()> {
+    let reports = REPORTS
+        .prefix(subspace_id)
+        .range(storage, None, None, Order::Ascending)
+        .filter_map(|item| match item {
+            Ok((id, report)) => match ReportTarget::try_from(report.target) {
+                Ok(ReportTarget::Post { post_id: target }) if target.u64() == post_id => {
+                    Some(Ok(id))
+                }
+                _ => None,
+            },
+            Err(err) => Some(Err(err)),
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+    for report_id in reports {
+        REPORTS.remove(storage, (subspace_id, report_id));
+    }
+    Ok(())
+}
+
+/// Removes all the reports and reasons of the given subspace.
+#[cfg(feature = "subspaces")]
+pub(super) fn remove_subspace_reports(
+    storage: &mut dyn Storage,
+    subspace_id: u64,
+) -> StdResult<()> {
+    let reports = REPORTS
+        .prefix(subspace_id)
+        .keys(storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+    for report_id in reports {
+        REPORTS.remove(storage, (subspace_id, report_id));
+    }
+    let reasons = REASONS
+        .prefix(subspace_id)
+        .keys(storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+    for reason_id in reasons {
+        REASONS.remove(storage, (subspace_id, reason_id));
+    }
+    NEXT_REPORT_IDS.remove(storage, subspace_id);
+    NEXT_REASON_IDS.remove(storage, subspace_id);
+    Ok(())
+}
+
+/// Loads the reason having the given id, returning an error if it does not exist.
+fn load_reason(storage: &dyn Storage, subspace_id: u64, reason_id: u32) -> AnyResult<Reason> {
+    REASONS
+        .may_load(storage, (subspace_id, reason_id))?
+        .ok_or_else(|| anyhow!(ChainError::reason_not_found(reason_id)))
+}
+
+/// Stores a new reason inside the given subspace, returning the id assigned to it.
+fn save_reason(
+    storage: &mut dyn Storage,
+    subspace_id: Uint64,
+    title: String,
+    description: Option<String>,
+) -> StdResult<u32> {
+    let reason_id = NEXT_REASON_IDS
+        .may_load(storage, subspace_id.u64())?
+        .unwrap_or(1);
+    NEXT_REASON_IDS.save(storage, subspace_id.u64(), &(reason_id + 1))?;
+    REASONS.save(
+        storage,
+        (subspace_id.u64(), reason_id),
+        &Reason {
+            subspace_id,
+            id: reason_id,
+            title,
+            description,
+        },
+    )?;
+    Ok(reason_id)
+}
+
+/// Returns the type URL of the chain message corresponding to the given [`ReportsMsg`].
+pub(super) fn reports_msg_type_url(msg: &ReportsMsg) -> &'static str {
+    match msg {
+        ReportsMsg::CreateReport { .. } => "/desmos.reports.v1.MsgCreateReport",
+        ReportsMsg::DeleteReport { .. } => "/desmos.reports.v1.MsgDeleteReport",
+        ReportsMsg::SupportStandardReason { .. } => "/desmos.reports.v1.MsgSupportStandardReason",
+        ReportsMsg::AddReason { .. } => "/desmos.reports.v1.MsgAddReason",
+        ReportsMsg::RemoveReason { .. } => "/desmos.reports.v1.MsgRemoveReason",
+    }
+}
+
+impl DesmosKeeper {
+    /// Sets the standard reasons defined inside the x/reports module params,
+    /// which are the only ones that can be supported with [`ReportsMsg::SupportStandardReason`].
+    pub fn with_standard_reasons(mut self, standard_reasons: Vec<StandardReason>) -> Self {
+        self.standard_reasons = standard_reasons;
+        self
+    }
+
+    /// Handles [`ReportsMsg`](crate::reports::ReportsMsg) updating the x/reports module state,
+    /// then returns the response with proper [events](https://github.com/desmos-labs/desmos/blob/master/x/reports/spec/05-events.md).
+    pub(super) fn handle_reports_msg(
+        &self,
+        storage: &mut dyn Storage,
+        block: &BlockInfo,
+        msg: ReportsMsg,
+    ) -> AnyResult<AppResponse> {
+        match msg {
+            ReportsMsg::CreateReport {
+                subspace_id,
+                reasons_ids,
+                message,
+                reporter,
+                target,
+            } => {
+                #[cfg(feature = "subspaces")]
+                load_subspace(storage, subspace_id.u64())?;
+                for reason_id in &reasons_ids {
+                    load_reason(storage, subspace_id.u64(), *reason_id)?;
+                }
+                let report_target = ReportTarget::try_from(target.clone()).map_err(|err| {
+                    ChainError::invalid_request(format!("invalid report target: {}", err))
+                })?;
+                #[cfg(feature = "posts")]
+                if let ReportTarget::Post { post_id } = &report_target {
+                    load_post(storage, subspace_id.u64(), post_id.u64())?;
+                }
+
+                let already_reported = REPORTS
+                    .prefix(subspace_id.u64())
+                    .range(storage, None, None, Order::Ascending)
+                    .any(|item| match item {
+                        Ok((_, report)) => report.reporter == reporter && report.target == target,
+                        Err(_) => false,
+                    });
+                if already_reported {
+                    bail!(ChainError::invalid_request(
+                        "you have already reported this target"
+                    ));
+                }
+
+                let report_id = NEXT_REPORT_IDS
+                    .may_load(storage, subspace_id.u64())?
+                    .unwrap_or(1);
+                NEXT_REPORT_IDS.save(storage, subspace_id.u64(), &(report_id + 1))?;
+                REPORTS.save(
+                    storage,
+                    (subspace_id.u64(), report_id),
+                    &Report {
+                        subspace_id,
+                        id: Uint64::new(report_id),
+                        reasons_ids,
+                        message,
+                        reporter: reporter.clone(),
+                        target,
+                        creation_date: to_rfc3339(&block.time),
+                    },
+                )?;
+
+                let mut events = Vec::with_capacity(2);
+                events.push(
+                    CreateReportEvent {
+                        subspace_id: subspace_id.u64(),
+                        report_id,
+                        reporter: reporter.clone(),
+                        creation_time: to_rfc3339(&block.time),
+                    }
+                    .into(),
+                );
+                match report_target {
+                    ReportTarget::Post { post_id } => {
+                        events.push(
+                            ReportedPostEvent {
+                                subspace_id: subspace_id.u64(),
+                                post_id: post_id.u64(),
+                                reporter,
+                            }
+                            .into(),
+                        );
+                    }
+                    ReportTarget::User { user } => {
+                        events.push(
+                            ReportedUserEvent {
+                                subspace_id: subspace_id.u64(),
+                                user,
+                                reporter,
+                            }
+                            .into(),
+                        );
+                    }
+                }
+                AnyResult::Ok(AppResponse { events, data: None })
+            }
+            ReportsMsg::DeleteReport {
+                subspace_id,
+                report_id,
+                ..
+            } => {
+                load_report(storage, subspace_id.u64(), report_id.u64())?;
+                REPORTS.remove(storage, (subspace_id.u64(), report_id.u64()));
+
+                let mut events = Vec::with_capacity(1);
+                events.push(
+                    DeleteReportEvent {
+                        subspace_id: subspace_id.u64(),
+                        report_id: report_id.u64(),
+                    }
+                    .into(),
+                );
+                AnyResult::Ok(AppResponse { events, data: None })
+            }
+            ReportsMsg::SupportStandardReason {
+                subspace_id,
+                standard_reason_id,
+                ..
+            } => {
+                #[cfg(feature = "subspaces")]
+                load_subspace(storage, subspace_id.u64())?;
+                let standard_reason = self
+                    .standard_reasons
+                    .iter()
+                    .find(|reason| reason.id == standard_reason_id)
+                    .ok_or_else(|| {
+                        anyhow!(ChainError::not_found(format!(
+                            "standard reason with id {} could not be found",
+                            standard_reason_id
+                        )))
+                    })?;
+                let reason_id = save_reason(
+                    storage,
+                    subspace_id,
+                    standard_reason.title.clone(),
+                    standard_reason.description.clone(),
+                )?;
+
+                let mut events = Vec::with_capacity(1);
+                events.push(
+                    SupportStandardReasonEvent {
+                        subspace_id: subspace_id.u64(),
+                        standard_reason_id,
+                        reason_id,
+                    }
+                    .into(),
+                );
+                AnyResult::Ok(AppResponse { events, data: None })
+            }
+            ReportsMsg::AddReason {
+                subspace_id,
+                title,
+                description,
+                ..
+            } => {
+                #[cfg(feature = "subspaces")]
+                load_subspace(storage, subspace_id.u64())?;
+                let reason_id = save_reason(storage, subspace_id, title, description)?;
+
+                let mut events = Vec::with_capacity(1);
+                events.push(
+                    AddReasonEvent {
+                        subspace_id: subspace_id.u64(),
+                        reason_id,
+                    }
+                    .into(),
+                );
+                AnyResult::Ok(AppResponse { events, data: None })
+            }
+            ReportsMsg::RemoveReason {
+                subspace_id,
+                reason_id,
+                ..
+            } => {
+                load_reason(storage, subspace_id.u64(), reason_id)?;
+                REASONS.remove(storage, (subspace_id.u64(), reason_id));
+
+                let mut events = Vec::with_capacity(1);
+                events.push(
+                    RemoveReasonEvent {
+                        subspace_id: subspace_id.u64(),
+                        reason_id,
+                    }
+                    .into(),
+                );
+                AnyResult::Ok(AppResponse { events, data: None })
+            }
+        }
+    }
+
+    /// Handles [`ReportsQuery`](crate::reports::query::ReportsQuery) reading the
+    /// x/reports module state.
+    pub(super) fn query_reports(
+        &self,
+        storage: &dyn Storage,
+        query: ReportsQuery,
+    ) -> AnyResult<Binary> {
+        match query {
+            ReportsQuery::Reports {
+                subspace_id,
+                target,
+                reporter,
+                pagination,
+                ..
+            } => {
+                // As on chain, the reporter is only considered when the target is provided.
+                let reports = REPORTS
+                    .prefix(subspace_id.u64())
+                    .range(storage, None, None, Order::Ascending)
+                    .map(|item| item.map(|(_, report)| report))
+                    .filter(|item| match (item, &target, &reporter) {
+                        (Ok(report), Some(target), Some(reporter)) => {
+                            report.target == *target && report.reporter == *reporter
+                        }
+                        (Ok(report), Some(target), None) => report.target == *target,
+                        _ => true,
+                    })
+                    .collect::<StdResult<Vec<_>>>()?;
+                let (reports, pagination) = paginate(reports, pagination.as_ref())?;
+                Ok(to_binary(&QueryReportsResponse {
+                    reports,
+                    pagination,
+                })?)
+            }
+            ReportsQuery::Report {
+                subspace_id,
+                report_id,
+            } => {
+                let report = load_report(storage, subspace_id.u64(), report_id.u64())?;
+                Ok(to_binary(&QueryReportResponse { report })?)
+            }
+            ReportsQuery::Reasons {
+                subspace_id,
+                pagination,
+                ..
+            } => {
+                let reasons = REASONS
+                    .prefix(subspace_id.u64())
+                    .range(storage, None, None, Order::Ascending)
+                    .map(|item| item.map(|(_, reason)| reason))
+                    .collect::<StdResult<Vec<_>>>()?;
+                let (reasons, pagination) = paginate(reasons, pagination.as_ref())?;
+                Ok(to_binary(&QueryReasonsResponse {
+                    reasons,
+                    pagination,
+                })?)
+            }
+            ReportsQuery::Reason {
+                subspace_id,
+                reason_id,
+            } => {
+                let reason = load_reason(storage, subspace_id.u64(), reason_id)?;
+                Ok(to_binary(&QueryReasonResponse { reason })?)
+            }
+        }
+    }
+
+    /// Stores the given [`ReportsGenesis`] inside the x/reports module state.
+    #[cfg_attr(not(feature = "posts"), allow(unused_variables))]
+    pub(super) fn init_reports_genesis(
+        &self,
+        storage: &mut dyn Storage,
+        genesis: ReportsGenesis,
+    ) -> AnyResult<()> {
+        for reason in genesis.reasons {
+            let subspace_id = reason.subspace_id.u64();
+            #[cfg(feature = "subspaces")]
+            load_subspace(storage, subspace_id)?;
+            REASONS.save(storage, (subspace_id, reason.id), &reason)?;
+            bump_next_id(storage, &NEXT_REASON_IDS, subspace_id, reason.id)?;
+        }
+
+        for report in genesis.reports {
+            let subspace_id = report.subspace_id.u64();
+            for reason_id in &report.reasons_ids {
+                load_reason(storage, subspace_id, *reason_id)?;
+            }
+            let report_target = ReportTarget::try_from(report.target.clone()).map_err(|err| {
+                ChainError::invalid_request(format!("invalid report target: {}", err))
+            })?;
+            #[cfg(feature = "posts")]
+            if let ReportTarget::Post { post_id } = &report_target {
+                load_post(storage, subspace_id, post_id.u64())?;
+            }
+            REPORTS.save(storage, (subspace_id, report.id.u64()), &report)?;
+            bump_next_id(storage, &NEXT_REPORT_IDS, subspace_id, report.id.u64())?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::events::find_event;
+    use crate::mocks::mock_apps::{custom_desmos_app, mock_desmos_app, DesmosApp};
+    use crate::mocks::mock_keeper::{DesmosKeeper, StandardReason};
+    use crate::posts::{models::ReplySetting, msg::PostsMsg};
+    use crate::reports::{
+        events::{CreateReportEvent, ReportedPostEvent, ReportedUserEvent},
+        models::ReportTarget,
+        msg::ReportsMsg,
+        querier::ReportsQuerier,
+    };
+    use crate::subspaces::msg::SubspacesMsg;
+    use cosmwasm_std::{Addr, Uint64};
+    use cw_multi_test::{AppResponse, Executor};
+    use std::ops::Deref;
+
+    const OWNER: &str = "desmos1nwp8gxrnmrsrzjdhvk47vvmthzxjtphgxp5ftc";
+    const REPORTER: &str = "desmos1rfv0f7mx7w9d3jv3h803u38vqym9ygg344asm3";
+
+    fn setup_subspace(app: &mut DesmosApp) {
+        app.execute(
+            Addr::unchecked(OWNER),
+            SubspacesMsg::create_subspace(
+                "Test subspace",
+                "Test subspace",
+                Addr::unchecked(OWNER),
+                Addr::unchecked(OWNER),
+                Addr::unchecked(OWNER),
+            )
+            .into(),
+        )
+        .unwrap();
+        app.execute(
+            Addr::unchecked(OWNER),
+            ReportsMsg::add_reason(1, "Spam", None::<String>, Addr::unchecked(OWNER)).into(),
+        )
+        .unwrap();
+    }
+
+    fn user_target(user: &str) -> ReportTarget {
+        ReportTarget::User {
+            user: Addr::unchecked(user),
+        }
+    }
+
+    fn create_report(
+        app: &mut DesmosApp,
+        reporter: &str,
+        reasons_ids: Vec<u32>,
+        target: ReportTarget,
+    ) -> anyhow::Result<AppResponse> {
+        app.execute(
+            Addr::unchecked(reporter),
+            ReportsMsg::create_report(
+                1,
+                reasons_ids,
+                Some("Report message"),
+                Addr::unchecked(reporter),
+                target,
+            )
+            .into(),
+        )
+    }
+
+    #[test]
+    fn create_report_properly() {
+        let mut app = mock_desmos_app();
+        setup_subspace(&mut app);
+        create_report(&mut app, REPORTER, vec![1], user_target(OWNER)).unwrap();
+        create_report(&mut app, OWNER, vec![1], user_target(REPORTER)).unwrap();
+
+        let wrapper = app.wrap();
+        let querier = ReportsQuerier::new(wrapper.deref());
+        let report = querier.query_report(1, 2).unwrap().report;
+        assert_eq!(Uint64::new(2), report.id);
+        assert_eq!(Addr::unchecked(OWNER), report.reporter);
+        assert_eq!(vec![1], report.reasons_ids);
+        assert_eq!(Some("Report message".to_string()), report.message);
+    }
+
+    #[test]
+    fn create_report_emits_the_target_event() {
+        let mut app = mock_desmos_app();
+        setup_subspace(&mut app);
+        let response = create_report(&mut app, REPORTER, vec![1], user_target(OWNER)).unwrap();
+
+        let report = find_event::<CreateReportEvent>(&response.events)
+            .unwrap()
+            .unwrap();
+        assert_eq!(1, report.report_id);
+        assert_eq!(Addr::unchecked(REPORTER), report.reporter);
+        assert_eq!(
+            Some(ReportedUserEvent {
+                subspace_id: 1,
+                user: Addr::unchecked(OWNER),
+                reporter: Addr::unchecked(REPORTER),
+            }),
+            find_event(&response.events).unwrap()
+        );
+        assert_eq!(
+            None,
+            find_event::<ReportedPostEvent>(&response.events).unwrap()
+        );
+    }
+
+    #[test]
+    fn create_report_with_missing_reason_fails() {
+        let mut app = mock_desmos_app();
+        setup_subspace(&mut app);
+        assert!(create_report(&mut app, REPORTER, vec![1, 2], user_target(OWNER)).is_err());
+    }
+
+    #[test]
+    fn create_report_for_missing_post_fails() {
+        let mut app = mock_desmos_app();
+        setup_subspace(&mut app);
+        let post_target = ReportTarget::Post {
+            post_id: Uint64::new(1),
+        };
+        assert!(create_report(&mut app, REPORTER, vec![1], post_target.clone()).is_err());
+
+        app.execute(
+            Addr::unchecked(OWNER),
+            PostsMsg::create_post(
+                1,
+                0,
+                None,
+                Some("Hello"),
+                None,
+                None,
+                Addr::unchecked(OWNER),
+                None,
+                ReplySetting::Everyone,
+                vec![],
+            )
+            .into(),
+        )
+        .unwrap();
+        assert!(create_report(&mut app, REPORTER, vec![1], post_target).is_ok());
+    }
+
+    #[test]
+    fn report_same_target_twice_fails() {
+        let mut app = mock_desmos_app();
+        setup_subspace(&mut app);
+        create_report(&mut app, REPORTER, vec![1], user_target(OWNER)).unwrap();
+        assert!(create_report(&mut app, REPORTER, vec![1], user_target(OWNER)).is_err());
+    }
+
+    #[test]
+    fn delete_report_properly() {
+        let mut app = mock_desmos_app();
+        setup_subspace(&mut app);
+        let delete = ReportsMsg::delete_report(1, 1, Addr::unchecked(REPORTER));
+        assert!(app
+            .execute(Addr::unchecked(REPORTER), delete.clone().into())
+            .is_err());
+
+        create_report(&mut app, REPORTER, vec![1], user_target(OWNER)).unwrap();
+        app.execute(Addr::unchecked(REPORTER), delete.into())
+            .unwrap();
+        let wrapper = app.wrap();
+        let querier = ReportsQuerier::new(wrapper.deref());
+        assert!(querier.query_report(1, 1).is_err());
+    }
+
+    #[test]
+    fn remove_reason_properly() {
+        let mut app = mock_desmos_app();
+        setup_subspace(&mut app);
+        let remove = ReportsMsg::remove_reason(1, 1, Addr::unchecked(OWNER));
+        app.execute(Addr::unchecked(OWNER), remove.clone().into())
+            .unwrap();
+        assert!(app.execute(Addr::unchecked(OWNER), remove.into()).is_err());
+        assert!(create_report(&mut app, REPORTER, vec![1], user_target(OWNER)).is_err());
+    }
+
+    #[test]
+    fn support_standard_reason_properly() {
+        let keeper = DesmosKeeper::new().with_standard_reasons(vec![StandardReason {
+            id: 3,
+            title: "Scam".to_string(),
+            description: Some("Scam content".to_string()),
+        }]);
+        let mut app = custom_desmos_app(keeper, |_, _, _| {});
+        setup_subspace(&mut app);
+        let support = |id| ReportsMsg::support_standard_reason(1, id, Addr::unchecked(OWNER));
+        assert!(app
+            .execute(Addr::unchecked(OWNER), support(1).into())
+            .is_err());
+        app.execute(Addr::unchecked(OWNER), support(3).into())
+            .unwrap();
+
+        let wrapper = app.wrap();
+        let querier = ReportsQuerier::new(wrapper.deref());
+        let reason = querier.query_reason(1, 2).unwrap().reason;
+        assert_eq!("Scam", reason.title);
+        assert_eq!(Some("Scam content".to_string()), reason.description);
+        assert_eq!(2, querier.query_reasons(1, None).unwrap().reasons.len());
+    }
+
+    #[test]
+    fn query_reports_filters_properly() {
+        let mut app = mock_desmos_app();
+        setup_subspace(&mut app);
+        create_report(&mut app, REPORTER, vec![1], user_target(OWNER)).unwrap();
+        create_report(&mut app, OWNER, vec![1], user_target(REPORTER)).unwrap();
+        create_report(&mut app, REPORTER, vec![1], user_target(REPORTER)).unwrap();
+
+        let wrapper = app.wrap();
+        let querier = ReportsQuerier::new(wrapper.deref());
+        assert_eq!(
+            3,
+            querier
+                .query_reports(1, None, None, None)
+                .unwrap()
+                .reports
+                .len()
+        );
+
+        let by_target = querier
+            .query_reports(1, Some(user_target(REPORTER)), None, None)
+            .unwrap();
+        assert_eq!(2, by_target.reports.len());
+
+        let by_reporter = querier
+            .query_reports(
+                1,
+                Some(user_target(REPORTER)),
+                Some(Addr::unchecked(OWNER)),
+                None,
+            )
+            .unwrap();
+        assert_eq!(1, by_reporter.reports.len());
+        assert_eq!(Uint64::new(2), by_reporter.reports[0].id);
+
+        // The reporter is ignored when the target is not provided.
+        let only_reporter = querier
+            .query_reports(1, None, Some(Addr::unchecked(OWNER)), None)
+            .unwrap();
+        assert_eq!(3, only_reporter.reports.len());
+    }
+}
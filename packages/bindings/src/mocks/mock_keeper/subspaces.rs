@@ -0,0 +1,1350 @@
+//! Contains the simulation of the Desmos x/subspaces module used by the [`DesmosKeeper`].
+
+use crate::mocks::errors::ChainError;
+#[cfg(feature = "posts")]
+use crate::mocks::mock_keeper::posts::{remove_section_posts, remove_subspace_posts};
+#[cfg(feature = "reactions")]
+use crate::mocks::mock_keeper::reactions::remove_subspace_reactions;
+#[cfg(feature = "relationships")]
+use crate::mocks::mock_keeper::relationships::remove_subspace_relationships;
+#[cfg(feature = "reports")]
+use crate::mocks::mock_keeper::reports::remove_subspace_reports;
+use crate::mocks::mock_keeper::{
+    bump_next_id, genesis::SubspacesGenesis, time::to_rfc3339, updated_field, DesmosKeeper,
+};
+use crate::pagination::paginate;
+use crate::subspaces::{
+    events::{
+        AddUserToUserGroupEvent, CreateSectionEvent, CreateSubspaceEvent, CreateUserGroupEvent,
+        DeleteSectionEvent, DeleteSubspaceEvent, DeleteUserGroupEvent, EditSectionEvent,
+        EditSubspaceEvent, EditUserGroupEvent, MoveSectionEvent, MoveUserGroupEvent,
+        RemoveUserFromUserGroupEvent, SetUserGroupPermissionsEvent, SetUserPermissionsEvent,
+    },
+    models::{
+        GroupPermissions, Permission, PermissionDetail, Section, Subspace, UserGroup,
+        UserPermissions,
+    },
+    models_query::{
+        QuerySectionResponse, QuerySectionsResponse, QuerySubspaceResponse, QuerySubspacesResponse,
+        QueryUserGroupMembersResponse, QueryUserGroupResponse, QueryUserGroupsResponse,
+        QueryUserPermissionsResponse,
+    },
+    msg::SubspacesMsg,
+    query::SubspacesQuery,
+};
+use anyhow::{anyhow, bail, Result as AnyResult};
+use cosmwasm_std::{to_binary, Addr, Binary, BlockInfo, Empty, Order, StdResult, Storage, Uint64};
+use cw_multi_test::AppResponse;
+use cw_storage_plus::{Item, Map};
+
+/// Id of the section that is created by default inside every subspace.
+pub(super) const ROOT_SECTION_ID: u32 = 0;
+/// Id of the user group that is created by default inside every subspace
+/// and of which all the users are implicitly members.
+const DEFAULT_GROUP_ID: u32 = 0;
+
+/// Id that will be assigned to the next created subspace.
+const NEXT_SUBSPACE_ID: Item<u64> = Item::new("desmos_subspaces_next_subspace_id");
+/// Subspaces stored by their id.
+const SUBSPACES: Map<u64, Subspace> = Map::new("desmos_subspaces");
+/// Id that will be assigned to the next section created inside a subspace.
+const NEXT_SECTION_IDS: Map<u64, u32> = Map::new("desmos_subspaces_next_section_ids");
+/// Sections stored by (subspace id, section id).
+const SECTIONS: Map<(u64, u32), Section> = Map::new("desmos_subspaces_sections");
+/// Id that will be assigned to the next user group created inside a subspace.
+const NEXT_GROUP_IDS: Map<u64, u32> = Map::new("desmos_subspaces_next_group_ids");
+/// User groups stored by (subspace id, group id).
+const USER_GROUPS: Map<(u64, u32), UserGroup> = Map::new("desmos_subspaces_user_groups");
+/// User groups members stored by (subspace id, group id, member).
+const USER_GROUP_MEMBERS: Map<(u64, u32, &Addr), Empty> =
+    Map::new("desmos_subspaces_user_group_members");
+/// Permissions set to the users stored by (subspace id, section id, user).
+const USER_PERMISSIONS: Map<(u64, u32, &Addr), Vec<Permission>> =
+    Map::new("desmos_subspaces_user_permissions");
+
+/// Loads the subspace having the given id, returning an error if it does not exist.
+pub(super) fn load_subspace(storage: &dyn Storage, subspace_id: u64) -> AnyResult<Subspace> {
+    SUBSPACES
+        .may_load(storage, subspace_id)?
+        .ok_or_else(|| anyhow!(ChainError::subspace_not_found(subspace_id)))
+}
+
+/// Loads the section having the given id, returning an error if it does not exist.
+pub(super) fn load_section(
+    storage: &dyn Storage,
+    subspace_id: u64,
+    section_id: u32,
+) -> AnyResult<Section> {
+    SECTIONS
+        .may_load(storage, (subspace_id, section_id))?
+        .ok_or_else(|| anyhow!(ChainError::section_not_found(subspace_id, section_id)))
+}
+
+/// Loads the user group having the given id, returning an error if it does not exist.
+pub(super) fn load_user_group(
+    storage: &dyn Storage,
+    subspace_id: u64,
+    group_id: u32,
+) -> AnyResult<UserGroup> {
+    USER_GROUPS
+        .may_load(storage, (subspace_id, group_id))?
+        .ok_or_else(|| anyhow!(ChainError::user_group_not_found(subspace_id, group_id)))
+}
+
+/// Returns the ids of the sections that go from the given section up to the root section.
+pub(super) fn section_path(
+    storage: &dyn Storage,
+    subspace_id: u64,
+    section_id: u32,
+) -> AnyResult<Vec<u32>> {
+    let mut path = vec![section_id];
+    let mut section = load_section(storage, subspace_id, section_id)?;
+    while let Some(parent_id) = section.parent_id {
+        path.push(parent_id);
+        section = load_section(storage, subspace_id, parent_id)?;
+    }
+    Ok(path)
+}
+
+/// Tells whether the given user is a member of the provided group.
+pub(super) fn is_group_member(storage: &dyn Storage, group: &UserGroup, user: &Addr) -> bool {
+    group.id == DEFAULT_GROUP_ID
+        || USER_GROUP_MEMBERS.has(storage, (group.subspace_id.u64(), group.id, user))
+}
+
+/// Adds to `permissions` all the `new_permissions` that are not already present.
+fn merge_permissions(permissions: &mut Vec<Permission>, new_permissions: &[Permission]) {
+    for permission in new_permissions {
+        if !permissions.contains(permission) {
+            permissions.push(permission.clone());
+        }
+    }
+}
+
+/// Returns the details of all the permissions that the given user has inside the section path
+/// that goes from the provided section up to the root section.
+pub(super) fn permission_details(
+    storage: &dyn Storage,
+    subspace_id: u64,
+    section_id: u32,
+    user: &Addr,
+) -> AnyResult<Vec<PermissionDetail>> {
+    let path = section_path(storage, subspace_id, section_id)?;
+    let groups = USER_GROUPS
+        .prefix(subspace_id)
+        .range(storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(_, group)| group))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    let mut details = Vec::new();
+    for section_id in path {
+        if let Some(permissions) =
+            USER_PERMISSIONS.may_load(storage, (subspace_id, section_id, user))?
+        {
+            details.push(PermissionDetail {
+                subspace_id: Uint64::new(subspace_id),
+                section_id,
+                user: Some(UserPermissions {
+                    user: user.clone(),
+                    permission: permissions,
+                }),
+                group: None,
+            });
+        }
+        for group in groups.iter().filter(|group| {
+            group.section_id.unwrap_or(ROOT_SECTION_ID) == section_id
+                && is_group_member(storage, group, user)
+        }) {
+            details.push(PermissionDetail {
+                subspace_id: Uint64::new(subspace_id),
+                section_id,
+                user: None,
+                group: Some(GroupPermissions {
+                    group_id: group.id,
+                    permission: group.permissions.clone(),
+                }),
+            });
+        }
+    }
+    Ok(details)
+}
+
+/// Returns the permissions that the given user has inside a section, combining the ones
+/// set to the user with the ones of the groups they are part of along the section path.
+pub(super) fn user_permissions(
+    storage: &dyn Storage,
+    subspace_id: u64,
+    section_id: u32,
+    user: &Addr,
+) -> AnyResult<Vec<Permission>> {
+    let mut permissions = Vec::new();
+    for detail in permission_details(storage, subspace_id, section_id, user)? {
+        if let Some(user_permissions) = detail.user {
+            merge_permissions(&mut permissions, &user_permissions.permission);
+        }
+        if let Some(group_permissions) = detail.group {
+            merge_permissions(&mut permissions, &group_permissions.permission);
+        }
+    }
+    Ok(permissions)
+}
+
+/// Tells whether the given user has the provided permission inside a section.
+/// The subspace owner has all the permissions, and [`Permission::Everything`] grants any permission.
+pub(super) fn has_permission(
+    storage: &dyn Storage,
+    subspace_id: u64,
+    section_id: u32,
+    user: &Addr,
+    permission: &Permission,
+) -> AnyResult<bool> {
+    if load_subspace(storage, subspace_id)?.owner == *user {
+        return Ok(true);
+    }
+    let permissions = user_permissions(storage, subspace_id, section_id, user)?;
+    Ok(permissions.contains(permission) || permissions.contains(&Permission::Everything))
+}
+
+/// Saves the root section and the default user group that every subspace has.
+fn save_default_section_and_group(storage: &mut dyn Storage, subspace_id: u64) -> StdResult<()> {
+    SECTIONS.save(
+        storage,
+        (subspace_id, ROOT_SECTION_ID),
+        &Section {
+            subspace_id: Uint64::new(subspace_id),
+            id: ROOT_SECTION_ID,
+            parent_id: None,
+            name: "Default section".to_string(),
+            description: String::new(),
+        },
+    )?;
+    NEXT_SECTION_IDS.save(storage, subspace_id, &(ROOT_SECTION_ID + 1))?;
+    USER_GROUPS.save(
+        storage,
+        (subspace_id, DEFAULT_GROUP_ID),
+        &UserGroup {
+            subspace_id: Uint64::new(subspace_id),
+            section_id: Some(ROOT_SECTION_ID),
+            id: DEFAULT_GROUP_ID,
+            name: "Default".to_string(),
+            description: "This is a default user group which all users are automatically part of"
+                .to_string(),
+            permissions: vec![],
+        },
+    )?;
+    NEXT_GROUP_IDS.save(storage, subspace_id, &(DEFAULT_GROUP_ID + 1))?;
+    Ok(())
+}
+
+/// Removes a user group along with all its members.
+fn remove_user_group(storage: &mut dyn Storage, subspace_id: u64, group_id: u32) -> StdResult<()> {
+    let members = USER_GROUP_MEMBERS
+        .prefix((subspace_id, group_id))
+        .keys(storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+    for member in members {
+        USER_GROUP_MEMBERS.remove(storage, (subspace_id, group_id, &member));
+    }
+    USER_GROUPS.remove(storage, (subspace_id, group_id));
+    Ok(())
+}
+
+/// Removes a section along with all its sub sections, user groups, users permissions and posts.
+fn remove_section(storage: &mut dyn Storage, subspace_id: u64, section_id: u32) -> StdResult<()> {
+    let children = SECTIONS
+        .prefix(subspace_id)
+        .range(storage, None, None, Order::Ascending)
+        .filter_map(|item| match item {
+            Ok((id, section)) if section.parent_id == Some(section_id) => Some(Ok(id)),
+            Ok(_) => None,
+            Err(err) => Some(Err(err)),
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+    for child_id in children {
+        remove_section(storage, subspace_id, child_id)?;
+    }
+
+    let groups = USER_GROUPS
+        .prefix(subspace_id)
+        .range(storage, None, None, Order::Ascending)
+        .filter_map(|item| match item {
+            Ok((id, group)) if group.section_id.unwrap_or(ROOT_SECTION_ID) == section_id => {
+                Some(Ok(id))
+            }
+            Ok(_) => None,
+            Err(err) => Some(Err(err)),
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+    for group_id in groups {
+        remove_user_group(storage, subspace_id, group_id)?;
+    }
+
+    let users = USER_PERMISSIONS
+        .prefix((subspace_id, section_id))
+        .keys(storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+    for user in users {
+        USER_PERMISSIONS.remove(storage, (subspace_id, section_id, &user));
+    }
+
+    #[cfg(feature = "posts")]
+    remove_section_posts(storage, subspace_id, section_id)?;
+    SECTIONS.remove(storage, (subspace_id, section_id));
+    Ok(())
+}
+
+/// Returns the type URL of the chain message corresponding to the given [`SubspacesMsg`].
+pub(super) fn subspaces_msg_type_url(msg: &SubspacesMsg) -> &'static str {
+    match msg {
+        SubspacesMsg::CreateSubspace { .. } => "/desmos.subspaces.v3.MsgCreateSubspace",
+        SubspacesMsg::EditSubspace { .. } => "/desmos.subspaces.v3.MsgEditSubspace",
+        SubspacesMsg::DeleteSubspace { .. } => "/desmos.subspaces.v3.MsgDeleteSubspace",
+        SubspacesMsg::CreateSection { .. } => "/desmos.subspaces.v3.MsgCreateSection",
+        SubspacesMsg::EditSection { .. } => "/desmos.subspaces.v3.MsgEditSection",
+        SubspacesMsg::MoveSection { .. } => "/desmos.subspaces.v3.MsgMoveSection",
+        SubspacesMsg::DeleteSection { .. } => "/desmos.subspaces.v3.MsgDeleteSection",
+        SubspacesMsg::CreateUserGroup { .. } => "/desmos.subspaces.v3.MsgCreateUserGroup",
+        SubspacesMsg::EditUserGroup { .. } => "/desmos.subspaces.v3.MsgEditUserGroup",
+        SubspacesMsg::MoveUserGroup { .. } => "/desmos.subspaces.v3.MsgMoveUserGroup",
+        SubspacesMsg::SetUserGroupPermissions { .. } => {
+            "/desmos.subspaces.v3.MsgSetUserGroupPermissions"
+        }
+        SubspacesMsg::DeleteUserGroup { .. } => "/desmos.subspaces.v3.MsgDeleteUserGroup",
+        SubspacesMsg::AddUserToUserGroup { .. } => "/desmos.subspaces.v3.MsgAddUserToUserGroup",
+        SubspacesMsg::RemoveUserFromUserGroup { .. } => {
+            "/desmos.subspaces.v3.MsgRemoveUserFromUserGroup"
+        }
+        SubspacesMsg::SetUserPermissions { .. } => "/desmos.subspaces.v3.MsgSetUserPermissions",
+    }
+}
+
+impl DesmosKeeper {
+    /// Handles [`SubspacesMsg`](crate::subspaces::SubspacesMsg) updating the x/subspaces module state,
+    /// then returns the response with proper [events](https://github.com/desmos-labs/desmos/blob/master/x/subspaces/spec/05-events.md).
+    pub(super) fn handle_subspaces_msg(
+        &self,
+        storage: &mut dyn Storage,
+        block: &BlockInfo,
+        msg: SubspacesMsg,
+    ) -> AnyResult<AppResponse> {
+        match msg {
+            SubspacesMsg::CreateSubspace {
+                name,
+                description,
+                treasury,
+                owner,
+                creator,
+            } => {
+                let subspace_id = NEXT_SUBSPACE_ID.may_load(storage)?.unwrap_or(1);
+                NEXT_SUBSPACE_ID.save(storage, &(subspace_id + 1))?;
+                SUBSPACES.save(
+                    storage,
+                    subspace_id,
+                    &Subspace {
+                        id: Uint64::new(subspace_id),
+                        name: name.clone(),
+                        description,
+                        treasury,
+                        owner,
+                        creator: creator.clone(),
+                        creation_time: to_rfc3339(&block.time),
+                    },
+                )?;
+                save_default_section_and_group(storage, subspace_id)?;
+
+                let mut events = Vec::with_capacity(1);
+                events.push(
+                    CreateSubspaceEvent {
+                        subspace_id,
+                        name,
+                        creator,
+                        creation_time: to_rfc3339(&block.time),
+                    }
+                    .into(),
+                );
+                AnyResult::Ok(AppResponse { events, data: None })
+            }
+            SubspacesMsg::EditSubspace {
+                subspace_id,
+                name,
+                description,
+                treasury,
+                owner,
+                ..
+            } => {
+                let subspace = load_subspace(storage, subspace_id.u64())?;
+                let subspace = Subspace {
+                    name: updated_field(subspace.name, name),
+                    description: updated_field(subspace.description, description),
+                    treasury: Addr::unchecked(updated_field(
+                        subspace.treasury.into_string(),
+                        treasury.into_string(),
+                    )),
+                    owner: Addr::unchecked(updated_field(
+                        subspace.owner.into_string(),
+                        owner.into_string(),
+                    )),
+                    ..subspace
+                };
+                SUBSPACES.save(storage, subspace_id.u64(), &subspace)?;
+
+                let mut events = Vec::with_capacity(1);
+
+                events.push(
+                    EditSubspaceEvent {
+                        subspace_id: subspace_id.u64(),
+                    }
+                    .into(),
+                );
+                AnyResult::Ok(AppResponse { events, data: None })
+            }
+            SubspacesMsg::DeleteSubspace { subspace_id, .. } => {
+                let id = subspace_id.u64();
+                load_subspace(storage, id)?;
+                remove_section(storage, id, ROOT_SECTION_ID)?;
+                // The other modules delete the subspace contents through the subspaces hooks
+                #[cfg(feature = "posts")]
+                remove_subspace_posts(storage, id)?;
+                #[cfg(feature = "reactions")]
+                remove_subspace_reactions(storage, id)?;
+                #[cfg(feature = "reports")]
+                remove_subspace_reports(storage, id)?;
+                #[cfg(feature = "relationships")]
+                remove_subspace_relationships(storage, id)?;
+                NEXT_SECTION_IDS.remove(storage, id);
+                NEXT_GROUP_IDS.remove(storage, id);
+                SUBSPACES.remove(storage, id);
+
+                let mut events = Vec::with_capacity(1);
+
+                events.push(
+                    DeleteSubspaceEvent {
+                        subspace_id: subspace_id.u64(),
+                    }
+                    .into(),
+                );
+                AnyResult::Ok(AppResponse { events, data: None })
+            }
+            SubspacesMsg::CreateSection {
+                subspace_id,
+                name,
+                description,
+                parent_id,
+                ..
+            } => {
+                load_subspace(storage, subspace_id.u64())?;
+                let parent_id = parent_id.unwrap_or(ROOT_SECTION_ID);
+                load_section(storage, subspace_id.u64(), parent_id)?;
+
+                let section_id = NEXT_SECTION_IDS.load(storage, subspace_id.u64())?;
+                NEXT_SECTION_IDS.save(storage, subspace_id.u64(), &(section_id + 1))?;
+                SECTIONS.save(
+                    storage,
+                    (subspace_id.u64(), section_id),
+                    &Section {
+                        subspace_id,
+                        id: section_id,
+                        parent_id: Some(parent_id),
+                        name,
+                        description: description.unwrap_or_default(),
+                    },
+                )?;
+
+                let mut events = Vec::with_capacity(1);
+                events.push(
+                    CreateSectionEvent {
+                        subspace_id: subspace_id.u64(),
+                        section_id,
+                    }
+                    .into(),
+                );
+                AnyResult::Ok(AppResponse { events, data: None })
+            }
+            SubspacesMsg::EditSection {
+                subspace_id,
+                section_id,
+                name,
+                description,
+                ..
+            } => {
+                let section = load_section(storage, subspace_id.u64(), section_id)?;
+                let section = Section {
+                    name: name.unwrap_or(section.name),
+                    description: description.unwrap_or(section.description),
+                    ..section
+                };
+                SECTIONS.save(storage, (subspace_id.u64(), section_id), &section)?;
+
+                let mut events = Vec::with_capacity(1);
+                events.push(
+                    EditSectionEvent {
+                        subspace_id: subspace_id.u64(),
+                        section_id,
+                    }
+                    .into(),
+                );
+                AnyResult::Ok(AppResponse { events, data: None })
+            }
+            SubspacesMsg::MoveSection {
+                subspace_id,
+                section_id,
+                new_parent_id,
+                ..
+            } => {
+                let section = load_section(storage, subspace_id.u64(), section_id)?;
+                if section_path(storage, subspace_id.u64(), new_parent_id)?.contains(&section_id) {
+                    bail!(ChainError::invalid_request(format!(
+                        "invalid new parent section id {}",
+                        new_parent_id
+                    )));
+                }
+                SECTIONS.save(
+                    storage,
+                    (subspace_id.u64(), section_id),
+                    &Section {
+                        parent_id: Some(new_parent_id),
+                        ..section
+                    },
+                )?;
+
+                let mut events = Vec::with_capacity(1);
+                events.push(
+                    MoveSectionEvent {
+                        subspace_id: subspace_id.u64(),
+                        section_id,
+                    }
+                    .into(),
+                );
+                AnyResult::Ok(AppResponse { events, data: None })
+            }
+            SubspacesMsg::DeleteSection {
+                subspace_id,
+                section_id,
+                ..
+            } => {
+                load_section(storage, subspace_id.u64(), section_id)?;
+                remove_section(storage, subspace_id.u64(), section_id)?;
+
+                let mut events = Vec::with_capacity(1);
+                events.push(
+                    DeleteSectionEvent {
+                        subspace_id: subspace_id.u64(),
+                        section_id,
+                    }
+                    .into(),
+                );
+                AnyResult::Ok(AppResponse { events, data: None })
+            }
+            SubspacesMsg::CreateUserGroup {
+                subspace_id,
+                section_id,
+                name,
+                description,
+                default_permissions,
+                ..
+            } => {
+                load_subspace(storage, subspace_id.u64())?;
+                let section_id = section_id.unwrap_or(ROOT_SECTION_ID);
+                load_section(storage, subspace_id.u64(), section_id)?;
+
+                let group_id = NEXT_GROUP_IDS.load(storage, subspace_id.u64())?;
+                NEXT_GROUP_IDS.save(storage, subspace_id.u64(), &(group_id + 1))?;
+                USER_GROUPS.save(
+                    storage,
+                    (subspace_id.u64(), group_id),
+                    &UserGroup {
+                        subspace_id,
+                        section_id: Some(section_id),
+                        id: group_id,
+                        name,
+                        description: description.unwrap_or_default(),
+                        permissions: default_permissions,
+                    },
+                )?;
+
+                let mut events = Vec::with_capacity(1);
+                events.push(
+                    CreateUserGroupEvent {
+                        subspace_id: subspace_id.u64(),
+                        group_id,
+                    }
+                    .into(),
+                );
+                AnyResult::Ok(AppResponse { events, data: None })
+            }
+            SubspacesMsg::EditUserGroup {
+                subspace_id,
+                group_id,
+                name,
+                description,
+                ..
+            } => {
+                let group = load_user_group(storage, subspace_id.u64(), group_id)?;
+                let group = UserGroup {
+                    name: name.unwrap_or(group.name),
+                    description: description.unwrap_or(group.description),
+                    ..group
+                };
+                USER_GROUPS.save(storage, (subspace_id.u64(), group_id), &group)?;
+
+                let mut events = Vec::with_capacity(1);
+                events.push(
+                    EditUserGroupEvent {
+                        subspace_id: subspace_id.u64(),
+                        group_id,
+                    }
+                    .into(),
+                );
+                AnyResult::Ok(AppResponse { events, data: None })
+            }
+            SubspacesMsg::MoveUserGroup {
+                subspace_id,
+                group_id,
+                new_section_id,
+                ..
+            } => {
+                let group = load_user_group(storage, subspace_id.u64(), group_id)?;
+                load_section(storage, subspace_id.u64(), new_section_id)?;
+                USER_GROUPS.save(
+                    storage,
+                    (subspace_id.u64(), group_id),
+                    &UserGroup {
+                        section_id: Some(new_section_id),
+                        ..group
+                    },
+                )?;
+
+                let mut events = Vec::with_capacity(1);
+                events.push(
+                    MoveUserGroupEvent {
+                        subspace_id: subspace_id.u64(),
+                        group_id,
+                    }
+                    .into(),
+                );
+                AnyResult::Ok(AppResponse { events, data: None })
+            }
+            SubspacesMsg::SetUserGroupPermissions {
+                subspace_id,
+                group_id,
+                permissions,
+                ..
+            } => {
+                let group = load_user_group(storage, subspace_id.u64(), group_id)?;
+                USER_GROUPS.save(
+                    storage,
+                    (subspace_id.u64(), group_id),
+                    &UserGroup {
+                        permissions,
+                        ..group
+                    },
+                )?;
+
+                let mut events = Vec::with_capacity(1);
+                events.push(
+                    SetUserGroupPermissionsEvent {
+                        subspace_id: subspace_id.u64(),
+                        group_id,
+                    }
+                    .into(),
+                );
+                AnyResult::Ok(AppResponse { events, data: None })
+            }
+            SubspacesMsg::DeleteUserGroup {
+                subspace_id,
+                group_id,
+                ..
+            } => {
+                load_user_group(storage, subspace_id.u64(), group_id)?;
+                remove_user_group(storage, subspace_id.u64(), group_id)?;
+
+                let mut events = Vec::with_capacity(1);
+                events.push(
+                    DeleteUserGroupEvent {
+                        subspace_id: subspace_id.u64(),
+                        group_id,
+                    }
+                    .into(),
+                );
+                AnyResult::Ok(AppResponse { events, data: None })
+            }
+            SubspacesMsg::AddUserToUserGroup {
+                subspace_id,
+                group_id,
+                user,
+                ..
+            } => {
+                load_user_group(storage, subspace_id.u64(), group_id)?;
+                let key = (subspace_id.u64(), group_id, &user);
+                if USER_GROUP_MEMBERS.has(storage, key) {
+                    bail!(ChainError::invalid_request(format!(
+                        "user is already part of group {}",
+                        group_id
+                    )));
+                }
+                USER_GROUP_MEMBERS.save(storage, key, &Empty {})?;
+
+                let mut events = Vec::with_capacity(1);
+                events.push(
+                    AddUserToUserGroupEvent {
+                        subspace_id: subspace_id.u64(),
+                        group_id,
+                        user,
+                    }
+                    .into(),
+                );
+                AnyResult::Ok(AppResponse { events, data: None })
+            }
+            SubspacesMsg::RemoveUserFromUserGroup {
+                subspace_id,
+                group_id,
+                user,
+                ..
+            } => {
+                load_user_group(storage, subspace_id.u64(), group_id)?;
+                let key = (subspace_id.u64(), group_id, &user);
+                if !USER_GROUP_MEMBERS.has(storage, key) {
+                    bail!(ChainError::invalid_request(format!(
+                        "user is not part of group {}",
+                        group_id
+                    )));
+                }
+                USER_GROUP_MEMBERS.remove(storage, key);
+
+                let mut events = Vec::with_capacity(1);
+                events.push(
+                    RemoveUserFromUserGroupEvent {
+                        subspace_id: subspace_id.u64(),
+                        group_id,
+                        user,
+                    }
+                    .into(),
+                );
+                AnyResult::Ok(AppResponse { events, data: None })
+            }
+            SubspacesMsg::SetUserPermissions {
+                subspace_id,
+                section_id,
+                user,
+                permissions,
+                ..
+            } => {
+                load_section(storage, subspace_id.u64(), section_id)?;
+                let key = (subspace_id.u64(), section_id, &user);
+                if permissions.is_empty() {
+                    USER_PERMISSIONS.remove(storage, key);
+                } else {
+                    USER_PERMISSIONS.save(storage, key, &permissions)?;
+                }
+
+                let mut events = Vec::with_capacity(1);
+                events.push(
+                    SetUserPermissionsEvent {
+                        subspace_id: subspace_id.u64(),
+                        section_id,
+                        user,
+                    }
+                    .into(),
+                );
+                AnyResult::Ok(AppResponse { events, data: None })
+            }
+        }
+    }
+
+    /// Handles [`SubspacesQuery`](crate::subspaces::query::SubspacesQuery) reading the
+    /// x/subspaces module state.
+    pub(super) fn query_subspaces(
+        &self,
+        storage: &dyn Storage,
+        query: SubspacesQuery,
+    ) -> AnyResult<Binary> {
+        match query {
+            SubspacesQuery::Subspaces { pagination, .. } => {
+                let subspaces = SUBSPACES
+                    .range(storage, None, None, Order::Ascending)
+                    .map(|item| item.map(|(_, subspace)| subspace))
+                    .collect::<StdResult<Vec<_>>>()?;
+                let (subspaces, pagination) = paginate(subspaces, pagination.as_ref())?;
+                Ok(to_binary(&QuerySubspacesResponse {
+                    subspaces,
+                    pagination,
+                })?)
+            }
+            SubspacesQuery::Subspace { subspace_id } => {
+                let subspace = load_subspace(storage, subspace_id.u64())?;
+                Ok(to_binary(&QuerySubspaceResponse { subspace })?)
+            }
+            SubspacesQuery::Sections {
+                subspace_id,
+                pagination,
+                ..
+            } => {
+                let sections = SECTIONS
+                    .prefix(subspace_id.u64())
+                    .range(storage, None, None, Order::Ascending)
+                    .map(|item| item.map(|(_, section)| section))
+                    .collect::<StdResult<Vec<_>>>()?;
+                let (sections, pagination) = paginate(sections, pagination.as_ref())?;
+                Ok(to_binary(&QuerySectionsResponse {
+                    sections,
+                    pagination,
+                })?)
+            }
+            SubspacesQuery::Section {
+                subspace_id,
+                section_id,
+            } => {
+                let section = load_section(storage, subspace_id.u64(), section_id)?;
+                Ok(to_binary(&QuerySectionResponse { section })?)
+            }
+            SubspacesQuery::UserGroups {
+                subspace_id,
+                section_id,
+                pagination,
+                ..
+            } => {
+                let groups = USER_GROUPS
+                    .prefix(subspace_id.u64())
+                    .range(storage, None, None, Order::Ascending)
+                    .filter(|item| match (item, section_id) {
+                        (Ok((_, group)), Some(section_id)) => {
+                            group.section_id.unwrap_or(ROOT_SECTION_ID) == section_id
+                        }
+                        _ => true,
+                    })
+                    .map(|item| item.map(|(_, group)| group))
+                    .collect::<StdResult<Vec<_>>>()?;
+                let (groups, pagination) = paginate(groups, pagination.as_ref())?;
+                Ok(to_binary(&QueryUserGroupsResponse { groups, pagination })?)
+            }
+            SubspacesQuery::UserGroup {
+                subspace_id,
+                group_id,
+            } => {
+                let group = load_user_group(storage, subspace_id.u64(), group_id)?;
+                Ok(to_binary(&QueryUserGroupResponse { group })?)
+            }
+            SubspacesQuery::UserGroupMembers {
+                subspace_id,
+                group_id,
+                pagination,
+                ..
+            } => {
+                load_user_group(storage, subspace_id.u64(), group_id)?;
+                let members = USER_GROUP_MEMBERS
+                    .prefix((subspace_id.u64(), group_id))
+                    .keys(storage, None, None, Order::Ascending)
+                    .collect::<StdResult<Vec<_>>>()?;
+                let (members, pagination) = paginate(members, pagination.as_ref())?;
+                Ok(to_binary(&QueryUserGroupMembersResponse {
+                    members,
+                    pagination,
+                })?)
+            }
+            SubspacesQuery::UserPermissions {
+                subspace_id,
+                section_id,
+                user,
+            } => {
+                let section_id = section_id.unwrap_or(ROOT_SECTION_ID);
+                Ok(to_binary(&QueryUserPermissionsResponse {
+                    permissions: user_permissions(storage, subspace_id.u64(), section_id, &user)?,
+                    details: permission_details(storage, subspace_id.u64(), section_id, &user)?,
+                })?)
+            }
+        }
+    }
+
+    /// Stores the given [`SubspacesGenesis`] inside the x/subspaces module state.
+    pub(super) fn init_subspaces_genesis(
+        &self,
+        storage: &mut dyn Storage,
+        genesis: SubspacesGenesis,
+    ) -> AnyResult<()> {
+        for subspace in genesis.subspaces {
+            let subspace_id = subspace.id.u64();
+            if SUBSPACES.has(storage, subspace_id) {
+                bail!(ChainError::invalid_request(format!(
+                    "subspace with id {} already exists",
+                    subspace_id
+                )));
+            }
+            SUBSPACES.save(storage, subspace_id, &subspace)?;
+            save_default_section_and_group(storage, subspace_id)?;
+            let next_subspace_id = NEXT_SUBSPACE_ID.may_load(storage)?.unwrap_or(1);
+            NEXT_SUBSPACE_ID.save(storage, &next_subspace_id.max(subspace_id + 1))?;
+        }
+
+        for section in genesis.sections.iter() {
+            let subspace_id = section.subspace_id.u64();
+            load_subspace(storage, subspace_id)?;
+            SECTIONS.save(storage, (subspace_id, section.id), section)?;
+            bump_next_id(storage, &NEXT_SECTION_IDS, subspace_id, section.id)?;
+        }
+        for section in genesis.sections.iter() {
+            if let Some(parent_id) = section.parent_id {
+                load_section(storage, section.subspace_id.u64(), parent_id)?;
+            }
+        }
+
+        for group in genesis.user_groups {
+            let subspace_id = group.subspace_id.u64();
+            load_section(
+                storage,
+                subspace_id,
+                group.section_id.unwrap_or(ROOT_SECTION_ID),
+            )?;
+            USER_GROUPS.save(storage, (subspace_id, group.id), &group)?;
+            bump_next_id(storage, &NEXT_GROUP_IDS, subspace_id, group.id)?;
+        }
+
+        for member in genesis.user_groups_members {
+            let subspace_id = member.subspace_id.u64();
+            load_user_group(storage, subspace_id, member.group_id)?;
+            USER_GROUP_MEMBERS.save(
+                storage,
+                (subspace_id, member.group_id, &member.user),
+                &Empty {},
+            )?;
+        }
+
+        for entry in genesis.user_permissions {
+            let subspace_id = entry.subspace_id.u64();
+            load_section(storage, subspace_id, entry.section_id)?;
+            USER_PERMISSIONS.save(
+                storage,
+                (subspace_id, entry.section_id, &entry.user),
+                &entry.permissions,
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::mocks::mock_apps::{mock_desmos_app, DesmosApp};
+    use crate::posts::{models::ReplySetting, msg::PostsMsg, querier::PostsQuerier};
+    use crate::reactions::{models::ReactionValue, msg::ReactionsMsg, querier::ReactionsQuerier};
+    use crate::reports::{models::ReportTarget, msg::ReportsMsg, querier::ReportsQuerier};
+    use crate::subspaces::{models::Permission, msg::SubspacesMsg, querier::SubspacesQuerier};
+    use cosmwasm_std::Addr;
+    use cw_multi_test::{AppResponse, Executor};
+    use std::ops::Deref;
+
+    const OWNER: &str = "desmos1nwp8gxrnmrsrzjdhvk47vvmthzxjtphgxp5ftc";
+    const USER: &str = "desmos1rfv0f7mx7w9d3jv3h803u38vqym9ygg344asm3";
+
+    fn execute(app: &mut DesmosApp, msg: SubspacesMsg) -> AppResponse {
+        app.execute(Addr::unchecked(OWNER), msg.into()).unwrap()
+    }
+
+    fn create_subspace(app: &mut DesmosApp) -> AppResponse {
+        execute(
+            app,
+            SubspacesMsg::create_subspace(
+                "Test subspace",
+                "Test subspace",
+                Addr::unchecked(OWNER),
+                Addr::unchecked(OWNER),
+                Addr::unchecked(OWNER),
+            ),
+        )
+    }
+
+    fn event_attribute(response: &AppResponse, event: &str, key: &str) -> String {
+        response
+            .events
+            .iter()
+            .find(|e| e.ty == event)
+            .and_then(|e| e.attributes.iter().find(|attr| attr.key == key))
+            .map(|attr| attr.value.clone())
+            .unwrap()
+    }
+
+    #[test]
+    fn create_subspace_allocates_sequential_ids() {
+        let mut app = mock_desmos_app();
+        let first = create_subspace(&mut app);
+        let second = create_subspace(&mut app);
+        assert_eq!(
+            "1",
+            event_attribute(&first, "create_subspace", "subspace_id")
+        );
+        assert_eq!(
+            "2",
+            event_attribute(&second, "create_subspace", "subspace_id")
+        );
+
+        let querier = app.wrap();
+        let querier = SubspacesQuerier::new(querier.deref());
+        assert_eq!(2, querier.query_subspaces(None).unwrap().subspaces.len());
+        let subspace = querier.query_subspace(2).unwrap().subspace;
+        assert_eq!(2, subspace.id.u64());
+        assert_eq!(Addr::unchecked(OWNER), subspace.owner);
+
+        // Every subspace has a root section and a default group
+        let sections = querier.query_sections(2, None).unwrap().sections;
+        assert_eq!(1, sections.len());
+        assert_eq!(0, sections[0].id);
+        assert_eq!(None, sections[0].parent_id);
+        let groups = querier.query_user_groups(2, None, None).unwrap().groups;
+        assert_eq!(1, groups.len());
+        assert_eq!(0, groups[0].id);
+    }
+
+    #[test]
+    fn edit_subspace_keeps_do_not_modify_fields() {
+        let mut app = mock_desmos_app();
+        create_subspace(&mut app);
+        execute(
+            &mut app,
+            SubspacesMsg::edit_subspace(
+                1,
+                "New name",
+                "[do-not-modify]",
+                Addr::unchecked("[do-not-modify]"),
+                Addr::unchecked(USER),
+                Addr::unchecked(OWNER),
+            ),
+        );
+
+        let querier = app.wrap();
+        let subspace = SubspacesQuerier::new(querier.deref())
+            .query_subspace(1)
+            .unwrap()
+            .subspace;
+        assert_eq!("New name", subspace.name);
+        assert_eq!("Test subspace", subspace.description);
+        assert_eq!(Addr::unchecked(OWNER), subspace.treasury);
+        assert_eq!(Addr::unchecked(USER), subspace.owner);
+    }
+
+    #[test]
+    fn sections_are_stored_as_a_tree() {
+        let mut app = mock_desmos_app();
+        create_subspace(&mut app);
+        let response = execute(
+            &mut app,
+            SubspacesMsg::create_section(1, "Parent", None, None, Addr::unchecked(OWNER)),
+        );
+        assert_eq!(
+            "1",
+            event_attribute(&response, "create_section", "section_id")
+        );
+        let response = execute(
+            &mut app,
+            SubspacesMsg::create_section(1, "Child", None, Some(1), Addr::unchecked(OWNER)),
+        );
+        assert_eq!(
+            "2",
+            event_attribute(&response, "create_section", "section_id")
+        );
+
+        let querier = app.wrap();
+        let querier = SubspacesQuerier::new(querier.deref());
+        assert_eq!(
+            Some(0),
+            querier.query_section(1, 1).unwrap().section.parent_id
+        );
+        assert_eq!(
+            Some(1),
+            querier.query_section(1, 2).unwrap().section.parent_id
+        );
+
+        // A section can't be moved inside one of its children
+        let result = app.execute(
+            Addr::unchecked(OWNER),
+            SubspacesMsg::move_section(1, 1, 2, Addr::unchecked(OWNER)).into(),
+        );
+        assert!(result.is_err());
+
+        // Deleting a section deletes its children too
+        execute(
+            &mut app,
+            SubspacesMsg::delete_section(1, 1, Addr::unchecked(OWNER)),
+        );
+        let querier = app.wrap();
+        let querier = SubspacesQuerier::new(querier.deref());
+        assert_eq!(1, querier.query_sections(1, None).unwrap().sections.len());
+        assert!(querier.query_section(1, 2).is_err());
+    }
+
+    #[test]
+    fn create_section_inside_non_existing_subspace_errors() {
+        let mut app = mock_desmos_app();
+        let result = app.execute(
+            Addr::unchecked(OWNER),
+            SubspacesMsg::create_section(1, "Section", None, None, Addr::unchecked(OWNER)).into(),
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn user_groups_members_are_stored() {
+        let mut app = mock_desmos_app();
+        create_subspace(&mut app);
+        let response = execute(
+            &mut app,
+            SubspacesMsg::create_user_group(
+                1,
+                None,
+                "Admins".to_string(),
+                None,
+                vec![Permission::ManageGroups],
+                Addr::unchecked(OWNER),
+            ),
+        );
+        assert_eq!(
+            "1",
+            event_attribute(&response, "create_user_group", "user_group_id")
+        );
+        execute(
+            &mut app,
+            SubspacesMsg::add_user_to_user_group(
+                1,
+                1,
+                Addr::unchecked(USER),
+                Addr::unchecked(OWNER),
+            ),
+        );
+
+        // The same user can't be added twice
+        let result = app.execute(
+            Addr::unchecked(OWNER),
+            SubspacesMsg::add_user_to_user_group(
+                1,
+                1,
+                Addr::unchecked(USER),
+                Addr::unchecked(OWNER),
+            )
+            .into(),
+        );
+        assert!(result.is_err());
+
+        let querier = app.wrap();
+        let querier = SubspacesQuerier::new(querier.deref());
+        assert_eq!(
+            vec![Addr::unchecked(USER)],
+            querier
+                .query_user_group_members(1, 1, None)
+                .unwrap()
+                .members
+        );
+
+        execute(
+            &mut app,
+            SubspacesMsg::delete_user_group(1, 1, Addr::unchecked(OWNER)),
+        );
+        let querier = app.wrap();
+        let querier = SubspacesQuerier::new(querier.deref());
+        assert!(querier.query_user_group(1, 1).is_err());
+    }
+
+    #[test]
+    fn user_permissions_combine_users_and_groups_permissions() {
+        let mut app = mock_desmos_app();
+        create_subspace(&mut app);
+        execute(
+            &mut app,
+            SubspacesMsg::create_section(1, "Section", None, None, Addr::unchecked(OWNER)),
+        );
+        execute(
+            &mut app,
+            SubspacesMsg::create_user_group(
+                1,
+                None,
+                "Moderators".to_string(),
+                None,
+                vec![Permission::ModerateContent],
+                Addr::unchecked(OWNER),
+            ),
+        );
+        execute(
+            &mut app,
+            SubspacesMsg::add_user_to_user_group(
+                1,
+                1,
+                Addr::unchecked(USER),
+                Addr::unchecked(OWNER),
+            ),
+        );
+        execute(
+            &mut app,
+            SubspacesMsg::set_user_permissions(
+                1,
+                1,
+                Addr::unchecked(USER),
+                vec![Permission::Write],
+                Addr::unchecked(OWNER),
+            ),
+        );
+
+        let querier = app.wrap();
+        let querier = SubspacesQuerier::new(querier.deref());
+        let response = querier
+            .query_user_permissions(1, Some(1), Addr::unchecked(USER))
+            .unwrap();
+        assert_eq!(
+            vec![Permission::Write, Permission::ModerateContent],
+            response.permissions
+        );
+
+        // Permissions set inside a sub section are not valid inside the root section
+        let response = querier
+            .query_user_permissions(1, None, Addr::unchecked(USER))
+            .unwrap();
+        assert_eq!(vec![Permission::ModerateContent], response.permissions);
+    }
+
+    #[test]
+    fn delete_subspace_removes_all_its_data() {
+        let mut app = mock_desmos_app();
+        create_subspace(&mut app);
+        execute(
+            &mut app,
+            SubspacesMsg::create_section(1, "Section", None, None, Addr::unchecked(OWNER)),
+        );
+        execute(
+            &mut app,
+            SubspacesMsg::delete_subspace(1, Addr::unchecked(OWNER)),
+        );
+
+        let querier = app.wrap();
+        let querier = SubspacesQuerier::new(querier.deref());
+        assert!(querier.query_subspace(1).is_err());
+        assert!(querier.query_sections(1, None).unwrap().sections.is_empty());
+
+        // Ids are never reused
+        let response = create_subspace(&mut app);
+        assert_eq!(
+            "2",
+            event_attribute(&response, "create_subspace", "subspace_id")
+        );
+    }
+
+    /// Creates a post inside the given section, along with a reaction and a report made toward it.
+    fn create_reported_post(app: &mut DesmosApp, section_id: u32) {
+        app.execute(
+            Addr::unchecked(OWNER),
+            PostsMsg::create_post(
+                1,
+                section_id,
+                None,
+                Some("Hello"),
+                None,
+                None,
+                Addr::unchecked(OWNER),
+                None,
+                ReplySetting::Everyone,
+                vec![],
+            )
+            .into(),
+        )
+        .unwrap();
+        let post_id = PostsQuerier::new(app.wrap().deref())
+            .query_subspace_posts(1, None)
+            .unwrap()
+            .posts
+            .iter()
+            .map(|post| post.id)
+            .max()
+            .unwrap();
+        app.execute(
+            Addr::unchecked(USER),
+            ReactionsMsg::add_reaction(
+                1,
+                post_id.u64(),
+                ReactionValue::FreeText {
+                    text: "Nice".to_string(),
+                },
+                Addr::unchecked(USER),
+            )
+            .into(),
+        )
+        .unwrap();
+        app.execute(
+            Addr::unchecked(USER),
+            ReportsMsg::create_report(
+                1,
+                vec![1],
+                None::<String>,
+                Addr::unchecked(USER),
+                ReportTarget::Post { post_id },
+            )
+            .into(),
+        )
+        .unwrap();
+    }
+
+    fn setup_subspace_contents(app: &mut DesmosApp) {
+        create_subspace(app);
+        execute(
+            app,
+            SubspacesMsg::create_section(1, "Section", None, None, Addr::unchecked(OWNER)),
+        );
+        app.execute(
+            Addr::unchecked(OWNER),
+            ReactionsMsg::add_registered_reaction(1, ":heart:", "❤️", Addr::unchecked(OWNER))
+                .into(),
+        )
+        .unwrap();
+        app.execute(
+            Addr::unchecked(OWNER),
+            ReportsMsg::add_reason(1, "Spam", None::<String>, Addr::unchecked(OWNER)).into(),
+        )
+        .unwrap();
+        create_reported_post(app, 0);
+        create_reported_post(app, 1);
+    }
+
+    #[test]
+    fn delete_subspace_removes_its_contents() {
+        let mut app = mock_desmos_app();
+        setup_subspace_contents(&mut app);
+        execute(
+            &mut app,
+            SubspacesMsg::delete_subspace(1, Addr::unchecked(OWNER)),
+        );
+
+        let wrapper = app.wrap();
+        let posts_querier = PostsQuerier::new(wrapper.deref());
+        assert_eq!(None, posts_querier.find_post(1, 1).unwrap());
+        assert_eq!(None, posts_querier.find_post(1, 2).unwrap());
+
+        let reactions_querier = ReactionsQuerier::new(wrapper.deref());
+        assert_eq!(None, reactions_querier.find_reaction(1, 1, 1).unwrap());
+        assert_eq!(
+            None,
+            reactions_querier.find_registered_reaction(1, 1).unwrap()
+        );
+
+        let reports_querier = ReportsQuerier::new(wrapper.deref());
+        assert_eq!(None, reports_querier.find_report(1, 1).unwrap());
+        assert_eq!(None, reports_querier.find_reason(1, 1).unwrap());
+    }
+
+    #[test]
+    fn delete_section_removes_its_posts() {
+        let mut app = mock_desmos_app();
+        setup_subspace_contents(&mut app);
+        execute(
+            &mut app,
+            SubspacesMsg::delete_section(1, 1, Addr::unchecked(OWNER)),
+        );
+
+        let wrapper = app.wrap();
+        let posts_querier = PostsQuerier::new(wrapper.deref());
+        assert!(posts_querier.find_post(1, 1).unwrap().is_some());
+        assert_eq!(None, posts_querier.find_post(1, 2).unwrap());
+
+        let reactions_querier = ReactionsQuerier::new(wrapper.deref());
+        assert!(reactions_querier.find_reaction(1, 1, 1).unwrap().is_some());
+        assert_eq!(None, reactions_querier.find_reaction(1, 2, 1).unwrap());
+
+        let reports_querier = ReportsQuerier::new(wrapper.deref());
+        assert!(reports_querier.find_report(1, 1).unwrap().is_some());
+        assert_eq!(None, reports_querier.find_report(1, 2).unwrap());
+        assert!(reports_querier.find_reason(1, 1).unwrap().is_some());
+    }
+}
@@ -0,0 +1,166 @@
+//! Contains the utilities used by the [`DesmosKeeper`](super::DesmosKeeper) to handle the times
+//! the same way the chain does.
+
+use crate::mocks::errors::ChainError;
+use anyhow::{anyhow, bail, Result as AnyResult};
+use cosmwasm_std::Timestamp;
+use std::convert::TryFrom;
+
+/// Seconds contained in a single day.
+const SECONDS_PER_DAY: u64 = 86_400;
+
+/// Formats a [`Timestamp`] as a RFC 3339 string with the same precision used by the chain.
+/// example: 2022-02-21T13:18:27.257641Z.
+pub fn to_rfc3339(time: &Timestamp) -> String {
+    let seconds = time.seconds();
+    let (year, month, day) = civil_from_days((seconds / SECONDS_PER_DAY) as i64);
+    let seconds_of_day = seconds % SECONDS_PER_DAY;
+
+    let mut formatted = format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}",
+        year,
+        month,
+        day,
+        seconds_of_day / 3600,
+        seconds_of_day % 3600 / 60,
+        seconds_of_day % 60
+    );
+    let nanos = time.subsec_nanos();
+    if nanos > 0 {
+        formatted.push('.');
+        formatted.push_str(format!("{:09}", nanos).trim_end_matches('0'));
+    }
+    formatted.push('Z');
+    formatted
+}
+
+/// Parses a RFC 3339 string (e.g. 2022-02-21T13:18:27.257641Z) into a [`Timestamp`].
+pub fn from_rfc3339(time: &str) -> AnyResult<Timestamp> {
+    let invalid_time = || {
+        anyhow!(ChainError::invalid_request(format!(
+            "invalid time {}",
+            time
+        )))
+    };
+    let (date, clock) = time.split_once('T').ok_or_else(invalid_time)?;
+
+    let date = date
+        .split('-')
+        .map(|value| value.parse::<i64>().map_err(|_| invalid_time()))
+        .collect::<AnyResult<Vec<_>>>()?;
+    if date.len() != 3 {
+        bail!(invalid_time());
+    }
+
+    let (clock, offset) = match clock.strip_suffix('Z') {
+        Some(clock) => (clock, 0),
+        None => {
+            let index = clock.rfind(['+', '-']).ok_or_else(invalid_time)?;
+            let (clock, offset) = clock.split_at(index);
+            let (hours, minutes) = offset[1..].split_once(':').ok_or_else(invalid_time)?;
+            let seconds = hours.parse::<i64>().map_err(|_| invalid_time())? * 3600
+                + minutes.parse::<i64>().map_err(|_| invalid_time())? * 60;
+            if offset.starts_with('-') {
+                (clock, -seconds)
+            } else {
+                (clock, seconds)
+            }
+        }
+    };
+    let (clock, nanos) = match clock.split_once('.') {
+        Some((clock, fraction)) if !fraction.is_empty() && fraction.len() <= 9 => {
+            let nanos = format!("{:0<9}", fraction)
+                .parse::<u64>()
+                .map_err(|_| invalid_time())?;
+            (clock, nanos)
+        }
+        Some(_) => bail!(invalid_time()),
+        None => (clock, 0),
+    };
+    let clock = clock
+        .split(':')
+        .map(|value| value.parse::<i64>().map_err(|_| invalid_time()))
+        .collect::<AnyResult<Vec<_>>>()?;
+    if clock.len() != 3 {
+        bail!(invalid_time());
+    }
+
+    let seconds = days_from_civil(date[0], date[1], date[2]) * SECONDS_PER_DAY as i64
+        + clock[0] * 3600
+        + clock[1] * 60
+        + clock[2]
+        - offset;
+    let seconds = u64::try_from(seconds).map_err(|_| invalid_time())?;
+    Ok(Timestamp::from_seconds(seconds).plus_nanos(nanos))
+}
+
+/// Converts the number of days elapsed since 1970-01-01 into a `(year, month, day)` date
+/// of the proleptic Gregorian calendar.
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * month_index + 2) / 5 + 1) as u32;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    } as u32;
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+/// Converts a `(year, month, day)` date of the proleptic Gregorian calendar into the
+/// number of days elapsed since 1970-01-01.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let month_index = if month > 2 { month - 3 } else { month + 9 };
+    let day_of_year = (153 * month_index + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn to_rfc3339_formats_properly() {
+        assert_eq!(
+            "2019-10-23T02:23:39.879305533Z",
+            to_rfc3339(&Timestamp::from_nanos(1_571_797_419_879_305_533))
+        );
+        assert_eq!(
+            "2022-02-21T13:18:27.257641Z",
+            to_rfc3339(&Timestamp::from_nanos(1_645_449_507_257_641_000))
+        );
+        assert_eq!(
+            "2000-02-29T00:00:00Z",
+            to_rfc3339(&Timestamp::from_seconds(951_782_400))
+        );
+    }
+
+    #[test]
+    fn from_rfc3339_parses_properly() {
+        assert_eq!(
+            Timestamp::from_nanos(1_571_797_419_879_305_533),
+            from_rfc3339("2019-10-23T02:23:39.879305533Z").unwrap()
+        );
+        assert_eq!(
+            Timestamp::from_nanos(1_645_449_507_257_641_000),
+            from_rfc3339("2022-02-21T13:18:27.257641Z").unwrap()
+        );
+        assert_eq!(
+            Timestamp::from_seconds(951_782_400),
+            from_rfc3339("2000-02-29T02:00:00+02:00").unwrap()
+        );
+        assert!(from_rfc3339("2000-02-29").is_err());
+        assert!(from_rfc3339("2000-02-29T00:00:00.Z").is_err());
+    }
+}
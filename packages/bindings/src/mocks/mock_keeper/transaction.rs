@@ -0,0 +1,124 @@
+//! Contains the utilities used by the [`DesmosKeeper`](super::DesmosKeeper) to apply the
+//! state changes of the genesis and of the sudo messages atomically, like the chain does.
+//! This is needed by the genesis, that the App initializes outside of any storage transaction.
+
+use anyhow::Result as AnyResult;
+use cosmwasm_std::{Order, Record, Storage};
+use std::collections::BTreeMap;
+
+/// Storage that writes through to the wrapped one, while keeping track of the values
+/// that the modified keys had before, so that the changes can be reverted.
+struct JournaledStorage<'a> {
+    storage: &'a mut dyn Storage,
+    /// Values of the modified keys before their first change.
+    journal: BTreeMap<Vec<u8>, Option<Vec<u8>>>,
+}
+
+impl<'a> JournaledStorage<'a> {
+    fn new(storage: &'a mut dyn Storage) -> Self {
+        JournaledStorage {
+            storage,
+            journal: BTreeMap::new(),
+        }
+    }
+
+    /// Records the current value of the given key, unless it has already been modified.
+    fn record(&mut self, key: &[u8]) {
+        if !self.journal.contains_key(key) {
+            let value = self.storage.get(key);
+            self.journal.insert(key.to_vec(), value);
+        }
+    }
+
+    /// Restores the values that the modified keys had before their first change.
+    fn revert(self) {
+        for (key, value) in self.journal {
+            match value {
+                Some(value) => self.storage.set(&key, &value),
+                None => self.storage.remove(&key),
+            }
+        }
+    }
+}
+
+impl Storage for JournaledStorage<'_> {
+    fn get(&self, key: &[u8]) -> Option<Vec<u8>> {
+        self.storage.get(key)
+    }
+
+    fn range<'a>(
+        &'a self,
+        start: Option<&[u8]>,
+        end: Option<&[u8]>,
+        order: Order,
+    ) -> Box<dyn Iterator<Item = Record> + 'a> {
+        self.storage.range(start, end, order)
+    }
+
+    fn set(&mut self, key: &[u8], value: &[u8]) {
+        self.record(key);
+        self.storage.set(key, value);
+    }
+
+    fn remove(&mut self, key: &[u8]) {
+        self.record(key);
+        self.storage.remove(key);
+    }
+}
+
+/// Runs the given action on the storage, reverting all the changes it made if it returns an error.
+pub(super) fn atomically<T>(
+    storage: &mut dyn Storage,
+    action: impl FnOnce(&mut dyn Storage) -> AnyResult<T>,
+) -> AnyResult<T> {
+    let mut journaled = JournaledStorage::new(storage);
+    let result = action(&mut journaled);
+    if result.is_err() {
+        journaled.revert();
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::atomically;
+    use anyhow::bail;
+    use cosmwasm_std::testing::MockStorage;
+    use cosmwasm_std::Storage;
+
+    #[test]
+    fn failed_action_changes_are_reverted() {
+        let mut storage = MockStorage::new();
+        storage.set(b"updated", b"old");
+        storage.set(b"removed", b"old");
+
+        let result = atomically(&mut storage, |storage| -> anyhow::Result<()> {
+            storage.set(b"updated", b"new");
+            storage.set(b"updated", b"newer");
+            storage.remove(b"removed");
+            storage.set(b"created", b"new");
+            bail!("failed")
+        });
+
+        assert!(result.is_err());
+        assert_eq!(Some(b"old".to_vec()), storage.get(b"updated"));
+        assert_eq!(Some(b"old".to_vec()), storage.get(b"removed"));
+        assert_eq!(None, storage.get(b"created"));
+    }
+
+    #[test]
+    fn successful_action_changes_are_kept() {
+        let mut storage = MockStorage::new();
+        storage.set(b"removed", b"old");
+
+        atomically(&mut storage, |storage| {
+            storage.set(b"created", b"new");
+            storage.remove(b"removed");
+            Ok(())
+        })
+        .unwrap();
+
+        assert_eq!(Some(b"new".to_vec()), storage.get(b"created"));
+        assert_eq!(None, storage.get(b"removed"));
+    }
+}
@@ -0,0 +1,1052 @@
+//! Contains the stateless checks that the [`DesmosKeeper`](super::DesmosKeeper) performs
+//! before handling a message, mirroring the `ValidateBasic` methods of the chain messages.
+
+use crate::msg::DesmosMsg;
+use anyhow::Result as AnyResult;
+use cosmwasm_std::Api;
+
+#[cfg(feature = "posts")]
+use crate::posts::{
+    models::{Entities, PostAttachment, PostReferenceType, RawPostAttachment, ReplySetting},
+    msg::PostsMsg,
+};
+#[cfg(feature = "profiles")]
+use crate::profiles::msg::ProfilesMsg;
+#[cfg(feature = "reactions")]
+use crate::reactions::{models::ReactionValue, msg::ReactionsMsg};
+#[cfg(feature = "relationships")]
+use crate::relationships::msg::RelationshipsMsg;
+#[cfg(feature = "reports")]
+use crate::reports::{models::ReportTarget, msg::ReportsMsg};
+#[cfg(feature = "subspaces")]
+use crate::subspaces::msg::SubspacesMsg;
+#[cfg(any(
+    feature = "subspaces",
+    feature = "posts",
+    feature = "reactions",
+    feature = "reports"
+))]
+use cosmwasm_std::Uint64;
+#[cfg(any(feature = "profiles", feature = "reactions"))]
+use regex::Regex;
+#[cfg(any(feature = "posts", feature = "reactions", feature = "reports"))]
+use std::convert::TryFrom;
+#[cfg(any(
+    feature = "profiles",
+    feature = "subspaces",
+    feature = "relationships",
+    feature = "posts",
+    feature = "reactions",
+    feature = "reports"
+))]
+use {crate::mocks::errors::ChainError, anyhow::ensure, cosmwasm_std::Addr};
+
+#[cfg(any(feature = "profiles", feature = "subspaces"))]
+use super::DO_NOT_MODIFY;
+
+/// Regex that the DTags must match according to the default x/profiles params.
+#[cfg(feature = "profiles")]
+const DTAG_REGEX: &str = "^[A-Za-z0-9_]+$";
+/// Minimum and maximum length of the DTags according to the default x/profiles params.
+#[cfg(feature = "profiles")]
+const DTAG_LENGTH: (usize, usize) = (3, 30);
+/// Minimum and maximum length of the nicknames according to the default x/profiles params.
+#[cfg(feature = "profiles")]
+const NICKNAME_LENGTH: (usize, usize) = (2, 1000);
+/// Maximum length of the biographies according to the default x/profiles params.
+#[cfg(feature = "profiles")]
+const MAX_BIO_LENGTH: usize = 1000;
+
+/// Checks that the given address is valid, returning an invalid address error
+/// that refers to the provided field otherwise.
+#[cfg(any(
+    feature = "profiles",
+    feature = "subspaces",
+    feature = "relationships",
+    feature = "posts",
+    feature = "reactions",
+    feature = "reports"
+))]
+fn validate_address(api: &dyn Api, address: &Addr, field: &str) -> AnyResult<()> {
+    api.addr_validate(address.as_str())
+        .map_err(|_| ChainError::invalid_address(format!("invalid {}: {}", field, address)))?;
+    Ok(())
+}
+
+/// Checks that the given subspace id is valid.
+#[cfg(any(
+    feature = "subspaces",
+    feature = "posts",
+    feature = "reactions",
+    feature = "reports"
+))]
+fn validate_subspace_id(subspace_id: Uint64) -> AnyResult<()> {
+    ensure!(
+        !subspace_id.is_zero(),
+        ChainError::invalid_request(format!("invalid subspace id: {}", subspace_id))
+    );
+    Ok(())
+}
+
+/// Checks that the given post id is valid.
+#[cfg(any(feature = "posts", feature = "reactions", feature = "reports"))]
+fn validate_post_id(post_id: Uint64) -> AnyResult<()> {
+    ensure!(
+        !post_id.is_zero(),
+        ChainError::invalid_request(format!("invalid post id: {}", post_id))
+    );
+    Ok(())
+}
+
+/// Checks that the given text is neither empty nor blank.
+#[cfg(any(
+    feature = "profiles",
+    feature = "subspaces",
+    feature = "posts",
+    feature = "reactions",
+    feature = "reports"
+))]
+fn validate_not_blank(value: &str, field: &str) -> AnyResult<()> {
+    ensure!(
+        !value.trim().is_empty(),
+        ChainError::invalid_request(format!("{} cannot be empty or blank", field))
+    );
+    Ok(())
+}
+
+/// Performs the stateless checks done by the chain on the given message,
+/// before it is handled by its module.
+// Hide these warnings since when we compile the package without any module feature
+// there is no message to validate.
+#[cfg_attr(
+    not(any(
+        feature = "profiles",
+        feature = "subspaces",
+        feature = "relationships",
+        feature = "posts",
+        feature = "reactions",
+        feature = "reports"
+    )),
+    allow(unreachable_code, unused_variables)
+)]
+pub(super) fn validate_basic(api: &dyn Api, msg: &DesmosMsg) -> AnyResult<()> {
+    match *msg {
+        #[cfg(feature = "profiles")]
+        DesmosMsg::Profiles(ref msg) => validate_profiles_msg(api, msg),
+        #[cfg(feature = "subspaces")]
+        DesmosMsg::Subspaces(ref msg) => validate_subspaces_msg(api, msg),
+        #[cfg(feature = "relationships")]
+        DesmosMsg::Relationships(ref msg) => validate_relationships_msg(api, msg),
+        #[cfg(feature = "posts")]
+        DesmosMsg::Posts(ref msg) => validate_posts_msg(api, msg),
+        #[cfg(feature = "reports")]
+        DesmosMsg::Reports(ref msg) => validate_reports_msg(api, msg),
+        #[cfg(feature = "reactions")]
+        DesmosMsg::Reactions(ref msg) => validate_reactions_msg(api, msg),
+    }
+}
+
+/// Checks that the given DTag respects the default x/profiles params.
+#[cfg(feature = "profiles")]
+fn validate_dtag(dtag: &str) -> AnyResult<()> {
+    let (min_length, max_length) = DTAG_LENGTH;
+    let regex = Regex::new(DTAG_REGEX).expect("the DTag regex must be valid");
+    ensure!(
+        regex.is_match(dtag),
+        ChainError::invalid_request(format!(
+            "invalid profile dtag, it should match the following regEx {}",
+            DTAG_REGEX
+        ))
+    );
+    ensure!(
+        dtag.chars().count() >= min_length,
+        ChainError::invalid_request(format!(
+            "profile dtag cannot be less than {} characters",
+            min_length
+        ))
+    );
+    ensure!(
+        dtag.chars().count() <= max_length,
+        ChainError::invalid_request(format!(
+            "profile dtag cannot exceed {} characters",
+            max_length
+        ))
+    );
+    Ok(())
+}
+
+/// Checks that the given DTag transfer request involves two different valid users.
+#[cfg(feature = "profiles")]
+fn validate_dtag_transfer_users(api: &dyn Api, sender: &Addr, receiver: &Addr) -> AnyResult<()> {
+    validate_address(api, sender, "sender address")?;
+    validate_address(api, receiver, "receiver address")?;
+    ensure!(
+        sender != receiver,
+        ChainError::invalid_request("the sender and receiver must be different")
+    );
+    Ok(())
+}
+
+/// Checks that the given chain name is valid.
+#[cfg(feature = "profiles")]
+fn validate_chain_name(chain_name: &str) -> AnyResult<()> {
+    validate_not_blank(chain_name, "chain name")?;
+    ensure!(
+        chain_name.to_lowercase() == chain_name,
+        ChainError::invalid_request("chain name must be lowercase")
+    );
+    Ok(())
+}
+
+/// Checks the fields of a [`ProfilesMsg`].
+#[cfg(feature = "profiles")]
+fn validate_profiles_msg(api: &dyn Api, msg: &ProfilesMsg) -> AnyResult<()> {
+    match msg {
+        ProfilesMsg::SaveProfile {
+            dtag,
+            nickname,
+            bio,
+            creator,
+            ..
+        } => {
+            validate_address(api, creator, "creator address")?;
+            validate_not_blank(dtag, "profile dtag")?;
+            if dtag != DO_NOT_MODIFY {
+                validate_dtag(dtag)?;
+            }
+            if !nickname.is_empty() && nickname != DO_NOT_MODIFY {
+                let (min_length, max_length) = NICKNAME_LENGTH;
+                let length = nickname.chars().count();
+                ensure!(
+                    length >= min_length,
+                    ChainError::invalid_request(format!(
+                        "profile nickname cannot be less than {} characters",
+                        min_length
+                    ))
+                );
+                ensure!(
+                    length <= max_length,
+                    ChainError::invalid_request(format!(
+                        "profile nickname cannot exceed {} characters",
+                        max_length
+                    ))
+                );
+            }
+            if bio != DO_NOT_MODIFY {
+                ensure!(
+                    bio.chars().count() <= MAX_BIO_LENGTH,
+                    ChainError::invalid_request(format!(
+                        "profile biography cannot exceed {} characters",
+                        MAX_BIO_LENGTH
+                    ))
+                );
+            }
+            Ok(())
+        }
+        ProfilesMsg::DeleteProfile { creator } => validate_address(api, creator, "creator address"),
+        ProfilesMsg::RequestDtagTransfer { sender, receiver }
+        | ProfilesMsg::RefuseDtagTransferRequest { sender, receiver }
+        | ProfilesMsg::CancelDtagTransferRequest { sender, receiver } => {
+            validate_dtag_transfer_users(api, sender, receiver)
+        }
+        ProfilesMsg::AcceptDtagTransferRequest {
+            new_dtag,
+            sender,
+            receiver,
+        } => {
+            validate_dtag_transfer_users(api, sender, receiver)?;
+            validate_not_blank(new_dtag, "new DTag")
+        }
+        ProfilesMsg::LinkChainAccount {
+            chain_address,
+            proof,
+            chain_config,
+            signer,
+        } => {
+            validate_not_blank(&chain_address.value, "chain address")?;
+            ensure!(
+                !proof.pub_key.key.is_empty(),
+                ChainError::invalid_request("public key field cannot be nil")
+            );
+            ensure!(
+                !proof.signature.signature.is_empty(),
+                ChainError::invalid_request("signature field cannot be nil")
+            );
+            ensure!(
+                !proof.plain_text.is_empty()
+                    && proof.plain_text.chars().all(|c| c.is_ascii_hexdigit()),
+                ChainError::invalid_request("invalid hex-encoded plain text")
+            );
+            validate_chain_name(&chain_config.name)?;
+            validate_address(api, signer, "signer address")
+        }
+        ProfilesMsg::UnlinkChainAccount {
+            owner,
+            chain_name,
+            target,
+        } => {
+            validate_address(api, owner, "owner address")?;
+            validate_chain_name(chain_name)?;
+            validate_not_blank(target, "target address")
+        }
+        ProfilesMsg::SetDefaultExternalAddress {
+            chain_name,
+            target,
+            signer,
+        } => {
+            validate_chain_name(chain_name)?;
+            validate_not_blank(target, "target address")?;
+            validate_address(api, signer, "signer address")
+        }
+        ProfilesMsg::LinkApplication {
+            sender,
+            link_data,
+            call_data,
+            source_port,
+            source_channel,
+            ..
+        } => {
+            validate_address(api, sender, "sender address")?;
+            validate_not_blank(&link_data.application, "application")?;
+            validate_not_blank(&link_data.username, "application username")?;
+            validate_not_blank(call_data, "call data")?;
+            validate_not_blank(source_port, "source port")?;
+            validate_not_blank(source_channel, "source channel")
+        }
+        ProfilesMsg::UnlinkApplication {
+            application,
+            username,
+            signer,
+        } => {
+            validate_not_blank(application, "application")?;
+            validate_not_blank(username, "application username")?;
+            validate_address(api, signer, "signer address")
+        }
+    }
+}
+
+/// Checks that the given group id does not refer to the default user group.
+#[cfg(feature = "subspaces")]
+fn validate_group_id(group_id: u32, error: &str) -> AnyResult<()> {
+    ensure!(group_id != 0, ChainError::invalid_request(error));
+    Ok(())
+}
+
+/// Checks the fields of a [`SubspacesMsg`].
+#[cfg(feature = "subspaces")]
+fn validate_subspaces_msg(api: &dyn Api, msg: &SubspacesMsg) -> AnyResult<()> {
+    match msg {
+        SubspacesMsg::CreateSubspace {
+            name,
+            treasury,
+            owner,
+            creator,
+            ..
+        } => {
+            validate_not_blank(name, "subspace name")?;
+            if !treasury.as_str().is_empty() {
+                validate_address(api, treasury, "treasury address")?;
+            }
+            validate_address(api, owner, "owner address")?;
+            validate_address(api, creator, "creator address")
+        }
+        SubspacesMsg::EditSubspace {
+            subspace_id,
+            name,
+            treasury,
+            owner,
+            signer,
+            ..
+        } => {
+            validate_subspace_id(*subspace_id)?;
+            validate_not_blank(name, "subspace name")?;
+            for (address, field) in [(treasury, "treasury address"), (owner, "owner address")] {
+                if !address.as_str().is_empty() && address.as_str() != DO_NOT_MODIFY {
+                    validate_address(api, address, field)?;
+                }
+            }
+            validate_address(api, signer, "signer address")
+        }
+        SubspacesMsg::DeleteSubspace {
+            subspace_id,
+            signer,
+        } => {
+            validate_subspace_id(*subspace_id)?;
+            validate_address(api, signer, "signer address")
+        }
+        SubspacesMsg::CreateSection {
+            subspace_id,
+            name,
+            creator,
+            ..
+        } => {
+            validate_subspace_id(*subspace_id)?;
+            validate_not_blank(name, "section name")?;
+            validate_address(api, creator, "creator address")
+        }
+        SubspacesMsg::EditSection {
+            subspace_id,
+            editor,
+            ..
+        } => {
+            validate_subspace_id(*subspace_id)?;
+            validate_address(api, editor, "editor address")
+        }
+        SubspacesMsg::MoveSection {
+            subspace_id,
+            section_id,
+            new_parent_id,
+            signer,
+        } => {
+            validate_subspace_id(*subspace_id)?;
+            ensure!(
+                *section_id != 0,
+                ChainError::invalid_request("cannot move the root section")
+            );
+            ensure!(
+                section_id != new_parent_id,
+                ChainError::invalid_request(format!(
+                    "invalid new parent section id {}",
+                    new_parent_id
+                ))
+            );
+            validate_address(api, signer, "signer address")
+        }
+        SubspacesMsg::DeleteSection {
+            subspace_id,
+            section_id,
+            signer,
+        } => {
+            validate_subspace_id(*subspace_id)?;
+            ensure!(
+                *section_id != 0,
+                ChainError::invalid_request("cannot delete the root section")
+            );
+            validate_address(api, signer, "signer address")
+        }
+        SubspacesMsg::CreateUserGroup {
+            subspace_id,
+            name,
+            creator,
+            ..
+        } => {
+            validate_subspace_id(*subspace_id)?;
+            validate_not_blank(name, "group name")?;
+            validate_address(api, creator, "creator address")
+        }
+        SubspacesMsg::EditUserGroup {
+            subspace_id,
+            signer,
+            ..
+        }
+        | SubspacesMsg::MoveUserGroup {
+            subspace_id,
+            signer,
+            ..
+        }
+        | SubspacesMsg::SetUserGroupPermissions {
+            subspace_id,
+            signer,
+            ..
+        } => {
+            validate_subspace_id(*subspace_id)?;
+            validate_address(api, signer, "signer address")
+        }
+        SubspacesMsg::DeleteUserGroup {
+            subspace_id,
+            group_id,
+            signer,
+        } => {
+            validate_subspace_id(*subspace_id)?;
+            validate_group_id(*group_id, "cannot delete the default user group")?;
+            validate_address(api, signer, "signer address")
+        }
+        SubspacesMsg::AddUserToUserGroup {
+            subspace_id,
+            group_id,
+            user,
+            signer,
+        } => {
+            validate_subspace_id(*subspace_id)?;
+            validate_group_id(*group_id, "cannot add users to the default user group")?;
+            validate_address(api, user, "user address")?;
+            validate_address(api, signer, "signer address")
+        }
+        SubspacesMsg::RemoveUserFromUserGroup {
+            subspace_id,
+            group_id,
+            user,
+            signer,
+        } => {
+            validate_subspace_id(*subspace_id)?;
+            validate_group_id(*group_id, "cannot remove users from the default user group")?;
+            validate_address(api, user, "user address")?;
+            validate_address(api, signer, "signer address")
+        }
+        SubspacesMsg::SetUserPermissions {
+            subspace_id,
+            user,
+            signer,
+            ..
+        } => {
+            validate_subspace_id(*subspace_id)?;
+            validate_address(api, user, "user address")?;
+            validate_address(api, signer, "signer address")
+        }
+    }
+}
+
+/// Checks the fields of a [`RelationshipsMsg`].
+#[cfg(feature = "relationships")]
+fn validate_relationships_msg(api: &dyn Api, msg: &RelationshipsMsg) -> AnyResult<()> {
+    match msg {
+        RelationshipsMsg::CreateRelationship {
+            signer,
+            counterparty,
+            ..
+        }
+        | RelationshipsMsg::DeleteRelationship {
+            signer,
+            counterparty,
+            ..
+        } => {
+            validate_address(api, signer, "signer address")?;
+            validate_address(api, counterparty, "counterparty address")?;
+            ensure!(
+                signer != counterparty,
+                ChainError::invalid_request("creator and counterparty must be different")
+            );
+            Ok(())
+        }
+        RelationshipsMsg::BlockUser {
+            blocker, blocked, ..
+        }
+        | RelationshipsMsg::UnblockUser {
+            blocker, blocked, ..
+        } => {
+            validate_address(api, blocker, "blocker address")?;
+            validate_address(api, blocked, "blocked address")?;
+            ensure!(
+                blocker != blocked,
+                ChainError::invalid_request("blocker and blocked must be different")
+            );
+            Ok(())
+        }
+    }
+}
+
+/// Checks that the given entities are well formed and do not overlap each other.
+#[cfg(feature = "posts")]
+fn validate_entities(entities: &Entities) -> AnyResult<()> {
+    let mut ranges = Vec::new();
+    for entity in entities.hashtags.iter().chain(entities.mentions.iter()) {
+        validate_not_blank(&entity.tag, "entity tag")?;
+        ranges.push((entity.start, entity.end));
+    }
+    for entity in entities.urls.iter() {
+        validate_not_blank(&entity.url, "entity url")?;
+        ranges.push((entity.start, entity.end));
+    }
+    for (start, end) in ranges.iter() {
+        ensure!(
+            start <= end,
+            ChainError::invalid_request(format!(
+                "invalid entity: start {} is greater than end {}",
+                start, end
+            ))
+        );
+    }
+    ranges.sort_unstable();
+    ensure!(
+        ranges.windows(2).all(|pair| pair[0].1 < pair[1].0),
+        ChainError::invalid_request("entities cannot overlap")
+    );
+    Ok(())
+}
+
+/// Checks that the given attachment is well formed and can be added to a post.
+#[cfg(feature = "posts")]
+fn validate_attachment(attachment: &RawPostAttachment) -> AnyResult<()> {
+    let attachment = PostAttachment::try_from(attachment.clone())
+        .map_err(|error| ChainError::invalid_request(error.to_string()))?;
+    match attachment {
+        PostAttachment::Media { uri, mime_type } => {
+            validate_not_blank(&uri, "media uri")?;
+            validate_not_blank(&mime_type, "media mime type")
+        }
+        PostAttachment::Poll {
+            question,
+            provided_answers,
+            end_date,
+            final_tally_results,
+            ..
+        } => {
+            validate_not_blank(&question, "poll question")?;
+            ensure!(
+                provided_answers.len() >= 2,
+                ChainError::invalid_request("insufficient amount of provided answers")
+            );
+            for (index, answer) in provided_answers.iter().enumerate() {
+                let text = answer.text.as_deref().unwrap_or_default();
+                ensure!(
+                    !text.trim().is_empty() || !answer.attachments.is_empty(),
+                    ChainError::invalid_request("provided answers must have a text or attachments")
+                );
+                ensure!(
+                    text.is_empty()
+                        || provided_answers[index + 1..]
+                            .iter()
+                            .all(|other| other.text.as_deref() != Some(text)),
+                    ChainError::invalid_request(format!("duplicated provided answer: {}", text))
+                );
+            }
+            validate_not_blank(&end_date, "poll end date")?;
+            ensure!(
+                final_tally_results.is_none(),
+                ChainError::invalid_request("poll tally results must be nil")
+            );
+            Ok(())
+        }
+    }
+}
+
+/// Checks the fields of a [`PostsMsg`].
+#[cfg(feature = "posts")]
+fn validate_posts_msg(api: &dyn Api, msg: &PostsMsg) -> AnyResult<()> {
+    match msg {
+        PostsMsg::CreatePost {
+            subspace_id,
+            entities,
+            attachments,
+            author,
+            conversation_id,
+            reply_settings,
+            referenced_posts,
+            ..
+        } => {
+            validate_subspace_id(*subspace_id)?;
+            if let Some(entities) = entities {
+                validate_entities(entities)?;
+            }
+            for attachment in attachments.iter().flatten() {
+                validate_attachment(attachment)?;
+            }
+            validate_address(api, author, "author address")?;
+            if let Some(conversation_id) = conversation_id {
+                validate_post_id(*conversation_id)?;
+            }
+            ensure!(
+                *reply_settings != ReplySetting::Unspecified,
+                ChainError::invalid_request("invalid reply setting")
+            );
+            for reference in referenced_posts {
+                ensure!(
+                    reference.ref_type != PostReferenceType::Unspecified,
+                    ChainError::invalid_request("invalid reference type")
+                );
+                validate_post_id(reference.post_id)?;
+                ensure!(
+                    reference.position.is_none() || reference.ref_type == PostReferenceType::Quote,
+                    ChainError::invalid_request(
+                        "reference position should be used only with quotes"
+                    )
+                );
+            }
+            Ok(())
+        }
+        PostsMsg::EditPost {
+            subspace_id,
+            post_id,
+            entities,
+            editor,
+            ..
+        } => {
+            validate_subspace_id(*subspace_id)?;
+            validate_post_id(*post_id)?;
+            if let Some(entities) = entities {
+                validate_entities(entities)?;
+            }
+            validate_address(api, editor, "editor address")
+        }
+        PostsMsg::DeletePost {
+            subspace_id,
+            post_id,
+            signer,
+        } => {
+            validate_subspace_id(*subspace_id)?;
+            validate_post_id(*post_id)?;
+            validate_address(api, signer, "signer address")
+        }
+        PostsMsg::AddPostAttachment {
+            subspace_id,
+            post_id,
+            content,
+            editor,
+        } => {
+            validate_subspace_id(*subspace_id)?;
+            validate_post_id(*post_id)?;
+            validate_attachment(content)?;
+            validate_address(api, editor, "editor address")
+        }
+        PostsMsg::RemovePostAttachment {
+            subspace_id,
+            post_id,
+            attachment_id,
+            editor,
+        } => {
+            validate_subspace_id(*subspace_id)?;
+            validate_post_id(*post_id)?;
+            ensure!(
+                *attachment_id != 0,
+                ChainError::invalid_request(format!("invalid attachment id: {}", attachment_id))
+            );
+            validate_address(api, editor, "editor address")
+        }
+        PostsMsg::AnswerPoll {
+            subspace_id,
+            post_id,
+            poll_id,
+            answers_indexes,
+            signer,
+        } => {
+            validate_subspace_id(*subspace_id)?;
+            validate_post_id(*post_id)?;
+            ensure!(
+                *poll_id != 0,
+                ChainError::invalid_request(format!("invalid poll id: {}", poll_id))
+            );
+            ensure!(
+                !answers_indexes.is_empty(),
+                ChainError::invalid_request("answer indexes cannot be empty")
+            );
+            for (position, index) in answers_indexes.iter().enumerate() {
+                ensure!(
+                    !answers_indexes[position + 1..].contains(index),
+                    ChainError::invalid_request(format!("duplicated answer index: {}", index))
+                );
+            }
+            validate_address(api, signer, "signer address")
+        }
+    }
+}
+
+/// Checks the fields of a [`ReportsMsg`].
+#[cfg(feature = "reports")]
+fn validate_reports_msg(api: &dyn Api, msg: &ReportsMsg) -> AnyResult<()> {
+    match msg {
+        ReportsMsg::CreateReport {
+            subspace_id,
+            reasons_ids,
+            reporter,
+            target,
+            ..
+        } => {
+            validate_subspace_id(*subspace_id)?;
+            ensure!(
+                !reasons_ids.is_empty(),
+                ChainError::invalid_request("report reasons cannot be empty")
+            );
+            for (position, reason_id) in reasons_ids.iter().enumerate() {
+                ensure!(
+                    *reason_id != 0,
+                    ChainError::invalid_request(format!("invalid reason id: {}", reason_id))
+                );
+                ensure!(
+                    !reasons_ids[position + 1..].contains(reason_id),
+                    ChainError::invalid_request(format!("duplicated reason id: {}", reason_id))
+                );
+            }
+            validate_address(api, reporter, "reporter address")?;
+            match ReportTarget::try_from(target.clone())
+                .map_err(|error| ChainError::invalid_request(error.to_string()))?
+            {
+                ReportTarget::User { user } => validate_address(api, &user, "reported user"),
+                ReportTarget::Post { post_id } => validate_post_id(post_id),
+            }
+        }
+        ReportsMsg::DeleteReport {
+            subspace_id,
+            report_id,
+            signer,
+        } => {
+            validate_subspace_id(*subspace_id)?;
+            ensure!(
+                !report_id.is_zero(),
+                ChainError::invalid_request(format!("invalid report id: {}", report_id))
+            );
+            validate_address(api, signer, "signer address")
+        }
+        ReportsMsg::SupportStandardReason {
+            subspace_id,
+            standard_reason_id,
+            signer,
+        } => {
+            validate_subspace_id(*subspace_id)?;
+            ensure!(
+                *standard_reason_id != 0,
+                ChainError::invalid_request(format!(
+                    "invalid standard reason id: {}",
+                    standard_reason_id
+                ))
+            );
+            validate_address(api, signer, "signer address")
+        }
+        ReportsMsg::AddReason {
+            subspace_id,
+            title,
+            signer,
+            ..
+        } => {
+            validate_subspace_id(*subspace_id)?;
+            validate_not_blank(title, "reason title")?;
+            validate_address(api, signer, "signer address")
+        }
+        ReportsMsg::RemoveReason {
+            subspace_id,
+            reason_id,
+            signer,
+        } => {
+            validate_subspace_id(*subspace_id)?;
+            ensure!(
+                *reason_id != 0,
+                ChainError::invalid_request(format!("invalid reason id: {}", reason_id))
+            );
+            validate_address(api, signer, "signer address")
+        }
+    }
+}
+
+/// Checks that the given registered reaction id is valid.
+#[cfg(feature = "reactions")]
+fn validate_registered_reaction_id(registered_reaction_id: u32) -> AnyResult<()> {
+    ensure!(
+        registered_reaction_id != 0,
+        ChainError::invalid_request(format!(
+            "invalid registered reaction id: {}",
+            registered_reaction_id
+        ))
+    );
+    Ok(())
+}
+
+/// Checks the fields of a [`ReactionsMsg`].
+#[cfg(feature = "reactions")]
+fn validate_reactions_msg(api: &dyn Api, msg: &ReactionsMsg) -> AnyResult<()> {
+    match msg {
+        ReactionsMsg::AddReaction {
+            subspace_id,
+            post_id,
+            value,
+            user,
+        } => {
+            validate_subspace_id(*subspace_id)?;
+            validate_post_id(*post_id)?;
+            match ReactionValue::try_from(value.clone())
+                .map_err(|error| ChainError::invalid_request(error.to_string()))?
+            {
+                ReactionValue::Registered {
+                    registered_reaction_id,
+                } => validate_registered_reaction_id(registered_reaction_id)?,
+                ReactionValue::FreeText { text } => validate_not_blank(&text, "reaction text")?,
+            }
+            validate_address(api, user, "user address")
+        }
+        ReactionsMsg::RemoveReaction {
+            subspace_id,
+            post_id,
+            reaction_id,
+            user,
+        } => {
+            validate_subspace_id(*subspace_id)?;
+            validate_post_id(*post_id)?;
+            ensure!(
+                *reaction_id != 0,
+                ChainError::invalid_request(format!("invalid reaction id: {}", reaction_id))
+            );
+            validate_address(api, user, "user address")
+        }
+        ReactionsMsg::AddRegisteredReaction {
+            subspace_id,
+            shorthand_code,
+            display_value,
+            user,
+        } => {
+            validate_subspace_id(*subspace_id)?;
+            validate_not_blank(shorthand_code, "shorthand code")?;
+            validate_not_blank(display_value, "display value")?;
+            validate_address(api, user, "user address")
+        }
+        ReactionsMsg::EditRegisteredReaction {
+            subspace_id,
+            registered_reaction_id,
+            shorthand_code,
+            display_value,
+            user,
+        } => {
+            validate_subspace_id(*subspace_id)?;
+            validate_registered_reaction_id(*registered_reaction_id)?;
+            validate_not_blank(shorthand_code, "shorthand code")?;
+            validate_not_blank(display_value, "display value")?;
+            validate_address(api, user, "user address")
+        }
+        ReactionsMsg::RemoveRegisteredReaction {
+            subspace_id,
+            registered_reaction_id,
+            user,
+        } => {
+            validate_subspace_id(*subspace_id)?;
+            validate_registered_reaction_id(*registered_reaction_id)?;
+            validate_address(api, user, "user address")
+        }
+        ReactionsMsg::SetReactionsParams {
+            subspace_id,
+            free_text,
+            user,
+            ..
+        } => {
+            validate_subspace_id(*subspace_id)?;
+            ensure!(
+                free_text.reg_ex.is_empty() || Regex::new(&free_text.reg_ex).is_ok(),
+                ChainError::invalid_request(format!("invalid regex {}", free_text.reg_ex))
+            );
+            validate_address(api, user, "user address")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::validate_basic;
+    use crate::mocks::errors::ChainError;
+    use crate::msg::DesmosMsg;
+    use crate::posts::models::{PostAttachment, ProvidedAnswer};
+    use crate::posts::msg::PostsMsg;
+    use crate::profiles::msg::ProfilesMsg;
+    use crate::reports::{models::ReportTarget, msg::ReportsMsg};
+    use crate::subspaces::msg::SubspacesMsg;
+    use cosmwasm_std::testing::MockApi;
+    use cosmwasm_std::Addr;
+
+    const USER: &str = "desmos1nwp8gxrnmrsrzjdhvk47vvmthzxjtphgxp5ftc";
+    const OTHER: &str = "desmos1rfv0f7mx7w9d3jv3h803u38vqym9ygg344asm3";
+
+    fn validate(msg: impl Into<DesmosMsg>) -> Result<(), ChainError> {
+        validate_basic(&MockApi::default(), &msg.into())
+            .map_err(|error| error.downcast::<ChainError>().unwrap())
+    }
+
+    #[test]
+    fn subspace_with_blank_name_is_rejected() {
+        let user = Addr::unchecked(USER);
+        let msg = SubspacesMsg::create_subspace(" ", "", user.clone(), user.clone(), user);
+        assert_eq!(
+            Err(ChainError::invalid_request(
+                "subspace name cannot be empty or blank"
+            )),
+            validate(msg)
+        );
+    }
+
+    #[test]
+    fn dtag_not_matching_the_regex_is_rejected() {
+        let msg = ProfilesMsg::save_profile("my-dtag", Addr::unchecked(USER), "", "", "", "");
+        assert_eq!(
+            Err(ChainError::invalid_request(
+                "invalid profile dtag, it should match the following regEx ^[A-Za-z0-9_]+$"
+            )),
+            validate(msg)
+        );
+
+        let msg =
+            ProfilesMsg::save_profile("[do-not-modify]", Addr::unchecked(USER), "", "", "", "");
+        assert_eq!(Ok(()), validate(msg));
+    }
+
+    #[test]
+    fn poll_with_less_than_two_answers_is_rejected() {
+        let poll = PostAttachment::Poll {
+            question: "Question".to_string(),
+            provided_answers: vec![ProvidedAnswer {
+                text: Some("Answer".to_string()),
+                attachments: vec![],
+            }],
+            end_date: "2022-01-01T00:00:00Z".to_string(),
+            allows_multiple_answers: false,
+            allows_answer_edits: false,
+            final_tally_results: None,
+        };
+        let msg = PostsMsg::add_post_attachment(1, 1, poll, Addr::unchecked(USER));
+        assert_eq!(
+            Err(ChainError::invalid_request(
+                "insufficient amount of provided answers"
+            )),
+            validate(msg)
+        );
+    }
+
+    #[test]
+    fn report_without_reasons_is_rejected() {
+        let target = ReportTarget::User {
+            user: Addr::unchecked(OTHER),
+        };
+        let msg =
+            ReportsMsg::create_report(1, vec![], None::<String>, Addr::unchecked(USER), target);
+        assert_eq!(
+            Err(ChainError::invalid_request(
+                "report reasons cannot be empty"
+            )),
+            validate(msg)
+        );
+    }
+
+    #[test]
+    fn zero_subspace_id_is_rejected() {
+        let msg = PostsMsg::delete_post(0, 1, Addr::unchecked(USER));
+        assert_eq!(
+            Err(ChainError::invalid_request("invalid subspace id: 0")),
+            validate(msg)
+        );
+    }
+
+    #[test]
+    fn invalid_address_is_rejected() {
+        let msg = PostsMsg::delete_post(1, 1, Addr::unchecked("Desmos1"));
+        assert_eq!(
+            Err(ChainError::invalid_address(
+                "invalid signer address: Desmos1"
+            )),
+            validate(msg)
+        );
+    }
+
+    #[cfg(feature = "arbitrary")]
+    mod arbitrary {
+        use super::validate;
+        use crate::posts::msg::PostsMsg;
+        use crate::profiles::msg::ProfilesMsg;
+        use crate::reactions::msg::ReactionsMsg;
+        use crate::relationships::msg::RelationshipsMsg;
+        use crate::reports::msg::ReportsMsg;
+        use crate::subspaces::msg::SubspacesMsg;
+        use proptest::prelude::*;
+
+        proptest! {
+            #[test]
+            fn arbitrary_msgs_are_valid(
+                profiles in any::<ProfilesMsg>(),
+                subspaces in any::<SubspacesMsg>(),
+                relationships in any::<RelationshipsMsg>(),
+                posts in any::<PostsMsg>(),
+                reactions in any::<ReactionsMsg>(),
+                reports in any::<ReportsMsg>(),
+            ) {
+                prop_assert_eq!(Ok(()), validate(profiles));
+                prop_assert_eq!(Ok(()), validate(subspaces));
+                prop_assert_eq!(Ok(()), validate(relationships));
+                prop_assert_eq!(Ok(()), validate(posts));
+                prop_assert_eq!(Ok(()), validate(reactions));
+                prop_assert_eq!(Ok(()), validate(reports));
+            }
+        }
+    }
+}
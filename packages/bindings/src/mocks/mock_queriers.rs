@@ -1,46 +1,141 @@
 //! Contains some useful functions to perform unit testing of smart contracts.
 
+use crate::mocks::errors::{ChainError, QueryErrors};
+use crate::mocks::fixtures::DesmosFixtures;
+use crate::mocks::gas::GasMeter;
+#[cfg(feature = "subspaces")]
+use crate::mocks::mock_keeper::genesis::{UserGroupMemberEntry, UserPermissionEntry};
+use crate::mocks::mock_keeper::DesmosKeeper;
+use crate::mocks::world::DesmosWorld;
 #[cfg(feature = "posts")]
-use crate::posts::{mocks::mock_posts_query_response, query::PostsQuery};
+use crate::posts::{
+    mocks::mock_posts_query_response,
+    models::{Attachment, Post, UserAnswer},
+    query::PostsQuery,
+};
 #[cfg(feature = "profiles")]
-use crate::profiles::{mocks::mock_profiles_query_response, query::ProfilesQuery};
+use crate::profiles::{
+    mocks::mock_profiles_query_response, models_profile::Profile, query::ProfilesQuery,
+};
 use crate::query::DesmosQuery;
 #[cfg(feature = "reactions")]
-use crate::reactions::{mocks::mock_reactions_query_response, query::ReactionsQuery};
+use crate::reactions::{
+    mocks::mock_reactions_query_response, models::Reaction, query::ReactionsQuery,
+};
 #[cfg(feature = "relationships")]
-use crate::relationships::{mocks::mock_relationships_query_response, query::RelationshipsQuery};
+use crate::relationships::{
+    mocks::mock_relationships_query_response, models::Relationship, query::RelationshipsQuery,
+};
 #[cfg(feature = "reports")]
-use crate::reports::{mocks::mock_reports_query_response, query::ReportsQuery};
+use crate::reports::{mocks::mock_reports_query_response, models::Report, query::ReportsQuery};
 #[cfg(feature = "subspaces")]
-use crate::subspaces::{mocks::mock_subspaces_query_response, query::SubspacesQuery};
-use cosmwasm_std::testing::MockQuerierCustomHandlerResult;
+use crate::subspaces::{
+    mocks::mock_subspaces_query_response,
+    models::{Permission, Section, Subspace, UserGroup},
+    query::SubspacesQuery,
+};
+use anyhow::Result as AnyResult;
+use cosmwasm_std::testing::{mock_env, MockQuerierCustomHandlerResult};
 use cosmwasm_std::{
     from_slice,
     testing::{MockApi, MockQuerier, MockStorage},
     Binary, Coin, ContractResult, OwnedDeps, Querier, QuerierResult, QueryRequest, SystemError,
     SystemResult,
 };
+#[cfg(feature = "subspaces")]
+use cosmwasm_std::{Addr, Uint64};
+use cw_multi_test::Module;
 use serde::de::DeserializeOwned;
+use std::cell::RefCell;
 use std::marker::PhantomData;
 
+/// Identifies the Desmos module toward which a [`DesmosQuery`] is performed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum QueriedModule {
+    /// The x/profiles module.
+    #[cfg(feature = "profiles")]
+    Profiles,
+    /// The x/subspaces module.
+    #[cfg(feature = "subspaces")]
+    Subspaces,
+    /// The x/relationships module.
+    #[cfg(feature = "relationships")]
+    Relationships,
+    /// The x/posts module.
+    #[cfg(feature = "posts")]
+    Posts,
+    /// The x/reactions module.
+    #[cfg(feature = "reactions")]
+    Reactions,
+    /// The x/reports module.
+    #[cfg(feature = "reports")]
+    Reports,
+}
+
+impl QueriedModule {
+    /// Returns the module toward which the given query is performed.
+    pub fn of(query: &DesmosQuery) -> Self {
+        match *query {
+            #[cfg(feature = "profiles")]
+            DesmosQuery::Profiles(_) => QueriedModule::Profiles,
+            #[cfg(feature = "subspaces")]
+            DesmosQuery::Subspaces(_) => QueriedModule::Subspaces,
+            #[cfg(feature = "relationships")]
+            DesmosQuery::Relationships(_) => QueriedModule::Relationships,
+            #[cfg(feature = "posts")]
+            DesmosQuery::Posts(_) => QueriedModule::Posts,
+            #[cfg(feature = "reactions")]
+            DesmosQuery::Reactions(_) => QueriedModule::Reactions,
+            #[cfg(feature = "reports")]
+            DesmosQuery::Reports(_) => QueriedModule::Reports,
+        }
+    }
+}
+
+/// Handler used to answer the queries toward a Desmos module.
+// Hide this warning since when we compile the package without any module feature
+// this type is never used.
+#[allow(dead_code)]
+type QueryHandler<Q> = Box<dyn for<'a> Fn(&'a Q) -> MockQuerierCustomHandlerResult>;
+
 /// Custom querier that can be used during unit testing to simulate what a contract receive when
 /// perform a query toward Desmos’s modules.
 pub struct MockDesmosQuerier {
     /// Default CosmWASM mock querier.
     pub mock_querier: MockQuerier<DesmosQuery>,
     #[cfg(feature = "profiles")]
-    profiles_handler: Box<dyn for<'a> Fn(&'a ProfilesQuery) -> MockQuerierCustomHandlerResult>,
+    profiles_handler: Option<QueryHandler<ProfilesQuery>>,
     #[cfg(feature = "subspaces")]
-    subspaces_handler: Box<dyn for<'a> Fn(&'a SubspacesQuery) -> MockQuerierCustomHandlerResult>,
+    subspaces_handler: Option<QueryHandler<SubspacesQuery>>,
     #[cfg(feature = "posts")]
-    posts_handler: Box<dyn for<'a> Fn(&'a PostsQuery) -> MockQuerierCustomHandlerResult>,
+    posts_handler: Option<QueryHandler<PostsQuery>>,
     #[cfg(feature = "relationships")]
-    relationships_handler:
-        Box<dyn for<'a> Fn(&'a RelationshipsQuery) -> MockQuerierCustomHandlerResult>,
+    relationships_handler: Option<QueryHandler<RelationshipsQuery>>,
     #[cfg(feature = "reports")]
-    reports_handler: Box<dyn for<'a> Fn(&'a ReportsQuery) -> MockQuerierCustomHandlerResult>,
+    reports_handler: Option<QueryHandler<ReportsQuery>>,
     #[cfg(feature = "reactions")]
-    reactions_handler: Box<dyn for<'a> Fn(&'a ReactionsQuery) -> MockQuerierCustomHandlerResult>,
+    reactions_handler: Option<QueryHandler<ReactionsQuery>>,
+    /// Objects used to answer the queries not handled by a custom handler.
+    #[cfg_attr(
+        not(any(
+            feature = "profiles",
+            feature = "subspaces",
+            feature = "posts",
+            feature = "relationships",
+            feature = "reactions",
+            feature = "reports"
+        )),
+        allow(dead_code)
+    )]
+    fixtures: DesmosFixtures,
+    /// Errors returned in place of the responses of some queries.
+    query_errors: QueryErrors,
+    /// Keeper and storage containing the state of the [`DesmosWorld`] used to answer the queries.
+    world: Option<(DesmosKeeper, MockStorage)>,
+    /// Queries handled by the querier, present only if the recording has been enabled.
+    recorded_queries: Option<RefCell<Vec<QueryRequest<DesmosQuery>>>>,
+    /// Meter charging the gas of the queries toward the Desmos modules, if one has been provided.
+    gas_meter: Option<GasMeter>,
 }
 
 impl MockDesmosQuerier {
@@ -51,49 +146,138 @@ impl MockDesmosQuerier {
         MockDesmosQuerier {
             mock_querier: MockQuerier::new(balances),
             #[cfg(feature = "profiles")]
-            profiles_handler: Box::new(|q| SystemResult::Ok(mock_profiles_query_response(q))),
+            profiles_handler: None,
             #[cfg(feature = "subspaces")]
-            subspaces_handler: Box::new(|q| SystemResult::Ok(mock_subspaces_query_response(q))),
+            subspaces_handler: None,
             #[cfg(feature = "posts")]
-            posts_handler: Box::new(|q| SystemResult::Ok(mock_posts_query_response(q))),
+            posts_handler: None,
             #[cfg(feature = "relationships")]
-            relationships_handler: Box::new(|q| {
-                SystemResult::Ok(mock_relationships_query_response(q))
-            }),
+            relationships_handler: None,
             #[cfg(feature = "reports")]
-            reports_handler: Box::new(|q| SystemResult::Ok(mock_reports_query_response(q))),
+            reports_handler: None,
             #[cfg(feature = "reactions")]
-            reactions_handler: Box::new(|q| SystemResult::Ok(mock_reactions_query_response(q))),
+            reactions_handler: None,
+            fixtures: DesmosFixtures::default(),
+            query_errors: QueryErrors::default(),
+            world: None,
+            recorded_queries: None,
+            gas_meter: None,
         }
     }
 
     /// Handle the query request.
     pub fn handle_query(&self, request: &QueryRequest<DesmosQuery>) -> QuerierResult {
+        if let Some(recorded_queries) = &self.recorded_queries {
+            recorded_queries.borrow_mut().push(request.clone());
+        }
         match request {
             QueryRequest::Custom(desmos_query) => {
-                match desmos_query {
-                    #[cfg(feature = "profiles")]
-                    DesmosQuery::Profiles(query) => (*self.profiles_handler)(query),
-                    #[cfg(feature = "subspaces")]
-                    DesmosQuery::Subspaces(query) => (*self.subspaces_handler)(query),
-                    #[cfg(feature = "posts")]
-                    DesmosQuery::Posts(query) => (*self.posts_handler)(query),
-                    #[cfg(feature = "relationships")]
-                    DesmosQuery::Relationships(query) => (*self.relationships_handler)(query),
-                    #[cfg(feature = "reports")]
-                    DesmosQuery::Reports(query) => (*self.reports_handler)(query),
-                    #[cfg(feature = "reactions")]
-                    DesmosQuery::Reactions(query) => (*self.reactions_handler)(query),
-                    // Hide this warning since when we compile the package without any module feature
-                    // this pattern is reached.
-                    #[allow(unreachable_patterns)]
-                    _ => SystemResult::Err(SystemError::Unknown {}),
-                }
+                let result = self.desmos_query_response(desmos_query);
+                self.consume_query_gas(result)
             }
             _ => self.mock_querier.handle_query(request),
         }
     }
 
+    /// Answers the given query toward a Desmos module.
+    fn desmos_query_response(&self, desmos_query: &DesmosQuery) -> QuerierResult {
+        if let Some(error) = self.query_errors.find(desmos_query) {
+            return SystemResult::Ok(ContractResult::Err(error.redacted()));
+        }
+        match desmos_query {
+            #[cfg(feature = "profiles")]
+            DesmosQuery::Profiles(query) => match &self.profiles_handler {
+                Some(handler) => handler(query),
+                None => SystemResult::Ok(
+                    self.world_query_response(desmos_query)
+                        .or_else(|| self.fixtures.profiles_query_response(query))
+                        .unwrap_or_else(|| mock_profiles_query_response(query)),
+                ),
+            },
+            #[cfg(feature = "subspaces")]
+            DesmosQuery::Subspaces(query) => match &self.subspaces_handler {
+                Some(handler) => handler(query),
+                None => SystemResult::Ok(
+                    self.world_query_response(desmos_query)
+                        .or_else(|| self.fixtures.subspaces_query_response(query))
+                        .unwrap_or_else(|| mock_subspaces_query_response(query)),
+                ),
+            },
+            #[cfg(feature = "posts")]
+            DesmosQuery::Posts(query) => match &self.posts_handler {
+                Some(handler) => handler(query),
+                None => SystemResult::Ok(
+                    self.world_query_response(desmos_query)
+                        .or_else(|| self.fixtures.posts_query_response(query))
+                        .unwrap_or_else(|| mock_posts_query_response(query)),
+                ),
+            },
+            #[cfg(feature = "relationships")]
+            DesmosQuery::Relationships(query) => match &self.relationships_handler {
+                Some(handler) => handler(query),
+                None => SystemResult::Ok(
+                    self.world_query_response(desmos_query)
+                        .or_else(|| self.fixtures.relationships_query_response(query))
+                        .unwrap_or_else(|| mock_relationships_query_response(query)),
+                ),
+            },
+            #[cfg(feature = "reports")]
+            DesmosQuery::Reports(query) => match &self.reports_handler {
+                Some(handler) => handler(query),
+                None => SystemResult::Ok(
+                    self.world_query_response(desmos_query)
+                        .or_else(|| self.fixtures.reports_query_response(query))
+                        .unwrap_or_else(|| mock_reports_query_response(query)),
+                ),
+            },
+            #[cfg(feature = "reactions")]
+            DesmosQuery::Reactions(query) => match &self.reactions_handler {
+                Some(handler) => handler(query),
+                None => SystemResult::Ok(
+                    self.world_query_response(desmos_query)
+                        .or_else(|| self.fixtures.reactions_query_response(query))
+                        .unwrap_or_else(|| mock_reactions_query_response(query)),
+                ),
+            },
+            // Hide this warning since when we compile the package without any module feature
+            // this pattern is reached.
+            #[allow(unreachable_patterns)]
+            _ => SystemResult::Err(SystemError::Unknown {}),
+        }
+    }
+
+    /// Charges the gas of the given query result to the gas meter, if one has been provided.
+    ///
+    /// # Panics
+    /// Panics with an out of gas error if the meter limit has been exceeded.
+    fn consume_query_gas(&self, result: QuerierResult) -> QuerierResult {
+        let gas_meter = match &self.gas_meter {
+            Some(gas_meter) => gas_meter,
+            None => return result,
+        };
+        let response_size = match &result {
+            SystemResult::Ok(ContractResult::Ok(response)) => response.len(),
+            _ => 0,
+        };
+        gas_meter.charge_query(response_size);
+        result
+    }
+
+    /// Answers the given query with the state of the [`DesmosWorld`], if one has been provided.
+    fn world_query_response(&self, query: &DesmosQuery) -> Option<ContractResult<Binary>> {
+        self.world.as_ref().map(|(keeper, storage)| {
+            keeper
+                .query(
+                    &MockApi::default(),
+                    storage,
+                    &MockQuerier::<DesmosQuery>::new(&[]),
+                    &mock_env().block,
+                    query.clone(),
+                )
+                .into()
+        })
+    }
+
     /// Utility function to wrap the handler that returns a ContractResult<Binary>
     /// to make it return a SystemResult<ContractResult<Binary>>
     fn wrap_handler<'f, CH, Q>(
@@ -143,7 +327,7 @@ impl MockDesmosQuerier {
     where
         CH: Fn(&ProfilesQuery) -> ContractResult<Binary> + 'static,
     {
-        self.profiles_handler = MockDesmosQuerier::wrap_handler(handler);
+        self.profiles_handler = Some(MockDesmosQuerier::wrap_handler(handler));
         self
     }
 
@@ -174,7 +358,7 @@ impl MockDesmosQuerier {
     where
         CH: Fn(&SubspacesQuery) -> ContractResult<Binary> + 'static,
     {
-        self.subspaces_handler = MockDesmosQuerier::wrap_handler(handler);
+        self.subspaces_handler = Some(MockDesmosQuerier::wrap_handler(handler));
         self
     }
 
@@ -205,7 +389,7 @@ impl MockDesmosQuerier {
     where
         CH: Fn(&PostsQuery) -> ContractResult<Binary> + 'static,
     {
-        self.posts_handler = MockDesmosQuerier::wrap_handler(handler);
+        self.posts_handler = Some(MockDesmosQuerier::wrap_handler(handler));
         self
     }
 
@@ -237,7 +421,7 @@ impl MockDesmosQuerier {
     where
         CH: Fn(&RelationshipsQuery) -> ContractResult<Binary> + 'static,
     {
-        self.relationships_handler = MockDesmosQuerier::wrap_handler(handler);
+        self.relationships_handler = Some(MockDesmosQuerier::wrap_handler(handler));
         self
     }
 
@@ -268,7 +452,7 @@ impl MockDesmosQuerier {
     where
         CH: Fn(&ReportsQuery) -> ContractResult<Binary> + 'static,
     {
-        self.reports_handler = MockDesmosQuerier::wrap_handler(handler);
+        self.reports_handler = Some(MockDesmosQuerier::wrap_handler(handler));
         self
     }
 
@@ -299,9 +483,318 @@ impl MockDesmosQuerier {
     where
         CH: Fn(&ReactionsQuery) -> ContractResult<Binary> + 'static,
     {
-        self.reactions_handler = MockDesmosQuerier::wrap_handler(handler);
+        self.reactions_handler = Some(MockDesmosQuerier::wrap_handler(handler));
         self
     }
+
+    /// Adds the given profile to the objects used to answer the x/profiles queries.
+    ///
+    /// Once any object has been added with a `with_<object>` method, the queries about the
+    /// kinds of objects that can be added are answered using only the added objects:
+    /// the `Profile` queries return the profile owned by the requested address or DTag,
+    /// or a not found error if there is none.
+    ///
+    /// # Example
+    /// ```
+    /// use cosmwasm_std::{Addr, QuerierWrapper};
+    /// use desmos_bindings::mocks::mock_queriers::MockDesmosQuerier;
+    /// use desmos_bindings::profiles::mocks::MockProfilesQueries;
+    /// use desmos_bindings::profiles::querier::ProfilesQuerier;
+    /// use desmos_bindings::query::DesmosQuery;
+    /// use std::ops::Deref;
+    ///
+    /// let mut profile = MockProfilesQueries::get_mock_profile();
+    /// profile.account.address = Addr::unchecked("desmos1nwp8gxrnmrsrzjdhvk47vvmthzxjtphgxp5ftc");
+    /// let querier = MockDesmosQuerier::default().with_profile(profile.clone());
+    ///
+    /// let wrapper = QuerierWrapper::<DesmosQuery>::new(&querier);
+    /// let profiles_querier = ProfilesQuerier::new(wrapper.deref());
+    /// let response = profiles_querier.query_profile(profile.account.address.clone()).unwrap();
+    /// assert_eq!(profile, response.profile);
+    /// assert!(profiles_querier.query_profile(Addr::unchecked("unknown")).is_err());
+    /// ```
+    #[cfg(feature = "profiles")]
+    pub fn with_profile(mut self, profile: Profile) -> Self {
+        self.fixtures.add_profile(profile);
+        self
+    }
+
+    /// Adds the given subspace to the objects used to answer the x/subspaces queries.
+    /// The root section of the subspace is always present, while its default user group
+    /// must be added with [`with_user_group`](MockDesmosQuerier::with_user_group).
+    #[cfg(feature = "subspaces")]
+    pub fn with_subspace(mut self, subspace: Subspace) -> Self {
+        self.fixtures.add_subspace(subspace);
+        self
+    }
+
+    /// Adds the given section to the objects used to answer the x/subspaces queries.
+    #[cfg(feature = "subspaces")]
+    pub fn with_section(mut self, section: Section) -> Self {
+        self.fixtures.add_section(section);
+        self
+    }
+
+    /// Adds the given user group to the objects used to answer the x/subspaces queries.
+    #[cfg(feature = "subspaces")]
+    pub fn with_user_group(mut self, group: UserGroup) -> Self {
+        self.fixtures.add_user_group(group);
+        self
+    }
+
+    /// Adds the given user to the members of a group used to answer the x/subspaces queries.
+    #[cfg(feature = "subspaces")]
+    pub fn with_user_group_member(mut self, subspace_id: u64, group_id: u32, user: Addr) -> Self {
+        self.fixtures.add_user_group_member(UserGroupMemberEntry {
+            subspace_id: Uint64::new(subspace_id),
+            group_id,
+            user,
+        });
+        self
+    }
+
+    /// Sets the permissions of a user inside a section used to answer the x/subspaces queries.
+    /// The `UserPermissions` queries combine them with the ones of the groups of which the user
+    /// is a member, going from the requested section up to the root section.
+    #[cfg(feature = "subspaces")]
+    pub fn with_user_permissions(
+        mut self,
+        subspace_id: u64,
+        section_id: u32,
+        user: Addr,
+        permissions: Vec<Permission>,
+    ) -> Self {
+        self.fixtures.add_user_permissions(UserPermissionEntry {
+            subspace_id: Uint64::new(subspace_id),
+            section_id,
+            user,
+            permissions,
+        });
+        self
+    }
+
+    /// Adds the given post to the objects used to answer the x/posts queries.
+    #[cfg(feature = "posts")]
+    pub fn with_post(mut self, post: Post) -> Self {
+        self.fixtures.add_post(post);
+        self
+    }
+
+    /// Adds the given attachment to the objects used to answer the x/posts queries.
+    #[cfg(feature = "posts")]
+    pub fn with_attachment(mut self, attachment: Attachment) -> Self {
+        self.fixtures.add_attachment(attachment);
+        self
+    }
+
+    /// Adds the given poll answer to the objects used to answer the x/posts queries.
+    #[cfg(feature = "posts")]
+    pub fn with_poll_answer(mut self, answer: UserAnswer) -> Self {
+        self.fixtures.add_poll_answer(answer);
+        self
+    }
+
+    /// Adds the given relationship to the objects used to answer the x/relationships queries.
+    #[cfg(feature = "relationships")]
+    pub fn with_relationship(mut self, relationship: Relationship) -> Self {
+        self.fixtures.add_relationship(relationship);
+        self
+    }
+
+    /// Adds the given reaction to the objects used to answer the x/reactions queries.
+    #[cfg(feature = "reactions")]
+    pub fn with_reaction(mut self, reaction: Reaction) -> Self {
+        self.fixtures.add_reaction(reaction);
+        self
+    }
+
+    /// Adds the given report to the objects used to answer the x/reports queries.
+    #[cfg(feature = "reports")]
+    pub fn with_report(mut self, report: Report) -> Self {
+        self.fixtures.add_report(report);
+        self
+    }
+
+    /// Answers the queries using the state of the given [`DesmosWorld`], exactly as the
+    /// [`DesmosKeeper`] of a [`DesmosApp`](crate::mocks::mock_apps::DesmosApp) seeded with the
+    /// same world would do.
+    /// The world takes precedence over the objects added with the `with_<object>` methods,
+    /// while the custom handlers and the injected errors take precedence over the world.
+    ///
+    /// Returns an error if the world objects reference objects that do not exist.
+    pub fn with_world(mut self, world: &DesmosWorld) -> AnyResult<Self> {
+        let keeper = DesmosKeeper::new();
+        let mut storage = MockStorage::default();
+        keeper.init_genesis(&mut storage, world.genesis().clone())?;
+        self.world = Some((keeper, storage));
+        Ok(self)
+    }
+
+    /// Makes the given query fail with the provided error, as the chain would do.
+    /// Like on chain, the contract receives the [redacted](ChainError::redacted) error.
+    /// The error is returned only when the query is performed with exactly the same arguments,
+    /// and takes precedence over the handlers and the errors set with [`Self::with_module_error`].
+    ///
+    /// # Example
+    /// ```
+    /// use cosmwasm_std::{Addr, QuerierWrapper};
+    /// use desmos_bindings::mocks::errors::ChainError;
+    /// use desmos_bindings::mocks::mock_queriers::MockDesmosQuerier;
+    /// use desmos_bindings::profiles::querier::ProfilesQuerier;
+    /// use desmos_bindings::profiles::query::ProfilesQuery;
+    /// use desmos_bindings::query::DesmosQuery;
+    /// use std::ops::Deref;
+    ///
+    /// let user = "desmos1nwp8gxrnmrsrzjdhvk47vvmthzxjtphgxp5ftc";
+    /// let querier = MockDesmosQuerier::default().with_query_error(
+    ///     ProfilesQuery::Profile { user: user.to_string() },
+    ///     ChainError::profile_not_found(user),
+    /// );
+    ///
+    /// let wrapper = QuerierWrapper::<DesmosQuery>::new(&querier);
+    /// let error = ProfilesQuerier::new(wrapper.deref())
+    ///     .query_profile(Addr::unchecked(user))
+    ///     .unwrap_err();
+    /// assert!(error.is_not_found());
+    /// ```
+    pub fn with_query_error(mut self, query: impl Into<DesmosQuery>, error: ChainError) -> Self {
+        self.query_errors.set_query_error(query.into(), error);
+        self
+    }
+
+    /// Makes all the queries toward the given module fail with the provided error,
+    /// taking precedence over the module handler.
+    pub fn with_module_error(mut self, module: QueriedModule, error: ChainError) -> Self {
+        self.query_errors.set_module_error(module, error);
+        self
+    }
+
+    /// Enables the recording of the requests handled by the querier, so that the tests can
+    /// check which queries have been performed by the contract under test.
+    ///
+    /// # Example
+    /// ```
+    /// use cosmwasm_std::{Addr, QuerierWrapper};
+    /// use desmos_bindings::mocks::mock_queriers::{QueriedModule, MockDesmosQuerier};
+    /// use desmos_bindings::profiles::querier::ProfilesQuerier;
+    /// use desmos_bindings::profiles::query::ProfilesQuery;
+    /// use desmos_bindings::query::DesmosQuery;
+    /// use std::ops::Deref;
+    ///
+    /// let querier = MockDesmosQuerier::default().with_query_recording();
+    /// let wrapper = QuerierWrapper::<DesmosQuery>::new(&querier);
+    /// ProfilesQuerier::new(wrapper.deref())
+    ///     .query_profile(Addr::unchecked("user"))
+    ///     .unwrap();
+    ///
+    /// querier.assert_queried_times(ProfilesQuery::Profile { user: "user".into() }, 1);
+    /// querier.assert_module_never_queried(QueriedModule::Posts);
+    /// ```
+    pub fn with_query_recording(mut self) -> Self {
+        self.recorded_queries = Some(RefCell::new(vec![]));
+        self
+    }
+
+    /// Charges the gas of the queries performed toward the Desmos modules to the given meter.
+    ///
+    /// A query that exceeds the meter limit panics with an out of gas error, since the chain
+    /// aborts the contract execution instead of returning the error to the contract.
+    /// The [`DesmosKeeper`] behaves the same way when its meter limit is exceeded by a query.
+    pub fn with_gas_meter(mut self, gas_meter: GasMeter) -> Self {
+        self.gas_meter = Some(gas_meter);
+        self
+    }
+
+    /// Returns all the requests handled by the querier since the recording has been enabled
+    /// or cleared, in the order in which they have been performed.
+    ///
+    /// # Panics
+    /// Panics if the recording has not been enabled with [`Self::with_query_recording`].
+    pub fn recorded_queries(&self) -> Vec<QueryRequest<DesmosQuery>> {
+        self.recorded_queries
+            .as_ref()
+            .expect("query recording is not enabled, use with_query_recording")
+            .borrow()
+            .clone()
+    }
+
+    /// Returns the recorded queries performed toward the Desmos modules.
+    ///
+    /// # Panics
+    /// Panics if the recording has not been enabled with [`Self::with_query_recording`].
+    pub fn recorded_desmos_queries(&self) -> Vec<DesmosQuery> {
+        self.recorded_queries()
+            .into_iter()
+            .filter_map(|request| match request {
+                QueryRequest::Custom(query) => Some(query),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Forgets all the recorded queries.
+    ///
+    /// # Panics
+    /// Panics if the recording has not been enabled with [`Self::with_query_recording`].
+    pub fn clear_recorded_queries(&self) {
+        self.recorded_queries
+            .as_ref()
+            .expect("query recording is not enabled, use with_query_recording")
+            .borrow_mut()
+            .clear();
+    }
+
+    /// Returns how many times the given query has been performed with exactly the same arguments.
+    pub fn query_count(&self, query: impl Into<DesmosQuery>) -> usize {
+        let query = query.into();
+        self.recorded_desmos_queries()
+            .iter()
+            .filter(|recorded| **recorded == query)
+            .count()
+    }
+
+    /// Returns how many queries have been performed toward the given module.
+    pub fn module_query_count(&self, module: QueriedModule) -> usize {
+        self.recorded_desmos_queries()
+            .iter()
+            .filter(|recorded| QueriedModule::of(recorded) == module)
+            .count()
+    }
+
+    /// Asserts that the given query has been performed exactly `times` times with the same arguments.
+    #[track_caller]
+    pub fn assert_queried_times(&self, query: impl Into<DesmosQuery>, times: usize) {
+        let query = query.into();
+        let count = self.query_count(query.clone());
+        assert_eq!(
+            times, count,
+            "expected {:?} to be queried {} times, but it was queried {} times",
+            query, times, count
+        );
+    }
+
+    /// Asserts that the given query has been performed at least once with the same arguments.
+    #[track_caller]
+    pub fn assert_queried_with(&self, query: impl Into<DesmosQuery>) {
+        let query = query.into();
+        assert!(
+            self.query_count(query.clone()) > 0,
+            "expected {:?} to be queried, recorded queries: {:?}",
+            query,
+            self.recorded_desmos_queries()
+        );
+    }
+
+    /// Asserts that no query has been performed toward the given module.
+    #[track_caller]
+    pub fn assert_module_never_queried(&self, module: QueriedModule) {
+        let count = self.module_query_count(module);
+        assert_eq!(
+            0, count,
+            "expected the {:?} module to never be queried, but it was queried {} times",
+            module, count
+        );
+    }
 }
 
 impl Querier for MockDesmosQuerier {
@@ -361,6 +854,18 @@ pub fn mock_desmos_dependencies_with_custom_querier(
     }
 }
 
+/// Creates an instance of [`OwnedDeps`](cosmwasm_std::OwnedDeps) whose [`MockDesmosQuerier`]
+/// answers the queries toward the Desmos modules using the state of the given [`DesmosWorld`].
+///
+/// Returns an error if the world objects reference objects that do not exist.
+pub fn mock_desmos_dependencies_with_world(
+    world: &DesmosWorld,
+) -> AnyResult<OwnedDeps<MockStorage, MockApi, MockDesmosQuerier, DesmosQuery>> {
+    Ok(mock_desmos_dependencies_with_custom_querier(
+        MockDesmosQuerier::default().with_world(world)?,
+    ))
+}
+
 /// Creates an instance of [`OwnedDeps`](cosmwasm_std::OwnedDeps) that is capable of
 /// handling queries towards Desmos's modules.
 pub fn mock_desmos_dependencies() -> OwnedDeps<MockStorage, MockApi, MockDesmosQuerier, DesmosQuery>
@@ -373,20 +878,41 @@ pub fn mock_desmos_dependencies() -> OwnedDeps<MockStorage, MockApi, MockDesmosQ
     }
 }
 
+/// Querier that answers all the queries with the given error log, as returned by the chain.
+#[cfg(test)]
+pub(crate) struct ChainErrorQuerier(pub &'static str);
+
+#[cfg(test)]
+impl Querier for ChainErrorQuerier {
+    fn raw_query(&self, _: &[u8]) -> QuerierResult {
+        SystemResult::Ok(ContractResult::Err(self.0.to_string()))
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::mocks::mock_queriers::{mock_desmos_dependencies, MockDesmosQuerier};
+    use crate::errors::DesmosQueryError;
+    use crate::mocks::errors::ChainError;
+    use crate::mocks::gas::{GasConfig, GasMeter, GasReport};
+    use crate::mocks::mock_queriers::{
+        mock_desmos_dependencies, mock_desmos_dependencies_with_custom_querier, MockDesmosQuerier,
+        QueriedModule,
+    };
+    use crate::pagination::paginate;
     use crate::posts::mocks::MockPostsQueries;
     use crate::posts::models_query::QueryPostResponse;
     use crate::posts::querier::PostsQuerier;
     use crate::posts::query::PostsQuery;
     use crate::profiles::query::ProfilesQuery;
+    use crate::query::DesmosQuery;
     use crate::reactions::models::ReactionValue;
     use crate::reactions::models_query::QueryReactionResponse;
     use crate::reactions::query::ReactionsQuery;
+    use crate::relationships::models::Relationship;
     use crate::relationships::query::RelationshipsQuery;
     use crate::reports::query::ReportsQuery;
     use crate::subspaces::query::SubspacesQuery;
+    use crate::types::PageRequest;
     use crate::{
         profiles::{
             mocks::MockProfilesQueries, models_query::QueryProfileResponse,
@@ -404,7 +930,7 @@ mod tests {
             mocks::MockReportsQueries, models_query::QueryReportResponse, querier::ReportsQuerier,
         },
         subspaces::{
-            mocks::MockSubspacesQueries, models_query::QuerySubspaceResponse,
+            mocks::MockSubspacesQueries, models::Permission, models_query::QuerySubspaceResponse,
             querier::SubspacesQuerier,
         },
     };
@@ -610,4 +1136,620 @@ mod tests {
 
         assert_eq!(response.reaction, reaction);
     }
+
+    #[test]
+    fn test_mock_paginated_query() {
+        let relationships: Vec<Relationship> = (0..3)
+            .map(|i| Relationship {
+                counterparty: Addr::unchecked(format!("counterparty_{}", i)),
+                ..MockRelationshipsQueries::get_mock_relationship()
+            })
+            .collect();
+        let fixture = relationships.clone();
+        let querier = MockDesmosQuerier::default().with_custom_relationships_handler(
+            move |query| match query {
+                RelationshipsQuery::Relationships { pagination, .. } => {
+                    paginate(fixture.clone(), pagination.as_ref())
+                        .and_then(|(relationships, pagination)| {
+                            to_binary(&QueryRelationshipsResponse {
+                                relationships,
+                                pagination,
+                            })
+                        })
+                        .into()
+                }
+                _ => ContractResult::Err("not supported".to_string()),
+            },
+        );
+
+        let relationships_querier = RelationshipsQuerier::new(&querier);
+        let request = PageRequest {
+            key: None,
+            offset: None,
+            limit: Uint64::new(2),
+            count_total: false,
+            reverse: true,
+        };
+        let first_page = relationships_querier
+            .query_relationships(1, None, None, Some(request.clone()))
+            .unwrap();
+        assert_eq!(
+            vec![relationships[2].clone(), relationships[1].clone()],
+            first_page.relationships
+        );
+
+        let second_page = relationships_querier
+            .query_relationships(
+                1,
+                None,
+                None,
+                Some(PageRequest {
+                    key: first_page.pagination.unwrap().next_key,
+                    ..request
+                }),
+            )
+            .unwrap();
+        assert_eq!(vec![relationships[0].clone()], second_page.relationships);
+        assert_eq!(None, second_page.pagination.unwrap().next_key);
+    }
+
+    #[test]
+    fn test_query_recording() {
+        let owned_deps = mock_desmos_dependencies_with_custom_querier(
+            MockDesmosQuerier::default().with_query_recording(),
+        );
+        let deps = owned_deps.as_ref();
+        let profiles_querier = ProfilesQuerier::new(deps.querier.deref());
+        let posts_querier = PostsQuerier::new(deps.querier.deref());
+        let user = Addr::unchecked("desmos1nwp8gxrnmrsrzjdhvk47vvmthzxjtphgxp5ftc");
+
+        profiles_querier.query_profile(user.clone()).unwrap();
+        profiles_querier.query_profile(user.clone()).unwrap();
+        posts_querier.query_post(1, 1).unwrap();
+
+        let profile_query = ProfilesQuery::Profile {
+            user: user.to_string(),
+        };
+        let post_query = PostsQuery::Post {
+            subspace_id: Uint64::new(1),
+            post_id: Uint64::new(1),
+        };
+        let querier = &owned_deps.querier;
+        assert_eq!(3, querier.recorded_queries().len());
+        assert_eq!(
+            vec![
+                DesmosQuery::from(profile_query.clone()),
+                DesmosQuery::from(profile_query.clone()),
+                DesmosQuery::from(post_query.clone())
+            ],
+            querier.recorded_desmos_queries()
+        );
+        querier.assert_queried_times(profile_query.clone(), 2);
+        querier.assert_queried_with(post_query);
+        querier.assert_module_never_queried(QueriedModule::Reactions);
+        assert_eq!(2, querier.module_query_count(QueriedModule::Profiles));
+
+        querier.clear_recorded_queries();
+        querier.assert_queried_times(profile_query, 0);
+        querier.assert_module_never_queried(QueriedModule::Posts);
+    }
+
+    #[test]
+    #[should_panic(expected = "to be queried 1 times, but it was queried 2 times")]
+    fn test_query_recording_detects_redundant_queries() {
+        let querier = MockDesmosQuerier::default().with_query_recording();
+        let wrapper = cosmwasm_std::QuerierWrapper::<DesmosQuery>::new(&querier);
+        let profiles_querier = ProfilesQuerier::new(wrapper.deref());
+        let user = Addr::unchecked("desmos1nwp8gxrnmrsrzjdhvk47vvmthzxjtphgxp5ftc");
+
+        profiles_querier.query_profile(user.clone()).unwrap();
+        profiles_querier.query_profile(user.clone()).unwrap();
+
+        querier.assert_queried_times(
+            ProfilesQuery::Profile {
+                user: user.to_string(),
+            },
+            1,
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "query recording is not enabled")]
+    fn test_recorded_queries_without_recording_panics() {
+        MockDesmosQuerier::default().recorded_queries();
+    }
+
+    #[test]
+    fn test_fixtures_answer_by_arguments() {
+        let user = Addr::unchecked("desmos1nwp8gxrnmrsrzjdhvk47vvmthzxjtphgxp5ftc");
+        let other_user = Addr::unchecked("desmos1rfv0f7mx7w9d3jv3h803u38vqym9ygg344asm3");
+        let mut profile = MockProfilesQueries::get_mock_profile();
+        profile.account.address = user.clone();
+        profile.dtag = "Alice".to_string();
+        let mut subspace = MockSubspacesQueries::get_mock_subspace();
+        subspace.id = Uint64::new(2);
+        let first_post = MockPostsQueries::get_mocked_post(Uint64::new(1), Uint64::new(2));
+        let mut second_post = MockPostsQueries::get_mocked_post(Uint64::new(2), Uint64::new(2));
+        second_post.section_id = 1;
+        let relationship = Relationship {
+            creator: user.clone(),
+            counterparty: other_user.clone(),
+            subspace_id: Uint64::new(2),
+        };
+        let mut reaction = MockReactionsQueries::get_mock_reaction();
+        reaction.subspace_id = Uint64::new(2);
+        reaction.post_id = Uint64::new(1);
+        reaction.author = other_user.clone();
+
+        let owned_deps = mock_desmos_dependencies_with_custom_querier(
+            MockDesmosQuerier::default()
+                .with_profile(profile.clone())
+                .with_subspace(subspace.clone())
+                .with_post(first_post.clone())
+                .with_post(second_post.clone())
+                .with_relationship(relationship.clone())
+                .with_reaction(reaction.clone()),
+        );
+        let deps = owned_deps.as_ref();
+
+        let profiles_querier = ProfilesQuerier::new(deps.querier.deref());
+        assert_eq!(
+            profile,
+            profiles_querier
+                .query_profile(user.clone())
+                .unwrap()
+                .profile
+        );
+        assert_eq!(
+            profile,
+            profiles_querier
+                .query_profile(Addr::unchecked("alice"))
+                .unwrap()
+                .profile
+        );
+        let error = profiles_querier
+            .query_profile(other_user.clone())
+            .unwrap_err();
+        assert!(error.is_not_found());
+
+        let subspaces_querier = SubspacesQuerier::new(deps.querier.deref());
+        assert_eq!(
+            subspace,
+            subspaces_querier.query_subspace(2).unwrap().subspace
+        );
+        assert!(subspaces_querier.query_subspace(1).is_err());
+        assert_eq!(
+            vec![subspace],
+            subspaces_querier.query_subspaces(None).unwrap().subspaces
+        );
+
+        let posts_querier = PostsQuerier::new(deps.querier.deref());
+        assert_eq!(second_post, posts_querier.query_post(2, 2).unwrap().post);
+        assert!(posts_querier.query_post(2, 3).is_err());
+        assert_eq!(
+            vec![first_post, second_post.clone()],
+            posts_querier.query_subspace_posts(2, None).unwrap().posts
+        );
+        assert_eq!(
+            vec![second_post],
+            posts_querier.query_section_posts(2, 1, None).unwrap().posts
+        );
+
+        let relationships_querier = RelationshipsQuerier::new(deps.querier.deref());
+        assert_eq!(
+            vec![relationship],
+            relationships_querier
+                .query_relationships(2, Some(user.clone()), None, None)
+                .unwrap()
+                .relationships
+        );
+        assert!(relationships_querier
+            .query_relationships(2, Some(other_user.clone()), None, None)
+            .unwrap()
+            .relationships
+            .is_empty());
+
+        let reactions_querier = ReactionsQuerier::new(deps.querier.deref());
+        assert_eq!(
+            reaction,
+            reactions_querier
+                .query_reaction(2, 1, reaction.id)
+                .unwrap()
+                .reaction
+        );
+        assert!(reactions_querier
+            .query_reaction(2, 1, reaction.id + 1)
+            .is_err());
+        assert_eq!(
+            vec![reaction],
+            reactions_querier
+                .query_reactions(2, 1, Some(other_user), None)
+                .unwrap()
+                .reactions
+        );
+        assert!(reactions_querier
+            .query_reactions(2, 1, Some(user), None)
+            .unwrap()
+            .reactions
+            .is_empty());
+    }
+
+    #[test]
+    fn test_report_fixtures_answer_by_arguments() {
+        let reporter = Addr::unchecked("desmos1nwp8gxrnmrsrzjdhvk47vvmthzxjtphgxp5ftc");
+        let mut first_report = MockReportsQueries::get_mocked_report(&Uint64::new(2));
+        first_report.subspace_id = Uint64::new(2);
+        first_report.id = Uint64::new(1);
+        let mut second_report = first_report.clone();
+        second_report.id = Uint64::new(2);
+        second_report.reporter = reporter.clone();
+
+        let querier = MockDesmosQuerier::default()
+            .with_report(first_report.clone())
+            .with_report(second_report.clone());
+        let reports_querier = ReportsQuerier::new(&querier);
+
+        assert_eq!(
+            second_report,
+            reports_querier.query_report(2, 2).unwrap().report
+        );
+        assert!(reports_querier
+            .query_report(2, 3)
+            .unwrap_err()
+            .is_not_found());
+        assert!(reports_querier
+            .query_report(1, 1)
+            .unwrap_err()
+            .is_not_found());
+        assert_eq!(
+            vec![first_report, second_report.clone()],
+            reports_querier
+                .query_reports(2, None, None, None)
+                .unwrap()
+                .reports
+        );
+        assert_eq!(
+            vec![second_report],
+            reports_querier
+                .query_reports(2, None, Some(reporter), None)
+                .unwrap()
+                .reports
+        );
+    }
+
+    #[test]
+    fn test_subspace_content_fixtures_answer_by_arguments() {
+        let user = Addr::unchecked("desmos1nwp8gxrnmrsrzjdhvk47vvmthzxjtphgxp5ftc");
+        let other_user = Addr::unchecked("desmos1rfv0f7mx7w9d3jv3h803u38vqym9ygg344asm3");
+        let subspace = MockSubspacesQueries::get_mock_subspace();
+        let section = MockSubspacesQueries::get_mock_section();
+        let mut child_section = section.clone();
+        child_section.id = 2;
+        child_section.parent_id = Some(1);
+        let mut root_group = MockSubspacesQueries::get_mock_user_group();
+        root_group.permissions = vec![Permission::Write];
+        let mut section_group = root_group.clone();
+        section_group.id = 2;
+        section_group.section_id = Some(1);
+        section_group.permissions = vec![Permission::ModerateContent];
+
+        let querier = MockDesmosQuerier::default()
+            .with_subspace(subspace)
+            .with_section(section.clone())
+            .with_section(child_section.clone())
+            .with_user_group(root_group.clone())
+            .with_user_group(section_group.clone())
+            .with_user_group_member(1, 1, user.clone())
+            .with_user_group_member(1, 2, user.clone())
+            .with_user_group_member(1, 2, other_user.clone())
+            .with_user_permissions(1, 2, user.clone(), vec![Permission::EditOwnContent]);
+        let subspaces_querier = SubspacesQuerier::new(&querier);
+
+        let sections = subspaces_querier.query_sections(1, None).unwrap().sections;
+        assert_eq!(
+            vec![0, 1, 2],
+            sections
+                .iter()
+                .map(|section| section.id)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            child_section,
+            subspaces_querier.query_section(1, 2).unwrap().section
+        );
+        assert_eq!(
+            vec![section_group.clone()],
+            subspaces_querier
+                .query_user_groups(1, Some(1), None)
+                .unwrap()
+                .groups
+        );
+        assert_eq!(
+            root_group,
+            subspaces_querier.query_user_group(1, 1).unwrap().group
+        );
+        assert_eq!(
+            vec![user.clone(), other_user.clone()],
+            subspaces_querier
+                .query_user_group_members(1, 2, None)
+                .unwrap()
+                .members
+        );
+
+        let response = subspaces_querier
+            .query_user_permissions(1, Some(2), user)
+            .unwrap();
+        assert_eq!(
+            vec![
+                Permission::EditOwnContent,
+                Permission::ModerateContent,
+                Permission::Write
+            ],
+            response.permissions
+        );
+        assert_eq!(
+            vec![(2, None), (1, Some(2)), (0, Some(1))],
+            response
+                .details
+                .iter()
+                .map(|detail| (
+                    detail.section_id,
+                    detail.group.as_ref().map(|group| group.group_id)
+                ))
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![Permission::ModerateContent],
+            subspaces_querier
+                .query_user_permissions(1, Some(1), other_user)
+                .unwrap()
+                .permissions
+        );
+    }
+
+    #[test]
+    fn test_post_content_fixtures_answer_by_arguments() {
+        let user = Addr::unchecked("desmos1nwp8gxrnmrsrzjdhvk47vvmthzxjtphgxp5ftc");
+        let other_user = Addr::unchecked("desmos1rfv0f7mx7w9d3jv3h803u38vqym9ygg344asm3");
+        let post = MockPostsQueries::get_mocked_post(Uint64::new(1), Uint64::new(2));
+        let attachments =
+            MockPostsQueries::get_mocked_post_attachments(&Uint64::new(2), &Uint64::new(1));
+        let answer = MockPostsQueries::get_mocked_poll_answers(
+            &Uint64::new(2),
+            &Uint64::new(1),
+            &1,
+            &Some(user.clone()),
+        )
+        .remove(0);
+        let mut other_answer = answer.clone();
+        other_answer.user = other_user.clone();
+        let mut other_poll_answer = answer.clone();
+        other_poll_answer.poll_id = 0;
+
+        let querier = MockDesmosQuerier::default()
+            .with_post(post)
+            .with_attachment(attachments[1].clone())
+            .with_attachment(attachments[0].clone())
+            .with_poll_answer(answer.clone())
+            .with_poll_answer(other_answer.clone())
+            .with_poll_answer(other_poll_answer);
+        let posts_querier = PostsQuerier::new(&querier);
+
+        assert_eq!(
+            attachments,
+            posts_querier
+                .query_post_attachments(2, 1, None)
+                .unwrap()
+                .attachments
+        );
+        assert_eq!(
+            vec![answer, other_answer.clone()],
+            posts_querier
+                .query_poll_answers(2, 1, 1, None, None)
+                .unwrap()
+                .answers
+        );
+        assert_eq!(
+            vec![other_answer],
+            posts_querier
+                .query_poll_answers(2, 1, 1, Some(other_user), None)
+                .unwrap()
+                .answers
+        );
+    }
+
+    #[test]
+    fn test_fixtures_miss_returns_not_found() {
+        let mut subspace = MockSubspacesQueries::get_mock_subspace();
+        subspace.id = Uint64::new(2);
+        let post = MockPostsQueries::get_mocked_post(Uint64::new(1), Uint64::new(2));
+        let querier = MockDesmosQuerier::default()
+            .with_subspace(subspace)
+            .with_post(post);
+
+        let profiles_querier = ProfilesQuerier::new(&querier);
+        assert!(profiles_querier
+            .query_profile(Addr::unchecked("goldrake"))
+            .unwrap_err()
+            .is_not_found());
+
+        let subspaces_querier = SubspacesQuerier::new(&querier);
+        assert_eq!(0, subspaces_querier.query_section(2, 0).unwrap().section.id);
+        assert!(subspaces_querier
+            .query_section(2, 1)
+            .unwrap_err()
+            .is_not_found());
+        assert!(subspaces_querier
+            .query_section(1, 0)
+            .unwrap_err()
+            .is_not_found());
+        assert!(subspaces_querier
+            .query_user_groups(2, None, None)
+            .unwrap()
+            .groups
+            .is_empty());
+        assert!(subspaces_querier
+            .query_user_groups(1, None, None)
+            .unwrap_err()
+            .is_not_found());
+        assert!(subspaces_querier
+            .query_user_group(2, 1)
+            .unwrap_err()
+            .is_not_found());
+        assert!(subspaces_querier
+            .query_user_group_members(2, 1, None)
+            .unwrap_err()
+            .is_not_found());
+        assert!(subspaces_querier
+            .query_user_permissions(2, Some(1), Addr::unchecked("user"))
+            .unwrap_err()
+            .is_not_found());
+
+        let posts_querier = PostsQuerier::new(&querier);
+        assert!(posts_querier
+            .query_post_attachments(2, 1, None)
+            .unwrap()
+            .attachments
+            .is_empty());
+        assert!(posts_querier
+            .query_poll_answers(2, 1, 1, None, None)
+            .unwrap()
+            .answers
+            .is_empty());
+        assert!(posts_querier
+            .query_post_attachments(2, 2, None)
+            .unwrap_err()
+            .is_not_found());
+        assert!(posts_querier
+            .query_poll_answers(2, 2, 1, None, None)
+            .unwrap_err()
+            .is_not_found());
+    }
+
+    #[test]
+    fn test_custom_handler_overrides_fixtures() {
+        let user = Addr::unchecked("desmos1nwp8gxrnmrsrzjdhvk47vvmthzxjtphgxp5ftc");
+        let mut profile = MockProfilesQueries::get_mock_profile();
+        profile.account.address = user.clone();
+        let querier = MockDesmosQuerier::default()
+            .with_profile(profile)
+            .with_custom_profiles_handler(|_| ContractResult::Err("custom".to_string()));
+        let owned_deps = mock_desmos_dependencies_with_custom_querier(querier);
+        let deps = owned_deps.as_ref();
+
+        let error = ProfilesQuerier::new(deps.querier.deref())
+            .query_profile(user)
+            .unwrap_err();
+        assert!(error.to_string().contains("custom"));
+    }
+
+    #[test]
+    fn test_injected_query_errors() {
+        let user = Addr::unchecked("desmos1nwp8gxrnmrsrzjdhvk47vvmthzxjtphgxp5ftc");
+        let querier = MockDesmosQuerier::default()
+            .with_query_error(
+                ProfilesQuery::Profile {
+                    user: user.to_string(),
+                },
+                ChainError::profile_not_found(user.as_str()),
+            )
+            .with_module_error(
+                QueriedModule::Posts,
+                ChainError::invalid_request("invalid post id"),
+            );
+        let owned_deps = mock_desmos_dependencies_with_custom_querier(querier);
+        let deps = owned_deps.as_ref();
+
+        let profiles_querier = ProfilesQuerier::new(deps.querier.deref());
+        let error = profiles_querier.query_profile(user.clone()).unwrap_err();
+        assert!(error
+            .to_string()
+            .contains(&ChainError::profile_not_found(user.as_str()).redacted()));
+        assert!(profiles_querier
+            .query_profile(Addr::unchecked(
+                "desmos1rfv0f7mx7w9d3jv3h803u38vqym9ygg344asm3"
+            ))
+            .is_ok());
+
+        let error = PostsQuerier::new(deps.querier.deref())
+            .query_post(1, 1)
+            .unwrap_err();
+        assert!(error
+            .to_string()
+            .contains(&ChainError::invalid_request("invalid post id").redacted()));
+    }
+
+    #[test]
+    fn test_gas_metering() {
+        let gas_meter = GasMeter::new(GasConfig {
+            query_cost: 1_000,
+            query_byte_cost: 3,
+            message_cost: 0,
+        });
+        let querier = MockDesmosQuerier::default().with_gas_meter(gas_meter.clone());
+        let owned_deps = mock_desmos_dependencies_with_custom_querier(querier);
+        let deps = owned_deps.as_ref();
+        let profiles_querier = ProfilesQuerier::new(deps.querier.deref());
+
+        let (response, report) =
+            gas_meter.measure(|| profiles_querier.query_profile(Addr::unchecked("user")));
+        let response_size = to_binary(&response.unwrap()).unwrap().len() as u64;
+        assert_eq!(
+            GasReport {
+                queries: 1,
+                query_bytes: response_size,
+                messages: 0,
+                gas_used: 1_000 + 3 * response_size,
+            },
+            report
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "out of gas in location: desmos query")]
+    fn test_gas_limit() {
+        let gas_meter = GasMeter::new(GasConfig::default()).with_limit(1_000);
+        let querier = MockDesmosQuerier::default().with_gas_meter(gas_meter);
+        let owned_deps = mock_desmos_dependencies_with_custom_querier(querier);
+        let deps = owned_deps.as_ref();
+
+        let _ = ProfilesQuerier::new(deps.querier.deref()).query_profile(Addr::unchecked("user"));
+    }
+
+    #[test]
+    fn test_typed_query_errors() {
+        let querier = MockDesmosQuerier::default()
+            .with_query_error(
+                PostsQuery::Post {
+                    subspace_id: Uint64::new(1),
+                    post_id: Uint64::new(1),
+                },
+                ChainError::post_not_found(1, 1),
+            )
+            .with_module_error(
+                QueriedModule::Reports,
+                ChainError::invalid_request("invalid subspace id"),
+            )
+            .with_custom_profiles_handler(|_| ContractResult::Err("unsupported".to_string()));
+        let owned_deps = mock_desmos_dependencies_with_custom_querier(querier);
+        let deps = owned_deps.as_ref();
+
+        let error = PostsQuerier::new(deps.querier.deref())
+            .query_post(1, 1)
+            .unwrap_err();
+        assert!(error.is_not_found());
+
+        let error = ReportsQuerier::new(deps.querier.deref())
+            .query_report(1, 1)
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            DesmosQueryError::InvalidRequest { code: 18, .. }
+        ));
+
+        let error = ProfilesQuerier::new(deps.querier.deref())
+            .query_profile(Addr::unchecked("user"))
+            .unwrap_err();
+        assert!(matches!(error, DesmosQueryError::System(_)));
+    }
 }
@@ -1,5 +1,9 @@
 //! The test utils to mock the quriers and desmos app
 
+pub mod errors;
+pub mod fixtures;
+pub mod gas;
 pub mod mock_apps;
-mod mock_keeper;
+pub mod mock_keeper;
 pub mod mock_queriers;
+pub mod world;
@@ -0,0 +1,238 @@
+//! Contains the [`DesmosWorld`], a single data set that can back both the
+//! [`MockDesmosQuerier`](crate::mocks::mock_queriers::MockDesmosQuerier) used in the unit tests
+//! and the [`DesmosKeeper`](crate::mocks::mock_keeper::DesmosKeeper) used in the integration tests.
+
+use crate::mocks::mock_keeper::DesmosGenesis;
+#[cfg(feature = "posts")]
+use crate::posts::models::Post;
+#[cfg(feature = "profiles")]
+use crate::profiles::models_profile::Profile;
+#[cfg(feature = "reactions")]
+use crate::reactions::models::Reaction;
+#[cfg(feature = "relationships")]
+use crate::relationships::models::{Relationship, UserBlock};
+#[cfg(feature = "reports")]
+use crate::reports::models::Report;
+#[cfg(feature = "subspaces")]
+use crate::subspaces::models::{Section, Subspace, UserGroup};
+use anyhow::Result as AnyResult;
+
+/// Data set describing the state of the Desmos modules.
+///
+/// The same world can be used to seed a [`DesmosApp`](crate::mocks::mock_apps::DesmosApp)
+/// with [`mock_desmos_app_with_world`](crate::mocks::mock_apps::mock_desmos_app_with_world)
+/// and to answer the queries of a [`MockDesmosQuerier`](crate::mocks::mock_queriers::MockDesmosQuerier)
+/// with [`mock_desmos_dependencies_with_world`](crate::mocks::mock_queriers::mock_desmos_dependencies_with_world),
+/// so that the unit and integration tests of a contract observe exactly the same responses.
+///
+/// As on chain, the objects must reference existing ones (e.g. a post must be created inside an
+/// existing subspace and section), otherwise the world can't be loaded.
+///
+/// # Example
+/// ```
+/// use cosmwasm_std::Addr;
+/// use desmos_bindings::mocks::mock_apps::mock_desmos_app_with_world;
+/// use desmos_bindings::mocks::mock_queriers::mock_desmos_dependencies_with_world;
+/// use desmos_bindings::mocks::world::DesmosWorld;
+/// use desmos_bindings::subspaces::mocks::MockSubspacesQueries;
+/// use desmos_bindings::subspaces::querier::SubspacesQuerier;
+/// use std::ops::Deref;
+///
+/// let world = DesmosWorld::new().with_subspace(MockSubspacesQueries::get_mock_subspace());
+///
+/// let deps = mock_desmos_dependencies_with_world(&world).unwrap();
+/// let unit_response = SubspacesQuerier::new(deps.as_ref().querier.deref())
+///     .query_subspace(1)
+///     .unwrap();
+///
+/// let app = mock_desmos_app_with_world(&world).unwrap();
+/// let wrapper = app.wrap();
+/// let integration_response = SubspacesQuerier::new(wrapper.deref())
+///     .query_subspace(1)
+///     .unwrap();
+///
+/// assert_eq!(unit_response, integration_response);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DesmosWorld {
+    genesis: DesmosGenesis,
+}
+
+impl DesmosWorld {
+    /// Returns a new empty [`DesmosWorld`].
+    pub fn new() -> Self {
+        DesmosWorld::default()
+    }
+
+    /// Returns a [`DesmosWorld`] containing the state described by the given [`DesmosGenesis`].
+    pub fn from_genesis(genesis: DesmosGenesis) -> Self {
+        DesmosWorld { genesis }
+    }
+
+    /// Parses a [`DesmosWorld`] from the given genesis-like JSON document.
+    pub fn from_json(json: &str) -> AnyResult<Self> {
+        Ok(DesmosWorld::from_genesis(DesmosGenesis::from_json(json)?))
+    }
+
+    /// Returns the [`DesmosGenesis`] describing the world state.
+    pub fn genesis(&self) -> &DesmosGenesis {
+        &self.genesis
+    }
+
+    /// Adds the given profile to the world.
+    #[cfg(feature = "profiles")]
+    pub fn with_profile(mut self, profile: Profile) -> Self {
+        self.genesis.profiles.profiles.push(profile);
+        self
+    }
+
+    /// Adds the given subspace to the world, along with its root section and default user group.
+    #[cfg(feature = "subspaces")]
+    pub fn with_subspace(mut self, subspace: Subspace) -> Self {
+        self.genesis.subspaces.subspaces.push(subspace);
+        self
+    }
+
+    /// Adds the given section to the world.
+    #[cfg(feature = "subspaces")]
+    pub fn with_section(mut self, section: Section) -> Self {
+        self.genesis.subspaces.sections.push(section);
+        self
+    }
+
+    /// Adds the given user group to the world.
+    #[cfg(feature = "subspaces")]
+    pub fn with_user_group(mut self, group: UserGroup) -> Self {
+        self.genesis.subspaces.user_groups.push(group);
+        self
+    }
+
+    /// Adds the given post to the world.
+    #[cfg(feature = "posts")]
+    pub fn with_post(mut self, post: Post) -> Self {
+        self.genesis.posts.posts.push(post);
+        self
+    }
+
+    /// Adds the given relationship to the world.
+    #[cfg(feature = "relationships")]
+    pub fn with_relationship(mut self, relationship: Relationship) -> Self {
+        self.genesis.relationships.relationships.push(relationship);
+        self
+    }
+
+    /// Adds the given user block to the world.
+    #[cfg(feature = "relationships")]
+    pub fn with_user_block(mut self, block: UserBlock) -> Self {
+        self.genesis.relationships.blocks.push(block);
+        self
+    }
+
+    /// Adds the given reaction to the world.
+    #[cfg(feature = "reactions")]
+    pub fn with_reaction(mut self, reaction: Reaction) -> Self {
+        self.genesis.reactions.reactions.push(reaction);
+        self
+    }
+
+    /// Adds the given report to the world.
+    #[cfg(feature = "reports")]
+    pub fn with_report(mut self, report: Report) -> Self {
+        self.genesis.reports.reports.push(report);
+        self
+    }
+}
+
+impl From<DesmosGenesis> for DesmosWorld {
+    fn from(genesis: DesmosGenesis) -> Self {
+        DesmosWorld::from_genesis(genesis)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DesmosWorld;
+    use crate::mocks::errors::ChainError;
+    use crate::mocks::mock_apps::mock_desmos_app_with_world;
+    use crate::mocks::mock_queriers::mock_desmos_dependencies_with_world;
+    use crate::posts::mocks::MockPostsQueries;
+    use crate::posts::querier::PostsQuerier;
+    use crate::profiles::mocks::MockProfilesQueries;
+    use crate::profiles::querier::ProfilesQuerier;
+    use crate::relationships::models::Relationship;
+    use crate::relationships::querier::RelationshipsQuerier;
+    use crate::subspaces::mocks::MockSubspacesQueries;
+    use crate::subspaces::querier::SubspacesQuerier;
+    use cosmwasm_std::{Addr, Querier, Uint64};
+    use std::ops::Deref;
+
+    fn test_world() -> DesmosWorld {
+        let mut profile = MockProfilesQueries::get_mock_profile();
+        profile.account.address = Addr::unchecked("desmos1nwp8gxrnmrsrzjdhvk47vvmthzxjtphgxp5ftc");
+        let mut post = MockPostsQueries::get_mocked_post(Uint64::new(1), Uint64::new(1));
+        post.section_id = 0;
+        DesmosWorld::new()
+            .with_profile(profile)
+            .with_subspace(MockSubspacesQueries::get_mock_subspace())
+            .with_post(post)
+            .with_relationship(Relationship {
+                creator: Addr::unchecked("desmos1nwp8gxrnmrsrzjdhvk47vvmthzxjtphgxp5ftc"),
+                counterparty: Addr::unchecked("desmos1rfv0f7mx7w9d3jv3h803u38vqym9ygg344asm3"),
+                subspace_id: Uint64::new(1),
+            })
+    }
+
+    #[test]
+    fn world_drives_the_querier_and_the_app_the_same_way() {
+        let world = test_world();
+        let deps = mock_desmos_dependencies_with_world(&world).unwrap();
+        let app = mock_desmos_app_with_world(&world).unwrap();
+        let app_wrapper = app.wrap();
+        let queriers: Vec<&dyn Querier> = vec![&deps.querier, app_wrapper.deref()];
+        let user = Addr::unchecked("desmos1nwp8gxrnmrsrzjdhvk47vvmthzxjtphgxp5ftc");
+
+        let responses: Vec<_> = queriers
+            .into_iter()
+            .map(|querier| {
+                (
+                    ProfilesQuerier::new(querier).query_profile(user.clone()),
+                    SubspacesQuerier::new(querier).query_subspaces(None),
+                    PostsQuerier::new(querier).query_subspace_posts(1, None),
+                    RelationshipsQuerier::new(querier).query_relationships(
+                        1,
+                        Some(user.clone()),
+                        None,
+                        None,
+                    ),
+                    PostsQuerier::new(querier)
+                        .query_post(1, 2)
+                        .map_err(|error| error.to_string()),
+                )
+            })
+            .collect();
+
+        let (profile, subspaces, posts, relationships, missing_post) = &responses[0];
+        assert_eq!(
+            world.genesis().profiles.profiles[0],
+            profile.as_ref().unwrap().profile
+        );
+        assert_eq!(1, subspaces.as_ref().unwrap().subspaces.len());
+        assert_eq!(1, posts.as_ref().unwrap().posts.len());
+        assert_eq!(1, relationships.as_ref().unwrap().relationships.len());
+        assert!(missing_post
+            .as_ref()
+            .unwrap_err()
+            .contains(&ChainError::post_not_found(1, 2).redacted()));
+        assert_eq!(responses[0], responses[1]);
+    }
+
+    #[test]
+    fn invalid_world_is_rejected() {
+        let world = DesmosWorld::new().with_post(MockPostsQueries::get_mocked_post(
+            Uint64::new(1),
+            Uint64::new(1),
+        ));
+        assert!(mock_desmos_dependencies_with_world(&world).is_err());
+        assert!(mock_desmos_app_with_world(&world).is_err());
+    }
+}
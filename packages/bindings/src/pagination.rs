@@ -0,0 +1,180 @@
+//! Contains the utilities used by the mocks to paginate their data like the chain does.
+
+use crate::types::{PageRequest, PageResponse};
+use cosmwasm_std::{Binary, StdError, StdResult};
+use std::convert::TryInto;
+
+/// Number of items returned by the chain when a [`PageRequest`] does not specify a limit.
+pub(crate) const DEFAULT_PAGE_LIMIT: u64 = 100;
+
+/// Returns the page of `items` selected by the given [`PageRequest`] along with the
+/// [`PageResponse`] that should be returned with it, following the Cosmos SDK semantics:
+/// * `key` and `offset` can not be used together;
+/// * a `limit` of zero means [`DEFAULT_PAGE_LIMIT`] items and implies `count_total`;
+/// * `total` is only computed when the page is selected with an `offset`;
+/// * `reverse` returns the items in descending order.
+///
+/// The `next_key` values are opaque and can only be used to fetch the next page of the same items.
+/// When no [`PageRequest`] is provided all the items are returned without a [`PageResponse`].
+pub(crate) fn paginate<T>(
+    mut items: Vec<T>,
+    pagination: Option<&PageRequest>,
+) -> StdResult<(Vec<T>, Option<PageResponse>)> {
+    let pagination = match pagination {
+        Some(pagination) => pagination,
+        None => return Ok((items, None)),
+    };
+    if pagination.key.is_some() && pagination.offset.is_some() {
+        return Err(StdError::generic_err(
+            "invalid request, either offset or key is expected, got both",
+        ));
+    }
+
+    let (limit, count_total) = match pagination.limit.u64() {
+        0 => (DEFAULT_PAGE_LIMIT, true),
+        limit => (limit, pagination.count_total),
+    };
+    let start = match (&pagination.key, &pagination.offset) {
+        (Some(key), _) => decode_page_key(key)?,
+        (None, Some(offset)) => offset.u64(),
+        (None, None) => 0,
+    };
+    let total = if pagination.key.is_none() && count_total {
+        Some((items.len() as u64).into())
+    } else {
+        None
+    };
+
+    if pagination.reverse {
+        items.reverse();
+    }
+    let end = start.saturating_add(limit);
+    let next_key = if end < items.len() as u64 {
+        Some(Binary::from(end.to_be_bytes().to_vec()))
+    } else {
+        None
+    };
+    let page = items
+        .into_iter()
+        .skip(start as usize)
+        .take(limit as usize)
+        .collect();
+
+    Ok((page, Some(PageResponse { next_key, total })))
+}
+
+/// Decodes a key previously returned as `next_key` by [`paginate`].
+fn decode_page_key(key: &Binary) -> StdResult<u64> {
+    let bytes: [u8; 8] = key
+        .as_slice()
+        .try_into()
+        .map_err(|_| StdError::generic_err("invalid pagination key"))?;
+    Ok(u64::from_be_bytes(bytes))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{paginate, DEFAULT_PAGE_LIMIT};
+    use crate::types::{PageRequest, PageResponse};
+    use cosmwasm_std::{Binary, Uint64};
+
+    fn page_request(limit: u64) -> PageRequest {
+        PageRequest {
+            key: None,
+            offset: None,
+            limit: limit.into(),
+            count_total: false,
+            reverse: false,
+        }
+    }
+
+    #[test]
+    fn paginate_without_request_returns_all_the_items() {
+        let (items, response) = paginate(vec![1, 2, 3], None).unwrap();
+        assert_eq!(vec![1, 2, 3], items);
+        assert_eq!(None, response);
+    }
+
+    #[test]
+    fn paginate_with_key_walks_all_the_pages() {
+        let items: Vec<u32> = (0..5).collect();
+        let mut request = page_request(2);
+        let mut pages = vec![];
+        loop {
+            let (page, response) = paginate(items.clone(), Some(&request)).unwrap();
+            pages.push(page);
+            let response = response.unwrap();
+            assert_eq!(None, response.total);
+            match response.next_key {
+                Some(key) => request.key = Some(key),
+                None => break,
+            }
+        }
+        assert_eq!(vec![vec![0, 1], vec![2, 3], vec![4]], pages);
+    }
+
+    #[test]
+    fn paginate_with_offset_counts_the_total() {
+        let request = PageRequest {
+            offset: Some(Uint64::new(1)),
+            count_total: true,
+            ..page_request(2)
+        };
+        let (page, response) = paginate(vec![1, 2, 3, 4], Some(&request)).unwrap();
+        assert_eq!(vec![2, 3], page);
+        assert_eq!(Some(Uint64::new(4)), response.unwrap().total);
+    }
+
+    #[test]
+    fn paginate_with_reverse_returns_descending_items() {
+        let request = PageRequest {
+            reverse: true,
+            ..page_request(2)
+        };
+        let (page, response) = paginate(vec![1, 2, 3], Some(&request)).unwrap();
+        assert_eq!(vec![3, 2], page);
+
+        let request = PageRequest {
+            key: response.unwrap().next_key,
+            ..request
+        };
+        let (page, response) = paginate(vec![1, 2, 3], Some(&request)).unwrap();
+        assert_eq!(vec![1], page);
+        assert_eq!(
+            Some(PageResponse {
+                next_key: None,
+                total: None
+            }),
+            response
+        );
+    }
+
+    #[test]
+    fn paginate_with_zero_limit_uses_the_default_one() {
+        let items: Vec<u64> = (0..DEFAULT_PAGE_LIMIT + 1).collect();
+        let (page, response) = paginate(items, Some(&page_request(0))).unwrap();
+        let response = response.unwrap();
+        assert_eq!(DEFAULT_PAGE_LIMIT as usize, page.len());
+        assert!(response.next_key.is_some());
+        assert_eq!(Some(Uint64::new(DEFAULT_PAGE_LIMIT + 1)), response.total);
+    }
+
+    #[test]
+    fn paginate_with_key_and_offset_fails() {
+        let request = PageRequest {
+            key: Some(Binary::from(1u64.to_be_bytes().to_vec())),
+            offset: Some(Uint64::new(1)),
+            ..page_request(1)
+        };
+        assert!(paginate(vec![1, 2], Some(&request)).is_err());
+    }
+
+    #[test]
+    fn paginate_with_invalid_key_fails() {
+        let request = PageRequest {
+            key: Some(Binary::from(vec![1])),
+            ..page_request(1)
+        };
+        assert!(paginate(vec![1, 2], Some(&request)).is_err());
+    }
+}
@@ -0,0 +1,395 @@
+//! Contains the [`Arbitrary`](proptest::arbitrary::Arbitrary) implementations of the
+//! x/posts module models, query responses and messages, along with the strategies used to
+//! build them.
+
+#[cfg(feature = "query")]
+use crate::arbitrary::page;
+use crate::arbitrary::{addr, id, impl_arbitrary, rfc3339_time, small_id, text, uri};
+use crate::posts::models::{
+    AnswerResult, Attachment, Entities, PollTallyResults, Post, PostAttachment, PostReference,
+    PostReferenceType, ProvidedAnswer, RawPostAttachment, ReplySetting, TextTagEntity, UrlEntity,
+    UserAnswer,
+};
+#[cfg(feature = "query")]
+use crate::posts::models_query::{
+    QueryPollAnswersResponse, QueryPostAttachmentsResponse, QueryPostResponse,
+    QuerySectionPostsResponse, QuerySubspacePostsResponse,
+};
+#[cfg(feature = "msg")]
+use crate::posts::msg::PostsMsg;
+use cosmwasm_std::Uint64;
+use proptest::collection::{btree_set, vec};
+use proptest::option;
+use proptest::prelude::*;
+
+/// Maximum length of the generated post texts.
+pub const MAX_TEXT_LENGTH: usize = 500;
+/// Maximum number of entities generated for a single text.
+const MAX_ENTITIES: usize = 5;
+
+/// Returns a strategy generating hashtags and mentions tags.
+pub fn tag() -> impl Strategy<Value = String> {
+    "[a-zA-Z0-9_]{1,20}"
+}
+
+/// Returns a strategy generating [`Entities`] that don't overlap each other and that
+/// lie inside a text of the given length.
+pub fn entities(text_length: usize) -> BoxedStrategy<Entities> {
+    let max_bounds = (2 * MAX_ENTITIES).min(text_length + 1);
+    btree_set(0..=text_length as u64, 0..=max_bounds)
+        .prop_flat_map(|bounds| {
+            let bounds: Vec<u64> = bounds.into_iter().collect();
+            // Each entity goes from a bound to the one preceding the next bound
+            let ranges: Vec<(u64, u64)> = bounds
+                .chunks_exact(2)
+                .map(|pair| (pair[0], pair[1] - 1))
+                .collect();
+            vec((0..3u8, tag(), uri()), ranges.len()).prop_map(move |kinds| {
+                let mut entities = Entities {
+                    hashtags: vec![],
+                    mentions: vec![],
+                    urls: vec![],
+                };
+                for (&(start, end), (kind, tag, url)) in ranges.iter().zip(kinds) {
+                    let (start, end) = (Uint64::new(start), Uint64::new(end));
+                    match kind {
+                        0 => entities.hashtags.push(TextTagEntity { start, end, tag }),
+                        1 => entities.mentions.push(TextTagEntity { start, end, tag }),
+                        _ => entities.urls.push(UrlEntity {
+                            start,
+                            end,
+                            display_url: url.trim_start_matches("https://").to_string(),
+                            url,
+                        }),
+                    }
+                }
+                entities
+            })
+        })
+        .boxed()
+}
+
+/// Returns a strategy generating a post text along with the entities that lie inside it.
+pub fn text_with_entities() -> impl Strategy<Value = (String, Option<Entities>)> {
+    text(MAX_TEXT_LENGTH).prop_flat_map(|text| {
+        let entities = option::of(entities(text.len()));
+        (Just(text), entities)
+    })
+}
+
+/// Returns a strategy generating an optional post text along with the entities that lie inside it.
+/// The entities are only generated when the text is present.
+pub fn optional_text_with_entities() -> impl Strategy<Value = (Option<String>, Option<Entities>)> {
+    prop_oneof![
+        Just((None, None)),
+        text_with_entities().prop_map(|(text, entities)| (Some(text), entities)),
+    ]
+}
+
+/// Returns a strategy generating the indexes of the answers given to a poll,
+/// that are sorted and without duplicates.
+pub fn answers_indexes() -> impl Strategy<Value = Vec<u32>> {
+    btree_set(0..10u32, 1..=5).prop_map(|indexes| indexes.into_iter().collect())
+}
+
+/// Returns a strategy generating polls with unique provided answers.
+/// The final tally results are only generated when `ended` is true, since the chain
+/// rejects the new polls that already have them.
+fn poll(ended: bool) -> impl Strategy<Value = PostAttachment> {
+    (
+        text(200),
+        btree_set(text(100), 2..=5),
+        rfc3339_time(),
+        any::<bool>(),
+        any::<bool>(),
+    )
+        .prop_flat_map(
+            move |(question, answers, end_date, allows_multiple_answers, allows_answer_edits)| {
+                let provided_answers: Vec<ProvidedAnswer> = answers
+                    .into_iter()
+                    .map(|text| ProvidedAnswer {
+                        text: Some(text),
+                        attachments: vec![],
+                    })
+                    .collect();
+                // The tally results can only refer to the provided answers
+                let results = vec(
+                    (0..provided_answers.len() as u32, any::<u64>()).prop_map(
+                        |(answer_index, votes)| AnswerResult {
+                            answer_index,
+                            votes: Uint64::new(votes),
+                        },
+                    ),
+                    0..=provided_answers.len(),
+                )
+                .prop_map(|results| PollTallyResults { results });
+                let final_tally_results = if ended {
+                    option::of(results).boxed()
+                } else {
+                    Just(None).boxed()
+                };
+                final_tally_results.prop_map(move |final_tally_results| PostAttachment::Poll {
+                    question: question.clone(),
+                    provided_answers: provided_answers.clone(),
+                    end_date: end_date.clone(),
+                    allows_multiple_answers,
+                    allows_answer_edits,
+                    final_tally_results,
+                })
+            },
+        )
+}
+
+/// Returns a strategy generating media attachments.
+fn media() -> impl Strategy<Value = PostAttachment> {
+    ("(image|video|audio)/[a-z]{3,5}", uri())
+        .prop_map(|(mime_type, uri)| PostAttachment::Media { mime_type, uri })
+}
+
+/// Returns a strategy generating the attachments that can be added to a post.
+#[cfg(feature = "msg")]
+fn new_attachment() -> impl Strategy<Value = RawPostAttachment> {
+    prop_oneof![media(), poll(false)].prop_map(RawPostAttachment::from)
+}
+
+impl_arbitrary! {
+    // The chain does not accept unspecified reply settings.
+    ReplySetting => prop_oneof![
+        Just(ReplySetting::Everyone),
+        Just(ReplySetting::Followers),
+        Just(ReplySetting::Mutual),
+        Just(ReplySetting::Mentions),
+    ];
+    // The chain does not accept unspecified references.
+    PostReferenceType => prop_oneof![
+        Just(PostReferenceType::Replay),
+        Just(PostReferenceType::Quote),
+        Just(PostReferenceType::Repost),
+    ];
+    // Only the quotes have a position inside the post text.
+    PostReference => (any::<PostReferenceType>(), id(), any::<u64>()).prop_map(
+        |(ref_type, post_id, position)| PostReference {
+            position: match ref_type {
+                PostReferenceType::Quote => Some(Uint64::new(position)),
+                _ => None,
+            },
+            ref_type,
+            post_id,
+        },
+    );
+    TextTagEntity => (0..=MAX_TEXT_LENGTH as u64, 0..=MAX_TEXT_LENGTH as u64, tag()).prop_map(
+        |(start, length, tag)| TextTagEntity {
+            start: Uint64::new(start),
+            end: Uint64::new(start + length),
+            tag,
+        },
+    );
+    UrlEntity => (0..=MAX_TEXT_LENGTH as u64, 0..=MAX_TEXT_LENGTH as u64, uri()).prop_map(
+        |(start, length, url)| UrlEntity {
+            start: Uint64::new(start),
+            end: Uint64::new(start + length),
+            display_url: url.trim_start_matches("https://").to_string(),
+            url,
+        },
+    );
+    Entities => entities(MAX_TEXT_LENGTH);
+    Post => (
+        (id(), id(), any::<u32>(), option::of(text(100))),
+        optional_text_with_entities(),
+        (vec(tag(), 0..5), addr(), option::of(id()), vec(any::<PostReference>(), 0..3)),
+        (any::<ReplySetting>(), rfc3339_time(), option::of(rfc3339_time())),
+    )
+        .prop_map(
+            |(
+                (id, subspace_id, section_id, eternal_id),
+                (text, entities),
+                (tags, author, conversation_id, referenced_posts),
+                (reply_settings, creation_date, last_edit_date),
+            )| Post {
+                id,
+                subspace_id,
+                section_id,
+                eternal_id,
+                text,
+                entities,
+                tags,
+                author,
+                conversation_id,
+                referenced_posts,
+                reply_settings,
+                creation_date,
+                last_edit_date,
+            },
+        );
+    AnswerResult => (any::<u32>(), any::<u64>()).prop_map(|(answer_index, votes)| AnswerResult {
+        answer_index,
+        votes: Uint64::new(votes),
+    });
+    PollTallyResults => vec(any::<AnswerResult>(), 0..5)
+        .prop_map(|results| PollTallyResults { results });
+    ProvidedAnswer => text(100).prop_map(|text| ProvidedAnswer {
+        text: Some(text),
+        attachments: vec![],
+    });
+    PostAttachment => prop_oneof![media(), poll(true)];
+    RawPostAttachment => any::<PostAttachment>().prop_map(RawPostAttachment::from);
+    Attachment => (id(), id(), small_id(), any::<RawPostAttachment>()).prop_map(
+        |(subspace_id, post_id, id, content)| Attachment {
+            subspace_id,
+            post_id,
+            id,
+            content,
+        },
+    );
+    UserAnswer => (id(), id(), small_id(), answers_indexes(), addr()).prop_map(
+        |(subspace_id, post_id, poll_id, answers_indexes, user)| UserAnswer {
+            subspace_id,
+            post_id,
+            poll_id,
+            answers_indexes,
+            user,
+        },
+    );
+}
+
+#[cfg(feature = "query")]
+impl_arbitrary! {
+    QuerySubspacePostsResponse => page()
+        .prop_map(|(posts, pagination)| QuerySubspacePostsResponse { posts, pagination });
+    QuerySectionPostsResponse => page()
+        .prop_map(|(posts, pagination)| QuerySectionPostsResponse { posts, pagination });
+    QueryPostResponse => any::<Post>().prop_map(|post| QueryPostResponse { post });
+    QueryPostAttachmentsResponse => page().prop_map(|(attachments, pagination)| {
+        QueryPostAttachmentsResponse {
+            attachments,
+            pagination,
+        }
+    });
+    QueryPollAnswersResponse => page()
+        .prop_map(|(answers, pagination)| QueryPollAnswersResponse { answers, pagination });
+}
+
+#[cfg(feature = "msg")]
+impl_arbitrary! {
+    PostsMsg => prop_oneof![
+        (
+            (id(), any::<u32>(), option::of(text(100))),
+            optional_text_with_entities(),
+            option::of(vec(new_attachment(), 1..3)),
+            (addr(), option::of(id()), any::<ReplySetting>(), vec(any::<PostReference>(), 0..3)),
+        )
+            .prop_map(
+                |(
+                    (subspace_id, section_id, external_id),
+                    (text, entities),
+                    attachments,
+                    (author, conversation_id, reply_settings, referenced_posts),
+                )| PostsMsg::CreatePost {
+                    subspace_id,
+                    section_id,
+                    external_id,
+                    text,
+                    entities,
+                    attachments,
+                    author,
+                    conversation_id,
+                    reply_settings,
+                    referenced_posts,
+                },
+            ),
+        (id(), id(), text_with_entities(), addr()).prop_map(
+            |(subspace_id, post_id, (text, entities), editor)| PostsMsg::EditPost {
+                subspace_id,
+                post_id,
+                text,
+                entities,
+                editor,
+            },
+        ),
+        (id(), id(), addr()).prop_map(|(subspace_id, post_id, signer)| PostsMsg::DeletePost {
+            subspace_id,
+            post_id,
+            signer,
+        }),
+        (id(), id(), new_attachment(), addr()).prop_map(
+            |(subspace_id, post_id, content, editor)| PostsMsg::AddPostAttachment {
+                subspace_id,
+                post_id,
+                content,
+                editor,
+            },
+        ),
+        (id(), id(), small_id(), addr()).prop_map(
+            |(subspace_id, post_id, attachment_id, editor)| PostsMsg::RemovePostAttachment {
+                subspace_id,
+                post_id,
+                attachment_id,
+                editor,
+            },
+        ),
+        (id(), id(), small_id(), answers_indexes(), addr()).prop_map(
+            |(subspace_id, post_id, poll_id, answers_indexes, signer)| PostsMsg::AnswerPoll {
+                subspace_id,
+                post_id,
+                poll_id,
+                answers_indexes,
+                signer,
+            },
+        ),
+    ];
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::posts::models::{Entities, Post, PostAttachment, RawPostAttachment};
+    use proptest::prelude::*;
+    use std::convert::TryFrom;
+
+    fn assert_entities_in_text(entities: &Entities, text: &str) {
+        let mut ranges: Vec<(u64, u64)> = entities
+            .hashtags
+            .iter()
+            .chain(entities.mentions.iter())
+            .map(|entity| (entity.start.u64(), entity.end.u64()))
+            .chain(
+                entities
+                    .urls
+                    .iter()
+                    .map(|entity| (entity.start.u64(), entity.end.u64())),
+            )
+            .collect();
+        ranges.sort_unstable();
+        for (start, end) in ranges.iter() {
+            assert!(start <= end && *end < text.len() as u64);
+        }
+        for pair in ranges.windows(2) {
+            assert!(pair[0].1 < pair[1].0);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn post_entities_lie_inside_the_text(post in any::<Post>()) {
+            prop_assert!(!post.id.is_zero() && !post.subspace_id.is_zero());
+            match (post.text, post.entities) {
+                (Some(text), Some(entities)) => assert_entities_in_text(&entities, &text),
+                (None, entities) => prop_assert_eq!(None, entities),
+                _ => {}
+            }
+        }
+
+        #[test]
+        fn raw_attachments_are_well_formed(attachment in any::<RawPostAttachment>()) {
+            match PostAttachment::try_from(attachment) {
+                Ok(PostAttachment::Poll { provided_answers, final_tally_results, .. }) => {
+                    prop_assert!(provided_answers.len() >= 2);
+                    for result in final_tally_results.map(|tally| tally.results).unwrap_or_default() {
+                        prop_assert!((result.answer_index as usize) < provided_answers.len());
+                    }
+                }
+                Ok(PostAttachment::Media { .. }) => {}
+                Err(error) => prop_assert!(false, "invalid attachment: {}", error),
+            }
+        }
+    }
+}
@@ -0,0 +1,88 @@
+//! Contains the events emitted by the Desmos x/posts module.
+//!
+//! Besides the [`MessageEvent`](crate::events::MessageEvent), each [`PostsMsg`](crate::posts::msg::PostsMsg)
+//! emits the following events:
+//!
+//! | Message                | Event type               | Attributes                                                        |
+//! |------------------------|--------------------------|-------------------------------------------------------------------|
+//! | `CreatePost`           | `create_post`            | `subspace_id`, `section_id`, `post_id`, `author`, `creation_time` |
+//! | `EditPost`             | `edit_post`              | `subspace_id`, `post_id`, `last_edit_time`                        |
+//! | `DeletePost`           | `delete_post`            | `subspace_id`, `post_id`                                          |
+//! | `AddPostAttachment`    | `add_post_attachment`    | `subspace_id`, `post_id`, `attachment_id`, `last_edit_time`       |
+//! | `RemovePostAttachment` | `remove_post_attachment` | `subspace_id`, `post_id`, `attachment_id`, `last_edit_time`       |
+//! | `AnswerPoll`           | `answer_poll`            | `subspace_id`, `post_id`, `poll_id`, `user`                       |
+//!
+//! The times are formatted as RFC 3339 strings.
+
+use crate::events::desmos_events;
+use cosmwasm_std::Addr;
+
+desmos_events! {
+    /// Event emitted when a post is created.
+    CreatePostEvent = "create_post" {
+        /// Id of the subspace containing the post.
+        subspace_id: u64 = "subspace_id",
+        /// Id of the section containing the post.
+        section_id: u32 = "section_id",
+        /// Id of the created post.
+        post_id: u64 = "post_id",
+        /// Address of the post author.
+        author: Addr = "author",
+        /// Creation time of the post.
+        creation_time: String = "creation_time",
+    }
+
+    /// Event emitted when a post is edited.
+    EditPostEvent = "edit_post" {
+        /// Id of the subspace containing the post.
+        subspace_id: u64 = "subspace_id",
+        /// Id of the edited post.
+        post_id: u64 = "post_id",
+        /// Time of the edit.
+        last_edit_time: String = "last_edit_time",
+    }
+
+    /// Event emitted when a post is deleted.
+    DeletePostEvent = "delete_post" {
+        /// Id of the subspace containing the post.
+        subspace_id: u64 = "subspace_id",
+        /// Id of the deleted post.
+        post_id: u64 = "post_id",
+    }
+
+    /// Event emitted when an attachment is added to a post.
+    AddPostAttachmentEvent = "add_post_attachment" {
+        /// Id of the subspace containing the post.
+        subspace_id: u64 = "subspace_id",
+        /// Id of the edited post.
+        post_id: u64 = "post_id",
+        /// Id of the added attachment.
+        attachment_id: u32 = "attachment_id",
+        /// Time of the edit.
+        last_edit_time: String = "last_edit_time",
+    }
+
+    /// Event emitted when an attachment is removed from a post.
+    RemovePostAttachmentEvent = "remove_post_attachment" {
+        /// Id of the subspace containing the post.
+        subspace_id: u64 = "subspace_id",
+        /// Id of the edited post.
+        post_id: u64 = "post_id",
+        /// Id of the removed attachment.
+        attachment_id: u32 = "attachment_id",
+        /// Time of the edit.
+        last_edit_time: String = "last_edit_time",
+    }
+
+    /// Event emitted when a poll is answered.
+    AnswerPollEvent = "answer_poll" {
+        /// Id of the subspace containing the post.
+        subspace_id: u64 = "subspace_id",
+        /// Id of the post containing the poll.
+        post_id: u64 = "post_id",
+        /// Id of the answered poll.
+        poll_id: u32 = "poll_id",
+        /// Address of the user that answered the poll.
+        user: Addr = "user",
+    }
+}
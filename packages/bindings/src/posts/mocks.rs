@@ -1,5 +1,6 @@
 //! Contains some useful mocks of the Desmos x/posts module's types made to be used in any test.
 
+use crate::pagination::paginate;
 use crate::posts::models::{Attachment, Post, PostAttachment, ReplySetting, UserAnswer};
 use crate::posts::models_query::{
     QueryPollAnswersResponse, QueryPostAttachmentsResponse, QueryPostResponse,
@@ -98,7 +99,7 @@ impl MockPostsQueries {
             author: Addr::unchecked("desmos1nwp8gxrnmrsrzjdhvk47vvmthzxjtphgxp5ftc"),
             conversation_id: None,
             referenced_posts: vec![],
-            reply_settings: ReplySetting::Unspecified,
+            reply_settings: ReplySetting::Everyone,
             creation_date: "".to_string(),
             last_edit_date: None,
         }
@@ -153,17 +154,26 @@ impl MockPostsQueries {
 /// Functions that mocks the posts query responses.
 pub fn mock_posts_query_response(query: &PostsQuery) -> ContractResult<Binary> {
     let response = match query {
-        PostsQuery::SubspacePosts { subspace_id, .. } => to_binary(&QuerySubspacePostsResponse {
-            posts: MockPostsQueries::get_mocked_subspace_posts(subspace_id),
-            pagination: None,
+        PostsQuery::SubspacePosts {
+            subspace_id,
+            pagination,
+        } => paginate(
+            MockPostsQueries::get_mocked_subspace_posts(subspace_id),
+            pagination.as_ref(),
+        )
+        .and_then(|(posts, pagination)| {
+            to_binary(&QuerySubspacePostsResponse { posts, pagination })
         }),
         PostsQuery::SectionPosts {
             subspace_id,
             section_id,
-            ..
-        } => to_binary(&QuerySectionPostsResponse {
-            posts: MockPostsQueries::get_mocked_section_posts(subspace_id, section_id),
-            pagination: None,
+            pagination,
+        } => paginate(
+            MockPostsQueries::get_mocked_section_posts(subspace_id, section_id),
+            pagination.as_ref(),
+        )
+        .and_then(|(posts, pagination)| {
+            to_binary(&QuerySectionPostsResponse { posts, pagination })
         }),
         PostsQuery::Post {
             subspace_id,
@@ -175,20 +185,32 @@ pub fn mock_posts_query_response(query: &PostsQuery) -> ContractResult<Binary> {
         PostsQuery::PostAttachments {
             subspace_id,
             post_id,
-            ..
-        } => to_binary(&QueryPostAttachmentsResponse {
-            attachments: MockPostsQueries::get_mocked_post_attachments(subspace_id, post_id),
-            pagination: None,
+            pagination,
+        } => paginate(
+            MockPostsQueries::get_mocked_post_attachments(subspace_id, post_id),
+            pagination.as_ref(),
+        )
+        .and_then(|(attachments, pagination)| {
+            to_binary(&QueryPostAttachmentsResponse {
+                attachments,
+                pagination,
+            })
         }),
         PostsQuery::PollAnswers {
             subspace_id,
             post_id,
             poll_id,
             user,
-            ..
-        } => to_binary(&QueryPollAnswersResponse {
-            answers: MockPostsQueries::get_mocked_poll_answers(subspace_id, post_id, poll_id, user),
-            pagination: None,
+            pagination,
+        } => paginate(
+            MockPostsQueries::get_mocked_poll_answers(subspace_id, post_id, poll_id, user),
+            pagination.as_ref(),
+        )
+        .and_then(|(answers, pagination)| {
+            to_binary(&QueryPollAnswersResponse {
+                answers,
+                pagination,
+            })
         }),
     };
     response.into()
@@ -1,5 +1,9 @@
 //! Contains utilities,structs and enum to interact with the Desmos x/posts module.
 
+#[cfg(feature = "arbitrary")]
+pub mod arbitrary;
+#[cfg(feature = "msg")]
+pub mod events;
 #[cfg(all(not(target_arch = "wasm32"), feature = "mocks"))]
 pub mod mocks;
 pub mod models;
@@ -1,17 +1,20 @@
 //! Contains the querier that can be used to query data related to the x/posts module.
 
+use crate::errors::{not_found_as_none, DesmosQueryResult};
+use crate::posts::models::Post;
 use crate::posts::models_query::{
     QueryPollAnswersResponse, QueryPostAttachmentsResponse, QueryPostResponse,
     QuerySectionPostsResponse, QuerySubspacePostsResponse,
 };
 use crate::posts::query::PostsQuery;
-use crate::query::DesmosQuery;
+use crate::query::{query_desmos, DesmosQuery};
 use crate::types::PageRequest;
-use cosmwasm_std::{Addr, Querier, QuerierWrapper, StdResult, Uint64};
+use cosmwasm_std::{Addr, Querier, QuerierWrapper, Uint64};
 #[cfg(feature = "iterators")]
 use {
+    crate::errors::DesmosQueryError,
     crate::iter::page_iterator::{Page, PageIterator},
-    crate::posts::models::{Attachment, Post, UserAnswer},
+    crate::posts::models::{Attachment, UserAnswer},
     cosmwasm_std::Binary,
 };
 
@@ -47,13 +50,13 @@ impl<'a> PostsQuerier<'a> {
         &self,
         subspace_id: u64,
         pagination: Option<PageRequest>,
-    ) -> StdResult<QuerySubspacePostsResponse> {
+    ) -> DesmosQueryResult<QuerySubspacePostsResponse> {
         let request = DesmosQuery::Posts(PostsQuery::SubspacePosts {
             subspace_id: Uint64::new(subspace_id),
             pagination,
         });
 
-        self.querier.query(&request.into())
+        query_desmos(&self.querier, &request.into())
     }
 
     /// Gives an iterator to scan over the posts created inside a subspace.
@@ -65,7 +68,7 @@ impl<'a> PostsQuerier<'a> {
         &self,
         subspace_id: u64,
         page_size: u64,
-    ) -> PageIterator<Post, Binary> {
+    ) -> PageIterator<Post, Binary, DesmosQueryError> {
         PageIterator::new(
             Box::new(move |key, limit| {
                 self.query_subspace_posts(
@@ -97,8 +100,9 @@ impl<'a> PostsQuerier<'a> {
         subspace_id: u64,
         section_id: u32,
         pagination: Option<PageRequest>,
-    ) -> StdResult<QuerySectionPostsResponse> {
-        self.querier.query(
+    ) -> DesmosQueryResult<QuerySectionPostsResponse> {
+        query_desmos(
+            &self.querier,
             &DesmosQuery::Posts(PostsQuery::SectionPosts {
                 subspace_id: subspace_id.into(),
                 section_id,
@@ -119,7 +123,7 @@ impl<'a> PostsQuerier<'a> {
         subspace_id: u64,
         section_id: u32,
         page_size: u64,
-    ) -> PageIterator<Post, Binary> {
+    ) -> PageIterator<Post, Binary, DesmosQueryError> {
         PageIterator::new(
             Box::new(move |key, limit| {
                 self.query_section_posts(
@@ -146,8 +150,13 @@ impl<'a> PostsQuerier<'a> {
     ///
     /// * `subspace_id` - Id of the subspace where the post is stored.
     /// * `post_id` - Id of the post to query for.
-    pub fn query_post(&self, subspace_id: u64, post_id: u64) -> StdResult<QueryPostResponse> {
-        self.querier.query(
+    pub fn query_post(
+        &self,
+        subspace_id: u64,
+        post_id: u64,
+    ) -> DesmosQueryResult<QueryPostResponse> {
+        query_desmos(
+            &self.querier,
             &DesmosQuery::Posts(PostsQuery::Post {
                 subspace_id: subspace_id.into(),
                 post_id: post_id.into(),
@@ -156,6 +165,14 @@ impl<'a> PostsQuerier<'a> {
         )
     }
 
+    /// Gives a single post inside a given subspace, or `None` if it does not exist.
+    ///
+    /// * `subspace_id` - Id of the subspace where the post is stored.
+    /// * `post_id` - Id of the post to query for.
+    pub fn find_post(&self, subspace_id: u64, post_id: u64) -> DesmosQueryResult<Option<Post>> {
+        Ok(not_found_as_none(self.query_post(subspace_id, post_id))?.map(|response| response.post))
+    }
+
     /// Queries the attachments of the post having the given `post_id`.
     ///
     /// * `subspace_id` - Id of the subspace where the post is stored.
@@ -166,8 +183,9 @@ impl<'a> PostsQuerier<'a> {
         subspace_id: u64,
         post_id: u64,
         pagination: Option<PageRequest>,
-    ) -> StdResult<QueryPostAttachmentsResponse> {
-        self.querier.query(
+    ) -> DesmosQueryResult<QueryPostAttachmentsResponse> {
+        query_desmos(
+            &self.querier,
             &DesmosQuery::Posts(PostsQuery::PostAttachments {
                 subspace_id: subspace_id.into(),
                 post_id: post_id.into(),
@@ -188,7 +206,7 @@ impl<'a> PostsQuerier<'a> {
         subspace_id: u64,
         post_id: u64,
         page_size: u64,
-    ) -> PageIterator<Attachment, Binary> {
+    ) -> PageIterator<Attachment, Binary, DesmosQueryError> {
         PageIterator::new(
             Box::new(move |key, limit| {
                 self.query_post_attachments(
@@ -224,8 +242,9 @@ impl<'a> PostsQuerier<'a> {
         poll_id: u32,
         user: Option<Addr>,
         pagination: Option<PageRequest>,
-    ) -> StdResult<QueryPollAnswersResponse> {
-        self.querier.query(
+    ) -> DesmosQueryResult<QueryPollAnswersResponse> {
+        query_desmos(
+            &self.querier,
             &DesmosQuery::Posts(PostsQuery::PollAnswers {
                 subspace_id: subspace_id.into(),
                 post_id: post_id.into(),
@@ -251,7 +270,7 @@ impl<'a> PostsQuerier<'a> {
         poll_id: u32,
         user: Option<Addr>,
         page_size: u64,
-    ) -> PageIterator<UserAnswer, Binary> {
+    ) -> PageIterator<UserAnswer, Binary, DesmosQueryError> {
         PageIterator::new(
             Box::new(move |key, limit| {
                 self.query_poll_answers(
@@ -279,9 +298,15 @@ impl<'a> PostsQuerier<'a> {
 
 #[cfg(test)]
 mod tests {
+    use crate::errors::DesmosQueryError;
+    use crate::mocks::errors::ChainError;
     use crate::mocks::mock_queriers::mock_desmos_dependencies;
+    use crate::mocks::mock_queriers::{
+        mock_desmos_dependencies_with_custom_querier, ChainErrorQuerier, MockDesmosQuerier,
+    };
     use crate::posts::mocks::MockPostsQueries;
     use crate::posts::querier::PostsQuerier;
+    use crate::posts::query::PostsQuery;
     use cosmwasm_std::Uint64;
     use std::ops::Deref;
 
@@ -462,4 +487,57 @@ mod tests {
         // The second item should be none since the mock function provides only 1 response.
         assert!(iterator.next().is_none());
     }
+
+    #[test]
+    fn test_find_post() {
+        let querier = MockDesmosQuerier::default().with_query_error(
+            PostsQuery::Post {
+                subspace_id: Uint64::new(1),
+                post_id: Uint64::new(2),
+            },
+            ChainError::post_not_found(1, 2),
+        );
+        let owned_deps = mock_desmos_dependencies_with_custom_querier(querier);
+        let deps = owned_deps.as_ref();
+        let querier = PostsQuerier::new(deps.querier.deref());
+
+        assert_eq!(
+            Some(MockPostsQueries::get_mocked_post(
+                Uint64::new(1),
+                Uint64::new(1)
+            )),
+            querier.find_post(1, 1).unwrap()
+        );
+        assert_eq!(None, querier.find_post(1, 2).unwrap());
+    }
+
+    #[test]
+    fn test_find_post_with_chain_not_found_error() {
+        // Redacted ErrNotFound error returned by the chain when the queried object does not exist
+        let querier = PostsQuerier::new(&ChainErrorQuerier("codespace: sdk, code: 38"));
+
+        assert_eq!(None, querier.find_post(1, 2).unwrap());
+    }
+
+    #[test]
+    fn test_find_post_with_chain_not_found_log() {
+        // Error returned by the chain when the queried post does not exist, if not redacted
+        let querier = PostsQuerier::new(&ChainErrorQuerier(
+            "rpc error: code = NotFound desc = post with id 2 not found",
+        ));
+
+        assert_eq!(None, querier.find_post(1, 2).unwrap());
+    }
+
+    #[test]
+    fn test_find_post_propagates_redacted_unregistered_errors() {
+        // Redacted error returned by the chain when the module failed with an unregistered error,
+        // that can't be told apart from a missing object
+        let querier = PostsQuerier::new(&ChainErrorQuerier("codespace: undefined, code: 1"));
+
+        assert!(matches!(
+            querier.find_post(1, 2),
+            Err(DesmosQueryError::InvalidRequest { codespace, code: 1, .. }) if codespace == "undefined"
+        ));
+    }
 }
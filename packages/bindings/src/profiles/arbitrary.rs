@@ -0,0 +1,325 @@
+//! Contains the [`Arbitrary`](proptest::arbitrary::Arbitrary) implementations of the
+//! x/profiles module models, query responses and messages, along with the strategies used to
+//! build them.
+
+use crate::arbitrary::{
+    addr, bech32_address, distinct_addrs, id, impl_arbitrary, page, rfc3339_time, text, uri,
+};
+use crate::profiles::models_app_links::{
+    AppLinkResult, ApplicationLink, ApplicationLinkOwnerDetails, ApplicationLinkState, CallData,
+    Data, OracleRequest,
+};
+use crate::profiles::models_chain_links::{
+    Address, ChainConfig, ChainLink, ChainLinkOwnerDetails, Proof, Signature, SignatureValueType,
+};
+use crate::profiles::models_dtag_requests::DtagTransferRequest;
+use crate::profiles::models_profile::{Account, Pictures, Profile};
+use crate::profiles::models_query::{
+    QueryApplicationLinkByClientIDResponse, QueryApplicationLinkOwnersResponse,
+    QueryApplicationLinksResponse, QueryChainLinkOwnersResponse, QueryChainLinksResponse,
+    QueryDefaultExternalAddressesResponse, QueryIncomingDtagTransferRequestResponse,
+    QueryProfileResponse,
+};
+#[cfg(feature = "msg")]
+use crate::profiles::msg::ProfilesMsg;
+#[cfg(feature = "msg")]
+use crate::types::Height;
+use crate::types::PubKey;
+use cosmwasm_std::{Binary, Uint64};
+use proptest::collection::vec;
+use proptest::prelude::*;
+
+/// Returns a strategy generating DTags that respect the default chain params.
+pub fn dtag() -> impl Strategy<Value = String> {
+    "[A-Za-z0-9_]{3,30}"
+}
+
+/// Returns a strategy generating nicknames that respect the default chain params.
+pub fn nickname() -> impl Strategy<Value = String> {
+    "[A-Za-z0-9]{2,20}( [A-Za-z0-9]{1,20})?"
+}
+
+/// Returns a strategy generating the names of the chains that can be linked to a profile.
+pub fn chain_name() -> impl Strategy<Value = String> {
+    "[a-z]{3,20}"
+}
+
+/// Returns a strategy generating the names of the applications that can be linked to a profile.
+pub fn application() -> impl Strategy<Value = String> {
+    prop_oneof![
+        Just("twitter".to_string()),
+        Just("github".to_string()),
+        Just("discord".to_string()),
+        Just("twitch".to_string()),
+    ]
+}
+
+/// Returns a strategy generating the usernames of the users of the linked applications.
+pub fn username() -> impl Strategy<Value = String> {
+    "[a-zA-Z0-9_]{1,30}"
+}
+
+/// Returns a strategy generating a hex encoded string of the given length in bytes.
+pub fn hex_string(length: usize) -> impl Strategy<Value = String> {
+    vec(any::<u8>(), length).prop_map(|bytes| {
+        bytes
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect::<String>()
+    })
+}
+
+/// Returns a strategy generating the bech32 addresses of the chains that can be linked to a profile.
+pub fn external_address() -> impl Strategy<Value = Address> {
+    prop_oneof![Just("cosmos"), Just("osmo"), Just("juno"), Just("akash")].prop_flat_map(|prefix| {
+        bech32_address(prefix).prop_map(move |value| Address {
+            proto_type: "/desmos.profiles.v3.Bech32Address".to_string(),
+            value,
+            prefix: Some(prefix.to_string()),
+        })
+    })
+}
+
+impl_arbitrary! {
+    Pictures => (prop_oneof![Just(String::new()), uri()], prop_oneof![Just(String::new()), uri()])
+        .prop_map(|(profile, cover)| Pictures { profile, cover });
+    Account => (addr(), any::<PubKey>(), any::<u64>(), any::<u64>()).prop_map(
+        |(address, pub_key, account_number, sequence)| Account {
+            proto_type: "/cosmos.auth.v1beta1.BaseAccount".to_string(),
+            address,
+            pub_key,
+            account_number: Uint64::new(account_number),
+            sequence: Uint64::new(sequence),
+        },
+    );
+    Profile => (
+        any::<Account>(),
+        dtag(),
+        nickname(),
+        prop_oneof![Just(String::new()), text(1000)],
+        any::<Pictures>(),
+        rfc3339_time(),
+    )
+        .prop_map(|(account, dtag, nickname, bio, pictures, creation_date)| Profile {
+            account,
+            dtag,
+            nickname,
+            bio,
+            pictures,
+            creation_date,
+        });
+    DtagTransferRequest => (dtag(), distinct_addrs()).prop_map(|(dtag_to_trade, (sender, receiver))| {
+        DtagTransferRequest {
+            dtag_to_trade,
+            sender,
+            receiver,
+        }
+    });
+    Address => external_address();
+    SignatureValueType => prop_oneof![
+        Just(SignatureValueType::Raw),
+        Just(SignatureValueType::CosmosDirect),
+        Just(SignatureValueType::CosmosAnimo),
+        Just(SignatureValueType::EVMPersonalSign),
+    ];
+    Signature => (any::<SignatureValueType>(), vec(any::<u8>(), 64)).prop_map(
+        |(value_type, signature)| Signature {
+            proto_type: "/desmos.profiles.v3.SingleSignature".to_string(),
+            value_type,
+            signature: Binary::from(signature),
+        },
+    );
+    Proof => (any::<PubKey>(), any::<Signature>(), hex_string(32)).prop_map(
+        |(pub_key, signature, plain_text)| Proof {
+            pub_key,
+            signature,
+            plain_text,
+        },
+    );
+    ChainConfig => chain_name().prop_map(|name| ChainConfig { name });
+    ChainLink => (addr(), any::<Address>(), any::<Proof>(), any::<ChainConfig>(), rfc3339_time())
+        .prop_map(|(user, address, proof, chain_config, creation_time)| ChainLink {
+            user,
+            address,
+            proof,
+            chain_config,
+            creation_time,
+        });
+    ChainLinkOwnerDetails => (addr(), chain_name(), any::<Address>()).prop_map(
+        |(user, chain_name, target)| ChainLinkOwnerDetails {
+            user,
+            chain_name,
+            target: target.value,
+        },
+    );
+    ApplicationLinkState => prop_oneof![
+        Just(ApplicationLinkState::Initialized),
+        Just(ApplicationLinkState::VerificationStarted),
+        Just(ApplicationLinkState::VerificationError),
+        Just(ApplicationLinkState::VerificationSuccess),
+        Just(ApplicationLinkState::TimedOut),
+    ];
+    Data => (application(), username())
+        .prop_map(|(application, username)| Data { application, username });
+    CallData => (application(), hex_string(32))
+        .prop_map(|(application, call_data)| CallData { application, call_data });
+    OracleRequest => (any::<u64>(), id(), any::<CallData>(), "[a-z0-9-]{1,64}").prop_map(
+        |(id, oracle_script_id, call_data, client_id)| OracleRequest {
+            id: Uint64::new(id),
+            oracle_script_id,
+            call_data,
+            client_id,
+        },
+    );
+    AppLinkResult => prop_oneof![
+        (hex_string(32), hex_string(64))
+            .prop_map(|(value, signature)| AppLinkResult::Success { value, signature }),
+        text(100).prop_map(|error| AppLinkResult::Failed { error }),
+    ];
+    // The result of a link is only set once the verification has ended, and it must match its state.
+    ApplicationLink => (
+        (addr(), any::<Data>(), any::<ApplicationLinkState>(), any::<OracleRequest>()),
+        (hex_string(32), hex_string(64), text(100)),
+        (rfc3339_time(), rfc3339_time()),
+    )
+        .prop_map(
+            |(
+                (user, data, state, oracle_request),
+                (value, signature, error),
+                (creation_time, expiration_time),
+            )| ApplicationLink {
+                result: match state {
+                    ApplicationLinkState::VerificationSuccess => {
+                        Some(AppLinkResult::Success { value, signature })
+                    }
+                    ApplicationLinkState::VerificationError => {
+                        Some(AppLinkResult::Failed { error })
+                    }
+                    _ => None,
+                },
+                user,
+                data,
+                state,
+                oracle_request,
+                creation_time,
+                expiration_time,
+            },
+        );
+    ApplicationLinkOwnerDetails => (addr(), application(), username()).prop_map(
+        |(user, application, username)| ApplicationLinkOwnerDetails {
+            user,
+            application,
+            username,
+        },
+    );
+}
+
+impl_arbitrary! {
+    QueryProfileResponse => any::<Profile>().prop_map(|profile| QueryProfileResponse { profile });
+    QueryIncomingDtagTransferRequestResponse => page().prop_map(|(requests, pagination)| {
+        QueryIncomingDtagTransferRequestResponse {
+            requests,
+            pagination,
+        }
+    });
+    QueryChainLinksResponse => page()
+        .prop_map(|(links, pagination)| QueryChainLinksResponse { links, pagination });
+    QueryChainLinkOwnersResponse => page()
+        .prop_map(|(owners, pagination)| QueryChainLinkOwnersResponse { owners, pagination });
+    QueryDefaultExternalAddressesResponse => page().prop_map(|(links, pagination)| {
+        QueryDefaultExternalAddressesResponse { links, pagination }
+    });
+    QueryApplicationLinksResponse => page()
+        .prop_map(|(links, pagination)| QueryApplicationLinksResponse { links, pagination });
+    QueryApplicationLinkByClientIDResponse => any::<ApplicationLink>()
+        .prop_map(|link| QueryApplicationLinkByClientIDResponse { link });
+    QueryApplicationLinkOwnersResponse => page().prop_map(|(owners, pagination)| {
+        QueryApplicationLinkOwnersResponse { owners, pagination }
+    });
+}
+
+#[cfg(feature = "msg")]
+impl_arbitrary! {
+    ProfilesMsg => prop_oneof![
+        (dtag(), nickname(), text(1000), any::<Pictures>(), addr()).prop_map(
+            |(dtag, nickname, bio, pictures, creator)| ProfilesMsg::SaveProfile {
+                dtag,
+                nickname,
+                bio,
+                profile_picture: pictures.profile,
+                cover_picture: pictures.cover,
+                creator,
+            },
+        ),
+        addr().prop_map(|creator| ProfilesMsg::DeleteProfile { creator }),
+        distinct_addrs().prop_map(|(sender, receiver)| ProfilesMsg::RequestDtagTransfer {
+            receiver,
+            sender,
+        }),
+        (dtag(), distinct_addrs()).prop_map(|(new_dtag, (sender, receiver))| {
+            ProfilesMsg::AcceptDtagTransferRequest {
+                new_dtag,
+                sender,
+                receiver,
+            }
+        }),
+        distinct_addrs().prop_map(|(sender, receiver)| ProfilesMsg::RefuseDtagTransferRequest {
+            sender,
+            receiver,
+        }),
+        distinct_addrs().prop_map(|(sender, receiver)| ProfilesMsg::CancelDtagTransferRequest {
+            receiver,
+            sender,
+        }),
+        (any::<Address>(), any::<Proof>(), any::<ChainConfig>(), addr()).prop_map(
+            |(chain_address, proof, chain_config, signer)| ProfilesMsg::LinkChainAccount {
+                chain_address,
+                proof,
+                chain_config,
+                signer,
+            },
+        ),
+        (addr(), chain_name(), any::<Address>()).prop_map(|(owner, chain_name, target)| {
+            ProfilesMsg::UnlinkChainAccount {
+                owner,
+                chain_name,
+                target: target.value,
+            }
+        }),
+        (chain_name(), any::<Address>(), addr()).prop_map(|(chain_name, target, signer)| {
+            ProfilesMsg::SetDefaultExternalAddress {
+                chain_name,
+                target: target.value,
+                signer,
+            }
+        }),
+        (
+            addr(),
+            any::<Data>(),
+            hex_string(32),
+            "channel-[0-9]{1,3}",
+            any::<Height>(),
+            any::<u64>(),
+        )
+            .prop_map(
+                |(sender, link_data, call_data, source_channel, timeout_height, timeout)| {
+                    ProfilesMsg::LinkApplication {
+                        sender,
+                        link_data,
+                        call_data,
+                        source_port: "ibc-profiles".to_string(),
+                        source_channel,
+                        timeout_height,
+                        timeout_timestamp: Uint64::new(timeout),
+                    }
+                },
+            ),
+        (application(), username(), addr()).prop_map(|(application, username, signer)| {
+            ProfilesMsg::UnlinkApplication {
+                application,
+                username,
+                signer,
+            }
+        }),
+    ];
+}
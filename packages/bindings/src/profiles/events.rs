@@ -0,0 +1,133 @@
+//! Contains the events emitted by the Desmos x/profiles module.
+//!
+//! Besides the [`MessageEvent`](crate::events::MessageEvent), each [`ProfilesMsg`](crate::profiles::msg::ProfilesMsg)
+//! emits the following events:
+//!
+//! | Message                     | Event type                     | Attributes                                                                                                          |
+//! |-----------------------------|--------------------------------|---------------------------------------------------------------------------------------------------------------------|
+//! | `SaveProfile`               | `save_profile`                 | `profile_dtag`, `profile_creator`, `profile_creation_time`                                                          |
+//! | `DeleteProfile`             | `delete_profile`               | `profile_creator`                                                                                                   |
+//! | `RequestDtagTransfer`       | `create_dtag_transfer_request` | `dtag_to_trade`, `request_sender`, `request_receiver`                                                               |
+//! | `AcceptDtagTransferRequest` | `accept_dtag_transfer_request` | `dtag_to_trade`, `new_dtag`, `request_sender`, `request_receiver`                                                   |
+//! | `RefuseDtagTransferRequest` | `refuse_dtag_transfer_request` | `request_sender`, `request_receiver`                                                                                |
+//! | `CancelDtagTransferRequest` | `cancel_dtag_transfer_request` | `request_sender`, `request_receiver`                                                                                |
+//! | `LinkChainAccount`          | `link_chain_account`           | `chain_link_account_target`, `chain_link_source_chain_name`, `chain_link_account_owner`, `chain_link_creation_time` |
+//! | `UnlinkChainAccount`        | `unlink_chain_account`         | `chain_link_account_target`, `chain_link_source_chain_name`, `chain_link_account_owner`                             |
+//! | `SetDefaultExternalAddress` | `set_default_external_address` | `chain_link_chain_name`, `chain_link_external_address`, `chain_link_owner`                                          |
+//! | `LinkApplication`           | `link_application`             | `user`, `application_name`, `application_username`, `application_link_creation_time`                                |
+//! | `UnlinkApplication`         | `unlink_application`           | `user`, `application_name`, `application_username`                                                                  |
+//!
+//! The times are formatted as RFC 3339 strings.
+
+use crate::events::desmos_events;
+use cosmwasm_std::Addr;
+
+desmos_events! {
+    /// Event emitted when a profile is saved.
+    SaveProfileEvent = "save_profile" {
+        /// DTag of the saved profile.
+        dtag: String = "profile_dtag",
+        /// Address of the profile owner.
+        creator: Addr = "profile_creator",
+        /// Creation time of the profile.
+        creation_time: String = "profile_creation_time",
+    }
+
+    /// Event emitted when a profile is deleted.
+    DeleteProfileEvent = "delete_profile" {
+        /// Address of the profile owner.
+        creator: Addr = "profile_creator",
+    }
+
+    /// Event emitted when a DTag transfer request is created.
+    CreateDtagTransferRequestEvent = "create_dtag_transfer_request" {
+        /// DTag requested by the sender.
+        dtag_to_trade: String = "dtag_to_trade",
+        /// Address of the user that sent the request.
+        request_sender: Addr = "request_sender",
+        /// Address of the user that received the request.
+        request_receiver: Addr = "request_receiver",
+    }
+
+    /// Event emitted when a DTag transfer request is accepted.
+    AcceptDtagTransferRequestEvent = "accept_dtag_transfer_request" {
+        /// DTag transferred to the sender.
+        dtag_to_trade: String = "dtag_to_trade",
+        /// New DTag of the receiver.
+        new_dtag: String = "new_dtag",
+        /// Address of the user that sent the request.
+        request_sender: Addr = "request_sender",
+        /// Address of the user that received the request.
+        request_receiver: Addr = "request_receiver",
+    }
+
+    /// Event emitted when a DTag transfer request is refused by its receiver.
+    RefuseDtagTransferRequestEvent = "refuse_dtag_transfer_request" {
+        /// Address of the user that sent the request.
+        request_sender: Addr = "request_sender",
+        /// Address of the user that received the request.
+        request_receiver: Addr = "request_receiver",
+    }
+
+    /// Event emitted when a DTag transfer request is canceled by its sender.
+    CancelDtagTransferRequestEvent = "cancel_dtag_transfer_request" {
+        /// Address of the user that sent the request.
+        request_sender: Addr = "request_sender",
+        /// Address of the user that received the request.
+        request_receiver: Addr = "request_receiver",
+    }
+
+    /// Event emitted when a chain link is created.
+    LinkChainAccountEvent = "link_chain_account" {
+        /// Linked external address.
+        target: String = "chain_link_account_target",
+        /// Name of the chain of the external address.
+        chain_name: String = "chain_link_source_chain_name",
+        /// Address of the link owner.
+        owner: Addr = "chain_link_account_owner",
+        /// Creation time of the link.
+        creation_time: String = "chain_link_creation_time",
+    }
+
+    /// Event emitted when a chain link is deleted.
+    UnlinkChainAccountEvent = "unlink_chain_account" {
+        /// Unlinked external address.
+        target: String = "chain_link_account_target",
+        /// Name of the chain of the external address.
+        chain_name: String = "chain_link_source_chain_name",
+        /// Address of the link owner.
+        owner: Addr = "chain_link_account_owner",
+    }
+
+    /// Event emitted when the default external address of a chain is set.
+    SetDefaultExternalAddressEvent = "set_default_external_address" {
+        /// Name of the chain of the external address.
+        chain_name: String = "chain_link_chain_name",
+        /// New default external address.
+        external_address: String = "chain_link_external_address",
+        /// Address of the link owner.
+        owner: Addr = "chain_link_owner",
+    }
+
+    /// Event emitted when an application link is created.
+    LinkApplicationEvent = "link_application" {
+        /// Address of the link owner.
+        user: Addr = "user",
+        /// Name of the linked application.
+        application: String = "application_name",
+        /// Username of the user on the linked application.
+        username: String = "application_username",
+        /// Creation time of the link.
+        creation_time: String = "application_link_creation_time",
+    }
+
+    /// Event emitted when an application link is deleted.
+    UnlinkApplicationEvent = "unlink_application" {
+        /// Address of the link owner.
+        user: Addr = "user",
+        /// Name of the unlinked application.
+        application: String = "application_name",
+        /// Username of the user on the unlinked application.
+        username: String = "application_username",
+    }
+}
@@ -1,5 +1,6 @@
 //! Contains some useful mocks of the Desmos x/profiles module's types made to be used in any test.
 
+use crate::pagination::paginate;
 use crate::profiles::models_app_links::ApplicationLinkState;
 use crate::profiles::models_chain_links::{Address, SignatureValueType};
 use crate::profiles::{
@@ -140,50 +141,49 @@ pub fn mock_profiles_query_response(query: &ProfilesQuery) -> ContractResult<Bin
             let profile = MockProfilesQueries::get_mock_profile();
             to_binary(&QueryProfileResponse { profile })
         }
-        ProfilesQuery::IncomingDtagTransferRequests { .. } => {
+        ProfilesQuery::IncomingDtagTransferRequests { pagination, .. } => {
             let incoming_dtag_requests = MockProfilesQueries::get_mock_dtag_transfer_request();
-            to_binary(&QueryIncomingDtagTransferRequestResponse {
-                requests: vec![incoming_dtag_requests],
-                pagination: Default::default(),
-            })
+            paginate(vec![incoming_dtag_requests], pagination.as_ref()).and_then(
+                |(requests, pagination)| {
+                    to_binary(&QueryIncomingDtagTransferRequestResponse {
+                        requests,
+                        pagination,
+                    })
+                },
+            )
         }
-        ProfilesQuery::ChainLinks { .. } => {
+        ProfilesQuery::ChainLinks { pagination, .. } => {
             let chain_link = MockProfilesQueries::get_mock_chain_link();
-            to_binary(&QueryChainLinksResponse {
-                links: vec![chain_link],
-                pagination: Default::default(),
+            paginate(vec![chain_link], pagination.as_ref()).and_then(|(links, pagination)| {
+                to_binary(&QueryChainLinksResponse { links, pagination })
             })
         }
-        ProfilesQuery::ChainLinkOwners { .. } => {
+        ProfilesQuery::ChainLinkOwners { pagination, .. } => {
             let owner = MockProfilesQueries::get_mock_chain_link_owner();
-            to_binary(&QueryChainLinkOwnersResponse {
-                owners: vec![owner],
-                pagination: Default::default(),
+            paginate(vec![owner], pagination.as_ref()).and_then(|(owners, pagination)| {
+                to_binary(&QueryChainLinkOwnersResponse { owners, pagination })
             })
         }
-        ProfilesQuery::DefaultExternalAddresses { .. } => {
+        ProfilesQuery::DefaultExternalAddresses { pagination, .. } => {
             let chain_link = MockProfilesQueries::get_mock_chain_link();
-            to_binary(&QueryDefaultExternalAddressesResponse {
-                links: vec![chain_link],
-                pagination: Default::default(),
+            paginate(vec![chain_link], pagination.as_ref()).and_then(|(links, pagination)| {
+                to_binary(&QueryDefaultExternalAddressesResponse { links, pagination })
             })
         }
-        ProfilesQuery::ApplicationLinks { .. } => {
+        ProfilesQuery::ApplicationLinks { pagination, .. } => {
             let app_link = MockProfilesQueries::get_mock_application_link();
-            to_binary(&QueryApplicationLinksResponse {
-                links: vec![app_link],
-                pagination: Default::default(),
+            paginate(vec![app_link], pagination.as_ref()).and_then(|(links, pagination)| {
+                to_binary(&QueryApplicationLinksResponse { links, pagination })
             })
         }
         ProfilesQuery::ApplicationLinkByChainID { .. } => {
             let app_link = MockProfilesQueries::get_mock_application_link();
             to_binary(&QueryApplicationLinkByClientIDResponse { link: app_link })
         }
-        ProfilesQuery::ApplicationLinkOwners { .. } => {
+        ProfilesQuery::ApplicationLinkOwners { pagination, .. } => {
             let owner = MockProfilesQueries::get_mock_application_link_owner();
-            to_binary(&QueryApplicationLinkOwnersResponse {
-                owners: vec![owner],
-                pagination: Default::default(),
+            paginate(vec![owner], pagination.as_ref()).and_then(|(owners, pagination)| {
+                to_binary(&QueryApplicationLinkOwnersResponse { owners, pagination })
             })
         }
     };
@@ -1,5 +1,9 @@
 //! Contains utilities,structs and enum to interact with the Desmos x/profiles module.
 
+#[cfg(feature = "arbitrary")]
+pub mod arbitrary;
+#[cfg(feature = "msg")]
+pub mod events;
 pub mod models_app_links;
 pub mod models_chain_links;
 pub mod models_dtag_requests;
@@ -2,6 +2,7 @@
 
 #[cfg(feature = "iterators")]
 use crate::{
+    errors::DesmosQueryError,
     iter::page_iterator::{Page, PageIterator},
     profiles::models_app_links::{ApplicationLink, ApplicationLinkOwnerDetails},
     profiles::models_chain_links::{ChainLink, ChainLinkOwnerDetails},
@@ -11,7 +12,9 @@ use crate::{
 use cosmwasm_std::Binary;
 
 use crate::{
+    errors::{not_found_as_none, DesmosQueryResult},
     profiles::{
+        models_profile::Profile,
         models_query::{
             QueryApplicationLinkByClientIDResponse, QueryApplicationLinkOwnersResponse,
             QueryApplicationLinksResponse, QueryChainLinkOwnersResponse, QueryChainLinksResponse,
@@ -20,10 +23,19 @@ use crate::{
         },
         query::ProfilesQuery,
     },
-    query::DesmosQuery,
+    query::{query_desmos, DesmosQuery},
     types::PageRequest,
 };
-use cosmwasm_std::{Addr, Querier, QuerierWrapper, StdResult};
+use cosmwasm_std::{Addr, Querier, QuerierWrapper};
+use serde::Deserialize;
+
+/// Response to [`ProfilesQuery::Profile`] used by [`ProfilesQuerier::find_profile`].
+/// The x/profiles module answers with a `null` profile, instead of an error,
+/// when the user has not created one.
+#[derive(Deserialize)]
+struct FindProfileResponse {
+    profile: Option<Profile>,
+}
 
 /// Querier able to query data from the Desmos x/profiles module.
 pub struct ProfilesQuerier<'a> {
@@ -52,15 +64,28 @@ impl<'a> ProfilesQuerier<'a> {
     /// Gives the Desmos profile associated to an user.
     ///
     /// * `user` - Address of the user to query the profile for.
-    pub fn query_profile(&self, user: Addr) -> StdResult<QueryProfileResponse> {
+    pub fn query_profile(&self, user: Addr) -> DesmosQueryResult<QueryProfileResponse> {
         let request = DesmosQuery::Profiles(ProfilesQuery::Profile {
             user: user.to_string(),
         });
 
-        let res: QueryProfileResponse = self.querier.query(&request.into())?;
+        let res: QueryProfileResponse = query_desmos(&self.querier, &request.into())?;
         Ok(res)
     }
 
+    /// Gives the Desmos profile associated to an user, or `None` if the user has not created one.
+    ///
+    /// * `user` - Address of the user to query the profile for.
+    pub fn find_profile(&self, user: Addr) -> DesmosQueryResult<Option<Profile>> {
+        let request = DesmosQuery::Profiles(ProfilesQuery::Profile {
+            user: user.to_string(),
+        });
+
+        let res: Option<FindProfileResponse> =
+            not_found_as_none(query_desmos(&self.querier, &request.into()))?;
+        Ok(res.and_then(|response| response.profile))
+    }
+
     /// Queries the user's dtag transfer requests.
     ///
     /// * `receiver` - Address of the user to which query the incoming requests for.
@@ -69,13 +94,14 @@ impl<'a> ProfilesQuerier<'a> {
         &self,
         receiver: Addr,
         pagination: Option<PageRequest>,
-    ) -> StdResult<QueryIncomingDtagTransferRequestResponse> {
+    ) -> DesmosQueryResult<QueryIncomingDtagTransferRequestResponse> {
         let request = DesmosQuery::Profiles(ProfilesQuery::IncomingDtagTransferRequests {
             receiver,
             pagination,
         });
 
-        let res: QueryIncomingDtagTransferRequestResponse = self.querier.query(&request.into())?;
+        let res: QueryIncomingDtagTransferRequestResponse =
+            query_desmos(&self.querier, &request.into())?;
         Ok(res)
     }
 
@@ -88,7 +114,7 @@ impl<'a> ProfilesQuerier<'a> {
         &self,
         receiver: Addr,
         page_size: u64,
-    ) -> PageIterator<DtagTransferRequest, Binary> {
+    ) -> PageIterator<DtagTransferRequest, Binary, DesmosQueryError> {
         PageIterator::new(
             Box::new(move |key, limit| {
                 self.query_incoming_dtag_transfer_requests(
@@ -125,7 +151,7 @@ impl<'a> ProfilesQuerier<'a> {
         chain_name: Option<String>,
         target: Option<String>,
         pagination: Option<PageRequest>,
-    ) -> StdResult<QueryChainLinksResponse> {
+    ) -> DesmosQueryResult<QueryChainLinksResponse> {
         let request = DesmosQuery::Profiles(ProfilesQuery::ChainLinks {
             user,
             chain_name,
@@ -133,7 +159,7 @@ impl<'a> ProfilesQuerier<'a> {
             pagination,
         });
 
-        let res: QueryChainLinksResponse = self.querier.query(&request.into())?;
+        let res: QueryChainLinksResponse = query_desmos(&self.querier, &request.into())?;
         Ok(res)
     }
 
@@ -153,7 +179,7 @@ impl<'a> ProfilesQuerier<'a> {
         chain_name: Option<String>,
         target: Option<String>,
         page_size: u64,
-    ) -> PageIterator<ChainLink, Binary> {
+    ) -> PageIterator<ChainLink, Binary, DesmosQueryError> {
         PageIterator::new(
             Box::new(move |key, limit| {
                 self.query_chain_links(
@@ -188,14 +214,14 @@ impl<'a> ProfilesQuerier<'a> {
         chain_name: Option<String>,
         target: Option<String>,
         pagination: Option<PageRequest>,
-    ) -> StdResult<QueryChainLinkOwnersResponse> {
+    ) -> DesmosQueryResult<QueryChainLinkOwnersResponse> {
         let request = DesmosQuery::Profiles(ProfilesQuery::ChainLinkOwners {
             chain_name,
             target,
             pagination,
         });
 
-        let res: QueryChainLinkOwnersResponse = self.querier.query(&request.into())?;
+        let res: QueryChainLinkOwnersResponse = query_desmos(&self.querier, &request.into())?;
         Ok(res)
     }
 
@@ -211,7 +237,7 @@ impl<'a> ProfilesQuerier<'a> {
         chain_name: Option<String>,
         target: Option<String>,
         page_size: u64,
-    ) -> PageIterator<ChainLinkOwnerDetails, Binary> {
+    ) -> PageIterator<ChainLinkOwnerDetails, Binary, DesmosQueryError> {
         PageIterator::new(
             Box::new(move |key, limit| {
                 self.query_chain_link_owners(
@@ -245,13 +271,14 @@ impl<'a> ProfilesQuerier<'a> {
         owner: Option<Addr>,
         chain_name: Option<String>,
         pagination: Option<PageRequest>,
-    ) -> StdResult<QueryDefaultExternalAddressesResponse> {
+    ) -> DesmosQueryResult<QueryDefaultExternalAddressesResponse> {
         let request = DesmosQuery::Profiles(ProfilesQuery::DefaultExternalAddresses {
             owner,
             chain_name,
             pagination,
         });
-        let res: QueryDefaultExternalAddressesResponse = self.querier.query(&request.into())?;
+        let res: QueryDefaultExternalAddressesResponse =
+            query_desmos(&self.querier, &request.into())?;
         Ok(res)
     }
 
@@ -267,7 +294,7 @@ impl<'a> ProfilesQuerier<'a> {
         owner: Option<Addr>,
         chain_name: Option<String>,
         page_size: u64,
-    ) -> PageIterator<ChainLink, Binary> {
+    ) -> PageIterator<ChainLink, Binary, DesmosQueryError> {
         PageIterator::new(
             Box::new(move |key, limit| {
                 self.query_default_external_addresses(
@@ -305,7 +332,7 @@ impl<'a> ProfilesQuerier<'a> {
         application: Option<String>,
         username: Option<String>,
         pagination: Option<PageRequest>,
-    ) -> StdResult<QueryApplicationLinksResponse> {
+    ) -> DesmosQueryResult<QueryApplicationLinksResponse> {
         let request = DesmosQuery::Profiles(ProfilesQuery::ApplicationLinks {
             user,
             application,
@@ -313,7 +340,7 @@ impl<'a> ProfilesQuerier<'a> {
             pagination,
         });
 
-        let res: QueryApplicationLinksResponse = self.querier.query(&request.into())?;
+        let res: QueryApplicationLinksResponse = query_desmos(&self.querier, &request.into())?;
         Ok(res)
     }
 
@@ -333,7 +360,7 @@ impl<'a> ProfilesQuerier<'a> {
         application: Option<String>,
         username: Option<String>,
         page_size: u64,
-    ) -> PageIterator<ApplicationLink, Binary> {
+    ) -> PageIterator<ApplicationLink, Binary, DesmosQueryError> {
         PageIterator::new(
             Box::new(move |key, limit| {
                 self.query_application_links(
@@ -363,12 +390,13 @@ impl<'a> ProfilesQuerier<'a> {
     pub fn query_application_link_by_client_id(
         &self,
         client_id: &str,
-    ) -> StdResult<QueryApplicationLinkByClientIDResponse> {
+    ) -> DesmosQueryResult<QueryApplicationLinkByClientIDResponse> {
         let request = DesmosQuery::Profiles(ProfilesQuery::ApplicationLinkByChainID {
             client_id: client_id.to_owned(),
         });
 
-        let res: QueryApplicationLinkByClientIDResponse = self.querier.query(&request.into())?;
+        let res: QueryApplicationLinkByClientIDResponse =
+            query_desmos(&self.querier, &request.into())?;
         Ok(res)
     }
 
@@ -383,14 +411,14 @@ impl<'a> ProfilesQuerier<'a> {
         application: Option<String>,
         username: Option<String>,
         pagination: Option<PageRequest>,
-    ) -> StdResult<QueryApplicationLinkOwnersResponse> {
+    ) -> DesmosQueryResult<QueryApplicationLinkOwnersResponse> {
         let request = DesmosQuery::Profiles(ProfilesQuery::ApplicationLinkOwners {
             application,
             username,
             pagination,
         });
 
-        let res: QueryApplicationLinkOwnersResponse = self.querier.query(&request.into())?;
+        let res: QueryApplicationLinkOwnersResponse = query_desmos(&self.querier, &request.into())?;
         Ok(res)
     }
 
@@ -407,7 +435,7 @@ impl<'a> ProfilesQuerier<'a> {
         application: Option<String>,
         username: Option<String>,
         page_size: u64,
-    ) -> PageIterator<ApplicationLinkOwnerDetails, Binary> {
+    ) -> PageIterator<ApplicationLinkOwnerDetails, Binary, DesmosQueryError> {
         PageIterator::new(
             Box::new(move |key, limit| {
                 self.query_application_link_owners(
@@ -434,9 +462,15 @@ impl<'a> ProfilesQuerier<'a> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::errors::DesmosQueryError;
+    use crate::mocks::errors::ChainError;
     use crate::mocks::mock_queriers::mock_desmos_dependencies;
+    use crate::mocks::mock_queriers::QueriedModule;
+    use crate::mocks::mock_queriers::{
+        mock_desmos_dependencies_with_custom_querier, ChainErrorQuerier, MockDesmosQuerier,
+    };
     use crate::profiles::mocks::MockProfilesQueries;
-    use cosmwasm_std::Addr;
+    use cosmwasm_std::{Addr, Binary, ContractResult};
     use std::ops::Deref;
 
     #[test]
@@ -701,4 +735,87 @@ mod tests {
         );
         assert!(it.next().is_none());
     }
+
+    #[test]
+    fn test_find_profile() {
+        let profile = MockProfilesQueries::get_mock_profile();
+        let querier = MockDesmosQuerier::default().with_profile(profile.clone());
+        let owned_deps = mock_desmos_dependencies_with_custom_querier(querier);
+        let deps = owned_deps.as_ref();
+        let profiles_querier = ProfilesQuerier::new(deps.querier.deref());
+
+        assert_eq!(
+            Some(profile.clone()),
+            profiles_querier
+                .find_profile(profile.account.address.clone())
+                .unwrap()
+        );
+        assert_eq!(
+            None,
+            profiles_querier
+                .find_profile(Addr::unchecked(
+                    "desmos1rfv0f7mx7w9d3jv3h803u38vqym9ygg344asm3"
+                ))
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_find_profile_propagates_errors() {
+        let querier = MockDesmosQuerier::default().with_module_error(
+            QueriedModule::Profiles,
+            ChainError::invalid_request("invalid user address"),
+        );
+        let owned_deps = mock_desmos_dependencies_with_custom_querier(querier);
+        let deps = owned_deps.as_ref();
+
+        let error = ProfilesQuerier::new(deps.querier.deref())
+            .find_profile(Addr::unchecked("user"))
+            .unwrap_err();
+        assert!(matches!(error, DesmosQueryError::InvalidRequest { .. }));
+    }
+
+    #[test]
+    fn test_find_profile_with_chain_not_found_error() {
+        // Redacted ErrNotFound error returned by the chain when the queried object does not exist
+        let querier = ProfilesQuerier::new(&ChainErrorQuerier("codespace: sdk, code: 38"));
+
+        assert_eq!(
+            None,
+            querier
+                .find_profile(Addr::unchecked(
+                    "desmos1rfv0f7mx7w9d3jv3h803u38vqym9ygg344asm3"
+                ))
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_find_profile_with_null_profile() {
+        // Response returned by the x/profiles module when the user has not created a profile
+        let querier = MockDesmosQuerier::default().with_custom_profiles_handler(|_| {
+            ContractResult::Ok(Binary::from(br#"{"profile":null}"#.as_slice()))
+        });
+        let owned_deps = mock_desmos_dependencies_with_custom_querier(querier);
+        let deps = owned_deps.as_ref();
+
+        assert_eq!(
+            None,
+            ProfilesQuerier::new(deps.querier.deref())
+                .find_profile(Addr::unchecked("user"))
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_find_profile_propagates_redacted_unregistered_errors() {
+        // Redacted error returned by the chain when the module failed with an unregistered error,
+        // that can't be told apart from a missing object
+        let querier = ProfilesQuerier::new(&ChainErrorQuerier("codespace: undefined, code: 1"));
+
+        assert!(matches!(
+            querier.find_profile(Addr::unchecked("user")),
+            Err(DesmosQueryError::InvalidRequest { codespace, code: 1, .. }) if codespace == "undefined"
+        ));
+    }
 }
@@ -0,0 +1,160 @@
+//! Contains the [`DesmosQuerier`], that gives access to the queriers of all the Desmos modules.
+
+#[cfg(feature = "posts")]
+use crate::posts::querier::PostsQuerier;
+#[cfg(feature = "profiles")]
+use crate::profiles::querier::ProfilesQuerier;
+use crate::query::DesmosQuery;
+#[cfg(feature = "reactions")]
+use crate::reactions::querier::ReactionsQuerier;
+#[cfg(feature = "relationships")]
+use crate::relationships::querier::RelationshipsQuerier;
+#[cfg(feature = "reports")]
+use crate::reports::querier::ReportsQuerier;
+#[cfg(feature = "subspaces")]
+use crate::subspaces::querier::SubspacesQuerier;
+use cosmwasm_std::{Deps, Querier, QuerierWrapper};
+#[cfg(any(
+    feature = "profiles",
+    feature = "subspaces",
+    feature = "relationships",
+    feature = "posts",
+    feature = "reactions",
+    feature = "reports"
+))]
+use std::ops::Deref;
+
+/// Querier able to query data from all the Desmos modules enabled with the crate features.
+pub struct DesmosQuerier<'a> {
+    // Hide this warning since when we compile the package without any module feature
+    // there is no module querier to build.
+    #[cfg_attr(
+        not(any(
+            feature = "profiles",
+            feature = "subspaces",
+            feature = "relationships",
+            feature = "posts",
+            feature = "reactions",
+            feature = "reports"
+        )),
+        allow(dead_code)
+    )]
+    querier: QuerierWrapper<'a, DesmosQuery>,
+}
+
+impl<'a> DesmosQuerier<'a> {
+    /// Creates a new instance of [`DesmosQuerier`].
+    ///
+    /// # Example
+    /// ```
+    /// use cosmwasm_std::{Addr, Deps, StdResult};
+    /// use desmos_bindings::querier::DesmosQuerier;
+    /// use desmos_bindings::query::DesmosQuery;
+    /// use std::ops::Deref;
+    ///
+    /// pub fn is_subspace_owner(deps: Deps<DesmosQuery>, subspace_id: u64, user: Addr) -> StdResult<bool> {
+    ///     let querier = DesmosQuerier::new(deps.querier.deref());
+    ///     let subspace = querier.subspaces().query_subspace(subspace_id)?.subspace;
+    ///     Ok(subspace.owner == user)
+    /// }
+    /// ```
+    pub fn new(querier: &'a dyn Querier) -> Self {
+        Self {
+            querier: QuerierWrapper::new(querier),
+        }
+    }
+
+    /// Gives the querier of the x/profiles module.
+    #[cfg(feature = "profiles")]
+    pub fn profiles(&self) -> ProfilesQuerier<'_> {
+        ProfilesQuerier::new(self.querier.deref())
+    }
+
+    /// Gives the querier of the x/subspaces module.
+    #[cfg(feature = "subspaces")]
+    pub fn subspaces(&self) -> SubspacesQuerier<'_> {
+        SubspacesQuerier::new(self.querier.deref())
+    }
+
+    /// Gives the querier of the x/relationships module.
+    #[cfg(feature = "relationships")]
+    pub fn relationships(&self) -> RelationshipsQuerier<'_> {
+        RelationshipsQuerier::new(self.querier.deref())
+    }
+
+    /// Gives the querier of the x/posts module.
+    #[cfg(feature = "posts")]
+    pub fn posts(&self) -> PostsQuerier<'_> {
+        PostsQuerier::new(self.querier.deref())
+    }
+
+    /// Gives the querier of the x/reactions module.
+    #[cfg(feature = "reactions")]
+    pub fn reactions(&self) -> ReactionsQuerier<'_> {
+        ReactionsQuerier::new(self.querier.deref())
+    }
+
+    /// Gives the querier of the x/reports module.
+    #[cfg(feature = "reports")]
+    pub fn reports(&self) -> ReportsQuerier<'_> {
+        ReportsQuerier::new(self.querier.deref())
+    }
+}
+
+impl<'a> From<QuerierWrapper<'a, DesmosQuery>> for DesmosQuerier<'a> {
+    fn from(querier: QuerierWrapper<'a, DesmosQuery>) -> Self {
+        Self { querier }
+    }
+}
+
+/// Creates a [`DesmosQuerier`] using the querier of the given [`Deps`].
+///
+/// # Example
+/// ```
+/// use cosmwasm_std::{Addr, Deps, StdResult};
+/// use desmos_bindings::querier::DesmosQuerier;
+/// use desmos_bindings::query::DesmosQuery;
+///
+/// pub fn is_subspace_owner(deps: Deps<DesmosQuery>, subspace_id: u64, user: Addr) -> StdResult<bool> {
+///     let querier = DesmosQuerier::from(deps);
+///     let subspace = querier.subspaces().query_subspace(subspace_id)?.subspace;
+///     Ok(subspace.owner == user)
+/// }
+/// ```
+impl<'a> From<Deps<'a, DesmosQuery>> for DesmosQuerier<'a> {
+    fn from(deps: Deps<'a, DesmosQuery>) -> Self {
+        Self::from(deps.querier)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::mocks::mock_queriers::mock_desmos_dependencies;
+    use crate::profiles::mocks::MockProfilesQueries;
+    use crate::querier::DesmosQuerier;
+    use crate::subspaces::mocks::MockSubspacesQueries;
+    use cosmwasm_std::Addr;
+
+    #[test]
+    fn test_query_modules() {
+        let owned_deps = mock_desmos_dependencies();
+        let querier = DesmosQuerier::from(owned_deps.as_ref());
+
+        let response = querier
+            .profiles()
+            .query_profile(Addr::unchecked(""))
+            .unwrap();
+        assert_eq!(MockProfilesQueries::get_mock_profile(), response.profile);
+
+        let response = querier.subspaces().query_subspace(1).unwrap();
+        assert_eq!(MockSubspacesQueries::get_mock_subspace(), response.subspace);
+    }
+
+    #[test]
+    fn test_from_querier_wrapper() {
+        let owned_deps = mock_desmos_dependencies();
+        let querier = DesmosQuerier::from(owned_deps.as_ref().querier);
+
+        assert!(querier.posts().query_post(1, 1).is_ok());
+    }
+}
@@ -1,5 +1,6 @@
 //! Implementations of [cosmwasm_std::CustomQuery] for [DesmosQuery].
 
+use crate::errors::{DesmosQueryError, DesmosQueryResult};
 #[cfg(feature = "posts")]
 use crate::posts::query::PostsQuery;
 #[cfg(feature = "profiles")]
@@ -12,8 +13,12 @@ use crate::relationships::query::RelationshipsQuery;
 use crate::reports::query::ReportsQuery;
 #[cfg(feature = "subspaces")]
 use crate::subspaces::query::SubspacesQuery;
-use cosmwasm_std::{CustomQuery, QueryRequest};
+use cosmwasm_std::{
+    from_binary, to_vec, Binary, ContractResult, CustomQuery, QuerierWrapper, QueryRequest,
+    StdError, SystemError, SystemResult,
+};
 use schemars::JsonSchema;
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 
 // Use the serde `rename_all` tag in order to produce the following json file structure
@@ -55,6 +60,43 @@ pub enum DesmosQuery {
 
 impl CustomQuery for DesmosQuery {}
 
+/// Performs the given query toward a Desmos module, decoding the error returned by the chain
+/// if the query fails.
+// Hide this warning since when we compile the package without any module feature
+// there is no querier using this function.
+#[allow(dead_code)]
+pub(crate) fn query_desmos<T: DeserializeOwned>(
+    querier: &QuerierWrapper<DesmosQuery>,
+    request: &QueryRequest<DesmosQuery>,
+) -> DesmosQueryResult<T> {
+    let raw_request = to_vec(request).map_err(|error| {
+        DesmosQueryError::System(SystemError::InvalidRequest {
+            error: format!("Serializing QueryRequest: {}", error),
+            request: Binary::default(),
+        })
+    })?;
+    match querier.raw_query(&raw_request) {
+        SystemResult::Err(error) => Err(DesmosQueryError::System(error)),
+        SystemResult::Ok(ContractResult::Err(error)) => {
+            Err(DesmosQueryError::from_chain_error(&error))
+        }
+        SystemResult::Ok(ContractResult::Ok(response)) => {
+            from_binary(&response).map_err(|error| match error {
+                StdError::ParseErr {
+                    target_type, msg, ..
+                } => DesmosQueryError::ParseResponse {
+                    target_type,
+                    message: msg,
+                },
+                error => DesmosQueryError::ParseResponse {
+                    target_type: std::any::type_name::<T>().to_string(),
+                    message: error.to_string(),
+                },
+            })
+        }
+    }
+}
+
 #[cfg(feature = "profiles")]
 impl From<ProfilesQuery> for DesmosQuery {
     fn from(query: ProfilesQuery) -> Self {
@@ -0,0 +1,171 @@
+//! Contains the [`Arbitrary`](proptest::arbitrary::Arbitrary) implementations of the
+//! x/reactions module models, query responses and messages, along with the strategies used to
+//! build them.
+
+use crate::arbitrary::{addr, id, impl_arbitrary, page, small_id, text, uri};
+use crate::reactions::models::{
+    FreeTextValueParams, RawReactionValue, Reaction, ReactionValue, RegisteredReaction,
+    RegisteredReactionValueParams, SubspaceReactionsParams,
+};
+use crate::reactions::models_query::{
+    QueryReactionResponse, QueryReactionsParamsResponse, QueryReactionsResponse,
+    QueryRegisteredReactionResponse, QueryRegisteredReactionsResponse,
+};
+#[cfg(feature = "msg")]
+use crate::reactions::msg::ReactionsMsg;
+use proptest::prelude::*;
+
+/// Returns a strategy generating the shorthand codes of the registered reactions (e.g. `:smile:`).
+pub fn shorthand_code() -> impl Strategy<Value = String> {
+    ":[a-z0-9_]{1,20}:"
+}
+
+/// Returns a strategy generating the values displayed for the registered reactions.
+pub fn display_value() -> impl Strategy<Value = String> {
+    prop_oneof![text(10), uri()]
+}
+
+/// Returns a strategy generating the regular expressions that free text reactions must match.
+pub fn reg_ex() -> impl Strategy<Value = String> {
+    prop_oneof![
+        Just(String::new()),
+        Just("[a-zA-Z0-9]".to_string()),
+        Just("^[a-z ]+$".to_string()),
+        Just("[\\w ]".to_string()),
+    ]
+}
+
+impl_arbitrary! {
+    ReactionValue => prop_oneof![
+        small_id().prop_map(|registered_reaction_id| ReactionValue::Registered {
+            registered_reaction_id,
+        }),
+        text(100).prop_map(|text| ReactionValue::FreeText { text }),
+    ];
+    RawReactionValue => any::<ReactionValue>().prop_map(RawReactionValue::from);
+    Reaction => (id(), id(), small_id(), any::<RawReactionValue>(), addr()).prop_map(
+        |(subspace_id, post_id, id, value, author)| Reaction {
+            subspace_id,
+            post_id,
+            id,
+            value,
+            author,
+        },
+    );
+    RegisteredReaction => (id(), small_id(), shorthand_code(), display_value()).prop_map(
+        |(subspace_id, id, shorthand_code, display_value)| RegisteredReaction {
+            subspace_id,
+            id,
+            shorthand_code,
+            display_value,
+        },
+    );
+    FreeTextValueParams => (any::<bool>(), 1..=1000u32, reg_ex()).prop_map(
+        |(enabled, max_length, reg_ex)| FreeTextValueParams {
+            enabled,
+            max_length,
+            reg_ex,
+        },
+    );
+    RegisteredReactionValueParams =>
+        any::<bool>().prop_map(|enabled| RegisteredReactionValueParams { enabled });
+    SubspaceReactionsParams => (
+        id(),
+        any::<RegisteredReactionValueParams>(),
+        any::<FreeTextValueParams>(),
+    )
+        .prop_map(|(subspace_id, registered_reaction, free_text)| SubspaceReactionsParams {
+            subspace_id,
+            registered_reaction,
+            free_text,
+        });
+}
+
+impl_arbitrary! {
+    QueryReactionsResponse => page()
+        .prop_map(|(reactions, pagination)| QueryReactionsResponse { reactions, pagination });
+    QueryReactionResponse => any::<Reaction>().prop_map(|reaction| QueryReactionResponse { reaction });
+    QueryRegisteredReactionsResponse => page().prop_map(|(registered_reactions, pagination)| {
+        QueryRegisteredReactionsResponse {
+            registered_reactions,
+            pagination,
+        }
+    });
+    QueryRegisteredReactionResponse => any::<RegisteredReaction>().prop_map(|registered_reaction| {
+        QueryRegisteredReactionResponse {
+            registered_reaction,
+        }
+    });
+    QueryReactionsParamsResponse => any::<SubspaceReactionsParams>()
+        .prop_map(|params| QueryReactionsParamsResponse { params });
+}
+
+#[cfg(feature = "msg")]
+impl_arbitrary! {
+    ReactionsMsg => prop_oneof![
+        (id(), id(), any::<RawReactionValue>(), addr()).prop_map(
+            |(subspace_id, post_id, value, user)| ReactionsMsg::AddReaction {
+                subspace_id,
+                post_id,
+                value,
+                user,
+            },
+        ),
+        (id(), id(), small_id(), addr()).prop_map(|(subspace_id, post_id, reaction_id, user)| {
+            ReactionsMsg::RemoveReaction {
+                subspace_id,
+                post_id,
+                reaction_id,
+                user,
+            }
+        }),
+        (id(), shorthand_code(), display_value(), addr()).prop_map(
+            |(subspace_id, shorthand_code, display_value, user)| {
+                ReactionsMsg::AddRegisteredReaction {
+                    subspace_id,
+                    shorthand_code,
+                    display_value,
+                    user,
+                }
+            },
+        ),
+        (any::<RegisteredReaction>(), addr()).prop_map(|(reaction, user)| {
+            ReactionsMsg::EditRegisteredReaction {
+                subspace_id: reaction.subspace_id,
+                registered_reaction_id: reaction.id,
+                shorthand_code: reaction.shorthand_code,
+                display_value: reaction.display_value,
+                user,
+            }
+        }),
+        (id(), small_id(), addr()).prop_map(|(subspace_id, registered_reaction_id, user)| {
+            ReactionsMsg::RemoveRegisteredReaction {
+                subspace_id,
+                registered_reaction_id,
+                user,
+            }
+        }),
+        (any::<SubspaceReactionsParams>(), addr()).prop_map(|(params, user)| {
+            ReactionsMsg::SetReactionsParams {
+                subspace_id: params.subspace_id,
+                registered_reaction: params.registered_reaction,
+                free_text: params.free_text,
+                user,
+            }
+        }),
+    ];
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::reactions::models::{Reaction, ReactionValue};
+    use proptest::prelude::*;
+    use std::convert::TryFrom;
+
+    proptest! {
+        #[test]
+        fn reaction_values_are_well_formed(reaction in any::<Reaction>()) {
+            prop_assert!(ReactionValue::try_from(reaction.value).is_ok());
+        }
+    }
+}
@@ -0,0 +1,70 @@
+//! Contains the events emitted by the Desmos x/reactions module.
+//!
+//! Besides the [`MessageEvent`](crate::events::MessageEvent), each [`ReactionsMsg`](crate::reactions::msg::ReactionsMsg)
+//! emits the following events:
+//!
+//! | Message                    | Event type                   | Attributes                                      |
+//! |----------------------------|------------------------------|-------------------------------------------------|
+//! | `AddReaction`              | `add_reaction`               | `subspace_id`, `post_id`, `reaction_id`, `user` |
+//! | `RemoveReaction`           | `remove_reaction`            | `subspace_id`, `post_id`, `reaction_id`         |
+//! | `AddRegisteredReaction`    | `add_registered_reaction`    | `subspace_id`, `registered_reaction_id`         |
+//! | `EditRegisteredReaction`   | `edit_registered_reaction`   | `subspace_id`, `registered_reaction_id`         |
+//! | `RemoveRegisteredReaction` | `remove_registered_reaction` | `subspace_id`, `registered_reaction_id`         |
+//! | `SetReactionsParams`       | `set_reactions_params`       | `subspace_id`                                   |
+
+use crate::events::desmos_events;
+use cosmwasm_std::Addr;
+
+desmos_events! {
+    /// Event emitted when a reaction is added to a post.
+    AddReactionEvent = "add_reaction" {
+        /// Id of the subspace containing the post.
+        subspace_id: u64 = "subspace_id",
+        /// Id of the post.
+        post_id: u64 = "post_id",
+        /// Id of the added reaction.
+        reaction_id: u32 = "reaction_id",
+        /// Address of the reaction author.
+        user: Addr = "user",
+    }
+
+    /// Event emitted when a reaction is removed from a post.
+    RemoveReactionEvent = "remove_reaction" {
+        /// Id of the subspace containing the post.
+        subspace_id: u64 = "subspace_id",
+        /// Id of the post.
+        post_id: u64 = "post_id",
+        /// Id of the removed reaction.
+        reaction_id: u32 = "reaction_id",
+    }
+
+    /// Event emitted when a registered reaction is added to a subspace.
+    AddRegisteredReactionEvent = "add_registered_reaction" {
+        /// Id of the subspace.
+        subspace_id: u64 = "subspace_id",
+        /// Id of the added registered reaction.
+        registered_reaction_id: u32 = "registered_reaction_id",
+    }
+
+    /// Event emitted when a registered reaction is edited.
+    EditRegisteredReactionEvent = "edit_registered_reaction" {
+        /// Id of the subspace.
+        subspace_id: u64 = "subspace_id",
+        /// Id of the edited registered reaction.
+        registered_reaction_id: u32 = "registered_reaction_id",
+    }
+
+    /// Event emitted when a registered reaction is removed from a subspace.
+    RemoveRegisteredReactionEvent = "remove_registered_reaction" {
+        /// Id of the subspace.
+        subspace_id: u64 = "subspace_id",
+        /// Id of the removed registered reaction.
+        registered_reaction_id: u32 = "registered_reaction_id",
+    }
+
+    /// Event emitted when the reactions params of a subspace are set.
+    SetReactionsParamsEvent = "set_reactions_params" {
+        /// Id of the subspace.
+        subspace_id: u64 = "subspace_id",
+    }
+}
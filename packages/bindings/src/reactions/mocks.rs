@@ -1,5 +1,6 @@
 //! Contains some useful mocks of the Desmos x/reactions module's types made to be used in any test.
 
+use crate::pagination::paginate;
 use crate::reactions::{
     models::{
         FreeTextValueParams, Reaction, ReactionValue, RegisteredReaction,
@@ -59,23 +60,29 @@ impl MockReactionsQueries {
 /// Functions that mocks the reactions query responses.
 pub fn mock_reactions_query_response(query: &ReactionsQuery) -> ContractResult<Binary> {
     let response = match query {
-        ReactionsQuery::Reactions { .. } => {
+        ReactionsQuery::Reactions { pagination, .. } => {
             let reaction = MockReactionsQueries::get_mock_reaction();
-            to_binary(&QueryReactionsResponse {
-                reactions: vec![reaction],
-                pagination: Default::default(),
+            paginate(vec![reaction], pagination.as_ref()).and_then(|(reactions, pagination)| {
+                to_binary(&QueryReactionsResponse {
+                    reactions,
+                    pagination,
+                })
             })
         }
         ReactionsQuery::Reaction { .. } => {
             let reaction = MockReactionsQueries::get_mock_reaction();
             to_binary(&QueryReactionResponse { reaction })
         }
-        ReactionsQuery::RegisteredReactions { .. } => {
+        ReactionsQuery::RegisteredReactions { pagination, .. } => {
             let registered_reaction = MockReactionsQueries::get_mock_registered_reaction();
-            to_binary(&QueryRegisteredReactionsResponse {
-                registered_reactions: vec![registered_reaction],
-                pagination: Default::default(),
-            })
+            paginate(vec![registered_reaction], pagination.as_ref()).and_then(
+                |(registered_reactions, pagination)| {
+                    to_binary(&QueryRegisteredReactionsResponse {
+                        registered_reactions,
+                        pagination,
+                    })
+                },
+            )
         }
         ReactionsQuery::RegisteredReaction { .. } => {
             let registered_reaction = MockReactionsQueries::get_mock_registered_reaction();
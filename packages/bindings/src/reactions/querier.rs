@@ -2,15 +2,17 @@
 
 #[cfg(feature = "iterators")]
 use crate::{
+    errors::DesmosQueryError,
     iter::page_iterator::{Page, PageIterator},
-    reactions::models::{Reaction, RegisteredReaction},
 };
 #[cfg(feature = "iterators")]
 use cosmwasm_std::Binary;
 
 use crate::{
-    query::DesmosQuery,
+    errors::{not_found_as_none, DesmosQueryResult},
+    query::{query_desmos, DesmosQuery},
     reactions::{
+        models::{Reaction, RegisteredReaction},
         models_query::{
             QueryReactionResponse, QueryReactionsParamsResponse, QueryReactionsResponse,
             QueryRegisteredReactionResponse, QueryRegisteredReactionsResponse,
@@ -19,7 +21,7 @@ use crate::{
     },
     types::PageRequest,
 };
-use cosmwasm_std::{Addr, Querier, QuerierWrapper, StdResult};
+use cosmwasm_std::{Addr, Querier, QuerierWrapper};
 
 /// Querier able to query data from the Desmos x/reactions module.
 pub struct ReactionsQuerier<'a> {
@@ -60,14 +62,14 @@ impl<'a> ReactionsQuerier<'a> {
         post_id: u64,
         user: Option<Addr>,
         pagination: Option<PageRequest>,
-    ) -> StdResult<QueryReactionsResponse> {
+    ) -> DesmosQueryResult<QueryReactionsResponse> {
         let request = DesmosQuery::from(ReactionsQuery::Reactions {
             subspace_id: subspace_id.into(),
             post_id: post_id.into(),
             user,
             pagination,
         });
-        let res: QueryReactionsResponse = self.querier.query(&request.into())?;
+        let res: QueryReactionsResponse = query_desmos(&self.querier, &request.into())?;
         Ok(res)
     }
 
@@ -84,7 +86,7 @@ impl<'a> ReactionsQuerier<'a> {
         post_id: u64,
         user: Option<Addr>,
         page_size: u64,
-    ) -> PageIterator<Reaction, Binary> {
+    ) -> PageIterator<Reaction, Binary, DesmosQueryError> {
         PageIterator::new(
             Box::new(move |key, limit| {
                 self.query_reactions(
@@ -121,16 +123,33 @@ impl<'a> ReactionsQuerier<'a> {
         subspace_id: u64,
         post_id: u64,
         reaction_id: u32,
-    ) -> StdResult<QueryReactionResponse> {
+    ) -> DesmosQueryResult<QueryReactionResponse> {
         let request = DesmosQuery::from(ReactionsQuery::Reaction {
             subspace_id: subspace_id.into(),
             post_id: post_id.into(),
             reaction_id,
         });
-        let res: QueryReactionResponse = self.querier.query(&request.into())?;
+        let res: QueryReactionResponse = query_desmos(&self.querier, &request.into())?;
         Ok(res)
     }
 
+    /// Gives a single reaction added to a post, or `None` if it does not exist.
+    ///
+    /// * `subspace_id` - Id of the subspace where the post is stored.
+    /// * `post_id` - Id of the post to query the reaction from.
+    /// * `reaction_id` - Id of the reaction to query.
+    pub fn find_reaction(
+        &self,
+        subspace_id: u64,
+        post_id: u64,
+        reaction_id: u32,
+    ) -> DesmosQueryResult<Option<Reaction>> {
+        Ok(
+            not_found_as_none(self.query_reaction(subspace_id, post_id, reaction_id))?
+                .map(|response| response.reaction),
+        )
+    }
+
     /// Queries all the reactions registered inside a subspace.
     ///
     /// * `subspace_id` - Id of the subspace to query the registered reactions for.
@@ -139,12 +158,12 @@ impl<'a> ReactionsQuerier<'a> {
         &self,
         subspace_id: u64,
         pagination: Option<PageRequest>,
-    ) -> StdResult<QueryRegisteredReactionsResponse> {
+    ) -> DesmosQueryResult<QueryRegisteredReactionsResponse> {
         let request = DesmosQuery::from(ReactionsQuery::RegisteredReactions {
             subspace_id: subspace_id.into(),
             pagination,
         });
-        let res: QueryRegisteredReactionsResponse = self.querier.query(&request.into())?;
+        let res: QueryRegisteredReactionsResponse = query_desmos(&self.querier, &request.into())?;
         Ok(res)
     }
 
@@ -157,7 +176,7 @@ impl<'a> ReactionsQuerier<'a> {
         &self,
         subspace_id: u64,
         page_size: u64,
-    ) -> PageIterator<RegisteredReaction, Binary> {
+    ) -> PageIterator<RegisteredReaction, Binary, DesmosQueryError> {
         PageIterator::new(
             Box::new(move |key, limit| {
                 self.query_registered_reactions(
@@ -189,26 +208,41 @@ impl<'a> ReactionsQuerier<'a> {
         &self,
         subspace_id: u64,
         reaction_id: u32,
-    ) -> StdResult<QueryRegisteredReactionResponse> {
+    ) -> DesmosQueryResult<QueryRegisteredReactionResponse> {
         let request = DesmosQuery::from(ReactionsQuery::RegisteredReaction {
             subspace_id: subspace_id.into(),
             reaction_id,
         });
-        let res: QueryRegisteredReactionResponse = self.querier.query(&request.into())?;
+        let res: QueryRegisteredReactionResponse = query_desmos(&self.querier, &request.into())?;
         Ok(res)
     }
 
+    /// Gives a single registered reaction of a subspace, or `None` if it does not exist.
+    ///
+    /// * `subspace_id` - Id of the subspace to query the registered reaction from.
+    /// * `reaction_id` - Id of the registered reaction to query.
+    pub fn find_registered_reaction(
+        &self,
+        subspace_id: u64,
+        reaction_id: u32,
+    ) -> DesmosQueryResult<Option<RegisteredReaction>> {
+        Ok(
+            not_found_as_none(self.query_registered_reaction(subspace_id, reaction_id))?
+                .map(|response| response.registered_reaction),
+        )
+    }
+
     /// Queries the reactions parameters inside the given subspace.
     ///
     /// * `subspace_id` - Id of the subspace to query the reactions parameters for.
     pub fn query_reactions_params(
         &self,
         subspace_id: u64,
-    ) -> StdResult<QueryReactionsParamsResponse> {
+    ) -> DesmosQueryResult<QueryReactionsParamsResponse> {
         let request = DesmosQuery::from(ReactionsQuery::ReactionsParams {
             subspace_id: subspace_id.into(),
         });
-        let res: QueryReactionsParamsResponse = self.querier.query(&request.into())?;
+        let res: QueryReactionsParamsResponse = query_desmos(&self.querier, &request.into())?;
         Ok(res)
     }
 }
@@ -216,7 +250,12 @@ impl<'a> ReactionsQuerier<'a> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::errors::DesmosQueryError;
+    use crate::mocks::errors::ChainError;
     use crate::mocks::mock_queriers::mock_desmos_dependencies;
+    use crate::mocks::mock_queriers::{
+        mock_desmos_dependencies_with_custom_querier, ChainErrorQuerier, MockDesmosQuerier,
+    };
     use crate::reactions::mocks::MockReactionsQueries;
     use std::ops::Deref;
 
@@ -281,4 +320,63 @@ mod tests {
         };
         assert_eq!(response.ok(), Some(expected));
     }
+
+    #[test]
+    fn test_find_reactions() {
+        let querier = MockDesmosQuerier::default()
+            .with_query_error(
+                ReactionsQuery::Reaction {
+                    subspace_id: 1u64.into(),
+                    post_id: 1u64.into(),
+                    reaction_id: 2,
+                },
+                ChainError::reaction_not_found(2),
+            )
+            .with_query_error(
+                ReactionsQuery::RegisteredReaction {
+                    subspace_id: 1u64.into(),
+                    reaction_id: 2,
+                },
+                ChainError::not_found("registered reaction with id 2 not found"),
+            );
+        let owned_deps = mock_desmos_dependencies_with_custom_querier(querier);
+        let deps = owned_deps.as_ref();
+        let querier = ReactionsQuerier::new(deps.querier.deref());
+
+        assert_eq!(
+            Some(MockReactionsQueries::get_mock_reaction()),
+            querier.find_reaction(1, 1, 1).unwrap()
+        );
+        assert_eq!(None, querier.find_reaction(1, 1, 2).unwrap());
+        assert_eq!(
+            Some(MockReactionsQueries::get_mock_registered_reaction()),
+            querier.find_registered_reaction(1, 1).unwrap()
+        );
+        assert_eq!(None, querier.find_registered_reaction(1, 2).unwrap());
+    }
+
+    #[test]
+    fn test_find_reactions_with_chain_not_found_error() {
+        // Redacted ErrNotFound error returned by the chain when the queried object does not exist
+        let querier = ReactionsQuerier::new(&ChainErrorQuerier("codespace: sdk, code: 38"));
+
+        assert_eq!(None, querier.find_reaction(1, 1, 2).unwrap());
+        assert_eq!(None, querier.find_registered_reaction(1, 2).unwrap());
+    }
+
+    #[test]
+    fn test_find_reactions_propagates_redacted_unregistered_errors() {
+        // Redacted error returned by the chain when the module failed with an unregistered error,
+        // that can't be told apart from a missing object
+        let querier = ReactionsQuerier::new(&ChainErrorQuerier("codespace: undefined, code: 1"));
+
+        assert!(matches!(
+            querier.find_reaction(1, 1, 2),
+            Err(DesmosQueryError::InvalidRequest { codespace, code: 1, .. }) if codespace == "undefined"
+        ));
+        assert!(matches!(
+            querier.find_registered_reaction(1, 2),
+            Err(DesmosQueryError::InvalidRequest { codespace, code: 1, .. }) if codespace == "undefined"
+        ));
+    }
 }
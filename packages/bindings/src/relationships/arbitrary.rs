@@ -0,0 +1,97 @@
+//! Contains the [`Arbitrary`](proptest::arbitrary::Arbitrary) implementations of the
+//! x/relationships module models, query responses and messages, along with the strategies used to
+//! build them.
+
+use crate::arbitrary::{distinct_addrs, impl_arbitrary, page, text};
+use crate::relationships::models::{Relationship, UserBlock};
+use crate::relationships::models_query::{QueryBlocksResponse, QueryRelationshipsResponse};
+#[cfg(feature = "msg")]
+use crate::relationships::msg::RelationshipsMsg;
+use cosmwasm_std::Uint64;
+use proptest::prelude::*;
+
+/// Returns a strategy generating the ids of the subspaces in which the relationships are created.
+/// The zero id is included since it refers to the relationships that are valid across all the subspaces.
+pub fn relationship_subspace_id() -> impl Strategy<Value = Uint64> {
+    prop_oneof![Just(Uint64::zero()), any::<u64>().prop_map(Uint64::new)]
+}
+
+/// Returns a strategy generating the reasons for which a user is blocked.
+pub fn block_reason() -> impl Strategy<Value = String> {
+    prop_oneof![Just(String::new()), text(500)]
+}
+
+impl_arbitrary! {
+    // A user can't create a relationship with or block himself.
+    Relationship => (distinct_addrs(), relationship_subspace_id()).prop_map(
+        |((creator, counterparty), subspace_id)| Relationship {
+            creator,
+            counterparty,
+            subspace_id,
+        },
+    );
+    UserBlock => (distinct_addrs(), block_reason(), relationship_subspace_id()).prop_map(
+        |((blocker, blocked), reason, subspace_id)| UserBlock {
+            blocker,
+            blocked,
+            reason,
+            subspace_id,
+        },
+    );
+}
+
+impl_arbitrary! {
+    QueryRelationshipsResponse => page().prop_map(|(relationships, pagination)| {
+        QueryRelationshipsResponse {
+            relationships,
+            pagination,
+        }
+    });
+    QueryBlocksResponse => page()
+        .prop_map(|(blocks, pagination)| QueryBlocksResponse { blocks, pagination });
+}
+
+#[cfg(feature = "msg")]
+impl_arbitrary! {
+    RelationshipsMsg => prop_oneof![
+        any::<Relationship>().prop_map(|relationship| RelationshipsMsg::CreateRelationship {
+            signer: relationship.creator,
+            counterparty: relationship.counterparty,
+            subspace_id: relationship.subspace_id,
+        }),
+        any::<Relationship>().prop_map(|relationship| RelationshipsMsg::DeleteRelationship {
+            signer: relationship.creator,
+            counterparty: relationship.counterparty,
+            subspace_id: relationship.subspace_id,
+        }),
+        any::<UserBlock>().prop_map(|block| RelationshipsMsg::BlockUser {
+            blocker: block.blocker,
+            blocked: block.blocked,
+            reason: block.reason,
+            subspace_id: block.subspace_id,
+        }),
+        any::<UserBlock>().prop_map(|block| RelationshipsMsg::UnblockUser {
+            blocker: block.blocker,
+            blocked: block.blocked,
+            subspace_id: block.subspace_id,
+        }),
+    ];
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::relationships::models::{Relationship, UserBlock};
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn relationships_involve_two_users(relationship in any::<Relationship>()) {
+            prop_assert_ne!(relationship.creator, relationship.counterparty);
+        }
+
+        #[test]
+        fn users_never_block_themselves(block in any::<UserBlock>()) {
+            prop_assert_ne!(block.blocker, block.blocked);
+        }
+    }
+}
@@ -0,0 +1,56 @@
+//! Contains the events emitted by the Desmos x/relationships module.
+//!
+//! Besides the [`MessageEvent`](crate::events::MessageEvent), each [`RelationshipsMsg`](crate::relationships::msg::RelationshipsMsg)
+//! emits the following events:
+//!
+//! | Message              | Event type            | Attributes                            |
+//! |----------------------|-----------------------|---------------------------------------|
+//! | `CreateRelationship` | `create_relationship` | `creator`, `counterparty`, `subspace` |
+//! | `DeleteRelationship` | `delete_relationship` | `creator`, `counterparty`, `subspace` |
+//! | `BlockUser`          | `block_user`          | `blocker`, `blocked`, `subspace`      |
+//! | `UnblockUser`        | `unblock_user`        | `blocker`, `blocked`, `subspace`      |
+
+use crate::events::desmos_events;
+use cosmwasm_std::Addr;
+
+desmos_events! {
+    /// Event emitted when a relationship is created.
+    CreateRelationshipEvent = "create_relationship" {
+        /// Address of the relationship creator.
+        creator: Addr = "creator",
+        /// Address of the relationship counterparty.
+        counterparty: Addr = "counterparty",
+        /// Id of the subspace in which the relationship is created.
+        subspace_id: u64 = "subspace",
+    }
+
+    /// Event emitted when a relationship is deleted.
+    DeleteRelationshipEvent = "delete_relationship" {
+        /// Address of the relationship creator.
+        creator: Addr = "creator",
+        /// Address of the relationship counterparty.
+        counterparty: Addr = "counterparty",
+        /// Id of the subspace in which the relationship is deleted.
+        subspace_id: u64 = "subspace",
+    }
+
+    /// Event emitted when a user is blocked.
+    BlockUserEvent = "block_user" {
+        /// Address of the user that blocked the other one.
+        blocker: Addr = "blocker",
+        /// Address of the blocked user.
+        blocked: Addr = "blocked",
+        /// Id of the subspace in which the user is blocked.
+        subspace_id: u64 = "subspace",
+    }
+
+    /// Event emitted when a user is unblocked.
+    UnblockUserEvent = "unblock_user" {
+        /// Address of the user that unblocked the other one.
+        blocker: Addr = "blocker",
+        /// Address of the unblocked user.
+        blocked: Addr = "blocked",
+        /// Id of the subspace in which the user is unblocked.
+        subspace_id: u64 = "subspace",
+    }
+}
@@ -1,5 +1,6 @@
 //! Contains some useful mocks of the Desmos x/relationships module's types made to be used in any test.
 
+use crate::pagination::paginate;
 use crate::relationships::{
     models::{Relationship, UserBlock},
     models_query::{QueryBlocksResponse, QueryRelationshipsResponse},
@@ -35,18 +36,21 @@ impl MockRelationshipsQueries {
 /// Functions that mocks the relationships query responses.
 pub fn mock_relationships_query_response(query: &RelationshipsQuery) -> ContractResult<Binary> {
     let response = match query {
-        RelationshipsQuery::Relationships { .. } => {
+        RelationshipsQuery::Relationships { pagination, .. } => {
             let relationship = MockRelationshipsQueries::get_mock_relationship();
-            to_binary(&QueryRelationshipsResponse {
-                relationships: vec![relationship],
-                pagination: Default::default(),
-            })
+            paginate(vec![relationship], pagination.as_ref()).and_then(
+                |(relationships, pagination)| {
+                    to_binary(&QueryRelationshipsResponse {
+                        relationships,
+                        pagination,
+                    })
+                },
+            )
         }
-        RelationshipsQuery::Blocks { .. } => {
+        RelationshipsQuery::Blocks { pagination, .. } => {
             let block = MockRelationshipsQueries::get_mock_user_block();
-            to_binary(&QueryBlocksResponse {
-                blocks: vec![block],
-                pagination: Default::default(),
+            paginate(vec![block], pagination.as_ref()).and_then(|(blocks, pagination)| {
+                to_binary(&QueryBlocksResponse { blocks, pagination })
             })
         }
     };
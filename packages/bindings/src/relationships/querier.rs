@@ -2,6 +2,7 @@
 
 #[cfg(feature = "iterators")]
 use crate::{
+    errors::DesmosQueryError,
     iter::page_iterator::{Page, PageIterator},
     relationships::models::{Relationship, UserBlock},
 };
@@ -9,14 +10,15 @@ use crate::{
 use cosmwasm_std::Binary;
 
 use crate::{
-    query::DesmosQuery,
+    errors::DesmosQueryResult,
+    query::{query_desmos, DesmosQuery},
     relationships::{
         models_query::{QueryBlocksResponse, QueryRelationshipsResponse},
         query::RelationshipsQuery,
     },
     types::PageRequest,
 };
-use cosmwasm_std::{Addr, Querier, QuerierWrapper, StdResult};
+use cosmwasm_std::{Addr, Querier, QuerierWrapper};
 
 /// Querier able to query data from the Desmos x/relationships module.
 pub struct RelationshipsQuerier<'a> {
@@ -55,7 +57,7 @@ impl<'a> RelationshipsQuerier<'a> {
         user: Option<Addr>,
         counterparty: Option<Addr>,
         pagination: Option<PageRequest>,
-    ) -> StdResult<QueryRelationshipsResponse> {
+    ) -> DesmosQueryResult<QueryRelationshipsResponse> {
         let request = DesmosQuery::Relationships(RelationshipsQuery::Relationships {
             subspace_id: subspace_id.into(),
             user,
@@ -63,7 +65,7 @@ impl<'a> RelationshipsQuerier<'a> {
             pagination,
         });
 
-        let res: QueryRelationshipsResponse = self.querier.query(&request.into())?;
+        let res: QueryRelationshipsResponse = query_desmos(&self.querier, &request.into())?;
         Ok(res)
     }
 
@@ -79,7 +81,7 @@ impl<'a> RelationshipsQuerier<'a> {
         subspace_id: u64,
         user: Option<Addr>,
         page_size: u64,
-    ) -> PageIterator<Relationship, Binary> {
+    ) -> PageIterator<Relationship, Binary, DesmosQueryError> {
         PageIterator::new(
             Box::new(move |key, limit| {
                 self.query_relationships(
@@ -118,7 +120,7 @@ impl<'a> RelationshipsQuerier<'a> {
         blocker: Option<Addr>,
         blocked: Option<Addr>,
         pagination: Option<PageRequest>,
-    ) -> StdResult<QueryBlocksResponse> {
+    ) -> DesmosQueryResult<QueryBlocksResponse> {
         let request = DesmosQuery::Relationships(RelationshipsQuery::Blocks {
             subspace_id: subspace_id.into(),
             blocker,
@@ -126,10 +128,49 @@ impl<'a> RelationshipsQuerier<'a> {
             pagination,
         });
 
-        let res: QueryBlocksResponse = self.querier.query(&request.into())?;
+        let res: QueryBlocksResponse = query_desmos(&self.querier, &request.into())?;
         Ok(res)
     }
 
+    /// Tells whether the `user` follows the `counterparty` inside the given subspace.
+    ///
+    /// * `subspace_id` - Subspace in which the relationship has been created.
+    /// * `user` - Address of the user that should have created the relationship.
+    /// * `counterparty` - Address of the user that should be followed.
+    pub fn is_following(
+        &self,
+        subspace_id: u64,
+        user: Addr,
+        counterparty: Addr,
+    ) -> DesmosQueryResult<bool> {
+        let response = self.query_relationships(
+            subspace_id,
+            Some(user.clone()),
+            Some(counterparty.clone()),
+            single_item_page(),
+        )?;
+        Ok(response.relationships.iter().any(|relationship| {
+            relationship.creator == user && relationship.counterparty == counterparty
+        }))
+    }
+
+    /// Tells whether the two users follow each other inside the given subspace.
+    ///
+    /// * `subspace_id` - Subspace in which the relationships have been created.
+    /// * `user` - Address of the first user.
+    /// * `counterparty` - Address of the second user.
+    pub fn are_mutual(
+        &self,
+        subspace_id: u64,
+        user: Addr,
+        counterparty: Addr,
+    ) -> DesmosQueryResult<bool> {
+        Ok(
+            self.is_following(subspace_id, user.clone(), counterparty.clone())?
+                && self.is_following(subspace_id, counterparty, user)?,
+        )
+    }
+
     /// Gives an iterator to scan over the users blocked from a specific user in a subspace or
     /// all the blocks performed from the users in a subspace.
     ///
@@ -142,7 +183,7 @@ impl<'a> RelationshipsQuerier<'a> {
         subspace_id: u64,
         blocker: Option<Addr>,
         page_size: u64,
-    ) -> PageIterator<UserBlock, Binary> {
+    ) -> PageIterator<UserBlock, Binary, DesmosQueryError> {
         PageIterator::new(
             Box::new(move |key, limit| {
                 self.query_blocks(
@@ -167,17 +208,74 @@ impl<'a> RelationshipsQuerier<'a> {
             page_size,
         )
     }
+
+    /// Tells whether the `blocker` has blocked the `blocked` user inside the given subspace.
+    ///
+    /// * `subspace_id` - Subspace in which the block has been created.
+    /// * `blocker` - Address of the user that should have created the block.
+    /// * `blocked` - Address of the user that should be blocked.
+    pub fn is_blocked(
+        &self,
+        subspace_id: u64,
+        blocker: Addr,
+        blocked: Addr,
+    ) -> DesmosQueryResult<bool> {
+        let response = self.query_blocks(
+            subspace_id,
+            Some(blocker.clone()),
+            Some(blocked.clone()),
+            single_item_page(),
+        )?;
+        Ok(response
+            .blocks
+            .iter()
+            .any(|block| block.blocker == blocker && block.blocked == blocked))
+    }
+
+    /// Tells whether one of the two users has blocked the other inside the given subspace.
+    ///
+    /// * `subspace_id` - Subspace in which the blocks have been created.
+    /// * `user` - Address of the first user.
+    /// * `counterparty` - Address of the second user.
+    pub fn is_blocked_either_way(
+        &self,
+        subspace_id: u64,
+        user: Addr,
+        counterparty: Addr,
+    ) -> DesmosQueryResult<bool> {
+        Ok(
+            self.is_blocked(subspace_id, user.clone(), counterparty.clone())?
+                || self.is_blocked(subspace_id, counterparty, user)?,
+        )
+    }
+}
+
+/// Returns the pagination used to fetch only the first object matching a query.
+fn single_item_page() -> Option<PageRequest> {
+    Some(PageRequest {
+        key: None,
+        limit: 1u64.into(),
+        reverse: false,
+        count_total: false,
+        offset: None,
+    })
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::mocks::mock_queriers::mock_desmos_dependencies;
+    use crate::mocks::mock_queriers::{mock_desmos_dependencies, MockDesmosQuerier};
+    use crate::mocks::world::DesmosWorld;
+    use crate::query::DesmosQuery;
+    use crate::relationships::models::{Relationship, UserBlock};
+    use crate::relationships::query::RelationshipsQuery;
     use crate::relationships::{
         mocks::MockRelationshipsQueries,
         models_query::{QueryBlocksResponse, QueryRelationshipsResponse},
         querier::RelationshipsQuerier,
     };
+    use crate::subspaces::mocks::MockSubspacesQueries;
     use cosmwasm_std::Addr;
+    use cosmwasm_std::Uint64;
     use std::ops::Deref;
 
     #[test]
@@ -253,4 +351,128 @@ mod tests {
         );
         assert!(it.next().is_none());
     }
+
+    const USER: &str = "desmos1nwp8gxrnmrsrzjdhvk47vvmthzxjtphgxp5ftc";
+    const FOLLOWER: &str = "desmos1rfv0f7mx7w9d3jv3h803u38vqym9ygg344asm3";
+    const BLOCKER: &str = "desmos1jsdjhs8ggcs7ua0mxjz5e9q5q3j4fe7u4nqvt3";
+
+    fn social_graph_querier() -> MockDesmosQuerier {
+        let relationship = |creator: &str, counterparty: &str| Relationship {
+            creator: Addr::unchecked(creator),
+            counterparty: Addr::unchecked(counterparty),
+            subspace_id: Uint64::new(1),
+        };
+        let world = DesmosWorld::new()
+            .with_subspace(MockSubspacesQueries::get_mock_subspace())
+            .with_relationship(relationship(USER, FOLLOWER))
+            .with_relationship(relationship(FOLLOWER, USER))
+            .with_relationship(relationship(BLOCKER, USER))
+            .with_user_block(UserBlock {
+                blocker: Addr::unchecked(BLOCKER),
+                blocked: Addr::unchecked(FOLLOWER),
+                reason: "spam".to_string(),
+                subspace_id: Uint64::new(1),
+            });
+        MockDesmosQuerier::default()
+            .with_world(&world)
+            .unwrap()
+            .with_query_recording()
+    }
+
+    /// Asserts that all the recorded queries fetch at most a single object.
+    fn assert_single_item_queries(querier: &MockDesmosQuerier, count: usize) {
+        let queries = querier.recorded_desmos_queries();
+        assert_eq!(count, queries.len());
+        for query in queries {
+            let pagination = match query {
+                DesmosQuery::Relationships(RelationshipsQuery::Relationships {
+                    pagination,
+                    ..
+                })
+                | DesmosQuery::Relationships(RelationshipsQuery::Blocks { pagination, .. }) => {
+                    pagination
+                }
+                query => panic!("unexpected query {:?}", query),
+            };
+            assert_eq!(Uint64::new(1), pagination.unwrap().limit);
+        }
+    }
+
+    #[test]
+    fn test_is_following() {
+        let querier = social_graph_querier();
+        let relationships_querier = RelationshipsQuerier::new(&querier);
+
+        assert!(relationships_querier
+            .is_following(1, Addr::unchecked(USER), Addr::unchecked(FOLLOWER))
+            .unwrap());
+        assert!(!relationships_querier
+            .is_following(1, Addr::unchecked(USER), Addr::unchecked(BLOCKER))
+            .unwrap());
+        assert!(!relationships_querier
+            .is_following(2, Addr::unchecked(USER), Addr::unchecked(FOLLOWER))
+            .unwrap());
+        assert_single_item_queries(&querier, 3);
+    }
+
+    #[test]
+    fn test_are_mutual() {
+        let querier = social_graph_querier();
+        let relationships_querier = RelationshipsQuerier::new(&querier);
+
+        assert!(relationships_querier
+            .are_mutual(1, Addr::unchecked(USER), Addr::unchecked(FOLLOWER))
+            .unwrap());
+        assert_single_item_queries(&querier, 2);
+
+        querier.clear_recorded_queries();
+        assert!(!relationships_querier
+            .are_mutual(1, Addr::unchecked(BLOCKER), Addr::unchecked(USER))
+            .unwrap());
+        assert_single_item_queries(&querier, 2);
+
+        // The second query is skipped when the first user does not follow the second one
+        querier.clear_recorded_queries();
+        assert!(!relationships_querier
+            .are_mutual(1, Addr::unchecked(USER), Addr::unchecked(BLOCKER))
+            .unwrap());
+        assert_single_item_queries(&querier, 1);
+    }
+
+    #[test]
+    fn test_is_blocked() {
+        let querier = social_graph_querier();
+        let relationships_querier = RelationshipsQuerier::new(&querier);
+
+        assert!(relationships_querier
+            .is_blocked(1, Addr::unchecked(BLOCKER), Addr::unchecked(FOLLOWER))
+            .unwrap());
+        assert!(!relationships_querier
+            .is_blocked(1, Addr::unchecked(FOLLOWER), Addr::unchecked(BLOCKER))
+            .unwrap());
+        assert_single_item_queries(&querier, 2);
+    }
+
+    #[test]
+    fn test_is_blocked_either_way() {
+        let querier = social_graph_querier();
+        let relationships_querier = RelationshipsQuerier::new(&querier);
+
+        assert!(relationships_querier
+            .is_blocked_either_way(1, Addr::unchecked(BLOCKER), Addr::unchecked(FOLLOWER))
+            .unwrap());
+        assert_single_item_queries(&querier, 1);
+
+        querier.clear_recorded_queries();
+        assert!(relationships_querier
+            .is_blocked_either_way(1, Addr::unchecked(FOLLOWER), Addr::unchecked(BLOCKER))
+            .unwrap());
+        assert_single_item_queries(&querier, 2);
+
+        querier.clear_recorded_queries();
+        assert!(!relationships_querier
+            .is_blocked_either_way(1, Addr::unchecked(USER), Addr::unchecked(FOLLOWER))
+            .unwrap());
+        assert_single_item_queries(&querier, 2);
+    }
 }
@@ -0,0 +1,126 @@
+//! Contains the [`Arbitrary`](proptest::arbitrary::Arbitrary) implementations of the
+//! x/reports module models, query responses and messages, along with the strategies used to
+//! build them.
+
+#[cfg(feature = "query")]
+use crate::arbitrary::page;
+use crate::arbitrary::{addr, id, impl_arbitrary, rfc3339_time, small_id, text};
+use crate::reports::models::{RawReportTarget, Reason, Report, ReportTarget};
+#[cfg(feature = "query")]
+use crate::reports::models_query::{
+    QueryReasonResponse, QueryReasonsResponse, QueryReportResponse, QueryReportsResponse,
+};
+#[cfg(feature = "msg")]
+use crate::reports::msg::ReportsMsg;
+use proptest::collection::btree_set;
+use proptest::option;
+use proptest::prelude::*;
+
+/// Returns a strategy generating the ids of the reasons of a report,
+/// that are never empty and without duplicates.
+pub fn reasons_ids() -> impl Strategy<Value = Vec<u32>> {
+    btree_set(small_id(), 1..=5).prop_map(|ids| ids.into_iter().collect())
+}
+
+impl_arbitrary! {
+    ReportTarget => prop_oneof![
+        addr().prop_map(|user| ReportTarget::User { user }),
+        id().prop_map(|post_id| ReportTarget::Post { post_id }),
+    ];
+    RawReportTarget => any::<ReportTarget>().prop_map(RawReportTarget::from);
+    Report => (
+        (id(), id(), reasons_ids(), option::of(text(500))),
+        (addr(), any::<RawReportTarget>(), rfc3339_time()),
+    )
+        .prop_map(
+            |((subspace_id, id, reasons_ids, message), (reporter, target, creation_date))| {
+                Report {
+                    subspace_id,
+                    id,
+                    reasons_ids,
+                    message,
+                    reporter,
+                    target,
+                    creation_date,
+                }
+            },
+        );
+    Reason => (id(), small_id(), text(100), option::of(text(500))).prop_map(
+        |(subspace_id, id, title, description)| Reason {
+            subspace_id,
+            id,
+            title,
+            description,
+        },
+    );
+}
+
+#[cfg(feature = "query")]
+impl_arbitrary! {
+    QueryReportsResponse => page()
+        .prop_map(|(reports, pagination)| QueryReportsResponse { reports, pagination });
+    QueryReportResponse => any::<Report>().prop_map(|report| QueryReportResponse { report });
+    QueryReasonsResponse => page()
+        .prop_map(|(reasons, pagination)| QueryReasonsResponse { reasons, pagination });
+    QueryReasonResponse => any::<Reason>().prop_map(|reason| QueryReasonResponse { reason });
+}
+
+#[cfg(feature = "msg")]
+impl_arbitrary! {
+    ReportsMsg => prop_oneof![
+        (id(), reasons_ids(), option::of(text(500)), addr(), any::<RawReportTarget>()).prop_map(
+            |(subspace_id, reasons_ids, message, reporter, target)| ReportsMsg::CreateReport {
+                subspace_id,
+                reasons_ids,
+                message,
+                reporter,
+                target,
+            },
+        ),
+        (id(), id(), addr()).prop_map(|(subspace_id, report_id, signer)| {
+            ReportsMsg::DeleteReport {
+                subspace_id,
+                report_id,
+                signer,
+            }
+        }),
+        (id(), small_id(), addr()).prop_map(|(subspace_id, standard_reason_id, signer)| {
+            ReportsMsg::SupportStandardReason {
+                subspace_id,
+                standard_reason_id,
+                signer,
+            }
+        }),
+        (id(), text(100), option::of(text(500)), addr()).prop_map(
+            |(subspace_id, title, description, signer)| ReportsMsg::AddReason {
+                subspace_id,
+                title,
+                description,
+                signer,
+            },
+        ),
+        (id(), small_id(), addr()).prop_map(|(subspace_id, reason_id, signer)| {
+            ReportsMsg::RemoveReason {
+                subspace_id,
+                reason_id,
+                signer,
+            }
+        }),
+    ];
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::reports::models::{Report, ReportTarget};
+    use proptest::prelude::*;
+    use std::convert::TryFrom;
+
+    proptest! {
+        #[test]
+        fn reports_are_well_formed(report in any::<Report>()) {
+            prop_assert!(!report.reasons_ids.is_empty());
+            prop_assert!(report.reasons_ids.windows(2).all(|pair| pair[0] < pair[1]));
+            prop_assert!(ReportTarget::try_from(report.target).is_ok());
+        }
+    }
+}
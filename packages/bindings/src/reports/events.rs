@@ -0,0 +1,87 @@
+//! Contains the events emitted by the Desmos x/reports module.
+//!
+//! Besides the [`MessageEvent`](crate::events::MessageEvent), each [`ReportsMsg`](crate::reports::msg::ReportsMsg)
+//! emits the following events:
+//!
+//! | Message                 | Event type                     | Attributes                                              |
+//! |-------------------------|--------------------------------|---------------------------------------------------------|
+//! | `CreateReport`          | `create_report`                | `subspace_id`, `report_id`, `reporter`, `creation_time` |
+//! |                         | `reported_post` (post reports) | `subspace_id`, `post_id`, `reporter`                    |
+//! |                         | `reported_user` (user reports) | `subspace_id`, `user`, `reporter`                       |
+//! | `DeleteReport`          | `delete_report`                | `subspace_id`, `report_id`                              |
+//! | `SupportStandardReason` | `support_standard_reason`      | `subspace_id`, `standard_reason_id`, `reason_id`        |
+//! | `AddReason`             | `add_reason`                   | `subspace_id`, `reason_id`                              |
+//! | `RemoveReason`          | `remove_reason`                | `subspace_id`, `reason_id`                              |
+//!
+//! The times are formatted as RFC 3339 strings.
+
+use crate::events::desmos_events;
+use cosmwasm_std::Addr;
+
+desmos_events! {
+    /// Event emitted when a report is created.
+    CreateReportEvent = "create_report" {
+        /// Id of the subspace containing the report.
+        subspace_id: u64 = "subspace_id",
+        /// Id of the created report.
+        report_id: u64 = "report_id",
+        /// Address of the reporter.
+        reporter: Addr = "reporter",
+        /// Creation time of the report.
+        creation_time: String = "creation_time",
+    }
+
+    /// Event emitted when a post is reported.
+    ReportedPostEvent = "reported_post" {
+        /// Id of the subspace containing the post.
+        subspace_id: u64 = "subspace_id",
+        /// Id of the reported post.
+        post_id: u64 = "post_id",
+        /// Address of the reporter.
+        reporter: Addr = "reporter",
+    }
+
+    /// Event emitted when a user is reported.
+    ReportedUserEvent = "reported_user" {
+        /// Id of the subspace in which the user is reported.
+        subspace_id: u64 = "subspace_id",
+        /// Address of the reported user.
+        user: Addr = "user",
+        /// Address of the reporter.
+        reporter: Addr = "reporter",
+    }
+
+    /// Event emitted when a report is deleted.
+    DeleteReportEvent = "delete_report" {
+        /// Id of the subspace containing the report.
+        subspace_id: u64 = "subspace_id",
+        /// Id of the deleted report.
+        report_id: u64 = "report_id",
+    }
+
+    /// Event emitted when a standard reason is supported by a subspace.
+    SupportStandardReasonEvent = "support_standard_reason" {
+        /// Id of the subspace.
+        subspace_id: u64 = "subspace_id",
+        /// Id of the supported standard reason.
+        standard_reason_id: u32 = "standard_reason_id",
+        /// Id of the reason added to the subspace.
+        reason_id: u32 = "reason_id",
+    }
+
+    /// Event emitted when a reason is added to a subspace.
+    AddReasonEvent = "add_reason" {
+        /// Id of the subspace.
+        subspace_id: u64 = "subspace_id",
+        /// Id of the added reason.
+        reason_id: u32 = "reason_id",
+    }
+
+    /// Event emitted when a reason is removed from a subspace.
+    RemoveReasonEvent = "remove_reason" {
+        /// Id of the subspace.
+        subspace_id: u64 = "subspace_id",
+        /// Id of the removed reason.
+        reason_id: u32 = "reason_id",
+    }
+}
@@ -1,5 +1,6 @@
 //! Contains some useful mocks of the Desmos x/reports module's types made to be used in any test.
 
+use crate::pagination::paginate;
 use crate::reports::models::{Reason, Report, ReportTarget};
 use crate::reports::models_query::{
     QueryReasonResponse, QueryReasonsResponse, QueryReportResponse, QueryReportsResponse,
@@ -90,16 +91,35 @@ impl MockReportsQueries {
 /// Functions that mocks the reports query responses.
 pub fn mock_reports_query_response(query: &ReportsQuery) -> ContractResult<Binary> {
     let response = match query {
-        ReportsQuery::Reports { subspace_id, .. } => to_binary(&QueryReportsResponse {
-            reports: MockReportsQueries::get_mocked_reports(subspace_id),
-            pagination: None,
+        ReportsQuery::Reports {
+            subspace_id,
+            pagination,
+            ..
+        } => paginate(
+            MockReportsQueries::get_mocked_reports(subspace_id),
+            pagination.as_ref(),
+        )
+        .and_then(|(reports, pagination)| {
+            to_binary(&QueryReportsResponse {
+                reports,
+                pagination,
+            })
         }),
         ReportsQuery::Report { subspace_id, .. } => to_binary(&QueryReportResponse {
             report: MockReportsQueries::get_mocked_report(subspace_id),
         }),
-        ReportsQuery::Reasons { subspace_id, .. } => to_binary(&QueryReasonsResponse {
-            reasons: MockReportsQueries::get_mocked_reasons(subspace_id),
-            pagination: None,
+        ReportsQuery::Reasons {
+            subspace_id,
+            pagination,
+        } => paginate(
+            MockReportsQueries::get_mocked_reasons(subspace_id),
+            pagination.as_ref(),
+        )
+        .and_then(|(reasons, pagination)| {
+            to_binary(&QueryReasonsResponse {
+                reasons,
+                pagination,
+            })
         }),
         ReportsQuery::Reason { subspace_id, .. } => to_binary(&QueryReasonResponse {
             reason: MockReportsQueries::get_mocked_reason(subspace_id),
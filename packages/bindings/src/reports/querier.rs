@@ -1,17 +1,18 @@
 //! Contains the querier that can be used to query data related to the x/reports module.
 
-use crate::query::DesmosQuery;
-use crate::reports::models::{RawReportTarget, ReportTarget};
+use crate::errors::{not_found_as_none, DesmosQueryResult};
+use crate::query::{query_desmos, DesmosQuery};
+use crate::reports::models::{RawReportTarget, Reason, Report, ReportTarget};
 use crate::reports::models_query::{
     QueryReasonResponse, QueryReasonsResponse, QueryReportResponse, QueryReportsResponse,
 };
 use crate::reports::query::ReportsQuery;
 use crate::types::PageRequest;
-use cosmwasm_std::{Addr, Querier, QuerierWrapper, StdResult};
+use cosmwasm_std::{Addr, Querier, QuerierWrapper};
 #[cfg(feature = "iterators")]
 use {
+    crate::errors::DesmosQueryError,
     crate::iter::page_iterator::{Page, PageIterator},
-    crate::reports::models::{Reason, Report},
     cosmwasm_std::Binary,
 };
 
@@ -52,7 +53,7 @@ impl<'a> ReportsQuerier<'a> {
         target: Option<ReportTarget>,
         reporter: Option<Addr>,
         pagination: Option<PageRequest>,
-    ) -> StdResult<QueryReportsResponse> {
+    ) -> DesmosQueryResult<QueryReportsResponse> {
         let request = DesmosQuery::Reports(ReportsQuery::Reports {
             subspace_id: subspace_id.into(),
             target: target.map(RawReportTarget::from),
@@ -60,7 +61,7 @@ impl<'a> ReportsQuerier<'a> {
             pagination,
         });
 
-        self.querier.query(&request.into())
+        query_desmos(&self.querier, &request.into())
     }
 
     /// Gives an iterator to scan over the reports for a specific target.
@@ -77,7 +78,7 @@ impl<'a> ReportsQuerier<'a> {
         target: Option<ReportTarget>,
         reporter: Option<Addr>,
         page_size: u64,
-    ) -> PageIterator<Report, Binary> {
+    ) -> PageIterator<Report, Binary, DesmosQueryError> {
         PageIterator::new(
             Box::new(move |key, limit| {
                 self.query_reports(
@@ -107,13 +108,32 @@ impl<'a> ReportsQuerier<'a> {
     ///
     /// * `subspace_id` - Id of the subspace that holds the report to query for.
     /// * `report_id` - Id of the report to query for.
-    pub fn query_report(&self, subspace_id: u64, report_id: u64) -> StdResult<QueryReportResponse> {
+    pub fn query_report(
+        &self,
+        subspace_id: u64,
+        report_id: u64,
+    ) -> DesmosQueryResult<QueryReportResponse> {
         let request = DesmosQuery::Reports(ReportsQuery::Report {
             subspace_id: subspace_id.into(),
             report_id: report_id.into(),
         });
 
-        self.querier.query(&request.into())
+        query_desmos(&self.querier, &request.into())
+    }
+
+    /// Gives a single report inside a subspace, or `None` if it does not exist.
+    ///
+    /// * `subspace_id` - Id of the subspace to query the report from.
+    /// * `report_id` - Id of the report to query.
+    pub fn find_report(
+        &self,
+        subspace_id: u64,
+        report_id: u64,
+    ) -> DesmosQueryResult<Option<Report>> {
+        Ok(
+            not_found_as_none(self.query_report(subspace_id, report_id))?
+                .map(|response| response.report),
+        )
     }
 
     /// Queries the supported reporting reasons for a subspace.
@@ -124,13 +144,13 @@ impl<'a> ReportsQuerier<'a> {
         &self,
         subspace_id: u64,
         pagination: Option<PageRequest>,
-    ) -> StdResult<QueryReasonsResponse> {
+    ) -> DesmosQueryResult<QueryReasonsResponse> {
         let request = DesmosQuery::Reports(ReportsQuery::Reasons {
             subspace_id: subspace_id.into(),
             pagination,
         });
 
-        self.querier.query(&request.into())
+        query_desmos(&self.querier, &request.into())
     }
 
     /// Gives an iterator to scan over the supported reporting reasons for a subspace.
@@ -142,7 +162,7 @@ impl<'a> ReportsQuerier<'a> {
         &self,
         subspace_id: u64,
         page_size: u64,
-    ) -> PageIterator<Reason, Binary> {
+    ) -> PageIterator<Reason, Binary, DesmosQueryError> {
         PageIterator::new(
             Box::new(move |key, limit| {
                 self.query_reasons(
@@ -170,21 +190,46 @@ impl<'a> ReportsQuerier<'a> {
     ///
     /// * `subspace_id` - Id of the subspace that holds the reason to query for.
     /// * `reason_id` - Id of the reason to query for.
-    pub fn query_reason(&self, subspace_id: u64, reason_id: u32) -> StdResult<QueryReasonResponse> {
+    pub fn query_reason(
+        &self,
+        subspace_id: u64,
+        reason_id: u32,
+    ) -> DesmosQueryResult<QueryReasonResponse> {
         let request = DesmosQuery::Reports(ReportsQuery::Reason {
             subspace_id: subspace_id.into(),
             reason_id,
         });
 
-        self.querier.query(&request.into())
+        query_desmos(&self.querier, &request.into())
+    }
+
+    /// Gives a single reporting reason of a subspace, or `None` if it does not exist.
+    ///
+    /// * `subspace_id` - Id of the subspace to query the reason from.
+    /// * `reason_id` - Id of the reason to query.
+    pub fn find_reason(
+        &self,
+        subspace_id: u64,
+        reason_id: u32,
+    ) -> DesmosQueryResult<Option<Reason>> {
+        Ok(
+            not_found_as_none(self.query_reason(subspace_id, reason_id))?
+                .map(|response| response.reason),
+        )
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use crate::errors::DesmosQueryError;
+    use crate::mocks::errors::ChainError;
     use crate::mocks::mock_queriers::mock_desmos_dependencies;
+    use crate::mocks::mock_queriers::{
+        mock_desmos_dependencies_with_custom_querier, ChainErrorQuerier, MockDesmosQuerier,
+    };
     use crate::reports::mocks::MockReportsQueries;
     use crate::reports::querier::ReportsQuerier;
+    use crate::reports::query::ReportsQuery;
     use cosmwasm_std::Uint64;
     use std::ops::Deref;
 
@@ -273,4 +318,62 @@ mod tests {
             response.reason
         );
     }
+
+    #[test]
+    fn test_find_reports_objects() {
+        let querier = MockDesmosQuerier::default()
+            .with_query_error(
+                ReportsQuery::Report {
+                    subspace_id: Uint64::new(1),
+                    report_id: Uint64::new(2),
+                },
+                ChainError::report_not_found(2),
+            )
+            .with_query_error(
+                ReportsQuery::Reason {
+                    subspace_id: Uint64::new(1),
+                    reason_id: 2,
+                },
+                ChainError::not_found("reason with id 2 not found"),
+            );
+        let owned_deps = mock_desmos_dependencies_with_custom_querier(querier);
+        let deps = owned_deps.as_ref();
+        let reports_querier = ReportsQuerier::new(deps.querier.deref());
+
+        assert_eq!(
+            Some(MockReportsQueries::get_mocked_report(&Uint64::new(1))),
+            reports_querier.find_report(1, 1).unwrap()
+        );
+        assert_eq!(None, reports_querier.find_report(1, 2).unwrap());
+        assert_eq!(
+            Some(MockReportsQueries::get_mocked_reason(&Uint64::new(1))),
+            reports_querier.find_reason(1, 1).unwrap()
+        );
+        assert_eq!(None, reports_querier.find_reason(1, 2).unwrap());
+    }
+
+    #[test]
+    fn test_find_reports_objects_with_chain_not_found_error() {
+        // Redacted ErrNotFound error returned by the chain when the queried object does not exist
+        let querier = ReportsQuerier::new(&ChainErrorQuerier("codespace: sdk, code: 38"));
+
+        assert_eq!(None, querier.find_report(1, 2).unwrap());
+        assert_eq!(None, querier.find_reason(1, 2).unwrap());
+    }
+
+    #[test]
+    fn test_find_reports_objects_propagates_redacted_unregistered_errors() {
+        // Redacted error returned by the chain when the module failed with an unregistered error,
+        // that can't be told apart from a missing object
+        let querier = ReportsQuerier::new(&ChainErrorQuerier("codespace: undefined, code: 1"));
+
+        assert!(matches!(
+            querier.find_report(1, 2),
+            Err(DesmosQueryError::InvalidRequest { codespace, code: 1, .. }) if codespace == "undefined"
+        ));
+        assert!(matches!(
+            querier.find_reason(1, 2),
+            Err(DesmosQueryError::InvalidRequest { codespace, code: 1, .. }) if codespace == "undefined"
+        ));
+    }
 }
@@ -0,0 +1,336 @@
+//! Contains the [`Arbitrary`](proptest::arbitrary::Arbitrary) implementations of the
+//! x/subspaces module models, query responses and messages, along with the strategies used to
+//! build them.
+
+use crate::arbitrary::{addr, id, impl_arbitrary, page, rfc3339_time, text};
+use crate::subspaces::models::{
+    GroupPermissions, Permission, PermissionDetail, Section, Subspace, UserGroup, UserPermissions,
+};
+use crate::subspaces::models_query::{
+    QuerySectionResponse, QuerySectionsResponse, QuerySubspaceResponse, QuerySubspacesResponse,
+    QueryUserGroupMembersResponse, QueryUserGroupResponse, QueryUserGroupsResponse,
+    QueryUserPermissionsResponse,
+};
+#[cfg(feature = "msg")]
+use crate::subspaces::msg::SubspacesMsg;
+use crate::types::PageResponse;
+use proptest::collection::vec;
+use proptest::option;
+use proptest::prelude::*;
+use proptest::sample::subsequence;
+
+/// All the permissions that can be granted inside a subspace.
+const ALL_PERMISSIONS: [Permission; 10] = [
+    Permission::EditSubspace,
+    Permission::DeleteSubspace,
+    Permission::ManageSections,
+    Permission::ManageGroups,
+    Permission::SetPermissions,
+    Permission::Everything,
+    Permission::Write,
+    Permission::InteractWithContent,
+    Permission::EditOwnContent,
+    Permission::ModerateContent,
+];
+
+/// Returns a strategy generating sets of permissions without duplicates.
+pub fn permissions() -> impl Strategy<Value = Vec<Permission>> {
+    subsequence(ALL_PERMISSIONS.to_vec(), 0..=ALL_PERMISSIONS.len())
+}
+
+/// Returns a strategy generating the names of the subspaces, sections and groups.
+pub fn name() -> impl Strategy<Value = String> {
+    text(100)
+}
+
+/// Returns a strategy generating the descriptions of the subspaces, sections and groups.
+pub fn description() -> impl Strategy<Value = String> {
+    prop_oneof![Just(String::new()), text(1000)]
+}
+
+impl_arbitrary! {
+    Permission => proptest::sample::select(ALL_PERMISSIONS.to_vec());
+    Subspace => (id(), name(), description(), addr(), addr(), addr(), rfc3339_time()).prop_map(
+        |(id, name, description, treasury, owner, creator, creation_time)| Subspace {
+            id,
+            name,
+            description,
+            treasury,
+            owner,
+            creator,
+            creation_time,
+        },
+    );
+    // Only the root section has no parent, and the parents are created before their children.
+    Section => (id(), any::<u32>(), name(), description()).prop_flat_map(
+        |(subspace_id, id, name, description)| {
+            let parent_id = match id {
+                0 => Just(None).boxed(),
+                _ => (0..id).prop_map(Some).boxed(),
+            };
+            parent_id.prop_map(move |parent_id| Section {
+                subspace_id,
+                id,
+                parent_id,
+                name: name.clone(),
+                description: description.clone(),
+            })
+        },
+    );
+    UserGroup => (id(), option::of(any::<u32>()), any::<u32>(), name(), description(), permissions())
+        .prop_map(|(subspace_id, section_id, id, name, description, permissions)| UserGroup {
+            subspace_id,
+            section_id,
+            id,
+            name,
+            description,
+            permissions,
+        });
+    UserPermissions => (addr(), permissions())
+        .prop_map(|(user, permission)| UserPermissions { user, permission });
+    GroupPermissions => (any::<u32>(), permissions())
+        .prop_map(|(group_id, permission)| GroupPermissions { group_id, permission });
+    // Each detail refers either to a user or to a group.
+    PermissionDetail => (
+        id(),
+        any::<u32>(),
+        prop_oneof![
+            any::<UserPermissions>().prop_map(|user| (Some(user), None)),
+            any::<GroupPermissions>().prop_map(|group| (None, Some(group))),
+        ],
+    )
+        .prop_map(|(subspace_id, section_id, (user, group))| PermissionDetail {
+            subspace_id,
+            section_id,
+            user,
+            group,
+        });
+}
+
+impl_arbitrary! {
+    QuerySubspacesResponse => page()
+        .prop_map(|(subspaces, pagination)| QuerySubspacesResponse { subspaces, pagination });
+    QuerySubspaceResponse => any::<Subspace>().prop_map(|subspace| QuerySubspaceResponse { subspace });
+    QuerySectionsResponse => page()
+        .prop_map(|(sections, pagination)| QuerySectionsResponse { sections, pagination });
+    QuerySectionResponse => any::<Section>().prop_map(|section| QuerySectionResponse { section });
+    QueryUserGroupsResponse => page()
+        .prop_map(|(groups, pagination)| QueryUserGroupsResponse { groups, pagination });
+    QueryUserGroupResponse => any::<UserGroup>().prop_map(|group| QueryUserGroupResponse { group });
+    QueryUserGroupMembersResponse => (vec(addr(), 0..10), option::of(any::<PageResponse>()))
+        .prop_map(|(members, pagination)| QueryUserGroupMembersResponse { members, pagination });
+    // As on chain, the permissions are the combination of the ones listed inside the details.
+    QueryUserPermissionsResponse => vec(any::<PermissionDetail>(), 0..5).prop_map(|details| {
+        let mut permissions: Vec<Permission> = Vec::new();
+        for detail in &details {
+            let detail_permissions = detail
+                .user
+                .iter()
+                .flat_map(|user| user.permission.iter())
+                .chain(detail.group.iter().flat_map(|group| group.permission.iter()));
+            for permission in detail_permissions {
+                if !permissions.contains(permission) {
+                    permissions.push(permission.clone());
+                }
+            }
+        }
+        QueryUserPermissionsResponse {
+            permissions,
+            details,
+        }
+    });
+}
+
+#[cfg(feature = "msg")]
+impl_arbitrary! {
+    SubspacesMsg => prop_oneof![
+        (name(), description(), addr(), addr(), addr()).prop_map(
+            |(name, description, treasury, owner, creator)| SubspacesMsg::CreateSubspace {
+                name,
+                description,
+                treasury,
+                owner,
+                creator,
+            },
+        ),
+        (id(), name(), description(), addr(), addr(), addr()).prop_map(
+            |(subspace_id, name, description, treasury, owner, signer)| {
+                SubspacesMsg::EditSubspace {
+                    subspace_id,
+                    name,
+                    description,
+                    treasury,
+                    owner,
+                    signer,
+                }
+            },
+        ),
+        (id(), addr()).prop_map(|(subspace_id, signer)| SubspacesMsg::DeleteSubspace {
+            subspace_id,
+            signer,
+        }),
+        (id(), name(), option::of(text(1000)), option::of(any::<u32>()), addr()).prop_map(
+            |(subspace_id, name, description, parent_id, creator)| SubspacesMsg::CreateSection {
+                subspace_id,
+                name,
+                description,
+                parent_id,
+                creator,
+            },
+        ),
+        (id(), any::<u32>(), option::of(name()), option::of(text(1000)), addr()).prop_map(
+            |(subspace_id, section_id, name, description, editor)| SubspacesMsg::EditSection {
+                subspace_id,
+                section_id,
+                name,
+                description,
+                editor,
+            },
+        ),
+        // The root section can't be moved, nor a section can become its own parent.
+        (id(), 1..=u32::MAX, any::<u32>(), addr())
+            .prop_filter("a section can't be its own parent", |(_, section_id, parent_id, _)| {
+                section_id != parent_id
+            })
+            .prop_map(|(subspace_id, section_id, new_parent_id, signer)| SubspacesMsg::MoveSection {
+                subspace_id,
+                section_id,
+                new_parent_id,
+                signer,
+            },
+        ),
+        // The root section can't be deleted.
+        (id(), 1..=u32::MAX, addr()).prop_map(|(subspace_id, section_id, signer)| {
+            SubspacesMsg::DeleteSection {
+                subspace_id,
+                section_id,
+                signer,
+            }
+        }),
+        (
+            id(),
+            option::of(any::<u32>()),
+            name(),
+            option::of(text(1000)),
+            permissions(),
+            addr(),
+        )
+            .prop_map(
+                |(subspace_id, section_id, name, description, default_permissions, creator)| {
+                    SubspacesMsg::CreateUserGroup {
+                        subspace_id,
+                        section_id,
+                        name,
+                        description,
+                        default_permissions,
+                        creator,
+                    }
+                },
+            ),
+        (id(), any::<u32>(), option::of(name()), option::of(text(1000)), addr()).prop_map(
+            |(subspace_id, group_id, name, description, signer)| SubspacesMsg::EditUserGroup {
+                subspace_id,
+                group_id,
+                name,
+                description,
+                signer,
+            },
+        ),
+        (id(), any::<u32>(), any::<u32>(), addr()).prop_map(
+            |(subspace_id, group_id, new_section_id, signer)| SubspacesMsg::MoveUserGroup {
+                subspace_id,
+                group_id,
+                new_section_id,
+                signer,
+            },
+        ),
+        (id(), any::<u32>(), permissions(), addr()).prop_map(
+            |(subspace_id, group_id, permissions, signer)| {
+                SubspacesMsg::SetUserGroupPermissions {
+                    subspace_id,
+                    group_id,
+                    permissions,
+                    signer,
+                }
+            },
+        ),
+        // The default user group can't be deleted.
+        (id(), 1..=u32::MAX, addr()).prop_map(|(subspace_id, group_id, signer)| {
+            SubspacesMsg::DeleteUserGroup {
+                subspace_id,
+                group_id,
+                signer,
+            }
+        }),
+        (id(), 1..=u32::MAX, addr(), addr()).prop_map(|(subspace_id, group_id, user, signer)| {
+            SubspacesMsg::AddUserToUserGroup {
+                subspace_id,
+                group_id,
+                user,
+                signer,
+            }
+        }),
+        (id(), 1..=u32::MAX, addr(), addr()).prop_map(|(subspace_id, group_id, user, signer)| {
+            SubspacesMsg::RemoveUserFromUserGroup {
+                subspace_id,
+                group_id,
+                user,
+                signer,
+            }
+        }),
+        (id(), any::<u32>(), addr(), permissions(), addr()).prop_map(
+            |(subspace_id, section_id, user, permissions, signer)| {
+                SubspacesMsg::SetUserPermissions {
+                    subspace_id,
+                    section_id,
+                    user,
+                    permissions,
+                    signer,
+                }
+            },
+        ),
+    ];
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::subspaces::models::{PermissionDetail, Section, UserGroup};
+    use crate::subspaces::models_query::QueryUserPermissionsResponse;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn sections_parents_are_created_before_them(section in any::<Section>()) {
+            match section.parent_id {
+                Some(parent_id) => prop_assert!(parent_id < section.id),
+                None => prop_assert_eq!(0, section.id),
+            }
+        }
+
+        #[test]
+        fn groups_permissions_are_unique(group in any::<UserGroup>()) {
+            for (index, permission) in group.permissions.iter().enumerate() {
+                prop_assert!(!group.permissions[index + 1..].contains(permission));
+            }
+        }
+
+        #[test]
+        fn permission_details_refer_to_a_single_target(detail in any::<PermissionDetail>()) {
+            prop_assert!(detail.user.is_some() != detail.group.is_some());
+        }
+
+        #[test]
+        fn user_permissions_combine_the_details(response in any::<QueryUserPermissionsResponse>()) {
+            for detail in &response.details {
+                let detail_permissions = match (&detail.user, &detail.group) {
+                    (Some(user), _) => &user.permission,
+                    (_, Some(group)) => &group.permission,
+                    _ => unreachable!(),
+                };
+                for permission in detail_permissions {
+                    prop_assert!(response.permissions.contains(permission));
+                }
+            }
+        }
+    }
+}
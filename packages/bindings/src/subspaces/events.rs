@@ -0,0 +1,155 @@
+//! Contains the events emitted by the Desmos x/subspaces module.
+//!
+//! Besides the [`MessageEvent`](crate::events::MessageEvent), each [`SubspacesMsg`](crate::subspaces::msg::SubspacesMsg)
+//! emits the following events:
+//!
+//! | Message                   | Event type                    | Attributes                                                          |
+//! |---------------------------|-------------------------------|---------------------------------------------------------------------|
+//! | `CreateSubspace`          | `create_subspace`             | `subspace_id`, `subspace_name`, `subspace_creator`, `creation_date` |
+//! | `EditSubspace`            | `edit_subspace`               | `subspace_id`                                                       |
+//! | `DeleteSubspace`          | `delete_subspace`             | `subspace_id`                                                       |
+//! | `CreateSection`           | `create_section`              | `subspace_id`, `section_id`                                         |
+//! | `EditSection`             | `edit_section`                | `subspace_id`, `section_id`                                         |
+//! | `MoveSection`             | `move_section`                | `subspace_id`, `section_id`                                         |
+//! | `DeleteSection`           | `delete_section`              | `subspace_id`, `section_id`                                         |
+//! | `CreateUserGroup`         | `create_user_group`           | `subspace_id`, `user_group_id`                                      |
+//! | `EditUserGroup`           | `edit_user_group`             | `subspace_id`, `user_group_id`                                      |
+//! | `MoveUserGroup`           | `move_user_group`             | `subspace_id`, `user_group_id`                                      |
+//! | `SetUserGroupPermissions` | `set_user_group_permissions`  | `subspace_id`, `user_group_id`                                      |
+//! | `DeleteUserGroup`         | `delete_user_group`           | `subspace_id`, `user_group_id`                                      |
+//! | `AddUserToUserGroup`      | `add_user_to_user_group`      | `subspace_id`, `user_group_id`, `user`                              |
+//! | `RemoveUserFromUserGroup` | `remove_user_from_user_group` | `subspace_id`, `user_group_id`, `user`                              |
+//! | `SetUserPermissions`      | `set_user_permissions`        | `subspace_id`, `section_id`, `user`                                 |
+//!
+//! The times are formatted as RFC 3339 strings.
+
+use crate::events::desmos_events;
+use cosmwasm_std::Addr;
+
+desmos_events! {
+    /// Event emitted when a subspace is created.
+    CreateSubspaceEvent = "create_subspace" {
+        /// Id of the created subspace.
+        subspace_id: u64 = "subspace_id",
+        /// Name of the created subspace.
+        name: String = "subspace_name",
+        /// Address of the subspace creator.
+        creator: Addr = "subspace_creator",
+        /// Creation time of the subspace.
+        creation_time: String = "creation_date",
+    }
+
+    /// Event emitted when a subspace is edited.
+    EditSubspaceEvent = "edit_subspace" {
+        /// Id of the edited subspace.
+        subspace_id: u64 = "subspace_id",
+    }
+
+    /// Event emitted when a subspace is deleted.
+    DeleteSubspaceEvent = "delete_subspace" {
+        /// Id of the deleted subspace.
+        subspace_id: u64 = "subspace_id",
+    }
+
+    /// Event emitted when a section is created.
+    CreateSectionEvent = "create_section" {
+        /// Id of the subspace containing the section.
+        subspace_id: u64 = "subspace_id",
+        /// Id of the created section.
+        section_id: u32 = "section_id",
+    }
+
+    /// Event emitted when a section is edited.
+    EditSectionEvent = "edit_section" {
+        /// Id of the subspace containing the section.
+        subspace_id: u64 = "subspace_id",
+        /// Id of the edited section.
+        section_id: u32 = "section_id",
+    }
+
+    /// Event emitted when a section is moved under another parent.
+    MoveSectionEvent = "move_section" {
+        /// Id of the subspace containing the section.
+        subspace_id: u64 = "subspace_id",
+        /// Id of the moved section.
+        section_id: u32 = "section_id",
+    }
+
+    /// Event emitted when a section is deleted.
+    DeleteSectionEvent = "delete_section" {
+        /// Id of the subspace containing the section.
+        subspace_id: u64 = "subspace_id",
+        /// Id of the deleted section.
+        section_id: u32 = "section_id",
+    }
+
+    /// Event emitted when a user group is created.
+    CreateUserGroupEvent = "create_user_group" {
+        /// Id of the subspace containing the group.
+        subspace_id: u64 = "subspace_id",
+        /// Id of the created group.
+        group_id: u32 = "user_group_id",
+    }
+
+    /// Event emitted when a user group is edited.
+    EditUserGroupEvent = "edit_user_group" {
+        /// Id of the subspace containing the group.
+        subspace_id: u64 = "subspace_id",
+        /// Id of the edited group.
+        group_id: u32 = "user_group_id",
+    }
+
+    /// Event emitted when a user group is moved into another section.
+    MoveUserGroupEvent = "move_user_group" {
+        /// Id of the subspace containing the group.
+        subspace_id: u64 = "subspace_id",
+        /// Id of the moved group.
+        group_id: u32 = "user_group_id",
+    }
+
+    /// Event emitted when the permissions of a user group are set.
+    SetUserGroupPermissionsEvent = "set_user_group_permissions" {
+        /// Id of the subspace containing the group.
+        subspace_id: u64 = "subspace_id",
+        /// Id of the updated group.
+        group_id: u32 = "user_group_id",
+    }
+
+    /// Event emitted when a user group is deleted.
+    DeleteUserGroupEvent = "delete_user_group" {
+        /// Id of the subspace containing the group.
+        subspace_id: u64 = "subspace_id",
+        /// Id of the deleted group.
+        group_id: u32 = "user_group_id",
+    }
+
+    /// Event emitted when a user is added to a user group.
+    AddUserToUserGroupEvent = "add_user_to_user_group" {
+        /// Id of the subspace containing the group.
+        subspace_id: u64 = "subspace_id",
+        /// Id of the group.
+        group_id: u32 = "user_group_id",
+        /// Address of the added user.
+        user: Addr = "user",
+    }
+
+    /// Event emitted when a user is removed from a user group.
+    RemoveUserFromUserGroupEvent = "remove_user_from_user_group" {
+        /// Id of the subspace containing the group.
+        subspace_id: u64 = "subspace_id",
+        /// Id of the group.
+        group_id: u32 = "user_group_id",
+        /// Address of the removed user.
+        user: Addr = "user",
+    }
+
+    /// Event emitted when the permissions of a user are set.
+    SetUserPermissionsEvent = "set_user_permissions" {
+        /// Id of the subspace in which the permissions are set.
+        subspace_id: u64 = "subspace_id",
+        /// Id of the section in which the permissions are set.
+        section_id: u32 = "section_id",
+        /// Address of the user.
+        user: Addr = "user",
+    }
+}
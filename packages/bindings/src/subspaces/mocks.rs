@@ -1,5 +1,6 @@
 //! Contains some useful mocks of the Desmos x/subspaces module's types made to be used in any test.
 
+use crate::pagination::paginate;
 use crate::subspaces::models::{Permission, Section, UserPermissions};
 use crate::subspaces::models_query::{QuerySectionResponse, QuerySectionsResponse};
 use crate::subspaces::{
@@ -75,40 +76,48 @@ impl MockSubspacesQueries {
 /// Functions that mocks the subspaces query responses.
 pub fn mock_subspaces_query_response(query: &SubspacesQuery) -> ContractResult<Binary> {
     let response = match query {
-        SubspacesQuery::Subspaces { .. } => {
+        SubspacesQuery::Subspaces { pagination, .. } => {
             let subspace = MockSubspacesQueries::get_mock_subspace();
-            to_binary(&QuerySubspacesResponse {
-                subspaces: vec![subspace],
-                pagination: Default::default(),
+            paginate(vec![subspace], pagination.as_ref()).and_then(|(subspaces, pagination)| {
+                to_binary(&QuerySubspacesResponse {
+                    subspaces,
+                    pagination,
+                })
             })
         }
         SubspacesQuery::Subspace { .. } => {
             let subspace = MockSubspacesQueries::get_mock_subspace();
             to_binary(&QuerySubspaceResponse { subspace })
         }
-        SubspacesQuery::Sections { .. } => to_binary(&QuerySectionsResponse {
-            sections: vec![MockSubspacesQueries::get_mock_section()],
-            pagination: None,
-        }),
+        SubspacesQuery::Sections { pagination, .. } => {
+            let section = MockSubspacesQueries::get_mock_section();
+            paginate(vec![section], pagination.as_ref()).and_then(|(sections, pagination)| {
+                to_binary(&QuerySectionsResponse {
+                    sections,
+                    pagination,
+                })
+            })
+        }
         SubspacesQuery::Section { .. } => to_binary(&QuerySectionResponse {
             section: MockSubspacesQueries::get_mock_section(),
         }),
-        SubspacesQuery::UserGroups { .. } => {
+        SubspacesQuery::UserGroups { pagination, .. } => {
             let group = MockSubspacesQueries::get_mock_user_group();
-            to_binary(&QueryUserGroupsResponse {
-                groups: vec![group],
-                pagination: Default::default(),
+            paginate(vec![group], pagination.as_ref()).and_then(|(groups, pagination)| {
+                to_binary(&QueryUserGroupsResponse { groups, pagination })
             })
         }
         SubspacesQuery::UserGroup { .. } => {
             let group = MockSubspacesQueries::get_mock_user_group();
             to_binary(&QueryUserGroupResponse { group })
         }
-        SubspacesQuery::UserGroupMembers { .. } => {
+        SubspacesQuery::UserGroupMembers { pagination, .. } => {
             let member = MockSubspacesQueries::get_mock_group_member();
-            to_binary(&QueryUserGroupMembersResponse {
-                members: vec![member],
-                pagination: Default::default(),
+            paginate(vec![member], pagination.as_ref()).and_then(|(members, pagination)| {
+                to_binary(&QueryUserGroupMembersResponse {
+                    members,
+                    pagination,
+                })
             })
         }
         SubspacesQuery::UserPermissions { .. } => {
@@ -3,6 +3,10 @@
 #[cfg(all(not(target_arch = "wasm32"), feature = "query"))]
 pub mod mocks;
 
+#[cfg(feature = "arbitrary")]
+pub mod arbitrary;
+#[cfg(feature = "msg")]
+pub mod events;
 pub mod models;
 pub mod msg;
 #[cfg(feature = "query")]
@@ -1,8 +1,10 @@
 //! Contains a querier to query data from the Desmos x/subspaces module.
 
+use crate::subspaces::models::{Permission, Section, Subspace, UserGroup};
 use crate::subspaces::models_query::{QuerySectionResponse, QuerySectionsResponse};
 use crate::{
-    query::DesmosQuery,
+    errors::{not_found_as_none, DesmosQueryResult},
+    query::{query_desmos, DesmosQuery},
     subspaces::{
         models_query::{
             QuerySubspaceResponse, QuerySubspacesResponse, QueryUserGroupMembersResponse,
@@ -12,12 +14,11 @@ use crate::{
     },
     types::PageRequest,
 };
-use cosmwasm_std::{Addr, Querier, QuerierWrapper, StdResult, Uint64};
+use cosmwasm_std::{Addr, Querier, QuerierWrapper, Uint64};
 #[cfg(feature = "iterators")]
 use {
+    crate::errors::DesmosQueryError,
     crate::iter::page_iterator::{Page, PageIterator},
-    crate::subspaces::models::Section,
-    crate::subspaces::models::{Subspace, UserGroup},
     cosmwasm_std::Binary,
 };
 
@@ -54,9 +55,9 @@ impl<'a> SubspacesQuerier<'a> {
     pub fn query_subspaces(
         &self,
         pagination: Option<PageRequest>,
-    ) -> StdResult<QuerySubspacesResponse> {
+    ) -> DesmosQueryResult<QuerySubspacesResponse> {
         let request = DesmosQuery::from(SubspacesQuery::Subspaces { pagination });
-        let res: QuerySubspacesResponse = self.querier.query(&request.into())?;
+        let res: QuerySubspacesResponse = query_desmos(&self.querier, &request.into())?;
         Ok(res)
     }
 
@@ -64,7 +65,10 @@ impl<'a> SubspacesQuerier<'a> {
     ///
     /// * `page_size` - Size of the page requested to the chain.
     #[cfg(feature = "iterators")]
-    pub fn iterate_subspaces(&self, page_size: u64) -> PageIterator<Subspace, Binary> {
+    pub fn iterate_subspaces(
+        &self,
+        page_size: u64,
+    ) -> PageIterator<Subspace, Binary, DesmosQueryError> {
         PageIterator::new(
             Box::new(move |key, limit| {
                 self.query_subspaces(Some(PageRequest {
@@ -86,14 +90,21 @@ impl<'a> SubspacesQuerier<'a> {
     /// Queries the details of a subspace.
     ///
     /// * `subspace_id` - Subspace of interest.
-    pub fn query_subspace(&self, subspace_id: u64) -> StdResult<QuerySubspaceResponse> {
+    pub fn query_subspace(&self, subspace_id: u64) -> DesmosQueryResult<QuerySubspaceResponse> {
         let request = DesmosQuery::from(SubspacesQuery::Subspace {
             subspace_id: subspace_id.into(),
         });
-        let res: QuerySubspaceResponse = self.querier.query(&request.into())?;
+        let res: QuerySubspaceResponse = query_desmos(&self.querier, &request.into())?;
         Ok(res)
     }
 
+    /// Gives the subspace having the given id, or `None` if it does not exist.
+    ///
+    /// * `subspace_id` - Id of the subspace to query.
+    pub fn find_subspace(&self, subspace_id: u64) -> DesmosQueryResult<Option<Subspace>> {
+        Ok(not_found_as_none(self.query_subspace(subspace_id))?.map(|response| response.subspace))
+    }
+
     /// Queries all the sections created inside a subspace.
     ///
     /// * `subspace_id` - Subspace to which the sections belong.
@@ -102,12 +113,12 @@ impl<'a> SubspacesQuerier<'a> {
         &self,
         subspace_id: u64,
         pagination: Option<PageRequest>,
-    ) -> StdResult<QuerySectionsResponse> {
+    ) -> DesmosQueryResult<QuerySectionsResponse> {
         let request = DesmosQuery::from(SubspacesQuery::Sections {
             subspace_id: Uint64::new(subspace_id),
             pagination,
         });
-        let res: QuerySectionsResponse = self.querier.query(&request.into())?;
+        let res: QuerySectionsResponse = query_desmos(&self.querier, &request.into())?;
         Ok(res)
     }
 
@@ -120,7 +131,7 @@ impl<'a> SubspacesQuerier<'a> {
         &self,
         subspace_id: u64,
         page_size: u64,
-    ) -> PageIterator<Section, Binary> {
+    ) -> PageIterator<Section, Binary, DesmosQueryError> {
         PageIterator::new(
             Box::new(move |key, limit| {
                 self.query_sections(
@@ -150,15 +161,30 @@ impl<'a> SubspacesQuerier<'a> {
         &self,
         subspace_id: u64,
         section_id: u32,
-    ) -> StdResult<QuerySectionResponse> {
+    ) -> DesmosQueryResult<QuerySectionResponse> {
         let request = DesmosQuery::from(SubspacesQuery::Section {
             subspace_id: subspace_id.into(),
             section_id,
         });
-        let res: QuerySectionResponse = self.querier.query(&request.into())?;
+        let res: QuerySectionResponse = query_desmos(&self.querier, &request.into())?;
         Ok(res)
     }
 
+    /// Gives a single section inside a subspace, or `None` if it does not exist.
+    ///
+    /// * `subspace_id` - Id of the subspace to query the section from.
+    /// * `section_id` - Id of the section to query.
+    pub fn find_section(
+        &self,
+        subspace_id: u64,
+        section_id: u32,
+    ) -> DesmosQueryResult<Option<Section>> {
+        Ok(
+            not_found_as_none(self.query_section(subspace_id, section_id))?
+                .map(|response| response.section),
+        )
+    }
+
     /// Queries the user groups created in a subspace.
     ///
     /// * `subspace_id` - Subspace to which the groups belong.
@@ -169,13 +195,13 @@ impl<'a> SubspacesQuerier<'a> {
         subspace_id: u64,
         section_id: Option<u32>,
         pagination: Option<PageRequest>,
-    ) -> StdResult<QueryUserGroupsResponse> {
+    ) -> DesmosQueryResult<QueryUserGroupsResponse> {
         let request = DesmosQuery::from(SubspacesQuery::UserGroups {
             subspace_id: subspace_id.into(),
             section_id,
             pagination,
         });
-        let res: QueryUserGroupsResponse = self.querier.query(&request.into())?;
+        let res: QueryUserGroupsResponse = query_desmos(&self.querier, &request.into())?;
         Ok(res)
     }
 
@@ -190,7 +216,7 @@ impl<'a> SubspacesQuerier<'a> {
         subspace_id: u64,
         section_id: Option<u32>,
         page_size: u64,
-    ) -> PageIterator<UserGroup, Binary> {
+    ) -> PageIterator<UserGroup, Binary, DesmosQueryError> {
         PageIterator::new(
             Box::new(move |key, limit| {
                 self.query_user_groups(
@@ -221,15 +247,30 @@ impl<'a> SubspacesQuerier<'a> {
         &self,
         subspace_id: u64,
         group_id: u32,
-    ) -> StdResult<QueryUserGroupResponse> {
+    ) -> DesmosQueryResult<QueryUserGroupResponse> {
         let request = DesmosQuery::from(SubspacesQuery::UserGroup {
             subspace_id: subspace_id.into(),
             group_id,
         });
-        let res: QueryUserGroupResponse = self.querier.query(&request.into())?;
+        let res: QueryUserGroupResponse = query_desmos(&self.querier, &request.into())?;
         Ok(res)
     }
 
+    /// Gives a single user group inside a subspace, or `None` if it does not exist.
+    ///
+    /// * `subspace_id` - Id of the subspace to query the group from.
+    /// * `group_id` - Id of the group to query.
+    pub fn find_user_group(
+        &self,
+        subspace_id: u64,
+        group_id: u32,
+    ) -> DesmosQueryResult<Option<UserGroup>> {
+        Ok(
+            not_found_as_none(self.query_user_group(subspace_id, group_id))?
+                .map(|response| response.group),
+        )
+    }
+
     /// Queries the members of a group.
     ///
     /// * `subspace_id` - Subspace to which the group belong.
@@ -240,13 +281,13 @@ impl<'a> SubspacesQuerier<'a> {
         subspace_id: u64,
         group_id: u32,
         pagination: Option<PageRequest>,
-    ) -> StdResult<QueryUserGroupMembersResponse> {
+    ) -> DesmosQueryResult<QueryUserGroupMembersResponse> {
         let request = DesmosQuery::from(SubspacesQuery::UserGroupMembers {
             subspace_id: subspace_id.into(),
             group_id,
             pagination,
         });
-        let res: QueryUserGroupMembersResponse = self.querier.query(&request.into())?;
+        let res: QueryUserGroupMembersResponse = query_desmos(&self.querier, &request.into())?;
         Ok(res)
     }
 
@@ -261,7 +302,7 @@ impl<'a> SubspacesQuerier<'a> {
         subspace_id: u64,
         group_id: u32,
         page_size: u64,
-    ) -> PageIterator<Addr, Binary> {
+    ) -> PageIterator<Addr, Binary, DesmosQueryError> {
         PageIterator::new(
             Box::new(move |key, limit| {
                 self.query_user_group_members(
@@ -293,22 +334,156 @@ impl<'a> SubspacesQuerier<'a> {
         subspace_id: u64,
         section_id: Option<u32>,
         user: Addr,
-    ) -> StdResult<QueryUserPermissionsResponse> {
+    ) -> DesmosQueryResult<QueryUserPermissionsResponse> {
         let request = DesmosQuery::from(SubspacesQuery::UserPermissions {
             subspace_id: subspace_id.into(),
             section_id,
             user,
         });
-        let res: QueryUserPermissionsResponse = self.querier.query(&request.into())?;
+        let res: QueryUserPermissionsResponse = query_desmos(&self.querier, &request.into())?;
         Ok(res)
     }
+
+    /// Gives all the permissions that an user has inside a subspace section.
+    ///
+    /// The permissions are resolved like the x/subspaces module does when checking them:
+    /// they are the ones set to the user and to the groups it belongs to, both inside the section
+    /// and inside all its parent sections up to the root section. The section path is walked
+    /// explicitly, merging the [permission details](crate::subspaces::models::PermissionDetail)
+    /// returned by [`Self::query_user_permissions`] for each section of the path.
+    /// The subspace owner has all the permissions, that are returned as [`Permission::Everything`].
+    ///
+    /// * `subspace_id` - Subspace to which the section belongs.
+    /// * `section_id` - Section inside which the permissions should be resolved.
+    /// * `user` - User address.
+    pub fn effective_permissions(
+        &self,
+        subspace_id: u64,
+        section_id: u32,
+        user: Addr,
+    ) -> DesmosQueryResult<Vec<Permission>> {
+        if self.query_subspace(subspace_id)?.subspace.owner == user {
+            return Ok(vec![Permission::Everything]);
+        }
+        self.resolve_permissions(subspace_id, section_id, &user, |_| false)
+    }
+
+    /// Tells whether an user has the given permission inside a subspace section,
+    /// considering the same permissions as [`Self::effective_permissions`].
+    /// [`Permission::Everything`] grants any permission.
+    /// The section path is walked only until the permission is found.
+    ///
+    /// * `subspace_id` - Subspace to which the section belongs.
+    /// * `section_id` - Section inside which the permission should be checked.
+    /// * `user` - User address.
+    /// * `permission` - Permission to check.
+    ///
+    /// # Example
+    /// ```
+    /// use cosmwasm_std::{Addr, Deps, StdError, StdResult};
+    /// use desmos_bindings::query::DesmosQuery;
+    /// use desmos_bindings::subspaces::models::Permission;
+    /// use desmos_bindings::subspaces::querier::SubspacesQuerier;
+    /// use std::ops::Deref;
+    ///
+    /// pub fn check_can_moderate(deps: Deps<DesmosQuery>, section_id: u32, user: Addr) -> StdResult<()> {
+    ///     let querier = SubspacesQuerier::new(deps.querier.deref());
+    ///     if !querier.has_permission(1, section_id, user, Permission::ModerateContent)? {
+    ///         return Err(StdError::generic_err("unauthorized"));
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn has_permission(
+        &self,
+        subspace_id: u64,
+        section_id: u32,
+        user: Addr,
+        permission: Permission,
+    ) -> DesmosQueryResult<bool> {
+        if self.query_subspace(subspace_id)?.subspace.owner == user {
+            return Ok(true);
+        }
+        let permissions =
+            self.resolve_permissions(subspace_id, section_id, &user, |permissions| {
+                grants(permissions, &permission)
+            })?;
+        Ok(grants(&permissions, &permission))
+    }
+
+    /// Collects the permissions of the user walking the section path from the given section
+    /// up to the root section, stopping early once `is_enough` is satisfied.
+    fn resolve_permissions(
+        &self,
+        subspace_id: u64,
+        section_id: u32,
+        user: &Addr,
+        is_enough: impl Fn(&[Permission]) -> bool,
+    ) -> DesmosQueryResult<Vec<Permission>> {
+        let mut permissions = Vec::new();
+        let mut visited_sections = Vec::new();
+        let mut next_section_id = Some(section_id);
+        while let Some(section_id) = next_section_id {
+            // The chain does not allow sections cycles, this only guards against inconsistent data
+            if visited_sections.contains(&section_id) {
+                break;
+            }
+            visited_sections.push(section_id);
+
+            let response =
+                self.query_user_permissions(subspace_id, Some(section_id), user.clone())?;
+            for detail in response.details {
+                if let Some(user_permissions) = detail.user {
+                    merge_permissions(&mut permissions, &user_permissions.permission);
+                }
+                if let Some(group_permissions) = detail.group {
+                    merge_permissions(&mut permissions, &group_permissions.permission);
+                }
+            }
+            if is_enough(&permissions) || section_id == ROOT_SECTION_ID {
+                break;
+            }
+            // The sections created without a parent are children of the root section
+            next_section_id = Some(
+                self.query_section(subspace_id, section_id)?
+                    .section
+                    .parent_id
+                    .unwrap_or(ROOT_SECTION_ID),
+            );
+        }
+        Ok(permissions)
+    }
+}
+
+/// Id of the root section, that is created together with each subspace.
+const ROOT_SECTION_ID: u32 = 0;
+
+/// Adds to `permissions` all the `new_permissions` that are not already present.
+fn merge_permissions(permissions: &mut Vec<Permission>, new_permissions: &[Permission]) {
+    for permission in new_permissions {
+        if !permissions.contains(permission) {
+            permissions.push(permission.clone());
+        }
+    }
+}
+
+/// Tells whether the given permissions grant the provided one.
+fn grants(permissions: &[Permission], permission: &Permission) -> bool {
+    permissions.contains(permission) || permissions.contains(&Permission::Everything)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::errors::DesmosQueryError;
+    use crate::mocks::errors::ChainError;
     use crate::mocks::mock_queriers::mock_desmos_dependencies;
+    use crate::mocks::mock_queriers::{
+        mock_desmos_dependencies_with_custom_querier, ChainErrorQuerier, MockDesmosQuerier,
+    };
     use crate::subspaces::mocks::MockSubspacesQueries;
+    use crate::subspaces::models::{GroupPermissions, PermissionDetail, UserPermissions};
+    use cosmwasm_std::{to_binary, ContractResult};
     use std::ops::Deref;
 
     #[test]
@@ -475,4 +650,276 @@ mod tests {
         };
         assert_eq!(response.ok(), Some(expected));
     }
+
+    #[test]
+    fn test_find_subspace_objects() {
+        let querier = MockDesmosQuerier::default()
+            .with_query_error(
+                SubspacesQuery::Subspace {
+                    subspace_id: Uint64::new(2),
+                },
+                ChainError::subspace_not_found(2),
+            )
+            .with_query_error(
+                SubspacesQuery::Section {
+                    subspace_id: Uint64::new(1),
+                    section_id: 2,
+                },
+                ChainError::not_found("section with id 2 not found inside subspace 1"),
+            )
+            .with_query_error(
+                SubspacesQuery::UserGroup {
+                    subspace_id: Uint64::new(1),
+                    group_id: 2,
+                },
+                ChainError::not_found("group with id 2 not found inside subspace 1"),
+            );
+        let owned_deps = mock_desmos_dependencies_with_custom_querier(querier);
+        let deps = owned_deps.as_ref();
+        let querier = SubspacesQuerier::new(deps.querier.deref());
+
+        assert_eq!(
+            Some(MockSubspacesQueries::get_mock_subspace()),
+            querier.find_subspace(1).unwrap()
+        );
+        assert_eq!(None, querier.find_subspace(2).unwrap());
+        assert_eq!(
+            Some(MockSubspacesQueries::get_mock_section()),
+            querier.find_section(1, 1).unwrap()
+        );
+        assert_eq!(None, querier.find_section(1, 2).unwrap());
+        assert_eq!(
+            Some(MockSubspacesQueries::get_mock_user_group()),
+            querier.find_user_group(1, 1).unwrap()
+        );
+        assert_eq!(None, querier.find_user_group(1, 2).unwrap());
+    }
+
+    const MEMBER: &str = "desmos1nwp8gxrnmrsrzjdhvk47vvmthzxjtphgxp5ftc";
+    const ADMIN: &str = "desmos1rfv0f7mx7w9d3jv3h803u38vqym9ygg344asm3";
+    const GUEST: &str = "desmos1jsdjhs8ggcs7ua0mxjz5e9q5q3j4fe7u4nqvt3";
+
+    /// Returns the permission details that the chain returns for the given user
+    /// inside a single section of subspace 1, considering only the permissions set inside it.
+    fn section_permission_details(section_id: u32, user: &Addr) -> Vec<PermissionDetail> {
+        let user_detail = |permissions: Vec<Permission>| PermissionDetail {
+            subspace_id: Uint64::new(1),
+            section_id,
+            user: Some(UserPermissions {
+                user: user.clone(),
+                permission: permissions,
+            }),
+            group: None,
+        };
+        let group_detail = |group_id: u32, permissions: Vec<Permission>| PermissionDetail {
+            subspace_id: Uint64::new(1),
+            section_id,
+            user: None,
+            group: Some(GroupPermissions {
+                group_id,
+                permission: permissions,
+            }),
+        };
+        match (section_id, user.as_str()) {
+            // The default group, of which all the users are members
+            (0, _) => vec![group_detail(0, vec![Permission::Write])],
+            (1, MEMBER) => vec![group_detail(1, vec![Permission::ModerateContent])],
+            (1, ADMIN) => vec![user_detail(vec![Permission::Everything])],
+            (2, MEMBER) => vec![user_detail(vec![Permission::EditOwnContent])],
+            _ => vec![],
+        }
+    }
+
+    /// Returns a querier answering with the raw chain responses of a subspace having the root
+    /// section, a section 1 created without a parent and a section 2 child of section 1,
+    /// along with some permissions set inside them.
+    fn permissions_querier() -> MockDesmosQuerier {
+        MockDesmosQuerier::default()
+            .with_custom_subspaces_handler(|query| match query {
+                SubspacesQuery::Subspace { .. } => to_binary(&QuerySubspaceResponse {
+                    subspace: MockSubspacesQueries::get_mock_subspace(),
+                })
+                .into(),
+                SubspacesQuery::Section { section_id, .. } => to_binary(&QuerySectionResponse {
+                    section: Section {
+                        subspace_id: Uint64::new(1),
+                        id: *section_id,
+                        parent_id: match section_id {
+                            2 => Some(1),
+                            _ => None,
+                        },
+                        name: format!("Section {}", section_id),
+                        description: String::new(),
+                    },
+                })
+                .into(),
+                SubspacesQuery::UserPermissions {
+                    section_id, user, ..
+                } => {
+                    let details = section_permission_details(section_id.unwrap_or(0), user);
+                    let permissions = details
+                        .iter()
+                        .flat_map(|detail| {
+                            let user = detail.user.iter().map(|user| &user.permission);
+                            let group = detail.group.iter().map(|group| &group.permission);
+                            user.chain(group).flatten().cloned()
+                        })
+                        .collect();
+                    to_binary(&QueryUserPermissionsResponse {
+                        permissions,
+                        details,
+                    })
+                    .into()
+                }
+                _ => ContractResult::Err("not supported".to_string()),
+            })
+            .with_query_recording()
+    }
+
+    #[test]
+    fn test_effective_permissions() {
+        let querier = permissions_querier();
+        let subspaces_querier = SubspacesQuerier::new(&querier);
+        let owner = MockSubspacesQueries::get_mock_subspace().owner;
+
+        assert_eq!(
+            vec![Permission::Everything],
+            subspaces_querier
+                .effective_permissions(1, 2, owner)
+                .unwrap()
+        );
+        assert_eq!(
+            vec![
+                Permission::EditOwnContent,
+                Permission::ModerateContent,
+                Permission::Write
+            ],
+            subspaces_querier
+                .effective_permissions(1, 2, Addr::unchecked(MEMBER))
+                .unwrap()
+        );
+        assert_eq!(
+            vec![Permission::ModerateContent, Permission::Write],
+            subspaces_querier
+                .effective_permissions(1, 1, Addr::unchecked(MEMBER))
+                .unwrap()
+        );
+        assert_eq!(
+            vec![Permission::Write],
+            subspaces_querier
+                .effective_permissions(1, 2, Addr::unchecked(GUEST))
+                .unwrap()
+        );
+        // The root section has no parent to walk
+        assert_eq!(
+            0,
+            querier.query_count(SubspacesQuery::Section {
+                subspace_id: Uint64::new(1),
+                section_id: 0
+            })
+        );
+    }
+
+    #[test]
+    fn test_effective_permissions_propagates_errors() {
+        let querier = SubspacesQuerier::new(&ChainErrorQuerier("codespace: sdk, code: 38"));
+
+        assert!(querier
+            .effective_permissions(2, 0, Addr::unchecked(GUEST))
+            .unwrap_err()
+            .is_not_found());
+    }
+
+    #[test]
+    fn test_has_permission() {
+        let querier = permissions_querier();
+        let subspaces_querier = SubspacesQuerier::new(&querier);
+        let owner = MockSubspacesQueries::get_mock_subspace().owner;
+        let has_permission = |section_id: u32, user: &Addr, permission: Permission| {
+            subspaces_querier
+                .has_permission(1, section_id, user.clone(), permission)
+                .unwrap()
+        };
+
+        assert!(has_permission(0, &owner, Permission::DeleteSubspace));
+
+        let member = Addr::unchecked(MEMBER);
+        assert!(has_permission(2, &member, Permission::ModerateContent));
+        assert!(has_permission(2, &member, Permission::Write));
+        assert!(!has_permission(0, &member, Permission::ModerateContent));
+        assert!(!has_permission(1, &member, Permission::EditOwnContent));
+
+        let admin = Addr::unchecked(ADMIN);
+        assert!(has_permission(2, &admin, Permission::DeleteSubspace));
+        assert!(!has_permission(0, &admin, Permission::DeleteSubspace));
+    }
+
+    #[test]
+    fn test_has_permission_stops_walking_the_path_once_granted() {
+        let querier = permissions_querier();
+        let subspaces_querier = SubspacesQuerier::new(&querier);
+        let section_queries = |querier: &MockDesmosQuerier| {
+            (0..=2)
+                .map(|section_id| {
+                    querier.query_count(SubspacesQuery::Section {
+                        subspace_id: Uint64::new(1),
+                        section_id,
+                    })
+                })
+                .sum::<usize>()
+        };
+
+        // Granted inside section 2, no parent section is queried
+        assert!(subspaces_querier
+            .has_permission(1, 2, Addr::unchecked(MEMBER), Permission::EditOwnContent)
+            .unwrap());
+        assert_eq!(0, section_queries(&querier));
+        assert_eq!(2, querier.recorded_desmos_queries().len());
+
+        // Granted inside section 1, the root section is not reached
+        querier.clear_recorded_queries();
+        assert!(subspaces_querier
+            .has_permission(1, 2, Addr::unchecked(MEMBER), Permission::ModerateContent)
+            .unwrap());
+        assert_eq!(1, section_queries(&querier));
+        assert_eq!(4, querier.recorded_desmos_queries().len());
+
+        // Not granted, the whole path up to the root section is walked
+        querier.clear_recorded_queries();
+        assert!(!subspaces_querier
+            .has_permission(1, 2, Addr::unchecked(GUEST), Permission::ModerateContent)
+            .unwrap());
+        assert_eq!(2, section_queries(&querier));
+        assert_eq!(6, querier.recorded_desmos_queries().len());
+    }
+
+    #[test]
+    fn test_find_subspace_objects_with_chain_not_found_error() {
+        // Redacted ErrNotFound error returned by the chain when the queried object does not exist
+        let querier = SubspacesQuerier::new(&ChainErrorQuerier("codespace: sdk, code: 38"));
+
+        assert_eq!(None, querier.find_subspace(1).unwrap());
+        assert_eq!(None, querier.find_section(1, 1).unwrap());
+        assert_eq!(None, querier.find_user_group(1, 1).unwrap());
+    }
+
+    #[test]
+    fn test_find_subspace_objects_propagates_redacted_unregistered_errors() {
+        // Redacted error returned by the chain when the module failed with an unregistered error,
+        // that can't be told apart from a missing object
+        let querier = SubspacesQuerier::new(&ChainErrorQuerier("codespace: undefined, code: 1"));
+
+        assert!(matches!(
+            querier.find_subspace(1),
+            Err(DesmosQueryError::InvalidRequest { codespace, code: 1, .. }) if codespace == "undefined"
+        ));
+        assert!(matches!(
+            querier.find_section(1, 1),
+            Err(DesmosQueryError::InvalidRequest { codespace, code: 1, .. }) if codespace == "undefined"
+        ));
+        assert!(matches!(
+            querier.find_user_group(1, 1),
+            Err(DesmosQueryError::InvalidRequest { codespace, code: 1, .. }) if codespace == "undefined"
+        ));
+    }
 }